@@ -0,0 +1,60 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ec_core::{
+    distributions::collection::ConvertToCollectionGenerator,
+    individual::{
+        ec::{EcIndividual, WithScorer},
+        scorer::FnScorer,
+    },
+};
+use rand::{
+    distributions::{Distribution, Standard},
+    thread_rng,
+};
+
+const GENOME_LENGTH: usize = 100;
+const POPULATION_SIZE: usize = 1_000;
+
+type Population = Vec<EcIndividual<Vec<u8>, u64>>;
+
+// A deliberately expensive scorer, standing in for something like running a
+// Push program on many test cases.
+#[expect(
+    clippy::ptr_arg,
+    reason = "The scorer takes the population's genome type as is"
+)]
+fn slow_score(genome: &Vec<u8>) -> u64 {
+    (0..1_000_u64).fold(0, |acc, round| {
+        genome.iter().fold(acc, |acc, &gene| {
+            acc.rotate_left(5) ^ u64::from(gene) ^ round
+        })
+    })
+}
+
+// Compare scoring a new population serially with scoring it in parallel.
+pub fn population_scoring(c: &mut Criterion) {
+    let individual_generator = Standard
+        .into_collection_generator(GENOME_LENGTH)
+        .with_scorer(FnScorer(slow_score));
+
+    let mut group = c.benchmark_group("Score initial population");
+    group.sample_size(10);
+    group.bench_function("serial", |b| {
+        b.iter(|| {
+            let population: Population = individual_generator
+                .to_collection_generator(POPULATION_SIZE)
+                .sample(&mut thread_rng());
+            black_box(population)
+        });
+    });
+    let individual_generator = individual_generator.par_into_collection_generator(POPULATION_SIZE);
+    group.bench_function("parallel", |b| {
+        b.iter(|| {
+            let population: Population = individual_generator.sample(&mut thread_rng());
+            black_box(population)
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, population_scoring);
+criterion_main!(benches);
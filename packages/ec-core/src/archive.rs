@@ -0,0 +1,299 @@
+//! Accumulating noteworthy individuals across generations.
+//!
+//! An [`Archive`] holds copies of individuals that were admitted by some
+//! [`Admission`] criterion, e.g., the best individuals ever seen
+//! ([`BestEver`]) or all the non-dominated individuals ([`NonDominated`]).
+//! Members stay in the archive after they've left the population, so the
+//! archive can be used for things like best-ever tracking or novelty search.
+//!
+//! The archive is updated by passing it the individuals of each new
+//! population, e.g., calling `archive.update(generation.population())` after
+//! each call to `par_next` or `serial_next`. Alternatively, an archive behind
+//! a [`Mutex`] is a [`GenerationObserver`], so it can be passed (by reference)
+//! to [`Generation::with_observer`](crate::generation::Generation::with_observer)
+//! to be updated automatically.
+
+use std::sync::{Mutex, PoisonError};
+
+use itertools::Itertools;
+
+use crate::{
+    individual::Individual, observer::GenerationObserver, population::Population,
+    test_results::TestResults,
+};
+
+/// The criterion for admitting individuals into an [`Archive`].
+pub trait Admission<I> {
+    /// Should `candidate` be added to an archive that currently contains
+    /// `members`?
+    fn admits(&self, candidate: &I, members: &[I]) -> bool;
+
+    /// Should `member` be removed from the archive now that `newcomer` has
+    /// been added? By default members are only removed to keep the archive
+    /// within its maximum size.
+    fn evicts(&self, _newcomer: &I, _member: &I) -> bool {
+        false
+    }
+}
+
+/// Admits every individual that isn't already in the archive. Combined with
+/// [`Archive::with_max_size`] this keeps the best `max_size` individuals ever
+/// seen.
+pub struct BestEver;
+
+impl<I: PartialEq> Admission<I> for BestEver {
+    fn admits(&self, candidate: &I, members: &[I]) -> bool {
+        !members.contains(candidate)
+    }
+}
+
+/// Keeps the individuals whose test results aren't dominated by those of any
+/// other member, i.e., the (approximate) Pareto front across test cases.
+///
+/// One set of test results dominates another if it's at least as good on
+/// every test case, and strictly better on at least one.
+pub struct NonDominated;
+
+impl<I, R> Admission<I> for NonDominated
+where
+    I: Individual<TestResults = TestResults<R>> + PartialEq,
+    R: PartialOrd,
+{
+    fn admits(&self, candidate: &I, members: &[I]) -> bool {
+        members.iter().all(|member| {
+            member != candidate && !dominates(member.test_results(), candidate.test_results())
+        })
+    }
+
+    fn evicts(&self, newcomer: &I, member: &I) -> bool {
+        dominates(newcomer.test_results(), member.test_results())
+    }
+}
+
+fn dominates<R: PartialOrd>(x: &TestResults<R>, y: &TestResults<R>) -> bool {
+    x.results.len() == y.results.len()
+        && x.results.iter().zip(&y.results).all(|(x, y)| x >= y)
+        && x.results.iter().zip(&y.results).any(|(x, y)| x > y)
+}
+
+/// A collection of noteworthy individuals, accumulated across generations.
+pub struct Archive<I, A> {
+    members: Vec<I>,
+    admission: A,
+    max_size: Option<usize>,
+}
+
+impl<I, A> Archive<I, A> {
+    /// Create an empty archive using the given admission criterion. There is
+    /// no limit on the size of the archive by default.
+    #[must_use]
+    pub const fn new(admission: A) -> Self {
+        Self {
+            members: Vec::new(),
+            admission,
+            max_size: None,
+        }
+    }
+
+    /// Limit the archive to at most `max_size` members. When admitting an
+    /// individual takes the archive over this limit, the worst member (which
+    /// might be the newcomer) is removed.
+    #[must_use]
+    pub const fn with_max_size(mut self, max_size: usize) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    #[must_use]
+    pub fn members(&self) -> &[I] {
+        &self.members
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, I> {
+        self.members.iter()
+    }
+
+    /// The best member of the archive, or `None` if the archive is empty.
+    #[must_use]
+    pub fn best(&self) -> Option<&I>
+    where
+        I: Ord,
+    {
+        self.members.iter().max()
+    }
+}
+
+impl<I, A> Archive<I, A>
+where
+    I: Ord + Clone,
+    A: Admission<I>,
+{
+    /// Add (a copy of) `candidate` to the archive if the admission criterion
+    /// admits it, removing any members it evicts.
+    ///
+    /// Returns `true` if `candidate` was added and is still in the archive
+    /// after trimming it to its maximum size.
+    pub fn consider(&mut self, candidate: &I) -> bool {
+        if !self.admission.admits(candidate, &self.members) {
+            return false;
+        }
+        let admission = &self.admission;
+        self.members
+            .retain(|member| !admission.evicts(candidate, member));
+        self.members.push(candidate.clone());
+        if self
+            .max_size
+            .is_some_and(|max_size| self.members.len() > max_size)
+        {
+            if let Some(worst) = self.members.iter().position_min() {
+                self.members.swap_remove(worst);
+                return self.members.contains(candidate);
+            }
+        }
+        true
+    }
+
+    /// Consider each of `individuals` (typically the current population)
+    /// for admission to the archive.
+    pub fn update<'a>(&mut self, individuals: impl IntoIterator<Item = &'a I>)
+    where
+        I: 'a,
+    {
+        for individual in individuals {
+            self.consider(individual);
+        }
+    }
+}
+
+impl<P, I, A> GenerationObserver<P> for Mutex<Archive<I, A>>
+where
+    P: Population<Individual = I> + AsRef<[I]>,
+    I: Ord + Clone,
+    A: Admission<I>,
+{
+    fn on_generation_complete(&self, _: usize, population: &P) {
+        // `update` never leaves the archive in an inconsistent state, so it's
+        // safe to keep using it even if another thread panicked while holding
+        // the lock.
+        self.lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .update(population.as_ref());
+    }
+}
+
+impl<'a, I, A> IntoIterator for &'a Archive<I, A> {
+    type Item = &'a I;
+    type IntoIter = std::slice::Iter<'a, I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.members.iter()
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The archive is never empty when the tests look up its best member"
+)]
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "The test scores are small integers"
+)]
+mod tests {
+    use std::convert::Infallible;
+
+    use rand::RngCore;
+
+    use super::*;
+    use crate::{
+        generation::Generation,
+        individual::ec::EcIndividual,
+        operator::{
+            composable::Composable,
+            selector::{random::Random, Select},
+            Operator,
+        },
+        test_results::Error,
+    };
+
+    // Makes a child that is much worse than its parent, so the best individual
+    // in the initial population is never seen again.
+    struct MakeWorse;
+
+    impl<'a> Operator<&'a i64> for MakeWorse {
+        type Output = i64;
+        type Error = Infallible;
+
+        fn apply(&self, x: &'a i64, _: &mut dyn RngCore) -> Result<Self::Output, Self::Error> {
+            Ok(x - 100)
+        }
+    }
+    impl Composable for MakeWorse {}
+
+    #[test]
+    fn retains_best_after_it_leaves_population() {
+        let child_maker = Select::new(Random).then(MakeWorse);
+        let mut generation = Generation::new(child_maker, (0..10).collect::<Vec<i64>>());
+        let mut archive = Archive::new(BestEver).with_max_size(3);
+        archive.update(generation.population());
+
+        for _ in 0..3 {
+            generation.serial_next().unwrap();
+            archive.update(generation.population());
+        }
+
+        assert!(!generation.population().contains(&9));
+        assert_eq!(archive.best(), Some(&9));
+        assert_eq!(archive.len(), 3);
+        assert!(archive.iter().all(|x| [7, 8, 9].contains(x)));
+    }
+
+    #[test]
+    fn updates_as_an_observer() {
+        let child_maker = Select::new(Random).then(MakeWorse);
+        let archive = Mutex::new(Archive::new(BestEver).with_max_size(1));
+        let mut generation =
+            Generation::new(child_maker, (0..10).collect::<Vec<i64>>()).with_observer(&archive);
+
+        for _ in 0..3 {
+            generation.serial_next().unwrap();
+        }
+
+        // The initial population isn't observed, so the best individual ever
+        // observed is from the first generation of children.
+        let archive = archive.into_inner().unwrap();
+        assert_eq!(archive.len(), 1);
+        assert!(archive
+            .best()
+            .is_some_and(|best| (-100..=-91).contains(best)));
+    }
+
+    fn individual(errors: [i64; 2]) -> EcIndividual<&'static str, TestResults<Error<i64>>> {
+        EcIndividual::new("", errors.into())
+    }
+
+    #[test]
+    fn non_dominated_keeps_pareto_front() {
+        let mut archive = Archive::new(NonDominated);
+        assert!(archive.consider(&individual([5, 5])));
+        assert!(archive.consider(&individual([1, 9])));
+        // Dominated by [5, 5]
+        assert!(!archive.consider(&individual([6, 5])));
+        // Dominates [5, 5]
+        assert!(archive.consider(&individual([4, 4])));
+        // Already in the archive
+        assert!(!archive.consider(&individual([4, 4])));
+
+        assert_eq!(archive.members(), [individual([1, 9]), individual([4, 4])]);
+    }
+}
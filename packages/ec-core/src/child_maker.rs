@@ -1,5 +1,5 @@
 use anyhow::Result;
-use rand::rngs::ThreadRng;
+use rand::RngCore;
 
 use crate::{operator::selector::Selector, population::Population};
 
@@ -24,7 +24,7 @@ where
     /// That can include constructing or scoring the genome.
     fn make_child(
         &self,
-        rng: &mut ThreadRng,
+        rng: &mut dyn RngCore,
         population: &P,
         selector: &S,
     ) -> Result<P::Individual>;
@@ -40,7 +40,7 @@ where
 {
     fn make_child(
         &self,
-        rng: &mut ThreadRng,
+        rng: &mut dyn RngCore,
         population: &P,
         selector: &S,
     ) -> Result<P::Individual> {
@@ -55,7 +55,7 @@ where
 {
     fn make_child(
         &self,
-        rng: &mut ThreadRng,
+        rng: &mut dyn RngCore,
         population: &P,
         selector: &S,
     ) -> Result<P::Individual> {
@@ -70,7 +70,7 @@ where
 {
     fn make_child(
         &self,
-        rng: &mut ThreadRng,
+        rng: &mut dyn RngCore,
         population: &P,
         selector: &S,
     ) -> Result<P::Individual> {
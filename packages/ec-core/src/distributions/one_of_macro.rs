@@ -1,5 +1,7 @@
 /// Create a Distribution of a specified type, calling `.into()` on all
-/// elements. Similar to the `[...]` syntax of rust optionally allowing the
+/// elements.
+///
+/// Similar to the `[...]` syntax of rust optionally allowing the
 /// specification of the target type using `uniform_distribution_of![<T> ...]`.
 /// To sample from the Distribution, the elements are cloned.
 ///
@@ -46,7 +48,6 @@ macro_rules! uniform_distribution_of {
      (<$output_type:ty>  $($items:expr),+ $(,)?) => {
           // FIXME: is unwrap_unchecked a performance gain here?
           {
-               #[allow(clippy::unwrap_used)]
                let val = ::std::result::Result::unwrap(
                     $crate::distributions::conversion
                          ::IntoDistribution::<$output_type>::into_distribution([
@@ -60,7 +61,6 @@ macro_rules! uniform_distribution_of {
      ($($items:expr),+ $(,)?) => {
           // FIXME: is unwrap_unchecked a performance gain here?
           {
-               #[allow(clippy::unwrap_used)]
                let val = ::std::result::Result::unwrap(
                     $crate::distributions::conversion::IntoDistribution::into_distribution([
                          $($items),+
@@ -73,7 +73,10 @@ macro_rules! uniform_distribution_of {
 }
 
 #[cfg(test)]
-#[allow(clippy::unwrap_used)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The `one_of` lists in the tests are never empty"
+)]
 mod test {
     use crate::distributions::conversion::IntoDistribution;
 
@@ -5,7 +5,9 @@ use rand::{distributions::Uniform, prelude::Distribution};
 use crate::distributions::{choices::ChoicesDistribution, wrappers::slice_cloning::EmptySlice};
 
 /// Generate a random element from a collection of options, cloning the chosen
-/// element. The [`OneOfCloning`] struct takes ownership of the collection; the
+/// element.
+///
+/// The [`OneOfCloning`] struct takes ownership of the collection; the
 /// [`SliceCloning`](super::slice_cloning::SliceCloning) struct allows one to
 /// borrow the collection.
 #[derive(Debug, PartialEq, Eq)]
@@ -57,8 +59,14 @@ where
     /// # Errors
     /// - [`EmptySlice`] if an empty collection is passed in, since then no
     ///   element can be selected from there
-    #[allow(clippy::unwrap_used)]
-    #[allow(clippy::missing_panics_doc)]
+    #[expect(
+        clippy::unwrap_used,
+        reason = "`num_choices` is non-zero, so the range is never empty"
+    )]
+    #[expect(
+        clippy::missing_panics_doc,
+        reason = "`num_choices` is non-zero, so the range is never empty"
+    )]
     pub fn new(collection: T) -> Result<Self, EmptySlice> {
         let num_choices = NonZeroUsize::new(collection.borrow().len()).ok_or(EmptySlice)?;
 
@@ -76,10 +84,10 @@ impl<T, U> ChoicesDistribution for OneOfCloning<T, U> {
     }
 }
 
-impl<'a, T, U> Distribution<U> for OneOfCloning<T, U>
+impl<T, U> Distribution<U> for OneOfCloning<T, U>
 where
     T: Borrow<[U]>,
-    U: 'a + Clone,
+    U: Clone,
 {
     fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> U {
         let idx = self.range.sample(rng);
@@ -92,12 +100,15 @@ where
         );
 
         // FIXME: Check the performance of this
-        // // Safety: at construction time, it was ensured that the slice was
-        // // non-empty, and that the `Uniform` range produces values in range
-        // // for the slice
+        // // This is sound because at construction time, it was ensured that
+        // // the slice was non-empty, and that the `Uniform` range produces
+        // // values in range for the slice
         // let val = unsafe { slice.get_unchecked(idx) }
 
-        #[allow(clippy::unwrap_used)]
+        #[expect(
+            clippy::unwrap_used,
+            reason = "`idx` is sampled from `0..num_choices`, so it's always in bounds"
+        )]
         let val = slice.get(idx).unwrap();
 
         val.clone()
@@ -1,34 +1,250 @@
+use std::{cmp::Ordering, sync::OnceLock};
+
 use itertools::Itertools;
+use rand::{rngs::StdRng, RngCore, SeedableRng};
 use rayon::prelude::{FromParallelIterator, IntoParallelIterator, ParallelIterator};
 
-use crate::{operator::Operator, population::Population};
+use crate::{
+    lineage::{self, ChildRecord, Lineage},
+    observer::GenerationObserver,
+    operator::{selector::top_k::TopK, Operator},
+    population::Population,
+};
 
-pub struct Generation<P, C> {
+pub struct Generation<P, C, O = (), E = ()> {
     population: P,
     child_maker: C,
+    // The index of the best individual in `population`, computed the first
+    // time `best()` is called and cleared whenever we make a new population.
+    best_index: OnceLock<Option<usize>>,
+    // The genealogy of the run so far, if lineage recording is enabled.
+    lineage: Option<Lineage>,
+    // Picks the best individuals to carry over unchanged (and unscored)
+    // into each new generation; `()` carries over none.
+    elitism: E,
+    // How many generations have been made since the initial population.
+    number: usize,
+    // Called after each new generation is made; `()` does nothing.
+    observer: O,
 }
 
-impl<P, C> Generation<P, C> {
+impl<P, C, O, E> Generation<P, C, O, E> {
     pub const fn population(&self) -> &P {
         &self.population
     }
+
+    /// The number of the current generation, where the initial population
+    /// is generation 0 (unless this was made with
+    /// [`Generation::from_population`]). Each call to `par_next` or
+    /// `serial_next` increments this.
+    pub const fn generation_number(&self) -> usize {
+        self.number
+    }
+
+    /// The genealogy recorded so far, or `None` if lineage recording
+    /// isn't enabled.
+    pub const fn lineage(&self) -> Option<&Lineage> {
+        self.lineage.as_ref()
+    }
+
+    /// Enable recording of the [`Lineage`] of every individual created by
+    /// later calls to `par_next` or `serial_next`.
+    ///
+    /// Parents and operators are only recorded if the child maker reports
+    /// them, i.e., if it uses
+    /// [`TrackParents`](crate::operator::selector::track_parents::TrackParents)
+    /// and/or [`Labelled`](crate::operator::labelled::Labelled).
+    #[must_use]
+    pub fn with_lineage(mut self) -> Self {
+        self.lineage.get_or_insert_with(Lineage::new);
+        self
+    }
+
+    /// Carry the best `num_elites` individuals in each generation (as picked
+    /// by [`TopK::best`]) over unchanged into the next generation, with the
+    /// rest of the new population made by the child maker as usual.
+    ///
+    /// The elites are cloned along with their existing test results, so
+    /// they aren't re-scored; only genuinely new individuals are scored by
+    /// the child maker. If lineage is being recorded, each elite's record has
+    /// its old index as its only parent and the operator `"elitism"`.
+    #[must_use]
+    pub fn with_elitism(self, num_elites: usize) -> Generation<P, C, O, TopK> {
+        Generation {
+            population: self.population,
+            child_maker: self.child_maker,
+            best_index: self.best_index,
+            lineage: self.lineage,
+            elitism: TopK::best(num_elites),
+            number: self.number,
+            observer: self.observer,
+        }
+    }
+
+    /// Call `observer` (a [`GenerationObserver`]) at the end of every later
+    /// call to `par_next` or `serial_next`, with the new generation number
+    /// and population. This replaces any previous observer.
+    #[must_use]
+    pub fn with_observer<O2>(self, observer: O2) -> Generation<P, C, O2, E> {
+        Generation {
+            population: self.population,
+            child_maker: self.child_maker,
+            best_index: self.best_index,
+            lineage: self.lineage,
+            elitism: self.elitism,
+            number: self.number,
+            observer,
+        }
+    }
+
+    fn set_population(&mut self, population: P) {
+        self.population = population;
+        self.best_index = OnceLock::new();
+        self.number = self.number.saturating_add(1);
+    }
 }
 
 impl<P, C> Generation<P, C> {
     pub const fn new(child_maker: C, population: P) -> Self {
+        Self::from_population(child_maker, population, 0)
+    }
+
+    /// Resume a run from a saved population, e.g., one that was written to
+    /// disk (from [`Generation::population`]) at the end of generation
+    /// `generation_number` of an earlier run.
+    ///
+    /// Making the next generation from here is exactly like making it in the
+    /// original run: the child maker only depends on the current population
+    /// (and the random number generator). Lineage recording and elitism
+    /// aren't saved as part of the population, so they need to be enabled
+    /// again if they're wanted; any recorded lineage starts from the resumed
+    /// generation.
+    pub const fn from_population(child_maker: C, population: P, generation_number: usize) -> Self {
         Self {
             population,
             child_maker,
+            best_index: OnceLock::new(),
+            lineage: None,
+            elitism: (),
+            number: generation_number,
+            observer: (),
+        }
+    }
+}
+
+impl<P, C, O, E> Generation<P, C, O, E>
+where
+    P: Population + AsRef<[P::Individual]>,
+{
+    /// Returns the best individual in the current population, or `None` if
+    /// the population is empty.
+    ///
+    /// This uses the same ordering (and tie-breaking) as the
+    /// [`Best`](crate::operator::selector::best::Best) selector, but doesn't
+    /// need an `rng`. The result is cached, so repeated calls only scan the
+    /// population once per generation.
+    pub fn best(&self) -> Option<&P::Individual>
+    where
+        P::Individual: Ord,
+    {
+        let individuals = self.population.as_ref();
+        let index = *self
+            .best_index
+            .get_or_init(|| individuals.iter().position_max());
+        index.and_then(|index| individuals.get(index))
+    }
+
+    /// Returns the best individual in the current population according to
+    /// the given comparison function, or `None` if the population is empty.
+    ///
+    /// If several individuals are equally good, the last one is returned.
+    /// Unlike [`Generation::best`], this isn't cached since the result
+    /// depends on `compare`.
+    pub fn best_by<F>(&self, mut compare: F) -> Option<&P::Individual>
+    where
+        F: FnMut(&P::Individual, &P::Individual) -> Ordering,
+    {
+        self.population.as_ref().iter().max_by(|x, y| compare(x, y))
+    }
+
+    // Clones of the `n` best individuals, best first, to send to other
+    // islands in an `IslandModel`.
+    pub(crate) fn emigrants(&self, n: usize) -> Vec<P::Individual>
+    where
+        P::Individual: Ord + Clone,
+    {
+        let individuals = self.population.as_ref();
+        TopK::best(n)
+            .indices(individuals)
+            .into_iter()
+            .map(|index| individuals[index].clone())
+            .collect()
+    }
+
+    // Replace the worst individuals in the population with `immigrants` from
+    // other islands in an `IslandModel`, keeping the size of the population
+    // (so any immigrants beyond the population size are dropped). This
+    // doesn't change the generation number.
+    pub(crate) fn receive_immigrants(&mut self, immigrants: Vec<P::Individual>)
+    where
+        P: FromIterator<P::Individual>,
+        P::Individual: Ord + Clone,
+    {
+        if immigrants.is_empty() {
+            return;
+        }
+        let individuals = self.population.as_ref();
+        let mut replacements: Vec<Option<P::Individual>> =
+            individuals.iter().map(|_| None).collect();
+        for (index, immigrant) in TopK::worst(immigrants.len())
+            .indices(individuals)
+            .into_iter()
+            .zip(immigrants)
+        {
+            replacements[index] = Some(immigrant);
         }
+        self.population = individuals
+            .iter()
+            .zip(replacements)
+            .map(|(individual, replacement)| replacement.unwrap_or_else(|| individual.clone()))
+            .collect();
+        self.best_index = OnceLock::new();
     }
 }
 
-impl<P, C> Generation<P, C>
+impl<P, C, O, E> Generation<P, C, O, E>
+where
+    P: Population,
+    E: Elitism<P>,
+{
+    // Clones of the elite individuals, along with their lineage records if
+    // we're recording lineage.
+    fn elites(&self) -> (Vec<P::Individual>, Vec<ChildRecord>) {
+        let (elite_indices, elites): (Vec<_>, Vec<_>) =
+            self.elitism.elites(&self.population).into_iter().unzip();
+        let records = if self.lineage.is_some() {
+            elite_indices
+                .into_iter()
+                .map(|index| ChildRecord {
+                    parents: vec![index],
+                    operators: vec!["elitism"],
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+        (elites, records)
+    }
+}
+
+impl<P, C, O, E> Generation<P, C, O, E>
 where
     P: Population + FromParallelIterator<P::Individual> + Send + Sync,
     P::Individual: Send,
     for<'a> C: Operator<&'a P, Output = P::Individual, Error: Send> + Send + Sync,
     for<'a> anyhow::Error: From<<C as Operator<&'a P>>::Error>,
+    O: GenerationObserver<P>,
+    E: Elitism<P>,
 {
     /// Make the next generation using a Rayon parallel iterator.
     /// # Errors
@@ -36,25 +252,86 @@ where
     /// This can return errors if any aspect of creating the next generation
     /// fail. That can include constructing or scoring the genomes.
     pub fn par_next(&mut self) -> anyhow::Result<()> {
-        let pop_size = self.population.size();
-        let population = (0..pop_size)
-            .into_par_iter()
-            .map_init(rand::thread_rng, |rng, _| {
-                self.child_maker.apply(&self.population, rng)
-            })
-            .collect::<Result<_, _>>()?;
+        self.par_next_with_rngs(|_| rand::thread_rng())
+    }
+
+    /// Make the next generation using a Rayon parallel iterator, in a way
+    /// that can be reproduced exactly.
+    ///
+    /// Each child is made with its own random number generator, seeded from
+    /// `base_seed`, the current generation number, and the child's index in
+    /// the new population. So the results don't depend on how the work is
+    /// split between threads, and a run that calls this with the same
+    /// `base_seed` every generation (and the same initial population) is
+    /// reproducible. The results won't be the same as those from
+    /// [`Generation::serial_next_with_rng`], though.
+    ///
+    /// # Errors
+    ///
+    /// This can return errors if any aspect of creating the next generation
+    /// fail. That can include constructing or scoring the genomes.
+    pub fn par_next_seeded(&mut self, base_seed: u64) -> anyhow::Result<()> {
+        let generation_number = self.number;
+        self.par_next_with_rngs(|child_index| {
+            child_rng(
+                SeedDomain::Generation,
+                base_seed,
+                generation_number,
+                child_index,
+            )
+        })
+    }
+
+    fn par_next_with_rngs<R>(
+        &mut self,
+        rng_for_child: impl Fn(usize) -> R + Sync,
+    ) -> anyhow::Result<()>
+    where
+        R: RngCore,
+    {
+        let (elites, mut records) = self.elites();
+        let num_elites = elites.len();
+        let num_children = self.population.size().saturating_sub(num_elites);
+        let make_child = |index: usize| {
+            let mut rng = rng_for_child(num_elites.saturating_add(index));
+            self.child_maker.apply(&self.population, &mut rng)
+        };
+        let population = if let Some(lineage) = &mut self.lineage {
+            let (children, child_records): (Vec<_>, Vec<_>) = (0..num_children)
+                .into_par_iter()
+                .map(|index| {
+                    let (child, record) = lineage::record_child(|| make_child(index));
+                    child.map(|child| (child, record))
+                })
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter()
+                .unzip();
+            records.extend(child_records);
+            lineage.push_generation(records);
+            elites.into_par_iter().chain(children).collect()
+        } else {
+            elites
+                .into_par_iter()
+                .map(Ok)
+                .chain((0..num_children).into_par_iter().map(make_child))
+                .collect::<Result<_, _>>()?
+        };
         // TODO: We can reduce allocations by pre-allocating the memory for "old" and
         // "new"   population in `::new()` and then re-using those vectors here.
-        self.population = population;
+        self.set_population(population);
+        self.observer
+            .on_generation_complete(self.number, &self.population);
         Ok(())
     }
 }
 
-impl<P, C> Generation<P, C>
+impl<P, C, O, E> Generation<P, C, O, E>
 where
     P: Population + FromIterator<P::Individual>,
     C: for<'a> Operator<&'a P, Output = P::Individual>,
     for<'a> anyhow::Error: From<<C as Operator<&'a P>>::Error>,
+    O: GenerationObserver<P>,
+    E: Elitism<P>,
 {
     /// Make the next generation serially.
     /// # Errors
@@ -62,15 +339,474 @@ where
     /// This can return errors if any aspect of creating the next generation
     /// fail. That can include constructing or scoring the genomes.
     pub fn serial_next(&mut self) -> anyhow::Result<()> {
-        let pop_size = self.population.size();
-        let mut rng = rand::thread_rng();
+        self.serial_next_with_rng(&mut rand::thread_rng())
+    }
+
+    /// Make the next generation serially, using `rng` for all the random
+    /// choices. A run that uses the same seeded `rng` (e.g., a
+    /// [`StdRng`](rand::rngs::StdRng) from `seed_from_u64`) for every
+    /// generation, starting from the same initial population, is
+    /// reproducible.
+    ///
+    /// # Errors
+    ///
+    /// This can return errors if any aspect of creating the next generation
+    /// fail. That can include constructing or scoring the genomes.
+    pub fn serial_next_with_rng<R>(&mut self, rng: &mut R) -> anyhow::Result<()>
+    where
+        R: RngCore,
+    {
+        let (elites, mut records) = self.elites();
+        let num_children = self.population.size().saturating_sub(elites.len());
         // Switch to `repeat_with` and `take`
-        let new_population = (0..pop_size)
-            .map(|_| self.child_maker.apply(&self.population, &mut rng))
-            .try_collect()?;
+        let children = (0..num_children).map(|_| {
+            if self.lineage.is_none() {
+                return self.child_maker.apply(&self.population, rng);
+            }
+            let (child, record) =
+                lineage::record_child(|| self.child_maker.apply(&self.population, rng));
+            records.push(record);
+            child
+        });
+        let new_population = elites.into_iter().map(Ok).chain(children).try_collect()?;
+        if let Some(lineage) = &mut self.lineage {
+            lineage.push_generation(records);
+        }
         // TODO: We can reduce allocations by pre-allocating the memory for "old" and
         // "new"   population in `::new()` and then re-using those vectors here.
-        self.population = new_population;
+        self.set_population(new_population);
+        self.observer
+            .on_generation_complete(self.number, &self.population);
         Ok(())
     }
 }
+
+/// Picks the individuals a [`Generation`] carries over unchanged into the
+/// next generation.
+///
+/// [`Generation::with_elitism`] uses [`TopK`] to carry over the best
+/// individuals. The default, `()`, carries over none, so it doesn't need to
+/// compare or clone individuals.
+pub trait Elitism<P: Population> {
+    /// The indices in `population` of the individuals to carry over, along
+    /// with clones of those individuals.
+    fn elites(&self, population: &P) -> Vec<(usize, P::Individual)>;
+}
+
+impl<P: Population> Elitism<P> for () {
+    #[inline]
+    fn elites(&self, _: &P) -> Vec<(usize, P::Individual)> {
+        Vec::new()
+    }
+}
+
+impl<P> Elitism<P> for TopK
+where
+    P: Population + AsRef<[P::Individual]>,
+    P::Individual: Ord + Clone,
+{
+    fn elites(&self, population: &P) -> Vec<(usize, P::Individual)> {
+        let individuals = population.as_ref();
+        self.indices(individuals)
+            .into_iter()
+            .map(|index| (index, individuals[index].clone()))
+            .collect()
+    }
+}
+
+/// Apply `operator` to `input` `count` times using a Rayon parallel
+/// iterator, in a way that can be reproduced exactly.
+///
+/// This is the pattern [`Generation::par_next_seeded`] uses, for parallel
+/// pipelines that don't go through a [`Generation`] (e.g., making a batch of
+/// children to inspect or score outside a run). Each application gets its own
+/// random number generator, seeded from `base_seed` and its index in the
+/// output, so the results don't depend on how the work is split between
+/// threads. These seeds are derived separately from those used by
+/// [`Generation::par_next_seeded`], so using the same `base_seed` for both
+/// doesn't give any of their children the same random number generator.
+///
+/// # Errors
+///
+/// This returns the first error (in no particular order) that any
+/// application of `operator` returns.
+pub fn par_apply_seeded<I, Op>(
+    operator: &Op,
+    input: I,
+    count: usize,
+    base_seed: u64,
+) -> Result<Vec<Op::Output>, Op::Error>
+where
+    I: Clone + Sync,
+    Op: Operator<I> + Sync,
+    Op::Output: Send,
+    Op::Error: Send,
+{
+    (0..count)
+        .into_par_iter()
+        .map(|index| {
+            let mut rng = child_rng(SeedDomain::Apply, base_seed, 0, index);
+            operator.apply(input.clone(), &mut rng)
+        })
+        .collect()
+}
+
+// Which function a seed from `child_rng` is for, which is mixed into the seed
+// so that `par_next_seeded` and `par_apply_seeded` never hand out the same
+// random number generator for the same `base_seed`.
+#[derive(Debug, Clone, Copy)]
+enum SeedDomain {
+    Generation,
+    Apply,
+}
+
+impl SeedDomain {
+    const fn tag(self) -> u64 {
+        match self {
+            Self::Generation => 0,
+            Self::Apply => 1,
+        }
+    }
+}
+
+// The random number generator for the child at `child_index` in the new
+// population made from generation `generation_number` by `par_next_seeded`
+// (or for the `child_index`th application in `par_apply_seeded`).
+fn child_rng(
+    domain: SeedDomain,
+    base_seed: u64,
+    generation_number: usize,
+    child_index: usize,
+) -> StdRng {
+    let mut seed = <StdRng as SeedableRng>::Seed::default();
+    let words = [
+        base_seed,
+        u64::try_from(generation_number).unwrap_or(u64::MAX),
+        u64::try_from(child_index).unwrap_or(u64::MAX),
+        domain.tag(),
+    ];
+    for (bytes, word) in seed.chunks_exact_mut(8).zip(words) {
+        bytes.copy_from_slice(&word.to_le_bytes());
+    }
+    StdRng::from_seed(seed)
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The test operators are infallible, so every generation step succeeds"
+)]
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "The test scorers only sum small integers"
+)]
+mod tests {
+    use std::{
+        convert::Infallible,
+        sync::atomic::{AtomicUsize, Ordering::Relaxed},
+    };
+
+    use super::*;
+    use crate::{
+        individual::{ec::EcIndividual, scorer::FnScorer},
+        observer::BestTotalResult,
+        operator::{
+            composable::Composable,
+            genome_extractor::GenomeExtractor,
+            genome_scorer::GenomeScorer,
+            labelled::Labelled,
+            selector::{best::Best, random::Random, track_parents::TrackParents, Select, Selector},
+        },
+        test_results::TestResults,
+    };
+
+    #[test]
+    fn best_matches_best_selector() {
+        let generation = Generation::new((), vec![5, 8, 9, 6, 3, 9, 2, 0]);
+        let mut rng = rand::thread_rng();
+        let selected = Best.select(generation.population(), &mut rng).unwrap();
+        assert_eq!(Some(selected), generation.best());
+        // The second call uses the cached index.
+        assert_eq!(Some(selected), generation.best());
+    }
+
+    #[test]
+    fn best_by_uses_comparator() {
+        let generation = Generation::new((), vec![5, 8, 9, 6, 3, 2, 0]);
+        assert_eq!(Some(&0), generation.best_by(|x, y| x.cmp(y).reverse()));
+    }
+
+    #[test]
+    fn best_of_empty_population() {
+        let generation = Generation::new((), Vec::<i32>::new());
+        assert_eq!(None, generation.best());
+    }
+
+    // Individuals that can't be compared or cloned, which is fine as long
+    // as there's no elitism.
+    #[derive(Debug, PartialEq, Eq)]
+    struct Opaque(usize);
+
+    struct CountSize;
+
+    impl Operator<&Vec<Opaque>> for CountSize {
+        type Output = Opaque;
+        type Error = Infallible;
+
+        fn apply(
+            &self,
+            population: &Vec<Opaque>,
+            _: &mut dyn RngCore,
+        ) -> Result<Opaque, Infallible> {
+            Ok(Opaque(population.len()))
+        }
+    }
+    impl Composable for CountSize {}
+
+    #[test]
+    fn no_elitism_needs_no_ord_or_clone() {
+        let mut generation = Generation::new(CountSize, vec![Opaque(0), Opaque(1)]);
+        generation.par_next().unwrap();
+        generation.serial_next().unwrap();
+        assert_eq!(generation.population(), &vec![Opaque(2), Opaque(2)]);
+    }
+
+    struct Sum;
+
+    impl<'a> Operator<[&'a i64; 2]> for Sum {
+        type Output = i64;
+        type Error = Infallible;
+
+        fn apply(
+            &self,
+            [x, y]: [&'a i64; 2],
+            _: &mut dyn RngCore,
+        ) -> Result<Self::Output, Self::Error> {
+            Ok(x + y)
+        }
+    }
+    impl Composable for Sum {}
+
+    #[test]
+    fn lineage_references_valid_parents() {
+        let child_maker = Select::new(TrackParents::new(Random))
+            .apply_twice()
+            .then(Sum.wrap::<Labelled<_>>("sum"));
+        let mut generation =
+            Generation::new(child_maker, (0..10).collect::<Vec<i64>>()).with_lineage();
+
+        let num_generations = 4;
+        for n in 1..=num_generations {
+            let previous = generation.population().clone();
+            if n % 2 == 0 {
+                generation.par_next().unwrap();
+            } else {
+                generation.serial_next().unwrap();
+            }
+            let records = generation.lineage().unwrap().records(n).unwrap();
+            assert_eq!(records.len(), previous.len());
+            for (child, record) in generation.population().iter().zip(records) {
+                assert_eq!(record.parents.len(), 2);
+                let (x, y) = (record.parents[0], record.parents[1]);
+                assert!(x < previous.len() && y < previous.len());
+                assert_eq!(*child, previous[x] + previous[y]);
+                assert_eq!(record.operators, ["sum"]);
+            }
+        }
+        assert_eq!(
+            generation.lineage().unwrap().num_generations(),
+            num_generations
+        );
+    }
+
+    #[test]
+    fn no_lineage_by_default() {
+        let generation = Generation::new((), vec![1, 2, 3]);
+        assert!(generation.lineage().is_none());
+    }
+
+    #[test]
+    fn elites_are_not_rescored() {
+        let num_scored = AtomicUsize::new(0);
+        let scorer = FnScorer(|genome: &i64| {
+            num_scored.fetch_add(1, Relaxed);
+            *genome
+        });
+        let child_maker = Select::new(Random)
+            .then(GenomeExtractor)
+            .wrap::<GenomeScorer<_, _>>(&scorer);
+        // The test results are deliberately different from what the scorer
+        // would return, so we can tell if an elite was rescored.
+        let population: Vec<_> = (0..10_i64).map(|i| EcIndividual::new(i, 100 * i)).collect();
+        let mut generation = Generation::new(child_maker, population).with_elitism(3);
+
+        generation.serial_next().unwrap();
+        assert_eq!(num_scored.load(Relaxed), 7);
+        generation.par_next().unwrap();
+        assert_eq!(num_scored.load(Relaxed), 14);
+
+        let population = generation.population();
+        assert_eq!(population.len(), 10);
+        for (elite, i) in population.iter().zip([9, 8, 7]) {
+            assert_eq!(elite, &EcIndividual::new(i, 100 * i));
+        }
+    }
+
+    #[test]
+    fn resumed_run_matches_continuous_run() {
+        // A deterministic child maker, so the next generation only depends on
+        // the current population.
+        let child_maker = || {
+            Select::new(Best)
+                .then(GenomeExtractor)
+                .wrap::<GenomeScorer<_, _>>(FnScorer(|genome: &i64| *genome))
+        };
+        let population: Vec<_> = (0..10_i64).map(|i| EcIndividual::new(i, i)).collect();
+
+        let mut continuous = Generation::new(child_maker(), population).with_elitism(2);
+        continuous.serial_next().unwrap();
+        let saved = continuous.population().clone();
+        continuous.par_next().unwrap();
+        continuous.serial_next().unwrap();
+        assert_eq!(continuous.generation_number(), 3);
+
+        let mut resumed = Generation::from_population(child_maker(), saved, 1).with_elitism(2);
+        resumed.par_next().unwrap();
+        resumed.serial_next().unwrap();
+        assert_eq!(resumed.generation_number(), 3);
+        assert_eq!(resumed.population(), continuous.population());
+    }
+
+    #[test]
+    fn observer_records_best_total_result() {
+        let child_maker = Select::new(Random)
+            .then(GenomeExtractor)
+            .wrap::<GenomeScorer<_, _>>(FnScorer(|genome: &i64| TestResults::from([*genome])));
+        let population: Vec<_> = (0..10_i64)
+            .map(|i| EcIndividual::new(i, TestResults::from([i])))
+            .collect();
+        let recorder = BestTotalResult::<i64>::new();
+        let mut generation = Generation::new(child_maker, population)
+            .with_elitism(1)
+            .with_observer(&recorder);
+
+        for _ in 0..3 {
+            generation.serial_next().unwrap();
+            generation.par_next().unwrap();
+        }
+        // With an elite, the best individual (9) is never lost.
+        assert_eq!(recorder.take_history(), [9; 6]);
+        assert!(recorder.history().is_empty());
+    }
+
+    #[test]
+    fn observer_sees_generation_numbers() {
+        struct Numbers(std::sync::Mutex<Vec<usize>>);
+        impl<P> GenerationObserver<P> for Numbers {
+            fn on_generation_complete(&self, generation_number: usize, _: &P) {
+                self.0.lock().unwrap().push(generation_number);
+            }
+        }
+
+        let child_maker = Select::new(Random)
+            .then(GenomeExtractor)
+            .wrap::<GenomeScorer<_, _>>(FnScorer(|genome: &i64| *genome));
+        let population: Vec<_> = (0..4_i64).map(|i| EcIndividual::new(i, i)).collect();
+        let numbers = Numbers(std::sync::Mutex::new(Vec::new()));
+        let mut generation =
+            Generation::from_population(child_maker, population, 5).with_observer(&numbers);
+        generation.par_next().unwrap();
+        generation.serial_next().unwrap();
+        assert_eq!(*numbers.0.lock().unwrap(), [6, 7]);
+    }
+
+    // A child maker whose results depend on the random number generator in
+    // both selection and "mutation".
+    struct AddNoise;
+
+    impl Operator<i64> for AddNoise {
+        type Output = i64;
+        type Error = Infallible;
+
+        fn apply(&self, genome: i64, rng: &mut dyn RngCore) -> Result<Self::Output, Self::Error> {
+            Ok(genome + i64::from(rng.next_u32() % 7) - 3)
+        }
+    }
+    impl Composable for AddNoise {}
+
+    type NoisyIndividual = EcIndividual<i64, i64>;
+
+    fn noisy_generation() -> Generation<
+        Vec<NoisyIndividual>,
+        impl for<'a> Operator<
+                &'a Vec<NoisyIndividual>,
+                Output = NoisyIndividual,
+                Error = anyhow::Error,
+            > + Send
+            + Sync,
+        (),
+        TopK,
+    > {
+        let child_maker = Select::new(Random)
+            .then(GenomeExtractor)
+            .then(AddNoise)
+            .wrap::<GenomeScorer<_, _>>(FnScorer(|genome: &i64| *genome));
+        let population: Vec<_> = (0..20_i64).map(|i| EcIndividual::new(i, i)).collect();
+        Generation::new(child_maker, population).with_elitism(1)
+    }
+
+    #[test]
+    fn serial_runs_with_same_seed_are_identical() {
+        let run = |seed| {
+            let mut generation = noisy_generation();
+            let mut rng = StdRng::seed_from_u64(seed);
+            (0..5)
+                .map(|_| {
+                    generation.serial_next_with_rng(&mut rng).unwrap();
+                    generation.best().unwrap().clone()
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(run(42), run(42));
+        assert_ne!(run(42), run(43));
+    }
+
+    #[test]
+    fn seeded_parallel_runs_are_identical() {
+        let run = |seed| {
+            let mut generation = noisy_generation();
+            for _ in 0..5 {
+                generation.par_next_seeded(seed).unwrap();
+            }
+            generation.population().clone()
+        };
+        assert_eq!(run(42), run(42));
+        assert_ne!(run(42), run(43));
+    }
+    #[test]
+    fn seeded_parallel_application_is_deterministic() {
+        let population: Vec<_> = (0..20_i64).map(|i| EcIndividual::new(i, i)).collect();
+        let child_maker = Select::new(Random)
+            .then(GenomeExtractor)
+            .then(AddNoise)
+            .wrap::<GenomeScorer<_, _>>(FnScorer(|genome: &i64| *genome));
+        let run = |seed| par_apply_seeded(&child_maker, &population, 50, seed).unwrap();
+        let children = run(42);
+        assert_eq!(children.len(), 50);
+        // Every child is a (scored) mutant of some individual in the population.
+        assert!(children
+            .iter()
+            .all(|child| (-3..23).contains(&child.genome) && child.test_results == child.genome));
+        assert_eq!(children, run(42));
+        assert_ne!(children, run(43));
+    }
+
+    #[test]
+    fn seed_domains_give_different_rngs() {
+        for index in 0..10 {
+            let mut generation_rng = child_rng(SeedDomain::Generation, 42, 0, index);
+            let mut apply_rng = child_rng(SeedDomain::Apply, 42, 0, index);
+            assert_ne!(generation_rng.next_u64(), apply_rng.next_u64());
+        }
+    }
+}
@@ -1,3 +1,7 @@
 pub trait Genome {
     type Gene;
 }
+
+impl<T> Genome for Vec<T> {
+    type Gene = T;
+}
@@ -8,17 +8,22 @@ use std::{
 };
 
 use rand::prelude::Distribution;
+use rayon::prelude::{IntoParallelIterator, ParallelIterator};
 
 use super::{
     scorer::{FnScorer, Scorer},
     Individual,
 };
+use crate::distributions::collection::CollectionGenerator;
 
 /// `EcIndividual` is a struct that represents an individual in an evolutionary
 /// computation system. It contains a genome and the results of scoring the
 /// genome.
 #[derive(Debug, Eq, PartialEq, Clone)]
-#[allow(clippy::module_name_repetitions)]
+#[expect(
+    clippy::module_name_repetitions,
+    reason = "`EcIndividual` reads better than `Individual` where it's used"
+)]
 pub struct EcIndividual<G, R> {
     pub genome: G,
     pub test_results: R,
@@ -47,6 +52,28 @@ impl<G, R> EcIndividual<G, R> {
             test_results,
         }
     }
+
+    /// Transform the genome with `f`, keeping the same test results, e.g., to
+    /// simplify the genome of the best individual after a run.
+    ///
+    /// The test results aren't recomputed, so they'll be out of date if `f`
+    /// changes what the genome does; use a
+    /// [`GenomeScorer`](crate::operator::genome_scorer::GenomeScorer) or
+    /// [`Scorer`] to score the new genome if that matters.
+    #[must_use]
+    pub fn map_genome<NG>(self, f: impl FnOnce(G) -> NG) -> EcIndividual<NG, R> {
+        EcIndividual {
+            genome: f(self.genome),
+            test_results: self.test_results,
+        }
+    }
+
+    /// Replace the genome with `genome`, keeping the same test results (which,
+    /// as with [`EcIndividual::map_genome`], aren't recomputed).
+    #[must_use]
+    pub fn with_genome<NG>(self, genome: NG) -> EcIndividual<NG, R> {
+        self.map_genome(|_| genome)
+    }
 }
 
 impl<G: Eq, R: Ord> Ord for EcIndividual<G, R> {
@@ -89,6 +116,18 @@ impl<GG, S> IndividualGenerator<GG, S> {
             scorer,
         }
     }
+
+    /// Convert this into a generator of populations (`Vec`s) of `size`
+    /// individuals whose genomes are scored in parallel.
+    ///
+    /// This is the parallel counterpart of
+    /// [`into_collection_generator`](crate::distributions::collection::ConvertToCollectionGenerator::into_collection_generator).
+    pub const fn par_into_collection_generator(self, size: usize) -> ParCollectionGenerator<GG, S> {
+        ParCollectionGenerator {
+            individual_generator: self,
+            size,
+        }
+    }
 }
 
 /// A trait for adding a scorer to a genome generator, creating
@@ -139,3 +178,154 @@ where
         EcIndividual::new(genome, test_results)
     }
 }
+
+/// A generator of populations (`Vec`s) of individuals that scores the
+/// individuals in parallel.
+///
+/// This is typically much faster than
+/// [`CollectionGenerator`](crate::distributions::collection::CollectionGenerator)
+/// when scoring is expensive.
+///
+/// All the genomes are generated (serially) using the random number generator
+/// before any of them are scored, and scorers don't use the random number
+/// generator, so this generates exactly the same population as the serial
+/// version would with the same random number generator.
+pub struct ParCollectionGenerator<GG, S> {
+    pub individual_generator: IndividualGenerator<GG, S>,
+    pub size: usize,
+}
+
+impl<G, D, S> Distribution<Vec<EcIndividual<G, S::Score>>> for ParCollectionGenerator<D, S>
+where
+    D: Distribution<G>,
+    S: Scorer<G> + Sync,
+    S::Score: Send,
+    G: Send,
+{
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Vec<EcIndividual<G, S::Score>> {
+        let IndividualGenerator {
+            genome_generator,
+            scorer,
+        } = &self.individual_generator;
+        let genomes: Vec<G> = genome_generator.sample_iter(rng).take(self.size).collect();
+        genomes
+            .into_par_iter()
+            .map(|genome| {
+                let test_results = scorer.score(&genome);
+                EcIndividual::new(genome, test_results)
+            })
+            .collect()
+    }
+}
+
+impl<GG, S> CollectionGenerator<IndividualGenerator<GG, S>> {
+    /// Start every generated population with individuals made from `seeds`
+    /// (e.g., known-good genomes or genomes loaded from a checkpoint), scored
+    /// with the same scorer as the random individuals.
+    #[must_use]
+    pub const fn with_seeds<G>(self, seeds: Vec<G>) -> SeededCollectionGenerator<GG, S, G> {
+        SeededCollectionGenerator {
+            collection_generator: self,
+            seeds,
+        }
+    }
+}
+
+/// A generator of populations (`Vec`s) that start with individuals made from
+/// fixed "seed" genomes.
+///
+/// The seeds are followed by random individuals to make up the population
+/// size. The seeds are cloned (and scored) every time a population is
+/// generated.
+///
+/// If there are more seeds than the population size, the population is just
+/// the seeded individuals.
+pub struct SeededCollectionGenerator<GG, S, G> {
+    pub collection_generator: CollectionGenerator<IndividualGenerator<GG, S>>,
+    pub seeds: Vec<G>,
+}
+
+impl<G, D, S> Distribution<Vec<EcIndividual<G, S::Score>>> for SeededCollectionGenerator<D, S, G>
+where
+    D: Distribution<G>,
+    S: Scorer<G>,
+    G: Clone,
+{
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Vec<EcIndividual<G, S::Score>> {
+        let CollectionGenerator {
+            element_generator: individual_generator,
+            size,
+        } = &self.collection_generator;
+        let seeded = self.seeds.iter().cloned().map(|genome| {
+            let test_results = individual_generator.scorer.score(&genome);
+            EcIndividual::new(genome, test_results)
+        });
+        let random = individual_generator
+            .sample_iter(rng)
+            .take(size.saturating_sub(self.seeds.len()));
+        seeded.chain(random).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{distributions::Standard, rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::distributions::collection::ConvertToCollectionGenerator;
+
+    #[test]
+    fn parallel_and_serial_populations_match() {
+        let individual_generator =
+            Standard
+                .into_collection_generator(20)
+                .with_scorer(FnScorer(|genome: &Vec<u8>| {
+                    genome.iter().map(|&gene| u32::from(gene)).sum::<u32>()
+                }));
+
+        let serial: Vec<EcIndividual<Vec<u8>, u32>> = individual_generator
+            .to_collection_generator(500)
+            .sample(&mut StdRng::seed_from_u64(7));
+        let parallel: Vec<EcIndividual<Vec<u8>, u32>> = individual_generator
+            .par_into_collection_generator(500)
+            .sample(&mut StdRng::seed_from_u64(7));
+
+        assert_eq!(parallel.len(), 500);
+        assert_eq!(parallel, serial);
+    }
+
+    #[test]
+    fn seeds_are_at_the_front_of_the_population() {
+        let seeds = vec![vec![0_u8; 20], vec![1_u8; 20]];
+        let population: Vec<EcIndividual<Vec<u8>, u32>> = Standard
+            .into_collection_generator(20)
+            .with_scorer(FnScorer(|genome: &Vec<u8>| {
+                genome.iter().map(|&gene| u32::from(gene)).sum::<u32>()
+            }))
+            .into_collection_generator(10)
+            .with_seeds(seeds.clone())
+            .sample(&mut StdRng::seed_from_u64(0));
+
+        assert_eq!(population.len(), 10);
+        assert_eq!(
+            population[..2],
+            [
+                EcIndividual::new(seeds[0].clone(), 0),
+                EcIndividual::new(seeds[1].clone(), 20)
+            ]
+        );
+    }
+
+    #[test]
+    fn map_genome_keeps_test_results() {
+        let individual = EcIndividual::new(vec![3, -1, 0, 4], 7_u32);
+        let mapped = individual
+            .clone()
+            .map_genome(|genome: Vec<i32>| genome.into_iter().filter(|&gene| gene != 0).count());
+        assert_eq!(mapped, EcIndividual::new(3, 7));
+
+        let replaced = individual.with_genome(vec![1, 2]);
+        assert_eq!(replaced.genome, vec![1, 2]);
+        assert_eq!(replaced.test_results, 7);
+    }
+}
@@ -1,5 +1,7 @@
 pub mod ec;
+pub mod parsimony;
 pub mod scorer;
+pub mod time_limited;
 
 pub trait Individual {
     type Genome;
@@ -0,0 +1,260 @@
+use std::{cmp::Ordering, fmt::Display};
+
+use num_traits::{Bounded, NumCast, ToPrimitive};
+
+use super::scorer::Scorer;
+use crate::test_results::{Error, Score, TestResults};
+
+/// A scorer wrapper that adds pressure towards smaller genomes (parsimony
+/// pressure), to counter bloat.
+///
+/// `size` measures the size of a genome, e.g., `Linear::size` for linear
+/// genomes, or `Vec::len`. There are two modes:
+///
+/// - [`Parsimony::tie_breaking`] pairs every result (including the total)
+///   with the genome's size, so size only matters between otherwise equal
+///   results (see [`SizeTieBreak`]).
+/// - [`Parsimony::weighted`] adds `weight` times the genome's size as a
+///   penalty to the total result, leaving the individual results unchanged.
+///   The penalty is computed as an `f64`, and for integer results it's
+///   truncated (towards zero) when converted back, so fractional penalties
+///   are partly lost; see [`Penalize`].
+///
+/// Since this is a [`Scorer`], it can be used anywhere a scorer can, e.g.,
+/// `.wrap::<GenomeScorer<_, _>>(Parsimony::tie_breaking(scorer, Vec::len))`
+/// in a child-making pipeline, or with `with_scorer` when building the
+/// initial population.
+pub struct Parsimony<S, F, M> {
+    scorer: S,
+    size: F,
+    mode: M,
+}
+
+/// The tie-breaking [`Parsimony`] mode.
+pub struct TieBreak;
+
+/// The weighted [`Parsimony`] mode.
+pub struct Weighted {
+    weight: f64,
+}
+
+impl<S, F> Parsimony<S, F, TieBreak> {
+    pub const fn tie_breaking(scorer: S, size: F) -> Self {
+        Self {
+            scorer,
+            size,
+            mode: TieBreak,
+        }
+    }
+}
+
+impl<S, F> Parsimony<S, F, Weighted> {
+    pub const fn weighted(scorer: S, size: F, weight: f64) -> Self {
+        Self {
+            scorer,
+            size,
+            mode: Weighted { weight },
+        }
+    }
+}
+
+impl<G, S, F, R> Scorer<G> for Parsimony<S, F, TieBreak>
+where
+    S: Scorer<G, Score = TestResults<R>>,
+    F: Fn(&G) -> usize,
+{
+    type Score = TestResults<SizeTieBreak<R>>;
+
+    fn score(&self, genome: &G) -> Self::Score {
+        let size = (self.size)(genome);
+        let TestResults {
+            results,
+            total_result,
+        } = self.scorer.score(genome);
+        TestResults {
+            results: results
+                .into_iter()
+                .map(|result| SizeTieBreak { result, size })
+                .collect(),
+            total_result: SizeTieBreak {
+                result: total_result,
+                size,
+            },
+        }
+    }
+}
+
+impl<G, S, F, R> Scorer<G> for Parsimony<S, F, Weighted>
+where
+    S: Scorer<G, Score = TestResults<R>>,
+    F: Fn(&G) -> usize,
+    R: Penalize,
+{
+    type Score = TestResults<R>;
+
+    fn score(&self, genome: &G) -> Self::Score {
+        let penalty = self.mode.weight * (self.size)(genome).to_f64().unwrap_or(f64::INFINITY);
+        let mut test_results = self.scorer.score(genome);
+        test_results.total_result = test_results.total_result.penalize(penalty);
+        test_results
+    }
+}
+
+/// A result paired with the size of the genome it came from.
+///
+/// These are ordered by their results, with the size only used to break ties:
+/// among equal results, the one with the smaller size is better.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SizeTieBreak<R> {
+    pub result: R,
+    pub size: usize,
+}
+
+impl<R: Ord> Ord for SizeTieBreak<R> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.result
+            .cmp(&other.result)
+            .then_with(|| self.size.cmp(&other.size).reverse())
+    }
+}
+
+impl<R: PartialOrd> PartialOrd for SizeTieBreak<R> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.result
+            .partial_cmp(&other.result)
+            .map(|ordering| ordering.then_with(|| self.size.cmp(&other.size).reverse()))
+    }
+}
+
+impl<R: Display> Display for SizeTieBreak<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (size {})", self.result, self.size)
+    }
+}
+
+/// A result that can be made worse by a numeric penalty, for weighted
+/// [`Parsimony`].
+///
+/// The implementations for [`Error`] and [`Score`] do the arithmetic in
+/// `f64` and convert the result back with [`NumCast`], which truncates
+/// towards zero for integer types. So with integer results an error of `5`
+/// penalized by `0.5` is still `5`; use a float result type (e.g.,
+/// `OrderedFloat<f64>`), or weights and sizes whose products are whole
+/// numbers, if every bit of the penalty matters.
+pub trait Penalize {
+    /// This result made worse by `penalty`, saturating at the worst value
+    /// the result can represent.
+    #[must_use]
+    fn penalize(&self, penalty: f64) -> Self;
+}
+
+impl<T> Penalize for Error<T>
+where
+    T: ToPrimitive + NumCast + Bounded,
+{
+    fn penalize(&self, penalty: f64) -> Self {
+        self.error
+            .to_f64()
+            .and_then(|error| T::from(error + penalty))
+            .unwrap_or_else(T::max_value)
+            .into()
+    }
+}
+
+impl<T> Penalize for Score<T>
+where
+    T: ToPrimitive + NumCast + Bounded,
+{
+    fn penalize(&self, penalty: f64) -> Self {
+        self.score
+            .to_f64()
+            .and_then(|score| T::from(score - penalty))
+            .unwrap_or_else(T::min_value)
+            .into()
+    }
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "Scoring the test genomes can't fail")]
+mod tests {
+    use super::*;
+    use crate::{
+        individual::{ec::EcIndividual, scorer::FnScorer},
+        operator::{
+            composable::Composable,
+            genome_extractor::GenomeExtractor,
+            genome_scorer::GenomeScorer,
+            selector::{best::Best, Select},
+            Operator,
+        },
+    };
+
+    // The error on each test case is the absolute value of the gene.
+    fn scorer() -> FnScorer<impl Fn(&Vec<i32>) -> TestResults<Error<i32>>> {
+        FnScorer(|genome: &Vec<i32>| genome.iter().map(|gene| gene.abs()).collect())
+    }
+
+    #[test]
+    fn tie_breaking_prefers_smaller_genomes_only_among_equals() {
+        let parsimony = Parsimony::tie_breaking(scorer(), Vec::len);
+        let short = parsimony.score(&vec![3]);
+        let long = parsimony.score(&vec![1, 0, 2]);
+        let longer = parsimony.score(&vec![1, 0, 0, 0, 1]);
+        // Equal total errors, so the shorter genome is better.
+        assert!(short > long);
+        // A lower total error beats being shorter.
+        assert!(longer > short);
+        assert_eq!(long.results.len(), 3);
+    }
+
+    #[test]
+    fn weighted_penalizes_total_result() {
+        let parsimony = Parsimony::weighted(scorer(), Vec::len, 2.0);
+        let test_results = parsimony.score(&vec![1, -2, 3]);
+        assert_eq!(test_results.total_result, Error::from(12));
+        assert_eq!(test_results.results, [1, 2, 3].map(Error::from));
+
+        let test_results = Parsimony::weighted(
+            FnScorer(|_: &Vec<i32>| TestResults::<Score<i32>>::from([5])),
+            Vec::len,
+            1.5,
+        )
+        .score(&vec![0, 0]);
+        assert_eq!(test_results.total_result, Score::from(2));
+    }
+
+    #[test]
+    fn integer_penalties_are_truncated() {
+        assert_eq!(Error::from(5_i32).penalize(0.5), Error::from(5));
+        assert_eq!(Error::from(5_i32).penalize(1.5), Error::from(6));
+        assert_eq!(Score::from(5_i32).penalize(1.5), Score::from(3));
+    }
+
+    #[test]
+    fn penalty_saturates() {
+        assert_eq!(Error::from(250_u8).penalize(10.0), Error::from(u8::MAX));
+        assert_eq!(Score::from(3_u8).penalize(10.0), Score::from(0));
+    }
+
+    #[test]
+    fn composes_with_genome_scorer() {
+        let population = vec![
+            EcIndividual::new(
+                vec![0, 1],
+                Parsimony::tie_breaking(scorer(), Vec::len).score(&vec![0, 1]),
+            ),
+            EcIndividual::new(
+                vec![1],
+                Parsimony::tie_breaking(scorer(), Vec::len).score(&vec![1]),
+            ),
+        ];
+        let child_maker = Select::new(Best)
+            .then(GenomeExtractor)
+            .wrap::<GenomeScorer<_, _>>(Parsimony::tie_breaking(scorer(), Vec::len));
+        let child = child_maker
+            .apply(&population, &mut rand::thread_rng())
+            .unwrap();
+        assert_eq!(child.genome, [1]);
+        assert_eq!(child.test_results.total_result.size, 1);
+    }
+}
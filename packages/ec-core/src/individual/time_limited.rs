@@ -0,0 +1,165 @@
+use std::{
+    sync::{mpsc, Arc},
+    thread,
+    time::Duration,
+};
+
+use super::scorer::Scorer;
+
+/// A scorer wrapper that gives up on scoring a genome after a wall-clock
+/// time limit, returning a penalty score instead.
+///
+/// Each genome is scored by the wrapped scorer on a new thread, while the
+/// calling thread waits for at most `time_limit` for the result. Rust has no
+/// way to stop a running thread, so when the time limit is exceeded the
+/// scoring thread is abandoned: it is never joined or cancelled, and keeps
+/// running (and using a core) until the wrapped scorer finishes, and its
+/// result is thrown away. A scorer that never finishes therefore leaks a
+/// thread every time it times out (once per retry), so this is only suitable
+/// for scorers that always finish eventually. If the wrapped scorer panics,
+/// that is also treated as exceeding the time limit.
+///
+/// The overhead is spawning a thread and cloning the genome for every
+/// genome scored, which is typically tens of microseconds; that is small
+/// compared to running a set of test cases, but not negligible for very
+/// cheap scorers. Where the wrapped scorer can stop itself, that's both
+/// cheaper and better at actually freeing up the CPU, e.g., running Push
+/// programs with `PushState::run_with_budget` and a time budget. This
+/// wrapper is a guard for scorers that have no such limit.
+///
+/// By default the penalty is returned the first time a genome exceeds the
+/// time limit. With [`TimeLimitedScorer::with_retries`] the genome is scored
+/// again (on a new thread) up to `num_retries` more times first, which helps
+/// when scorers are random or when timeouts are caused by the machine being
+/// temporarily overloaded.
+///
+/// Since this is a [`Scorer`], it can be used anywhere a scorer can, e.g.,
+/// `.wrap::<GenomeScorer<_, _>>(TimeLimitedScorer::new(scorer, time_limit,
+/// penalty))` in a child-making pipeline.
+pub struct TimeLimitedScorer<S, R> {
+    scorer: Arc<S>,
+    time_limit: Duration,
+    penalty: R,
+    num_retries: usize,
+}
+
+impl<S, R> TimeLimitedScorer<S, R> {
+    /// Score genomes with `scorer`, returning `penalty` for any genome that
+    /// takes longer than `time_limit` to score.
+    pub fn new(scorer: S, time_limit: Duration, penalty: R) -> Self {
+        Self {
+            scorer: Arc::new(scorer),
+            time_limit,
+            penalty,
+            num_retries: 0,
+        }
+    }
+
+    /// Try scoring a genome up to `num_retries` more times after it first
+    /// exceeds the time limit, before returning the penalty.
+    #[must_use]
+    pub const fn with_retries(mut self, num_retries: usize) -> Self {
+        self.num_retries = num_retries;
+        self
+    }
+
+    #[must_use]
+    pub const fn time_limit(&self) -> Duration {
+        self.time_limit
+    }
+}
+
+impl<S, R> TimeLimitedScorer<S, R> {
+    // Score `genome` on a new thread, returning `None` if that takes longer
+    // than the time limit.
+    fn try_score<G>(&self, genome: &G) -> Option<R>
+    where
+        G: Clone + Send + 'static,
+        S: Scorer<G, Score = R> + Send + Sync + 'static,
+        R: Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel();
+        let scorer = Arc::clone(&self.scorer);
+        let genome = genome.clone();
+        thread::spawn(move || {
+            // Sending fails if we've already given up waiting, in which case
+            // nobody wants the score.
+            let _ = sender.send(scorer.score(&genome));
+        });
+        receiver.recv_timeout(self.time_limit).ok()
+    }
+}
+
+impl<G, S, R> Scorer<G> for TimeLimitedScorer<S, R>
+where
+    G: Clone + Send + 'static,
+    S: Scorer<G, Score = R> + Send + Sync + 'static,
+    R: Clone + Send + 'static,
+{
+    type Score = R;
+
+    fn score(&self, genome: &G) -> Self::Score {
+        (0..=self.num_retries)
+            .find_map(|_| self.try_score(genome))
+            .unwrap_or_else(|| self.penalty.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+
+    use super::*;
+    use crate::{individual::scorer::FnScorer, test_results::TestResults};
+
+    const PENALTY: u64 = 1_000_000;
+
+    // A scorer that takes `genome` milliseconds to score a genome.
+    fn sleepy_scorer() -> FnScorer<impl Fn(&u64) -> TestResults<u64>> {
+        FnScorer(|genome: &u64| {
+            thread::sleep(Duration::from_millis(*genome));
+            TestResults::from([*genome])
+        })
+    }
+
+    #[test]
+    fn fast_scorer_returns_normally() {
+        let scorer = TimeLimitedScorer::new(
+            sleepy_scorer(),
+            Duration::from_secs(5),
+            TestResults::from([PENALTY]),
+        );
+        assert_eq!(scorer.score(&1), TestResults::from([1_u64]));
+    }
+
+    #[test]
+    fn slow_scorer_gets_penalty() {
+        let scorer = TimeLimitedScorer::new(
+            sleepy_scorer(),
+            Duration::from_millis(20),
+            TestResults::from([PENALTY]),
+        );
+        assert_eq!(scorer.score(&2_000), TestResults::from([PENALTY]));
+    }
+
+    #[test]
+    fn retries_after_timeout() {
+        // Only the first attempt is slow.
+        let num_attempts = Arc::new(AtomicUsize::new(0));
+        let attempts = Arc::clone(&num_attempts);
+        let scorer = FnScorer(move |genome: &u64| -> TestResults<u64> {
+            if attempts.fetch_add(1, Relaxed) == 0 {
+                thread::sleep(Duration::from_secs(2));
+            }
+            TestResults::from([*genome])
+        });
+        let scorer = TimeLimitedScorer::new(
+            scorer,
+            Duration::from_millis(100),
+            TestResults::from([PENALTY]),
+        )
+        .with_retries(1);
+        assert_eq!(scorer.score(&3), TestResults::from([3_u64]));
+        assert_eq!(num_attempts.load(Relaxed), 2);
+    }
+}
@@ -0,0 +1,332 @@
+//! Island models, where several sub-populations (islands) evolve
+//! independently except for occasional migration between them.
+//!
+//! Each island is a [`Generation`] with its own population, child maker,
+//! and options (elitism, lineage, observers). Every step of an
+//! [`IslandModel`] makes the next generation on every island, and every
+//! `migration_interval` steps clones of the best individuals on each island
+//! replace the worst individuals on its neighbouring islands, as given by
+//! the [`Topology`]. Keeping the islands mostly separate helps preserve
+//! diversity, while migration lets good solutions spread.
+
+use std::num::NonZeroUsize;
+
+use rayon::prelude::{FromParallelIterator, IntoParallelRefMutIterator, ParallelIterator};
+
+use crate::{
+    generation::{Elitism, Generation},
+    observer::GenerationObserver,
+    operator::Operator,
+    population::Population,
+};
+
+/// Which islands send migrants to which in an [`IslandModel`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Topology {
+    /// Island `i` sends its migrants to island `i + 1`, and the last island
+    /// sends its migrants to the first.
+    #[default]
+    Ring,
+    /// Every island sends its migrants to every other island.
+    FullyConnected,
+}
+
+impl Topology {
+    // The islands that send their migrants to `island`.
+    fn sources(self, island: usize, num_islands: usize) -> Vec<usize> {
+        match self {
+            Self::Ring => {
+                let previous = island
+                    .checked_sub(1)
+                    .unwrap_or_else(|| num_islands.saturating_sub(1));
+                if previous == island {
+                    Vec::new()
+                } else {
+                    vec![previous]
+                }
+            }
+            Self::FullyConnected => (0..num_islands).filter(|&i| i != island).collect(),
+        }
+    }
+}
+
+pub struct IslandModel<P, C, O = (), E = ()> {
+    islands: Vec<Generation<P, C, O, E>>,
+    // Migrate after every `migration_interval` steps; `None` means the
+    // islands never exchange individuals.
+    migration_interval: Option<NonZeroUsize>,
+    // The number of individuals each island sends to each of its
+    // destinations.
+    migration_size: usize,
+    topology: Topology,
+    // How many steps have been taken since the model was made.
+    generation_number: usize,
+}
+
+impl<P, C, O, E> IslandModel<P, C, O, E> {
+    /// Make an island model from the given islands. There is no migration
+    /// until it's enabled with [`IslandModel::with_migration`].
+    #[must_use]
+    pub const fn new(islands: Vec<Generation<P, C, O, E>>) -> Self {
+        Self {
+            islands,
+            migration_interval: None,
+            migration_size: 0,
+            topology: Topology::Ring,
+            generation_number: 0,
+        }
+    }
+
+    /// Every `migration_interval` steps, have each island send clones of its
+    /// best `migration_size` individuals to each of its destinations (see
+    /// [`IslandModel::with_topology`]), where they replace the worst
+    /// individuals.
+    #[must_use]
+    pub const fn with_migration(
+        mut self,
+        migration_interval: NonZeroUsize,
+        migration_size: usize,
+    ) -> Self {
+        self.migration_interval = Some(migration_interval);
+        self.migration_size = migration_size;
+        self
+    }
+
+    /// Turn off migration, so each island evolves exactly as it would on its
+    /// own.
+    #[must_use]
+    pub const fn without_migration(mut self) -> Self {
+        self.migration_interval = None;
+        self
+    }
+
+    /// Use `topology` to decide where migrants go. The default is
+    /// [`Topology::Ring`].
+    #[must_use]
+    pub const fn with_topology(mut self, topology: Topology) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    #[must_use]
+    pub fn islands(&self) -> &[Generation<P, C, O, E>] {
+        &self.islands
+    }
+
+    /// The number of steps taken so far. Each call to `par_next` or
+    /// `serial_next` increments this.
+    #[must_use]
+    pub const fn generation_number(&self) -> usize {
+        self.generation_number
+    }
+
+    #[must_use]
+    pub const fn topology(&self) -> Topology {
+        self.topology
+    }
+}
+
+impl<P, C, O, E> IslandModel<P, C, O, E>
+where
+    P: Population + AsRef<[P::Individual]>,
+{
+    /// Returns the best individual across all the islands, or `None` if
+    /// every island's population is empty.
+    pub fn best(&self) -> Option<&P::Individual>
+    where
+        P::Individual: Ord,
+    {
+        self.islands.iter().filter_map(Generation::best).max()
+    }
+}
+
+impl<P, C, O, E> IslandModel<P, C, O, E>
+where
+    P: Population + AsRef<[P::Individual]> + FromIterator<P::Individual>,
+    P::Individual: Ord + Clone,
+{
+    // Count a step and migrate if it's time to.
+    fn finish_step(&mut self) {
+        self.generation_number = self.generation_number.saturating_add(1);
+        let Some(migration_interval) = self.migration_interval else {
+            return;
+        };
+        if self.generation_number.checked_rem(migration_interval.get()) == Some(0) {
+            self.migrate();
+        }
+    }
+
+    fn migrate(&mut self) {
+        // Choose all the emigrants before any island receives immigrants, so
+        // individuals only move one step around the topology per migration.
+        let emigrants: Vec<Vec<P::Individual>> = self
+            .islands
+            .iter()
+            .map(|island| island.emigrants(self.migration_size))
+            .collect();
+        let num_islands = self.islands.len();
+        for (index, island) in self.islands.iter_mut().enumerate() {
+            let immigrants = self
+                .topology
+                .sources(index, num_islands)
+                .into_iter()
+                .flat_map(|source| emigrants[source].iter().cloned())
+                .collect();
+            island.receive_immigrants(immigrants);
+        }
+    }
+}
+
+impl<P, C, O, E> IslandModel<P, C, O, E>
+where
+    P: Population
+        + AsRef<[P::Individual]>
+        + FromIterator<P::Individual>
+        + FromParallelIterator<P::Individual>
+        + Send
+        + Sync,
+    P::Individual: Ord + Clone + Send,
+    for<'a> C: Operator<&'a P, Output = P::Individual, Error: Send> + Send + Sync,
+    for<'a> anyhow::Error: From<<C as Operator<&'a P>>::Error>,
+    O: GenerationObserver<P> + Send,
+    E: Elitism<P> + Send,
+{
+    /// Make the next generation on every island, in parallel, and then
+    /// migrate if it's time to.
+    ///
+    /// # Errors
+    ///
+    /// This can return errors if any aspect of creating the next generation
+    /// on any island fails. That can include constructing or scoring the
+    /// genomes.
+    pub fn par_next(&mut self) -> anyhow::Result<()> {
+        self.islands
+            .par_iter_mut()
+            .try_for_each(Generation::par_next)?;
+        self.finish_step();
+        Ok(())
+    }
+}
+
+impl<P, C, O, E> IslandModel<P, C, O, E>
+where
+    P: Population + AsRef<[P::Individual]> + FromIterator<P::Individual>,
+    P::Individual: Ord + Clone,
+    C: for<'a> Operator<&'a P, Output = P::Individual>,
+    for<'a> anyhow::Error: From<<C as Operator<&'a P>>::Error>,
+    O: GenerationObserver<P>,
+    E: Elitism<P>,
+{
+    /// Make the next generation on every island, one island after another,
+    /// and then migrate if it's time to.
+    ///
+    /// # Errors
+    ///
+    /// This can return errors if any aspect of creating the next generation
+    /// on any island fails. That can include constructing or scoring the
+    /// genomes.
+    pub fn serial_next(&mut self) -> anyhow::Result<()> {
+        self.islands
+            .iter_mut()
+            .try_for_each(Generation::serial_next)?;
+        self.finish_step();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The test islands use infallible operators, so every step succeeds"
+)]
+mod tests {
+    use super::*;
+    use crate::{
+        individual::{ec::EcIndividual, scorer::FnScorer},
+        operator::{
+            composable::Composable,
+            genome_extractor::GenomeExtractor,
+            genome_scorer::GenomeScorer,
+            selector::{best::Best, Select},
+        },
+    };
+
+    // A deterministic child maker, where every child is a copy of the best
+    // individual, so the only way for an island to get better is through
+    // migration.
+    type Individual = EcIndividual<i64, i64>;
+
+    fn island(
+        genomes: std::ops::Range<i64>,
+    ) -> Generation<
+        Vec<Individual>,
+        impl for<'a> Operator<&'a Vec<Individual>, Output = Individual, Error = anyhow::Error>
+            + Send
+            + Sync,
+    > {
+        let child_maker = Select::new(Best)
+            .then(GenomeExtractor)
+            .wrap::<GenomeScorer<_, _>>(FnScorer(|genome: &i64| *genome));
+        let population = genomes.map(|i| EcIndividual::new(i, i)).collect();
+        Generation::new(child_maker, population)
+    }
+
+    fn genomes<C, O>(generation: &Generation<Vec<Individual>, C, O>) -> Vec<i64> {
+        generation
+            .population()
+            .iter()
+            .map(|individual| individual.genome)
+            .collect()
+    }
+
+    #[test]
+    fn migration_transfers_individuals() {
+        let mut model = IslandModel::new(vec![island(0..5), island(100..105)])
+            .with_migration(NonZeroUsize::MIN, 1);
+
+        model.serial_next().unwrap();
+        assert_eq!(model.generation_number(), 1);
+        let (first, second) = (&model.islands()[0], &model.islands()[1]);
+        // Each island's worst individual was replaced by the other island's
+        // best individual.
+        assert!(genomes(first).contains(&104));
+        assert!(genomes(second).contains(&4));
+
+        model.par_next().unwrap();
+        let (first, second) = (&model.islands()[0], &model.islands()[1]);
+        assert_eq!(genomes(first), [104; 5]);
+        assert_eq!(genomes(second), [104; 5]);
+        assert_eq!(model.best().map(|individual| individual.genome), Some(104));
+    }
+
+    #[test]
+    fn no_migration_is_independent_runs() {
+        let mut model = IslandModel::new(vec![island(0..5), island(100..105)])
+            .with_migration(NonZeroUsize::MIN, 1)
+            .without_migration();
+        let mut first = island(0..5);
+        let mut second = island(100..105);
+
+        for _ in 0..3 {
+            model.serial_next().unwrap();
+            model.par_next().unwrap();
+            first.serial_next().unwrap();
+            first.par_next().unwrap();
+            second.serial_next().unwrap();
+            second.par_next().unwrap();
+        }
+        let (model_first, model_second) = (&model.islands()[0], &model.islands()[1]);
+        assert_eq!(model_first.population(), first.population());
+        assert_eq!(model_second.population(), second.population());
+        assert_eq!(genomes(model_first), [4; 5]);
+        assert_eq!(model.best().map(|individual| individual.genome), Some(104));
+    }
+
+    #[test]
+    fn topology_sources() {
+        assert_eq!(Topology::Ring.sources(0, 3), [2]);
+        assert_eq!(Topology::Ring.sources(2, 3), [1]);
+        assert!(Topology::Ring.sources(0, 1).is_empty());
+        assert_eq!(Topology::FullyConnected.sources(1, 3), [0, 2]);
+    }
+}
@@ -1,8 +1,12 @@
+pub mod archive;
 pub mod child_maker;
 pub mod distributions;
 pub mod generation;
 pub mod genome;
 pub mod individual;
+pub mod island_model;
+pub mod lineage;
+pub mod observer;
 pub mod operator;
 pub mod population;
 pub mod test_results;
@@ -0,0 +1,179 @@
+//! Recording the genealogy of a run.
+//!
+//! When lineage recording is enabled on a
+//! [`Generation`](crate::generation::Generation), every new individual gets a
+//! [`ChildRecord`] containing the indices (in the previous population) of its
+//! parents, and the labels of the operators used to make it. These are
+//! accumulated in a [`Lineage`], which can be used after a run for ancestry
+//! analysis.
+//!
+//! `Generation` doesn't know anything about the internals of the child-making
+//! pipeline, so the pipeline has to report this information as it runs:
+//!    - Wrapping a selector in
+//!      [`TrackParents`](crate::operator::selector::track_parents::TrackParents)
+//!      records the index of every individual it selects as a parent.
+//!    - Wrapping an operator in [`Labelled`](crate::operator::labelled::Labelled)
+//!      records its label every time it's applied.
+//!
+//! This information is collected per thread while a single child is being
+//! made, so child-making pipelines shouldn't hand work off to other threads.
+//! Outside of `Generation` (or when lineage recording is disabled) the
+//! decorators don't record anything.
+
+use std::{cell::RefCell, collections::BTreeSet};
+
+/// How a single individual was created.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ChildRecord {
+    /// The indices of this individual's parents in the previous population, in
+    /// the order they were selected.
+    pub parents: Vec<usize>,
+    /// The labels of the (labelled) operators used to create this individual,
+    /// in the order they were applied.
+    pub operators: Vec<&'static str>,
+}
+
+/// The genealogy of a run, as a [`ChildRecord`] for every individual in every
+/// generation after the initial population.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Lineage {
+    // `generations[n]` holds the records for population `n + 1`, since the
+    // initial population (population 0) has no parents.
+    generations: Vec<Vec<ChildRecord>>,
+}
+
+impl Lineage {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            generations: Vec::new(),
+        }
+    }
+
+    /// The number of generations that have been recorded. This doesn't count
+    /// the initial population.
+    #[must_use]
+    pub const fn num_generations(&self) -> usize {
+        self.generations.len()
+    }
+
+    /// The records for each individual in population `generation`, where
+    /// population 0 is the initial population (which has no records).
+    #[must_use]
+    pub fn records(&self, generation: usize) -> Option<&[ChildRecord]> {
+        self.generations
+            .get(generation.checked_sub(1)?)
+            .map(Vec::as_slice)
+    }
+
+    /// The record for the individual at `index` in population `generation`.
+    #[must_use]
+    pub fn record(&self, generation: usize, index: usize) -> Option<&ChildRecord> {
+        self.records(generation)?.get(index)
+    }
+
+    /// Collect all the ancestors of the individual at `index` in population
+    /// `generation`.
+    ///
+    /// The result has one entry for every earlier population, where entry `g`
+    /// contains the indices of the ancestors in population `g`. Missing
+    /// records (e.g., an `index` that's out of range) are treated as having no
+    /// parents.
+    #[must_use]
+    pub fn ancestors(&self, generation: usize, index: usize) -> Vec<BTreeSet<usize>> {
+        let mut ancestors = vec![BTreeSet::new(); generation.min(self.num_generations())];
+        let mut frontier = BTreeSet::from([index]);
+        for (parents, records) in ancestors.iter_mut().zip(&self.generations).rev() {
+            *parents = frontier
+                .iter()
+                .filter_map(|&i| records.get(i))
+                .flat_map(|record| record.parents.iter().copied())
+                .collect();
+            frontier.clone_from(parents);
+        }
+        ancestors
+    }
+
+    pub(crate) fn push_generation(&mut self, records: Vec<ChildRecord>) {
+        self.generations.push(records);
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Vec<ChildRecord>> {
+        self.generations.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Lineage {
+    type Item = &'a Vec<ChildRecord>;
+    type IntoIter = std::slice::Iter<'a, Vec<ChildRecord>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.generations.iter()
+    }
+}
+
+thread_local! {
+    // The record for the child currently being made on this thread, if we're
+    // recording lineage.
+    static CURRENT_RECORD: RefCell<Option<ChildRecord>> = const { RefCell::new(None) };
+}
+
+/// Run `f` (which should make a single child), collecting the
+/// parents and operators reported while it runs.
+pub(crate) fn record_child<T>(f: impl FnOnce() -> T) -> (T, ChildRecord) {
+    let previous = CURRENT_RECORD.replace(Some(ChildRecord::default()));
+    let result = f();
+    let record = CURRENT_RECORD.replace(previous).unwrap_or_default();
+    (result, record)
+}
+
+fn update_current_record(f: impl FnOnce(&mut ChildRecord)) {
+    CURRENT_RECORD.with_borrow_mut(|record| {
+        if let Some(record) = record {
+            f(record);
+        }
+    });
+}
+
+pub(crate) fn record_parent(index: usize) {
+    update_current_record(|record| record.parents.push(index));
+}
+
+pub(crate) fn record_operator(label: &'static str) {
+    update_current_record(|record| record.operators.push(label));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(parents: &[usize]) -> ChildRecord {
+        ChildRecord {
+            parents: parents.to_vec(),
+            operators: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn nothing_recorded_outside_record_child() {
+        record_parent(3);
+        let ((), record) = record_child(|| record_parent(5));
+        assert_eq!(record.parents, vec![5]);
+    }
+
+    #[test]
+    fn ancestors() {
+        let mut lineage = Lineage::new();
+        lineage.push_generation(vec![record(&[0, 1]), record(&[2]), record(&[2, 3])]);
+        lineage.push_generation(vec![record(&[1]), record(&[0, 2])]);
+
+        assert_eq!(
+            lineage.ancestors(2, 1),
+            vec![BTreeSet::from([0, 1, 2, 3]), BTreeSet::from([0, 2])]
+        );
+        assert_eq!(
+            lineage.ancestors(2, 0),
+            vec![BTreeSet::from([2]), BTreeSet::from([1])]
+        );
+    }
+}
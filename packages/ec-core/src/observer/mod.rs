@@ -0,0 +1,101 @@
+//! Hooks for watching a run as each new generation is made.
+//!
+//! A [`GenerationObserver`] added to a
+//! [`Generation`](crate::generation::Generation) with
+//! [`with_observer`](crate::generation::Generation::with_observer) is called
+//! at the end of every call to `par_next` or `serial_next`, which makes it a
+//! good place for logging, collecting statistics, or deciding when to stop.
+//! Without an observer (the default), nothing is called and nothing is
+//! stored.
+
+#[cfg(feature = "run-log")]
+pub mod run_log;
+
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use crate::{individual::Individual, population::Population, test_results::TestResults};
+
+pub trait GenerationObserver<P> {
+    /// Called after `population` has become the current population, which is
+    /// generation number `generation_number`.
+    fn on_generation_complete(&self, generation_number: usize, population: &P);
+}
+
+/// The "no observer" observer, which does nothing.
+impl<P> GenerationObserver<P> for () {
+    #[inline]
+    fn on_generation_complete(&self, _: usize, _: &P) {}
+}
+
+impl<P, T> GenerationObserver<P> for &T
+where
+    T: GenerationObserver<P>,
+{
+    fn on_generation_complete(&self, generation_number: usize, population: &P) {
+        (*self).on_generation_complete(generation_number, population);
+    }
+}
+
+/// An observer that records the total result of the best individual in each
+/// generation, e.g., for plotting how the best total error changes over a
+/// run.
+///
+/// The history is kept behind a lock, so the recorder can be passed to
+/// [`with_observer`](crate::generation::Generation::with_observer) by
+/// reference and read while the run is going on.
+pub struct BestTotalResult<R> {
+    history: Mutex<Vec<R>>,
+}
+
+impl<R> BestTotalResult<R> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            history: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// The best total result in each generation observed so far, in order.
+    #[must_use]
+    pub fn history(&self) -> Vec<R>
+    where
+        R: Clone,
+    {
+        self.lock_history().clone()
+    }
+
+    /// Returns the history (as in [`BestTotalResult::history`]) and clears
+    /// it.
+    #[must_use]
+    pub fn take_history(&self) -> Vec<R> {
+        std::mem::take(&mut *self.lock_history())
+    }
+
+    fn lock_history(&self) -> MutexGuard<'_, Vec<R>> {
+        // The history is always left in a consistent state, so it's safe to
+        // keep using it even if another thread panicked while holding the
+        // lock.
+        self.history.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<R> Default for BestTotalResult<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P, R> GenerationObserver<P> for BestTotalResult<R>
+where
+    P: Population + AsRef<[P::Individual]>,
+    P::Individual: Individual<TestResults = TestResults<R>> + Ord,
+    R: Clone,
+{
+    /// Nothing is recorded for an empty population.
+    fn on_generation_complete(&self, _: usize, population: &P) {
+        if let Some(best) = population.as_ref().iter().max() {
+            self.lock_history()
+                .push(best.test_results().total_result.clone());
+        }
+    }
+}
@@ -0,0 +1,411 @@
+//! Observers that log statistics about each generation of a run, as CSV or
+//! as JSON lines, so examples don't each need their own `println!` loops.
+//!
+//! A [`RunLogger`] writes one row (or JSON object) per generation to any
+//! [`Write`] sink, e.g., a file or standard output. The generation number is
+//! always recorded, and the other columns are chosen with the `with_*`
+//! builder methods:
+//!
+//! ```
+//! # use ec_core::{individual::ec::EcIndividual, observer::{run_log::RunLogger, GenerationObserver}, test_results::{Error, TestResults}};
+//! let logger = RunLogger::csv(Vec::new())
+//!     .with_best_total_result()
+//!     .with_best_genome_size(|genome: &Vec<u8>| genome.len());
+//! let population = vec![
+//!     EcIndividual::new(vec![0, 1], TestResults::<Error<i64>>::from([3, 4])),
+//!     EcIndividual::new(vec![1], TestResults::<Error<i64>>::from([1, 1])),
+//! ];
+//! logger.on_generation_complete(0, &population);
+//! let output = String::from_utf8(logger.into_inner()?).unwrap();
+//! assert_eq!(output, "generation,best_total_result,best_genome_size\n0,2,1\n");
+//! # Ok::<(), std::io::Error>(())
+//! ```
+//!
+//! The output is flushed after every generation, so a run that is stopped
+//! early still has its statistics up to that point.
+//!
+//! This module is only available with the `run-log` feature.
+
+use std::{
+    hash::Hash,
+    io::{self, Write},
+    sync::{Mutex, MutexGuard, PoisonError},
+};
+
+use num_traits::ToPrimitive;
+
+use super::GenerationObserver;
+use crate::{
+    individual::Individual,
+    population::{diversity, Population},
+    test_results::{NumericResult, TestResults},
+};
+
+/// The output format of a [`RunLogger`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Comma-separated values, with a header row naming the columns.
+    Csv,
+    /// One JSON object per line, with the column names as keys.
+    JsonLines,
+}
+
+// A column of the log: its name, and how to compute its value from a
+// population. Values that can't be computed (e.g., the best result of an
+// empty population) are left empty in CSV and are `null` in JSON.
+struct Column<P> {
+    name: String,
+    value: ColumnValue<P>,
+}
+
+type ColumnValue<P> = Box<dyn Fn(&P) -> Option<f64> + Send + Sync>;
+
+enum Sink<W: Write> {
+    // Boxed since `csv::Writer` has a large buffer.
+    Csv(Box<csv::Writer<W>>),
+    JsonLines(W),
+}
+
+struct Output<W: Write> {
+    sink: Sink<W>,
+    header_written: bool,
+    // The first error from writing, after which nothing more is written.
+    error: Option<io::Error>,
+}
+
+/// A [`GenerationObserver`] that writes a row of statistics for every
+/// generation to a [`Write`] sink; see the [module documentation](self).
+pub struct RunLogger<W: Write, P> {
+    columns: Vec<Column<P>>,
+    output: Mutex<Output<W>>,
+}
+
+impl<W: Write, P> RunLogger<W, P> {
+    /// Log generations to `writer` as CSV.
+    #[must_use]
+    pub fn csv(writer: W) -> Self {
+        Self::new(Format::Csv, writer)
+    }
+
+    /// Log generations to `writer` as JSON lines.
+    #[must_use]
+    pub fn jsonl(writer: W) -> Self {
+        Self::new(Format::JsonLines, writer)
+    }
+
+    #[must_use]
+    pub fn new(format: Format, writer: W) -> Self {
+        let sink = match format {
+            Format::Csv => Sink::Csv(Box::new(
+                csv::WriterBuilder::new()
+                    .has_headers(false)
+                    .from_writer(writer),
+            )),
+            Format::JsonLines => Sink::JsonLines(writer),
+        };
+        Self {
+            columns: Vec::new(),
+            output: Mutex::new(Output {
+                sink,
+                header_written: false,
+                error: None,
+            }),
+        }
+    }
+
+    /// Stop logging, returning the writer, or the first error that occurred
+    /// while writing to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error from writing to the writer, if there was one.
+    pub fn into_inner(self) -> io::Result<W> {
+        let output = self
+            .output
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some(error) = output.error {
+            return Err(error);
+        }
+        match output.sink {
+            Sink::Csv(writer) => writer.into_inner().map_err(csv::IntoInnerError::into_error),
+            Sink::JsonLines(writer) => Ok(writer),
+        }
+    }
+}
+
+impl<W: Write, P> RunLogger<W, P> {
+    /// Add a column named `name` whose value for each generation is
+    /// `value(population)`, or empty (`null` in JSON) if that's `None`.
+    #[must_use]
+    pub fn with_column<F>(mut self, name: impl Into<String>, value: F) -> Self
+    where
+        F: Fn(&P) -> Option<f64> + Send + Sync + 'static,
+    {
+        self.columns.push(Column {
+            name: name.into(),
+            value: Box::new(value),
+        });
+        self
+    }
+
+    fn lock_output(&self) -> MutexGuard<'_, Output<W>> {
+        // The output is only written to between generations, so it's safe
+        // to keep using it even if another thread panicked while holding the
+        // lock.
+        self.output.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<W, P> RunLogger<W, P>
+where
+    W: Write,
+    P: Population + AsRef<[P::Individual]>,
+    P::Individual: Individual,
+{
+    /// Record the total result of the best individual in each generation.
+    #[must_use]
+    pub fn with_best_total_result<R>(self) -> Self
+    where
+        P::Individual: Individual<TestResults = TestResults<R>> + Ord,
+        R: NumericResult,
+    {
+        self.with_column("best_total_result", |population: &P| {
+            best(population)?.test_results().total_result.to_f64()
+        })
+    }
+
+    /// Record the mean of the total results of all the individuals in each
+    /// generation.
+    #[must_use]
+    pub fn with_mean_total_result<R>(self) -> Self
+    where
+        P::Individual: Individual<TestResults = TestResults<R>>,
+        R: NumericResult,
+    {
+        self.with_column("mean_total_result", |population: &P| {
+            let individuals = population.as_ref();
+            if individuals.is_empty() {
+                return None;
+            }
+            let total = individuals
+                .iter()
+                .map(|individual| individual.test_results().total_result.to_f64())
+                .sum::<Option<f64>>()?;
+            Some(total / individuals.len().to_f64()?)
+        })
+    }
+
+    /// Record the number of distinct genomes in each generation (see
+    /// [`diversity::num_distinct_genomes`]).
+    #[must_use]
+    pub fn with_num_distinct_genomes(self) -> Self
+    where
+        <P::Individual as Individual>::Genome: Eq + Hash,
+    {
+        self.with_column("num_distinct_genomes", |population: &P| {
+            diversity::num_distinct_genomes(population.as_ref()).to_f64()
+        })
+    }
+
+    /// Record the number of distinct test results in each generation (see
+    /// [`diversity::num_distinct_test_results`]).
+    #[must_use]
+    pub fn with_num_distinct_test_results(self) -> Self
+    where
+        <P::Individual as Individual>::TestResults: Eq + Hash,
+    {
+        self.with_column("num_distinct_test_results", |population: &P| {
+            diversity::num_distinct_test_results(population.as_ref()).to_f64()
+        })
+    }
+
+    /// Record the size of the best individual's genome in each generation,
+    /// as measured by `size` (e.g., `Vec::len`).
+    #[must_use]
+    pub fn with_best_genome_size<F>(self, size: F) -> Self
+    where
+        P::Individual: Ord,
+        F: Fn(&<P::Individual as Individual>::Genome) -> usize + Send + Sync + 'static,
+    {
+        self.with_column("best_genome_size", move |population: &P| {
+            size(best(population)?.genome()).to_f64()
+        })
+    }
+}
+
+// The best individual in `population`, as for `BestTotalResult`.
+fn best<P>(population: &P) -> Option<&P::Individual>
+where
+    P: Population + AsRef<[P::Individual]>,
+    P::Individual: Ord,
+{
+    population.as_ref().iter().max()
+}
+
+impl<W, P> RunLogger<W, P>
+where
+    W: Write,
+{
+    fn write_generation(
+        &self,
+        output: &mut Output<W>,
+        generation_number: usize,
+        population: &P,
+    ) -> io::Result<()> {
+        let values = self.columns.iter().map(|column| (column.value)(population));
+        match &mut output.sink {
+            Sink::Csv(writer) => {
+                if !output.header_written {
+                    output.header_written = true;
+                    writer.write_record(
+                        std::iter::once("generation")
+                            .chain(self.columns.iter().map(|column| column.name.as_str())),
+                    )?;
+                }
+                writer.write_record(std::iter::once(generation_number.to_string()).chain(
+                    values.map(|value| value.map(|value| value.to_string()).unwrap_or_default()),
+                ))?;
+                writer.flush()
+            }
+            // Every JSON line names its fields, so there's no header. The
+            // line is written by hand (rather than as a `serde_json::Map`) to
+            // keep the columns in order.
+            Sink::JsonLines(writer) => {
+                write!(writer, "{{\"generation\":{generation_number}")?;
+                for (column, value) in self.columns.iter().zip(values) {
+                    // `serde_json` writes non-finite numbers as `null`.
+                    write!(
+                        writer,
+                        ",{}:{}",
+                        serde_json::to_string(&column.name)?,
+                        serde_json::to_string(&value)?
+                    )?;
+                }
+                writeln!(writer, "}}")?;
+                writer.flush()
+            }
+        }
+    }
+}
+
+impl<W, P> GenerationObserver<P> for RunLogger<W, P>
+where
+    W: Write,
+{
+    /// Write the row for this generation (after the header, the first time)
+    /// and flush the writer. If writing fails, nothing more is written; the
+    /// error is returned by [`RunLogger::into_inner`].
+    fn on_generation_complete(&self, generation_number: usize, population: &P) {
+        let mut output = self.lock_output();
+        if output.error.is_some() {
+            return;
+        }
+        if let Err(error) = self.write_generation(&mut output, generation_number, population) {
+            output.error = Some(error);
+        }
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "Logging into an in-memory buffer can't fail, and it's always valid UTF-8"
+)]
+mod tests {
+    use super::*;
+    use crate::{individual::ec::EcIndividual, test_results::Error};
+
+    type Pop = Vec<EcIndividual<Vec<u8>, TestResults<Error<i64>>>>;
+
+    fn individual(
+        genome: &[u8],
+        errors: [i64; 2],
+    ) -> EcIndividual<Vec<u8>, TestResults<Error<i64>>> {
+        EcIndividual::new(genome.to_vec(), TestResults::from(errors))
+    }
+
+    fn populations() -> [Pop; 2] {
+        [
+            vec![
+                individual(&[0, 1, 2], [4, 4]),
+                individual(&[0, 1, 2], [4, 4]),
+                individual(&[3], [1, 2]),
+            ],
+            vec![individual(&[5, 6], [0, 0]), individual(&[7], [2, 2])],
+        ]
+    }
+
+    fn log(logger: &RunLogger<Vec<u8>, Pop>) {
+        for (generation_number, population) in populations().iter().enumerate() {
+            logger.on_generation_complete(generation_number, population);
+        }
+    }
+
+    fn logger(format: Format) -> RunLogger<Vec<u8>, Pop> {
+        RunLogger::new(format, Vec::new())
+            .with_best_total_result()
+            .with_mean_total_result()
+            .with_num_distinct_genomes()
+            .with_best_genome_size(Vec::len)
+    }
+
+    #[test]
+    fn csv_has_header_and_a_row_per_generation() {
+        let logger = logger(Format::Csv);
+        log(&logger);
+        let output = String::from_utf8(logger.into_inner().unwrap()).unwrap();
+        assert_eq!(
+            output.lines().collect::<Vec<_>>(),
+            [
+                "generation,best_total_result,mean_total_result,num_distinct_genomes,\
+                 best_genome_size",
+                "0,3,6.333333333333333,2,1",
+                "1,0,2,2,2",
+            ]
+        );
+    }
+
+    #[test]
+    fn jsonl_has_an_object_per_generation() {
+        let logger = logger(Format::JsonLines);
+        log(&logger);
+        let output = String::from_utf8(logger.into_inner().unwrap()).unwrap();
+        assert_eq!(
+            output.lines().collect::<Vec<_>>(),
+            [
+                r#"{"generation":0,"best_total_result":3.0,"mean_total_result":6.333333333333333,"num_distinct_genomes":2.0,"best_genome_size":1.0}"#,
+                r#"{"generation":1,"best_total_result":0.0,"mean_total_result":2.0,"num_distinct_genomes":2.0,"best_genome_size":2.0}"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_population_has_missing_values() {
+        let logger: RunLogger<Vec<u8>, Pop> = RunLogger::csv(Vec::new())
+            .with_best_total_result()
+            .with_column("size", |population: &Pop| population.len().to_f64());
+        logger.on_generation_complete(7, &Vec::new());
+        let output = String::from_utf8(logger.into_inner().unwrap()).unwrap();
+        assert_eq!(output, "generation,best_total_result,size\n7,,0\n");
+    }
+
+    #[test]
+    fn quotes_column_names() {
+        let name = "say \"hi\", again";
+        let csv: RunLogger<Vec<u8>, Pop> =
+            RunLogger::csv(Vec::new()).with_column(name, |_: &Pop| Some(1.0));
+        csv.on_generation_complete(0, &Vec::new());
+        let output = String::from_utf8(csv.into_inner().unwrap()).unwrap();
+        assert_eq!(output, "generation,\"say \"\"hi\"\", again\"\n0,1\n");
+
+        let jsonl: RunLogger<Vec<u8>, Pop> =
+            RunLogger::jsonl(Vec::new()).with_column(name, |_: &Pop| Some(f64::NAN));
+        jsonl.on_generation_complete(0, &Vec::new());
+        let output = String::from_utf8(jsonl.into_inner().unwrap()).unwrap();
+        assert_eq!(
+            output,
+            "{\"generation\":0,\"say \\\"hi\\\", again\":null}\n"
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use std::marker::PhantomData;
+
+use rand::RngCore;
+
+use super::{composable::Wrappable, Composable, Operator};
+use crate::population::Population;
+
+/// An operator that builds a population of type `P` by applying a
+/// child-making operator `size` times to (clones of) the same input.
+///
+/// This is what [`Generation`](crate::generation::Generation) does to make
+/// each new generation, but as an operator it can also be used to build
+/// populations outside of a `Generation`.
+pub struct BuildPopulation<C, P> {
+    child_maker: C,
+    size: usize,
+    _population: PhantomData<fn() -> P>,
+}
+
+impl<C, P> BuildPopulation<C, P> {
+    pub const fn new(child_maker: C, size: usize) -> Self {
+        Self {
+            child_maker,
+            size,
+            _population: PhantomData,
+        }
+    }
+}
+
+impl<C, P> Wrappable<C> for BuildPopulation<C, P> {
+    type Context = usize;
+
+    fn construct(child_maker: C, size: Self::Context) -> Self {
+        Self::new(child_maker, size)
+    }
+}
+
+impl<Input, C, P> Operator<Input> for BuildPopulation<C, P>
+where
+    Input: Clone,
+    C: Operator<Input>,
+    P: Population + FromIterator<C::Output>,
+{
+    type Output = P;
+    type Error = C::Error;
+
+    fn apply(&self, input: Input, rng: &mut dyn RngCore) -> Result<Self::Output, Self::Error> {
+        std::iter::repeat_with(|| self.child_maker.apply(input.clone(), rng))
+            .take(self.size)
+            .collect()
+    }
+}
+impl<C, P> Composable for BuildPopulation<C, P> {}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "Building a population of constants can't fail"
+)]
+mod tests {
+    use super::*;
+    use crate::operator::identity::Identity;
+
+    #[test]
+    fn build_population_of_constants() {
+        let mut rng = rand::thread_rng();
+        let population = Identity::new(5)
+            .wrap::<BuildPopulation<_, Vec<_>>>(10)
+            .apply((), &mut rng)
+            .unwrap();
+        assert_eq!(population.size(), 10);
+        assert!(population.iter().all(|&x| x == 5));
+    }
+}
@@ -0,0 +1,134 @@
+use rand::RngCore;
+
+use super::{composable::Wrappable, Composable, Operator};
+
+/// An operator wrapper that clamps the output of the wrapped operator to the
+/// range `[lo, hi]`, e.g., so that a few huge (or infinite) errors from a
+/// scorer don't dominate selection.
+///
+/// Outputs that can't be compared to the bounds (like `NaN`) are passed
+/// through unchanged.
+pub struct ClampOutput<O, T> {
+    operator: O,
+    lo: T,
+    hi: T,
+}
+
+impl<O, T> ClampOutput<O, T>
+where
+    T: PartialOrd,
+{
+    /// # Panics
+    ///
+    /// Panics if `lo > hi`, or if they can't be compared (e.g., one is
+    /// `NaN`), like [`f64::clamp`].
+    #[must_use]
+    pub fn new(operator: O, lo: T, hi: T) -> Self {
+        assert!(
+            lo <= hi,
+            "The lower bound must be no more than the upper bound"
+        );
+        Self { operator, lo, hi }
+    }
+}
+
+impl<O, T> Wrappable<O> for ClampOutput<O, T>
+where
+    T: PartialOrd,
+{
+    type Context = (T, T);
+
+    fn construct(operator: O, (lo, hi): Self::Context) -> Self {
+        Self::new(operator, lo, hi)
+    }
+}
+
+impl<O, T, Input> Operator<Input> for ClampOutput<O, T>
+where
+    O: Operator<Input, Output = T>,
+    T: PartialOrd + Clone,
+{
+    type Output = T;
+    type Error = O::Error;
+
+    fn apply(&self, input: Input, rng: &mut dyn RngCore) -> Result<Self::Output, Self::Error> {
+        let output = self.operator.apply(input, rng)?;
+        Ok(if output < self.lo {
+            self.lo.clone()
+        } else if output > self.hi {
+            self.hi.clone()
+        } else {
+            output
+        })
+    }
+}
+
+impl<O, T> Composable for ClampOutput<O, T> {}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The clamped test operators are infallible"
+)]
+mod tests {
+    use std::convert::Infallible;
+
+    use super::*;
+    use crate::operator::identity::Identity;
+
+    fn clamped(value: i64) -> i64 {
+        ClampOutput::new(Identity::new(value), 0, 10)
+            .apply((), &mut rand::thread_rng())
+            .unwrap()
+    }
+
+    #[test]
+    fn values_outside_the_range_are_clamped() {
+        assert_eq!(clamped(-5), 0);
+        assert_eq!(clamped(i64::MAX), 10);
+    }
+
+    #[test]
+    fn values_in_the_range_pass_through() {
+        for value in 0..=10 {
+            assert_eq!(clamped(value), value);
+        }
+    }
+
+    #[test]
+    fn infinite_errors_are_clamped() {
+        let clamp = Identity::new(f64::INFINITY).wrap::<ClampOutput<_, _>>((0.0, 1_000.0));
+        let output = clamp.apply((), &mut rand::thread_rng()).unwrap();
+        assert!((output - 1_000.0).abs() < f64::EPSILON);
+    }
+
+    struct Double;
+    impl Operator<i64> for Double {
+        type Output = i64;
+        type Error = Infallible;
+
+        fn apply(&self, input: i64, _: &mut dyn RngCore) -> Result<Self::Output, Self::Error> {
+            Ok(input.saturating_mul(2))
+        }
+    }
+    impl Composable for Double {}
+
+    #[test]
+    fn composes_with_then() {
+        let mut rng = rand::thread_rng();
+        // Clamping a chain of operators.
+        let clamp = Identity::new(8)
+            .then(Double)
+            .wrap::<ClampOutput<_, _>>((0, 10));
+        assert_eq!(clamp.apply((), &mut rng).unwrap(), 10);
+        // Following a clamp with another operator.
+        let then = ClampOutput::new(Identity::new(50), 0, 20).then(Double);
+        assert_eq!(then.apply((), &mut rng).unwrap(), 40);
+    }
+
+    #[test]
+    #[should_panic(expected = "lower bound")]
+    fn reversed_bounds_panic() {
+        let _ = ClampOutput::new(Identity::new(5), 10, 0);
+    }
+}
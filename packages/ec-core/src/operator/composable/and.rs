@@ -1,5 +1,5 @@
 use anyhow::Context;
-use rand::rngs::ThreadRng;
+use rand::RngCore;
 
 use super::{super::Operator, Composable};
 
@@ -27,7 +27,7 @@ where
     type Output = (F::Output, G::Output);
     type Error = anyhow::Error;
 
-    fn apply(&self, x: A, rng: &mut ThreadRng) -> Result<Self::Output, Self::Error> {
+    fn apply(&self, x: A, rng: &mut dyn RngCore) -> Result<Self::Output, Self::Error> {
         let f_value = self
             .f
             .apply(x.clone(), rng)
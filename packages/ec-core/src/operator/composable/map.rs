@@ -25,18 +25,18 @@ where
     fn apply(
         &self,
         [x, y]: [Input; 2],
-        rng: &mut rand::rngs::ThreadRng,
+        rng: &mut dyn rand::RngCore,
     ) -> Result<Self::Output, Self::Error> {
         let first_result = self
             .f
             .apply(x, rng)
             .map_err(anyhow::Error::from)
-            .context("Calling f with {x} in `Map` failed")?;
+            .context("Calling f with the first input in `Map` failed")?;
         let second_result = self
             .f
             .apply(y, rng)
             .map_err(anyhow::Error::from)
-            .context("Calling f with {y} in `Map` failed")?;
+            .context("Calling f with the second input in `Map` failed")?;
         Ok([first_result, second_result])
     }
 }
@@ -52,18 +52,18 @@ where
     fn apply(
         &self,
         (x, y): (Input, Input),
-        rng: &mut rand::rngs::ThreadRng,
+        rng: &mut dyn rand::RngCore,
     ) -> Result<Self::Output, Self::Error> {
         let first_result = self
             .f
             .apply(x, rng)
             .map_err(anyhow::Error::from)
-            .context("Calling f with {x} in `Map` failed")?;
+            .context("Calling f with the first input in `Map` failed")?;
         let second_result = self
             .f
             .apply(y, rng)
             .map_err(anyhow::Error::from)
-            .context("Calling f with {y} in `Map` failed")?;
+            .context("Calling f with the second input in `Map` failed")?;
         Ok((first_result, second_result))
     }
 }
@@ -79,7 +79,7 @@ where
     fn apply(
         &self,
         input: Vec<Input>,
-        rng: &mut rand::rngs::ThreadRng,
+        rng: &mut dyn rand::RngCore,
     ) -> Result<Self::Output, Self::Error> {
         input
             .into_iter()
@@ -87,7 +87,7 @@ where
                 self.f
                     .apply(x, rng)
                     .map_err(anyhow::Error::from)
-                    .context("Applying f to {x} in `Map` failed")
+                    .context("Applying f to an element in `Map` failed")
             })
             .collect()
     }
@@ -28,9 +28,12 @@ where
     fn apply(
         &self,
         input: Input,
-        rng: &mut rand::rngs::ThreadRng,
+        rng: &mut dyn rand::RngCore,
     ) -> Result<Self::Output, Self::Error> {
-        #[allow(clippy::panic)]
+        #[expect(
+            clippy::panic,
+            reason = "`take(N)` yields exactly `N` results, so the array conversion can't fail"
+        )]
         Ok(iter::repeat_with(|| self.f.apply(input.clone(), rng))
             .take(N)
             .collect::<Result<Vec<_>, _>>()?
@@ -48,7 +51,11 @@ where
 impl<F, const N: usize> Composable for RepeatWith<F, N> {}
 
 #[cfg(test)]
-#[allow(clippy::arithmetic_side_effects)]
+#[expect(clippy::unwrap_used, reason = "The repeated operators are infallible")]
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "The test operators only add small constants"
+)]
 mod tests {
     use std::{convert::Infallible, ops::Range};
 
@@ -64,7 +71,7 @@ mod tests {
         fn apply(
             &self,
             input: i32,
-            _: &mut rand::rngs::ThreadRng,
+            _: &mut dyn rand::RngCore,
         ) -> Result<Self::Output, Self::Error> {
             Ok(input + 1)
         }
@@ -77,7 +84,6 @@ mod tests {
         let desired_value = 7;
         let mut rng = thread_rng();
         let repeater: RepeatWith<AddOne, LENGTH> = RepeatWith::new(AddOne);
-        #[allow(clippy::unwrap_used)]
         let result = repeater.apply(desired_value, &mut rng).unwrap();
         assert_eq!(LENGTH, result.len());
         result.into_iter().all(|x| x == desired_value);
@@ -91,7 +97,7 @@ mod tests {
         fn apply(
             &self,
             range: Range<i32>,
-            rng: &mut rand::rngs::ThreadRng,
+            rng: &mut dyn rand::RngCore,
         ) -> Result<Self::Output, Self::Error> {
             Ok(rng.gen_range(range))
         }
@@ -104,7 +110,6 @@ mod tests {
         let range = 0..7;
         let mut rng = thread_rng();
         let repeater: RepeatWith<UniformRange, LENGTH> = RepeatWith::new(UniformRange);
-        #[allow(clippy::unwrap_used)]
         let result = repeater.apply(range.clone(), &mut rng).unwrap();
         assert_eq!(LENGTH, result.len());
         result.iter().all(|x| range.contains(x));
@@ -1,5 +1,5 @@
 use anyhow::Context;
-use rand::rngs::ThreadRng;
+use rand::RngCore;
 
 use super::{super::Operator, Composable};
 
@@ -23,7 +23,7 @@ where
     type Output = G::Output;
     type Error = anyhow::Error;
 
-    fn apply(&self, x: A, rng: &mut ThreadRng) -> Result<Self::Output, Self::Error> {
+    fn apply(&self, x: A, rng: &mut dyn RngCore) -> Result<Self::Output, Self::Error> {
         let f_result = self
             .f
             .apply(x, rng)
@@ -35,7 +35,14 @@ where
 impl<F, G> Composable for Then<F, G> {}
 
 #[cfg(test)]
-#[allow(clippy::arithmetic_side_effects)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "Composing infallible operators can't fail"
+)]
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "Incrementing and doubling small test values can't overflow"
+)]
 pub mod tests {
     use std::convert::Infallible;
 
@@ -48,7 +55,7 @@ pub mod tests {
         type Output = i32;
         type Error = Infallible;
 
-        fn apply(&self, input: i32, _: &mut ThreadRng) -> Result<Self::Output, Self::Error> {
+        fn apply(&self, input: i32, _: &mut dyn RngCore) -> Result<Self::Output, Self::Error> {
             Ok(input + 1)
         }
     }
@@ -59,14 +66,13 @@ pub mod tests {
         type Output = i32;
         type Error = Infallible;
 
-        fn apply(&self, input: i32, _: &mut ThreadRng) -> Result<Self::Output, Self::Error> {
+        fn apply(&self, input: i32, _: &mut dyn RngCore) -> Result<Self::Output, Self::Error> {
             Ok(input * 2)
         }
     }
     impl Composable for Double {}
 
     #[test]
-    #[allow(clippy::unwrap_used)]
     fn increment_then_double() {
         let combo = Increment.then(Double);
         let result = combo.apply(7, &mut thread_rng()).unwrap();
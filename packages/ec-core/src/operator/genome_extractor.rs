@@ -19,7 +19,7 @@ where
     fn apply(
         &self,
         individual: &I,
-        _: &mut rand::rngs::ThreadRng,
+        _: &mut dyn rand::RngCore,
     ) -> Result<Self::Output, Self::Error> {
         Ok(individual.genome().clone())
     }
@@ -40,7 +40,7 @@ where
     fn apply(
         &self,
         population: &'pop P,
-        rng: &mut rand::rngs::ThreadRng,
+        rng: &mut dyn rand::RngCore,
     ) -> Result<Self::Output, Self::Error> {
         let genome = self.genome_maker.apply(population, rng)?;
         let score = self.scorer.score(&genome);
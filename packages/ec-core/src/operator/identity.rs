@@ -1,4 +1,4 @@
-use rand::rngs::ThreadRng;
+use rand::RngCore;
 
 use super::{Composable, Operator};
 
@@ -19,7 +19,7 @@ where
     type Output = T;
     type Error = anyhow::Error;
 
-    fn apply(&self, (): (), _: &mut ThreadRng) -> Result<Self::Output, Self::Error> {
+    fn apply(&self, (): (), _: &mut dyn RngCore) -> Result<Self::Output, Self::Error> {
         Ok(self.value.clone())
     }
 }
@@ -0,0 +1,44 @@
+use rand::RngCore;
+
+use super::{composable::Wrappable, Composable, Operator};
+use crate::lineage;
+
+/// An operator wrapper that reports its label to the current
+/// [lineage](crate::lineage) record every time the wrapped operator is
+/// applied.
+///
+/// This has no effect unless lineage recording has been enabled on the
+/// [`Generation`](crate::generation::Generation) that's using it.
+pub struct Labelled<O> {
+    operator: O,
+    label: &'static str,
+}
+
+impl<O> Labelled<O> {
+    pub const fn new(operator: O, label: &'static str) -> Self {
+        Self { operator, label }
+    }
+}
+
+impl<O> Wrappable<O> for Labelled<O> {
+    type Context = &'static str;
+
+    fn construct(operator: O, label: Self::Context) -> Self {
+        Self::new(operator, label)
+    }
+}
+
+impl<O, Input> Operator<Input> for Labelled<O>
+where
+    O: Operator<Input>,
+{
+    type Output = O::Output;
+    type Error = O::Error;
+
+    fn apply(&self, input: Input, rng: &mut dyn RngCore) -> Result<Self::Output, Self::Error> {
+        let output = self.operator.apply(input, rng)?;
+        lineage::record_operator(self.label);
+        Ok(output)
+    }
+}
+impl<O> Composable for Labelled<O> {}
@@ -1,9 +1,12 @@
-use rand::rngs::ThreadRng;
+use rand::RngCore;
 
+pub mod build_population;
+pub mod clamp_output;
 pub mod composable;
 pub mod genome_extractor;
 pub mod genome_scorer;
 pub mod identity;
+pub mod labelled;
 pub mod mutator;
 pub mod recombinator;
 pub mod selector;
@@ -18,5 +21,5 @@ pub trait Operator<Input>: Composable {
     /// This will return an error if there's some problem applying the operator.
     /// Given how general this concept is, there's no good way of saying here
     /// what that might be.
-    fn apply(&self, input: Input, rng: &mut ThreadRng) -> Result<Self::Output, Self::Error>;
+    fn apply(&self, input: Input, rng: &mut dyn RngCore) -> Result<Self::Output, Self::Error>;
 }
@@ -1,12 +1,24 @@
-use rand::rngs::ThreadRng;
+use rand::RngCore;
 
 use super::{Composable, Operator};
 
+pub mod scheduled;
+pub mod weighted;
+
 pub trait Mutator<G> {
     /// # Errors
     /// This can return an error if there is an error mutating the given
     /// genome.
-    fn mutate(&self, genome: G, rng: &mut ThreadRng) -> anyhow::Result<G>;
+    fn mutate(&self, genome: G, rng: &mut dyn RngCore) -> anyhow::Result<G>;
+}
+
+impl<G, M> Mutator<G> for &M
+where
+    M: Mutator<G> + ?Sized,
+{
+    fn mutate(&self, genome: G, rng: &mut dyn RngCore) -> anyhow::Result<G> {
+        (**self).mutate(genome, rng)
+    }
 }
 
 pub struct Mutate<M> {
@@ -26,7 +38,7 @@ where
     type Output = G;
     type Error = anyhow::Error;
 
-    fn apply(&self, genome: G, rng: &mut ThreadRng) -> Result<Self::Output, Self::Error> {
+    fn apply(&self, genome: G, rng: &mut dyn RngCore) -> Result<Self::Output, Self::Error> {
         self.mutator.mutate(genome, rng)
     }
 }
@@ -0,0 +1,172 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rand::RngCore;
+
+use super::Mutator;
+use crate::observer::GenerationObserver;
+
+/// A mutator whose rate changes over a run according to a schedule, e.g.,
+/// annealing a mutation rate from large to small.
+///
+/// The pipeline that makes children is built once, before the run starts, so
+/// the generation number can't be passed to the mutator. Instead a
+/// `Scheduled` mutator keeps track of the current generation itself (behind
+/// an atomic, so it can be shared across threads), and is advanced by using
+/// it as the [`GenerationObserver`] of the
+/// [`Generation`](crate::generation::Generation) it's used in. Pass it to
+/// both by reference, e.g.,
+///
+/// ```ignore
+/// let scheduled = Scheduled::new(|generation| 0.1 / (generation + 1) as f64, |rate| {
+///     gene_generator.umad(rate, rate)
+/// });
+/// let child_maker = Select::new(selector)
+///     .then(GenomeExtractor)
+///     .then(Mutate::new(&scheduled))
+///     .wrap::<GenomeScorer<_, _>>(scorer);
+/// let generation = Generation::new(child_maker, population).with_observer(&scheduled);
+/// ```
+///
+/// Each time it's used, this calls `make_mutator` with `schedule(n)` to
+/// build the mutator to apply, where `n` is the number of the generation
+/// the parents come from (zero for the initial population). So
+/// `make_mutator` should be cheap, e.g., a call to a mutator's constructor.
+pub struct Scheduled<S, F> {
+    schedule: S,
+    make_mutator: F,
+    generation: AtomicUsize,
+}
+
+impl<S, F> Scheduled<S, F>
+where
+    S: Fn(usize) -> f64,
+{
+    #[must_use]
+    pub const fn new(schedule: S, make_mutator: F) -> Self {
+        Self {
+            schedule,
+            make_mutator,
+            generation: AtomicUsize::new(0),
+        }
+    }
+
+    /// The generation whose individuals are currently being mutated.
+    #[must_use]
+    pub fn generation(&self) -> usize {
+        self.generation.load(Ordering::Relaxed)
+    }
+
+    /// Set the current generation by hand, e.g., for runs that don't use a
+    /// [`Generation`](crate::generation::Generation).
+    pub fn set_generation(&self, generation: usize) {
+        self.generation.store(generation, Ordering::Relaxed);
+    }
+
+    /// The rate for the current generation, according to the schedule.
+    #[must_use]
+    pub fn rate(&self) -> f64 {
+        (self.schedule)(self.generation())
+    }
+}
+
+impl<G, S, F, M> Mutator<G> for Scheduled<S, F>
+where
+    S: Fn(usize) -> f64,
+    F: Fn(f64) -> M,
+    M: Mutator<G>,
+{
+    fn mutate(&self, genome: G, rng: &mut dyn RngCore) -> anyhow::Result<G> {
+        (self.make_mutator)(self.rate()).mutate(genome, rng)
+    }
+}
+
+impl<P, S, F> GenerationObserver<P> for Scheduled<S, F>
+where
+    S: Fn(usize) -> f64,
+{
+    fn on_generation_complete(&self, generation_number: usize, _: &P) {
+        self.set_generation(generation_number);
+    }
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "The test mutators are infallible")]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::{
+        generation::Generation,
+        individual::{ec::EcIndividual, scorer::FnScorer},
+        operator::{
+            genome_extractor::GenomeExtractor,
+            genome_scorer::GenomeScorer,
+            mutator::Mutate,
+            selector::{best::Best, Select},
+            Composable,
+        },
+    };
+
+    const RATES: [f64; 4] = [0.5, 0.25, 0.125, 0.0625];
+
+    fn schedule(generation: usize) -> f64 {
+        RATES[generation.min(RATES.len().saturating_sub(1))]
+    }
+
+    // A mutator that leaves the genome alone, and records the rate it was
+    // made with in `rates`.
+    struct Recorded<'a> {
+        rate: f64,
+        rates: &'a Mutex<Vec<f64>>,
+    }
+
+    impl Mutator<i64> for Recorded<'_> {
+        fn mutate(&self, genome: i64, _: &mut dyn RngCore) -> anyhow::Result<i64> {
+            self.rates.lock().unwrap().push(self.rate);
+            Ok(genome)
+        }
+    }
+
+    #[test]
+    fn rate_follows_schedule() {
+        let mut rng = rand::thread_rng();
+        let rates = Mutex::new(Vec::new());
+        let scheduled = Scheduled::new(schedule, |rate| Recorded {
+            rate,
+            rates: &rates,
+        });
+        for generation in 0..6 {
+            scheduled.on_generation_complete(generation, &());
+            assert_eq!(scheduled.generation(), generation);
+            scheduled.mutate(0, &mut rng).unwrap();
+        }
+        // The schedule stays at its last rate.
+        assert_eq!(
+            rates.into_inner().unwrap(),
+            [0.5, 0.25, 0.125, 0.0625, 0.0625, 0.0625]
+        );
+    }
+
+    #[test]
+    fn generation_advances_the_schedule() {
+        let rates = Mutex::new(Vec::new());
+        let scheduled = Scheduled::new(schedule, |rate| Recorded {
+            rate,
+            rates: &rates,
+        });
+        let child_maker = Select::new(Best)
+            .then(GenomeExtractor)
+            .then(Mutate::new(&scheduled))
+            .wrap::<GenomeScorer<_, _>>(FnScorer(|genome: &i64| *genome));
+        let population = vec![EcIndividual::new(0, 0); 2];
+        let mut generation = Generation::new(child_maker, population).with_observer(&scheduled);
+        for _ in 0..3 {
+            generation.serial_next().unwrap();
+        }
+        assert_eq!(scheduled.generation(), 3);
+        assert_eq!(
+            rates.into_inner().unwrap(),
+            [0.5, 0.5, 0.25, 0.25, 0.125, 0.125]
+        );
+    }
+}
@@ -0,0 +1,175 @@
+use anyhow::{ensure, Context, Result};
+use rand::{seq::IndexedRandom, Rng, RngCore};
+
+use super::Mutator;
+
+/// How a [`WeightedMutators`] decides which of its mutators to apply.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MutatorChoice {
+    /// Each mutator is applied with its own probability, independently of
+    /// the others, in the order the mutators were added. A genome can be
+    /// mutated by several of the mutators, or by none of them.
+    #[default]
+    Independent,
+    /// Exactly one mutator is applied, chosen with probability proportional
+    /// to its weight.
+    OneByWeight,
+}
+
+/// A mutator that combines several mutators, e.g., applying UMAD 30% of the
+/// time and point mutation 10% of the time.
+///
+/// Each mutator has a number that's either a probability (with
+/// [`MutatorChoice::Independent`], the default) or a weight (with
+/// [`MutatorChoice::OneByWeight`]); see [`WeightedMutators::with_choice`].
+pub struct WeightedMutators<G> {
+    mutators: Vec<(Box<dyn Mutator<G> + Send + Sync>, f64)>,
+    choice: MutatorChoice,
+}
+
+impl<G> WeightedMutators<G> {
+    // As with the `Weighted` selector, `new` takes an initial mutator so
+    // `mutators` is never empty.
+    #[must_use]
+    pub fn new<M>(mutator: M, probability: f64) -> Self
+    where
+        M: Mutator<G> + Send + Sync + 'static,
+    {
+        Self {
+            mutators: vec![(Box::new(mutator), probability)],
+            choice: MutatorChoice::Independent,
+        }
+    }
+
+    #[must_use]
+    pub fn with_mutator<M>(mut self, mutator: M, probability: f64) -> Self
+    where
+        M: Mutator<G> + Send + Sync + 'static,
+    {
+        self.mutators.push((Box::new(mutator), probability));
+        self
+    }
+
+    /// Set how the mutators to apply are chosen. The default is
+    /// [`MutatorChoice::Independent`].
+    #[must_use]
+    pub const fn with_choice(mut self, choice: MutatorChoice) -> Self {
+        self.choice = choice;
+        self
+    }
+
+    #[must_use]
+    pub const fn choice(&self) -> MutatorChoice {
+        self.choice
+    }
+}
+
+impl<G> Mutator<G> for WeightedMutators<G> {
+    fn mutate(&self, genome: G, rng: &mut dyn RngCore) -> Result<G> {
+        ensure!(
+            self.mutators
+                .iter()
+                .all(|&(_, probability)| probability.is_finite() && probability >= 0.0),
+            "Mutator probabilities and weights must be finite and non-negative"
+        );
+        match self.choice {
+            MutatorChoice::Independent => {
+                self.mutators
+                    .iter()
+                    .try_fold(genome, |genome, (mutator, probability)| {
+                        // Probabilities of 0 and 1 don't use `rng`, so a
+                        // single mutator with probability 1 behaves exactly
+                        // like that mutator on its own.
+                        let apply = *probability >= 1.0
+                            || (*probability > 0.0 && rng.gen_bool(*probability));
+                        if apply {
+                            mutator.mutate(genome, rng)
+                        } else {
+                            Ok(genome)
+                        }
+                    })
+            }
+            MutatorChoice::OneByWeight => {
+                ensure!(
+                    self.mutators.iter().any(|&(_, weight)| weight > 0.0),
+                    "All the mutator weights were zero"
+                );
+                let (mutator, _) = self
+                    .mutators
+                    .choose_weighted(rng, |&(_, weight)| weight)
+                    .context("Failed to choose a mutator by weight")?;
+                mutator.mutate(genome, rng)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The test mutators are infallible, so only the weights can cause an error"
+)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    // Adds a random number in `0..100` to the genome.
+    struct AddRandom;
+
+    impl Mutator<u64> for AddRandom {
+        fn mutate(&self, genome: u64, rng: &mut dyn RngCore) -> Result<u64> {
+            Ok(genome.saturating_add(rng.gen_range(0..100)))
+        }
+    }
+
+    struct Double;
+
+    impl Mutator<u64> for Double {
+        fn mutate(&self, genome: u64, _: &mut dyn RngCore) -> Result<u64> {
+            Ok(genome.saturating_mul(2))
+        }
+    }
+
+    #[test]
+    fn single_certain_mutator_is_the_same_as_the_mutator() {
+        let mutators = WeightedMutators::new(AddRandom, 1.0);
+        let mut weighted_rng = StdRng::seed_from_u64(0);
+        let mut direct_rng = StdRng::seed_from_u64(0);
+        for genome in 0..100 {
+            assert_eq!(
+                mutators.mutate(genome, &mut weighted_rng).unwrap(),
+                AddRandom.mutate(genome, &mut direct_rng).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn independent_mutators_are_applied_in_order() {
+        let mutators = WeightedMutators::new(Double, 1.0)
+            .with_mutator(AddRandom, 0.0)
+            .with_mutator(Double, 1.0);
+        let mut rng = rand::thread_rng();
+        assert_eq!(mutators.mutate(3, &mut rng).unwrap(), 12);
+    }
+
+    #[test]
+    fn one_by_weight_applies_exactly_one_mutator() {
+        let mutators = WeightedMutators::new(Double, 1.0)
+            .with_mutator(Double, 3.0)
+            .with_choice(MutatorChoice::OneByWeight);
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            assert_eq!(mutators.mutate(3, &mut rng).unwrap(), 6);
+        }
+    }
+
+    #[test]
+    fn invalid_probabilities_are_errors() {
+        let mut rng = rand::thread_rng();
+        let mutators = WeightedMutators::new(Double, f64::NAN);
+        assert!(mutators.mutate(3, &mut rng).is_err());
+        let mutators = WeightedMutators::new(Double, 0.0).with_choice(MutatorChoice::OneByWeight);
+        assert!(mutators.mutate(3, &mut rng).is_err());
+    }
+}
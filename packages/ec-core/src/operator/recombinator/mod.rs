@@ -1,4 +1,4 @@
-use rand::rngs::ThreadRng;
+use rand::RngCore;
 
 use super::{Composable, Operator};
 
@@ -18,7 +18,7 @@ pub trait Recombinator<GS> {
     /// # Errors
     /// This will return an error if there's some problem with the
     /// recombination.
-    fn recombine(&self, genomes: GS, rng: &mut ThreadRng) -> anyhow::Result<Self::Output>;
+    fn recombine(&self, genomes: GS, rng: &mut dyn RngCore) -> anyhow::Result<Self::Output>;
 }
 
 pub struct Recombine<R> {
@@ -38,7 +38,7 @@ where
     type Output = R::Output;
     type Error = anyhow::Error;
 
-    fn apply(&self, genomes: G, rng: &mut ThreadRng) -> Result<Self::Output, Self::Error> {
+    fn apply(&self, genomes: G, rng: &mut dyn RngCore) -> Result<Self::Output, Self::Error> {
         self.recombinator.recombine(genomes, rng)
     }
 }
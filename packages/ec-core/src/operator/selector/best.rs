@@ -1,18 +1,41 @@
+use std::cmp::Ordering;
+
 use anyhow::{Context, Result};
-use rand::rngs::ThreadRng;
+use rand::RngCore;
 
 use super::Selector;
 use crate::population::Population;
 
+/// Selects the best individual, i.e., the largest one according to its
+/// [`Ord`] implementation.
+///
+/// For [`EcIndividual`](crate::individual::ec::EcIndividual) that compares
+/// the test results. Use [`Best::by`] to compare individuals some other way.
 pub struct Best;
 
+impl Best {
+    /// A selector that selects the best individual according to `compare`,
+    /// i.e., the individual that `compare` says is greater than (or equal
+    /// to) all the others. This makes it possible to, e.g., break ties in
+    /// the test results by genome size without wrapping the individuals in a
+    /// new type.
+    #[must_use]
+    pub const fn by<F>(compare: F) -> BestBy<F> {
+        BestBy { compare }
+    }
+}
+
 impl<P> Selector<P> for Best
 where
     P: Population,
     for<'pop> &'pop P: IntoIterator<Item = &'pop P::Individual>,
     P::Individual: Ord,
 {
-    fn select<'pop>(&self, population: &'pop P, _: &mut ThreadRng) -> Result<&'pop P::Individual> {
+    fn select<'pop>(
+        &self,
+        population: &'pop P,
+        _: &mut dyn RngCore,
+    ) -> Result<&'pop P::Individual> {
         population
             .into_iter()
             .max()
@@ -20,16 +43,86 @@ where
     }
 }
 
+/// A selector that selects the best individual according to a custom
+/// comparison function; see [`Best::by`].
+pub struct BestBy<F> {
+    compare: F,
+}
+
+impl<P, F> Selector<P> for BestBy<F>
+where
+    P: Population,
+    for<'pop> &'pop P: IntoIterator<Item = &'pop P::Individual>,
+    F: Fn(&P::Individual, &P::Individual) -> Ordering,
+{
+    fn select<'pop>(
+        &self,
+        population: &'pop P,
+        _: &mut dyn RngCore,
+    ) -> Result<&'pop P::Individual> {
+        population
+            .into_iter()
+            .max_by(|x, y| (self.compare)(x, y))
+            .context("The population was empty")
+    }
+}
+
 #[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "There's always a best individual in the non-empty test populations"
+)]
 mod tests {
     use super::*;
+    use crate::{
+        individual::ec::EcIndividual,
+        test_results::{Error, TestResults},
+    };
 
     #[test]
-    #[allow(clippy::unwrap_used)]
     fn can_select_twice() {
         let pop = vec![5, 8, 9, 6, 3, 2, 0];
         let mut rng = rand::thread_rng();
         assert_eq!(&9, Best.select(&pop, &mut rng).unwrap());
         assert_eq!(&9, Best.select(&pop, &mut rng).unwrap());
     }
+
+    #[test]
+    fn select_with_key_returns_best_results() {
+        let population: Vec<_> = [[1, 2], [5, 5], [0, 0], [9, 0]]
+            .into_iter()
+            .enumerate()
+            .map(|(genome, results)| EcIndividual::new(genome, TestResults::<i64>::from(results)))
+            .collect();
+        let mut rng = rand::thread_rng();
+        let (best, key) = Best.select_with_key(&population, &mut rng).unwrap();
+        assert_eq!(best.genome, 1);
+        assert_eq!(key, &best.test_results);
+        assert_eq!(key.total_result, 10);
+    }
+
+    #[test]
+    fn select_by_error_then_shorter_genome() {
+        type Individual = EcIndividual<Vec<i64>, TestResults<Error<i64>>>;
+        let population: Vec<Individual> = [
+            (vec![1, 2, 3], 4),
+            (vec![1], 2),
+            (vec![1, 2], 2),
+            (vec![], 7),
+        ]
+        .into_iter()
+        .map(|(genome, error)| EcIndividual::new(genome, TestResults::from([error])))
+        .collect();
+        let selector = Best::by(|x: &Individual, y: &Individual| {
+            x.test_results
+                .cmp(&y.test_results)
+                .then_with(|| y.genome.len().cmp(&x.genome.len()))
+        });
+        let mut rng = rand::thread_rng();
+        let best = selector.select(&population, &mut rng).unwrap();
+        assert_eq!(best.genome, [1]);
+        // Without the tie-breaking, the last of the individuals with the
+        // smallest error is selected.
+        assert_eq!(Best.select(&population, &mut rng).unwrap().genome, [1, 2]);
+    }
 }
@@ -0,0 +1,120 @@
+use std::sync::{Mutex, PoisonError};
+
+use anyhow::Result;
+use rand::RngCore;
+
+use super::Selector;
+use crate::population::Population;
+
+/// A selector wrapper that counts how many times each individual (by its
+/// index in the population) is selected by the wrapped selector.
+///
+/// The counts are kept behind a lock, so a `CountingSelector` can be shared
+/// by reference across the threads making a generation in parallel, e.g.,
+/// `Select::new(&counting_selector)`. Call [`CountingSelector::take_counts`]
+/// after each generation to get that generation's histogram and start
+/// counting afresh.
+pub struct CountingSelector<S> {
+    selector: S,
+    counts: Mutex<Vec<usize>>,
+}
+
+impl<S> CountingSelector<S> {
+    pub const fn new(selector: S) -> Self {
+        Self {
+            selector,
+            counts: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// The number of times each population index has been selected since the
+    /// counts were last taken. Indices past the end of the result have never
+    /// been selected.
+    #[must_use]
+    pub fn counts(&self) -> Vec<usize> {
+        self.lock_counts().clone()
+    }
+
+    /// Returns the current counts (as in [`CountingSelector::counts`]) and
+    /// resets them all to zero.
+    #[must_use]
+    pub fn take_counts(&self) -> Vec<usize> {
+        std::mem::take(&mut *self.lock_counts())
+    }
+
+    fn lock_counts(&self) -> std::sync::MutexGuard<'_, Vec<usize>> {
+        // The counts are always left in a consistent state, so it's safe to
+        // keep using them even if another thread panicked while holding the
+        // lock.
+        self.counts.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<P, S> Selector<P> for CountingSelector<S>
+where
+    P: Population + AsRef<[P::Individual]>,
+    S: Selector<P>,
+{
+    fn select<'pop>(
+        &self,
+        population: &'pop P,
+        rng: &mut dyn RngCore,
+    ) -> Result<&'pop P::Individual> {
+        let selected = self.selector.select(population, rng)?;
+        if let Some(index) = population
+            .as_ref()
+            .iter()
+            .position(|individual| std::ptr::eq(individual, selected))
+        {
+            let mut counts = self.lock_counts();
+            if counts.len() <= index {
+                counts.resize(population.size().max(index.saturating_add(1)), 0);
+            }
+            counts[index] = counts[index].saturating_add(1);
+        }
+        Ok(selected)
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "Selecting from the non-empty test population can't fail"
+)]
+mod tests {
+    use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+
+    use super::*;
+    use crate::operator::selector::{best::Best, random::Random};
+
+    #[test]
+    fn counts_sum_to_number_of_selections() {
+        let population: Vec<i32> = (0..10).collect();
+        let selector = CountingSelector::new(Random);
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            selector.select(&population, &mut rng).unwrap();
+        }
+        (0..1_000)
+            .into_par_iter()
+            .for_each_init(rand::thread_rng, |rng, _| {
+                selector.select(&population, rng).unwrap();
+            });
+
+        let counts = selector.take_counts();
+        assert_eq!(counts.len(), population.len());
+        assert_eq!(counts.iter().sum::<usize>(), 1_100);
+        assert!(selector.counts().is_empty());
+    }
+
+    #[test]
+    fn counts_selected_index() {
+        let population = vec![3, 9, 1];
+        let selector = CountingSelector::new(Best);
+        let mut rng = rand::thread_rng();
+        for _ in 0..5 {
+            selector.select(&population, &mut rng).unwrap();
+        }
+        assert_eq!(selector.counts(), [0, 5, 0]);
+    }
+}
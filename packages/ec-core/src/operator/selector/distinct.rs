@@ -0,0 +1,136 @@
+use anyhow::{anyhow, ensure, Result};
+use rand::RngCore;
+
+use super::{Select, Selector};
+use crate::{
+    operator::{Composable, Operator},
+    population::Population,
+};
+
+/// An operator that uses a selector to select `N` _distinct_ individuals
+/// from a population, e.g., to get two genuinely different parents for a
+/// recombinator.
+///
+/// The wrapped selector is called repeatedly, and any individual that has
+/// already been chosen (the same individual in the population, not just an
+/// equal one) is rejected. Since a selector may keep returning the same
+/// individual (e.g., [`Best`](super::best::Best)), the number of calls to
+/// the wrapped selector is limited, and an error is returned if `N` distinct
+/// individuals haven't been found by then.
+///
+/// The result is an array of `N` individuals, so, e.g.,
+/// `Select::new(selector).distinct::<2>().then_map(GenomeExtractor)` can
+/// replace `Select::new(selector).apply_twice().then_map(GenomeExtractor)`.
+#[derive(Clone)]
+pub struct DistinctN<S, const N: usize> {
+    selector: S,
+    max_attempts: usize,
+}
+
+impl<S, const N: usize> DistinctN<S, N> {
+    /// The default number of calls to the wrapped selector for each
+    /// individual that is selected.
+    pub const DEFAULT_ATTEMPTS_PER_INDIVIDUAL: usize = 100;
+
+    pub const fn new(selector: S) -> Self {
+        Self {
+            selector,
+            max_attempts: N.saturating_mul(Self::DEFAULT_ATTEMPTS_PER_INDIVIDUAL),
+        }
+    }
+
+    /// Set the maximum total number of calls to the wrapped selector for
+    /// each application of this operator.
+    #[must_use]
+    pub const fn with_max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+}
+
+impl<S> Select<S> {
+    /// Select `N` distinct individuals with this selector; see [`DistinctN`].
+    pub fn distinct<const N: usize>(self) -> DistinctN<S, N> {
+        DistinctN::new(self.selector)
+    }
+}
+
+impl<'pop, P, S, const N: usize> Operator<&'pop P> for DistinctN<S, N>
+where
+    P: Population,
+    S: Selector<P>,
+{
+    type Output = [&'pop P::Individual; N];
+    type Error = anyhow::Error;
+
+    fn apply(&self, population: &'pop P, rng: &mut dyn RngCore) -> Result<Self::Output> {
+        ensure!(
+            population.size() >= N,
+            "Can't select {N} distinct individuals from a population of size {}",
+            population.size()
+        );
+        let mut selected: Vec<&'pop P::Individual> = Vec::with_capacity(N);
+        for _ in 0..self.max_attempts {
+            if selected.len() == N {
+                break;
+            }
+            let individual = self.selector.select(population, rng)?;
+            if !selected
+                .iter()
+                .any(|chosen| std::ptr::eq(*chosen, individual))
+            {
+                selected.push(individual);
+            }
+        }
+        let num_selected = selected.len();
+        selected.try_into().map_err(|_| {
+            anyhow!(
+                "Only found {num_selected} of {N} distinct individuals in {} attempts",
+                self.max_attempts
+            )
+        })
+    }
+}
+impl<S, const N: usize> Composable for DistinctN<S, N> {}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The population has enough distinct individuals for these selections"
+)]
+mod tests {
+    use super::*;
+    use crate::operator::selector::{best::Best, random::Random};
+
+    #[test]
+    fn selects_distinct_individuals() {
+        // Equal values are different individuals, so both 1s can be selected.
+        let population = vec![1, 1, 2];
+        let operator = Select::new(Random).distinct::<3>();
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let mut selected = operator.apply(&population, &mut rng).unwrap();
+            selected.sort_unstable();
+            assert_eq!(selected, [&1, &1, &2]);
+        }
+    }
+
+    #[test]
+    fn population_too_small() {
+        let population = vec![1, 2];
+        let mut rng = rand::thread_rng();
+        assert!(DistinctN::<_, 3>::new(Random)
+            .apply(&population, &mut rng)
+            .is_err());
+    }
+
+    #[test]
+    fn deterministic_selector_runs_out_of_attempts() {
+        let population = vec![1, 2, 3];
+        let mut rng = rand::thread_rng();
+        assert!(DistinctN::<_, 2>::new(Best)
+            .with_max_attempts(10)
+            .apply(&population, &mut rng)
+            .is_err());
+    }
+}
@@ -0,0 +1,157 @@
+use std::sync::{PoisonError, RwLock};
+
+use anyhow::{ensure, Context, Result};
+use num_traits::ToPrimitive;
+use rand::{
+    prelude::{IndexedRandom, SliceRandom},
+    RngCore,
+};
+
+use super::{lexicase::filter_on_cases, Selector};
+use crate::{individual::Individual, population::Population, test_results::TestResults};
+
+/// Lexicase selection that only filters on a random subset (a "down-sample")
+/// of the test cases, which can make selection much cheaper when there are
+/// a lot of test cases.
+///
+/// `num_test_cases` is the total number of test cases, exactly as for
+/// [`Lexicase::new`](super::lexicase::Lexicase::new), and individuals must
+/// still have results for all of them; the sample is a set of indices into
+/// those results. (This only reduces the cost of selection. Reducing the cost
+/// of scoring would require the scorer to use the same sample.)
+///
+/// The same sample is used for every selection until
+/// [`DownSampledLexicase::resample`] is called, which should typically happen
+/// once per generation. Since `resample` only needs a shared reference, the
+/// selector can be shared with the child maker, e.g.,
+/// `Select::new(&down_sampled_lexicase)`, and resampled between calls to
+/// `par_next` or `serial_next`.
+pub struct DownSampledLexicase {
+    num_test_cases: usize,
+    sample_size: usize,
+    sample: RwLock<Vec<usize>>,
+}
+
+impl DownSampledLexicase {
+    /// Create a down-sampled lexicase selector that uses `sample_size` of the
+    /// `num_test_cases` test cases. The sample size is limited to the number of
+    /// test cases. An initial sample is drawn using `rng`.
+    #[must_use]
+    pub fn new(num_test_cases: usize, sample_size: usize, rng: &mut dyn RngCore) -> Self {
+        let selector = Self {
+            num_test_cases,
+            sample_size: sample_size.min(num_test_cases),
+            sample: RwLock::new(Vec::new()),
+        };
+        selector.resample(rng);
+        selector
+    }
+
+    /// Create a down-sampled lexicase selector that uses the proportion
+    /// `sample_rate` of the `num_test_cases` test cases (rounded up, so that
+    /// at least one test case is used whenever `sample_rate` is positive).
+    #[must_use]
+    pub fn with_sample_rate(
+        num_test_cases: usize,
+        sample_rate: f64,
+        rng: &mut dyn RngCore,
+    ) -> Self {
+        let sample_size = num_test_cases
+            .to_f64()
+            .and_then(|n| (n * sample_rate.clamp(0.0, 1.0)).ceil().to_usize())
+            .unwrap_or(num_test_cases);
+        Self::new(num_test_cases, sample_size, rng)
+    }
+
+    /// Draw a new random sample of the test cases, which will be used by all
+    /// later selections.
+    pub fn resample(&self, rng: &mut dyn RngCore) {
+        let sample = rand::seq::index::sample(rng, self.num_test_cases, self.sample_size);
+        *self.sample.write().unwrap_or_else(PoisonError::into_inner) = sample.into_vec();
+    }
+
+    /// The indices of the test cases in the current sample.
+    #[must_use]
+    pub fn sample(&self) -> Vec<usize> {
+        self.sample
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+impl<P, R> Selector<P> for DownSampledLexicase
+where
+    P: Population,
+    for<'pop> &'pop P: IntoIterator<Item = &'pop P::Individual>,
+    P::Individual: Individual<TestResults = TestResults<R>>,
+    R: Ord,
+{
+    fn select<'pop>(
+        &self,
+        population: &'pop P,
+        rng: &mut dyn RngCore,
+    ) -> Result<&'pop P::Individual> {
+        let mut case_indices = self.sample();
+        case_indices.shuffle(rng);
+        let candidates: Vec<_> = population.into_iter().collect();
+        ensure!(!candidates.is_empty(), "The population was empty");
+        filter_on_cases(candidates, case_indices)
+            .choose(rng)
+            .copied()
+            .context("The pool of candidates was empty")
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "Selecting from the non-empty test population can't fail"
+)]
+mod tests {
+    use super::*;
+    use crate::individual::ec::EcIndividual;
+
+    #[test]
+    fn only_sampled_cases_are_used() {
+        // Individual `i` is the unique best on test case `i` and the worst on
+        // every other case, so whoever is selected tells us which case lexicase
+        // filtered on first.
+        let num_test_cases = 10;
+        let population: Vec<EcIndividual<usize, TestResults<i32>>> = (0..num_test_cases)
+            .map(|i| {
+                let results = (0..num_test_cases).map(|case| i32::from(case == i));
+                EcIndividual::new(i, results.into())
+            })
+            .collect();
+        let mut rng = rand::thread_rng();
+        let selector = DownSampledLexicase::new(num_test_cases, 3, &mut rng);
+
+        for _ in 0..5 {
+            let sample = selector.sample();
+            assert_eq!(sample.len(), 3);
+            for _ in 0..50 {
+                let selected = selector.select(&population, &mut rng).unwrap();
+                assert!(sample.contains(&selected.genome));
+            }
+            selector.resample(&mut rng);
+        }
+    }
+
+    #[test]
+    fn sample_rate_rounds_up() {
+        let mut rng = rand::thread_rng();
+        assert_eq!(
+            DownSampledLexicase::with_sample_rate(10, 0.25, &mut rng)
+                .sample()
+                .len(),
+            3
+        );
+        assert_eq!(
+            DownSampledLexicase::with_sample_rate(10, 2.0, &mut rng)
+                .sample()
+                .len(),
+            10
+        );
+    }
+}
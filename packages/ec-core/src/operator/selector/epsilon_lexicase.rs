@@ -0,0 +1,222 @@
+use std::cmp::Ordering;
+
+use anyhow::Result;
+use rand::{
+    prelude::{IndexedRandom, SliceRandom},
+    RngCore,
+};
+
+use super::{EmptyPopulation, Selector};
+use crate::{
+    individual::Individual,
+    population::Population,
+    test_results::{NumericResult, TestResults},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Epsilon {
+    MedianAbsoluteDeviation,
+    Fixed(f64),
+}
+
+/// Lexicase selection where, on each test case, every candidate within
+/// epsilon of the best result on that case survives.
+///
+/// Plain [`Lexicase`](super::lexicase::Lexicase) only keeps the candidates
+/// that exactly tie with the best. For things like floating-point errors,
+/// exact ties are rare, so plain lexicase tends to come down to a single
+/// test case.
+pub struct EpsilonLexicase {
+    num_test_cases: usize,
+    epsilon: Epsilon,
+}
+
+impl EpsilonLexicase {
+    /// Create an epsilon-lexicase selector where the epsilon for each test
+    /// case is the median absolute deviation of the population's results on
+    /// that case.
+    #[must_use]
+    pub const fn new(num_test_cases: usize) -> Self {
+        Self {
+            num_test_cases,
+            epsilon: Epsilon::MedianAbsoluteDeviation,
+        }
+    }
+
+    /// Use the same fixed `epsilon` for every test case instead of computing
+    /// one from the population.
+    #[must_use]
+    pub const fn with_epsilon(mut self, epsilon: f64) -> Self {
+        self.epsilon = Epsilon::Fixed(epsilon);
+        self
+    }
+
+    fn epsilons<I, R>(&self, population: &[&I]) -> Vec<f64>
+    where
+        I: Individual<TestResults = TestResults<R>>,
+        R: NumericResult,
+    {
+        match self.epsilon {
+            Epsilon::Fixed(epsilon) => vec![epsilon; self.num_test_cases],
+            Epsilon::MedianAbsoluteDeviation => (0..self.num_test_cases)
+                .map(|test_case_index| {
+                    let values = population
+                        .iter()
+                        .filter_map(|i| i.test_results().results[test_case_index].to_f64())
+                        .collect();
+                    median_absolute_deviation(values).unwrap_or(0.0)
+                })
+                .collect(),
+        }
+    }
+
+    /// Filter `population` down to the individuals that survive
+    /// epsilon-lexicase filtering on a random ordering of the test cases,
+    /// i.e., the set that [`EpsilonLexicase`]'s `select` would pick from at
+    /// random. This is empty if `population` is empty.
+    #[must_use]
+    pub fn survivors<'pop, I, R>(
+        &self,
+        population: impl IntoIterator<Item = &'pop I>,
+        rng: &mut dyn RngCore,
+    ) -> Vec<&'pop I>
+    where
+        I: Individual<TestResults = TestResults<R>> + 'pop,
+        R: PartialOrd + NumericResult,
+    {
+        let mut candidates: Vec<_> = population.into_iter().collect();
+        let epsilons = self.epsilons(&candidates);
+
+        let mut case_indices: Vec<usize> = (0..self.num_test_cases).collect();
+        case_indices.shuffle(rng);
+
+        for test_case_index in case_indices {
+            if candidates.len() <= 1 {
+                break;
+            }
+            let Some(best) = candidates
+                .iter()
+                .map(|&c| &c.test_results().results[test_case_index])
+                .max_by(|x, y| x.partial_cmp(y).unwrap_or(Ordering::Equal))
+            else {
+                break;
+            };
+            let best_value = best.to_f64();
+            let epsilon = epsilons[test_case_index];
+            candidates.retain(|c| {
+                let result = &c.test_results().results[test_case_index];
+                result == best
+                    || matches!(
+                        (result.to_f64(), best_value),
+                        (Some(x), Some(b)) if (x - b).abs() <= epsilon
+                    )
+            });
+        }
+
+        candidates
+    }
+}
+
+impl<P, R> Selector<P> for EpsilonLexicase
+where
+    P: Population,
+    for<'pop> &'pop P: IntoIterator<Item = &'pop P::Individual>,
+    P::Individual: Individual<TestResults = TestResults<R>>,
+    R: PartialOrd + NumericResult,
+{
+    fn select<'pop>(
+        &self,
+        population: &'pop P,
+        rng: &mut dyn RngCore,
+    ) -> Result<&'pop P::Individual> {
+        let candidates = self.survivors(population, rng);
+        Ok(candidates.choose(rng).copied().ok_or(EmptyPopulation)?)
+    }
+}
+
+fn median(values: &mut [f64]) -> Option<f64> {
+    values.sort_unstable_by(f64::total_cmp);
+    let middle = values.len() / 2;
+    if values.len().is_multiple_of(2) {
+        Some((values.get(middle.checked_sub(1)?)? + values.get(middle)?) / 2.0)
+    } else {
+        values.get(middle).copied()
+    }
+}
+
+fn median_absolute_deviation(mut values: Vec<f64>) -> Option<f64> {
+    let center = median(&mut values)?;
+    for value in &mut values {
+        *value = (*value - center).abs();
+    }
+    median(&mut values)
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The tests only unwrap selections from non-empty populations"
+)]
+mod tests {
+    use super::*;
+    use crate::{
+        individual::ec::EcIndividual, operator::selector::lexicase::Lexicase, test_results::Error,
+    };
+
+    type TestIndividual = EcIndividual<&'static str, TestResults<Error<i64>>>;
+
+    fn survivor_names(survivors: Vec<&TestIndividual>) -> Vec<&'static str> {
+        let mut names: Vec<_> = survivors.into_iter().map(|i| i.genome).collect();
+        names.sort_unstable();
+        names
+    }
+
+    #[test]
+    fn near_ties_survive_with_fixed_epsilon() {
+        let population: Vec<TestIndividual> = vec![
+            EcIndividual::new("a", [10].into()),
+            EcIndividual::new("b", [11].into()),
+            EcIndividual::new("c", [20].into()),
+        ];
+        let mut rng = rand::thread_rng();
+        let lexicase = Lexicase::new(1);
+        let epsilon_lexicase = EpsilonLexicase::new(1).with_epsilon(2.0);
+        for _ in 0..10 {
+            assert_eq!(
+                survivor_names(lexicase.survivors(&population, &mut rng)),
+                ["a"]
+            );
+            assert_eq!(
+                survivor_names(epsilon_lexicase.survivors(&population, &mut rng)),
+                ["a", "b"]
+            );
+        }
+    }
+
+    #[test]
+    fn median_absolute_deviation_epsilon() {
+        // The median is 1.5, and the median absolute deviation is 1.0.
+        let population: Vec<TestIndividual> = vec![
+            EcIndividual::new("a", [0].into()),
+            EcIndividual::new("b", [1].into()),
+            EcIndividual::new("c", [2].into()),
+            EcIndividual::new("d", [100].into()),
+        ];
+        let mut rng = rand::thread_rng();
+        let selector = EpsilonLexicase::new(1);
+        assert_eq!(
+            survivor_names(selector.survivors(&population, &mut rng)),
+            ["a", "b"]
+        );
+    }
+
+    #[test]
+    fn empty_population_is_an_error() {
+        let population: Vec<TestIndividual> = Vec::new();
+        let mut rng = rand::thread_rng();
+        let error = EpsilonLexicase::new(1)
+            .select(&population, &mut rng)
+            .unwrap_err();
+        assert_eq!(error.downcast_ref(), Some(&EmptyPopulation));
+    }
+}
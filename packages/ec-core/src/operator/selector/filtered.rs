@@ -0,0 +1,92 @@
+use anyhow::{ensure, Result};
+use rand::RngCore;
+
+use super::Selector;
+use crate::population::Population;
+
+/// A selector wrapper that only lets the wrapped selector choose from the
+/// individuals that satisfy `predicate`.
+///
+/// This can be used, e.g., to only select individuals whose genomes are
+/// below some length, or that pass a hard feasibility test.
+///
+/// The wrapped selector sees a view of the population, a `Vec` of
+/// references to the individuals that pass, so it needs to be a selector
+/// for `Vec<&Individual>`; that's the case for, e.g.,
+/// [`Best`](super::best::Best) and [`Random`](super::random::Random). An
+/// error is returned if no individual satisfies the predicate.
+pub struct Filtered<S, F> {
+    selector: S,
+    predicate: F,
+}
+
+impl<S, F> Filtered<S, F> {
+    pub const fn new(selector: S, predicate: F) -> Self {
+        Self {
+            selector,
+            predicate,
+        }
+    }
+}
+
+impl<P, S, F> Selector<P> for Filtered<S, F>
+where
+    P: Population,
+    for<'pop> &'pop P: IntoIterator<Item = &'pop P::Individual>,
+    S: for<'pop> Selector<Vec<&'pop P::Individual>>,
+    F: Fn(&P::Individual) -> bool,
+{
+    fn select<'pop>(
+        &self,
+        population: &'pop P,
+        rng: &mut dyn RngCore,
+    ) -> Result<&'pop P::Individual> {
+        let passing: Vec<&'pop P::Individual> = population
+            .into_iter()
+            .filter(|individual| (self.predicate)(individual))
+            .collect();
+        ensure!(
+            !passing.is_empty(),
+            "None of the {} individuals in the population satisfied the filter's predicate",
+            population.size()
+        );
+        self.selector.select(&passing, rng).copied()
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "Some individual passes the filter in the tests that unwrap"
+)]
+mod tests {
+    use super::*;
+    use crate::operator::selector::{best::Best, random::Random};
+
+    #[test]
+    fn selects_best_of_the_remainder() {
+        let population = vec![5, 8, 9, 6, 3, 2, 0];
+        let mut rng = rand::thread_rng();
+        // The true best, 9, is filtered out.
+        let selector = Filtered::new(Best, |&x: &i32| x < 9);
+        assert_eq!(selector.select(&population, &mut rng).unwrap(), &8);
+    }
+
+    #[test]
+    fn only_selects_passing_individuals() {
+        let population = vec![5, 8, 9, 6, 3, 2, 0];
+        let mut rng = rand::thread_rng();
+        let selector = Filtered::new(Random, |&x: &i32| x < 5);
+        for _ in 0..100 {
+            assert!([3, 2, 0].contains(selector.select(&population, &mut rng).unwrap()));
+        }
+    }
+
+    #[test]
+    fn error_if_nothing_passes() {
+        let population = vec![5, 8, 9];
+        let mut rng = rand::thread_rng();
+        let selector = Filtered::new(Best, |&x: &i32| x > 10);
+        assert!(selector.select(&population, &mut rng).is_err());
+    }
+}
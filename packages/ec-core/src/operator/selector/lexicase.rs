@@ -1,7 +1,7 @@
 use std::{mem::swap, ops::Not};
 
 use anyhow::{Context, Result};
-use rand::{prelude::SliceRandom, rngs::ThreadRng};
+use rand::{prelude::SliceRandom, RngCore};
 
 use super::Selector;
 use crate::{individual::Individual, population::Population, test_results::TestResults};
@@ -15,6 +15,80 @@ impl Lexicase {
     pub const fn new(num_test_cases: usize) -> Self {
         Self { num_test_cases }
     }
+
+    /// Filter `population` down to the individuals that survive lexicase
+    /// filtering on a random ordering of the test cases, i.e., the set
+    /// that [`Lexicase`]'s `select` would pick from at random.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `population` is empty and there are test cases.
+    #[must_use]
+    pub fn survivors<'pop, I, R>(
+        &self,
+        population: impl IntoIterator<Item = &'pop I>,
+        rng: &mut dyn RngCore,
+    ) -> Vec<&'pop I>
+    where
+        I: Individual<TestResults = TestResults<R>> + 'pop,
+        R: Ord,
+    {
+        let mut case_indices: Vec<usize> = (0..self.num_test_cases).collect();
+        case_indices.shuffle(rng);
+        filter_on_cases(population.into_iter().collect(), case_indices)
+    }
+}
+
+/// Filter `candidates` down to those that are the best on each of the test
+/// cases in `case_indices` in turn, stopping early if only one candidate is
+/// left.
+///
+/// # Panics
+///
+/// This panics if `candidates` is empty and there are test cases.
+pub(super) fn filter_on_cases<I, R>(
+    mut candidates: Vec<&I>,
+    case_indices: impl IntoIterator<Item = usize>,
+) -> Vec<&I>
+where
+    I: Individual<TestResults = TestResults<R>>,
+    R: Ord,
+{
+    // Candidate set is initially the whole population.
+    // For each test in turn:
+    //   * Find the best score of any individual still in in the candidate set on
+    //     that test case.
+    //   * Remove any individual from the candidate set that is worse than that best
+    //     score on that test case.
+    // Go until you get to a single individual or you run
+    // out of test cases.
+    let mut winners = Vec::with_capacity(candidates.len());
+    for test_case_index in case_indices {
+        assert!(
+            candidates.is_empty().not(),
+            "The set of lexicase candidates shouldn't be empty"
+        );
+        if candidates.len() == 1 {
+            break;
+        }
+        winners.clear();
+        winners.push(candidates[0]);
+        for c in &candidates[1..] {
+            if c.test_results().results[test_case_index]
+                > winners[0].test_results().results[test_case_index]
+            {
+                winners.clear();
+                winners.push(c);
+            } else if c.test_results().results[test_case_index]
+                == winners[0].test_results().results[test_case_index]
+            {
+                winners.push(c);
+            }
+        }
+        swap(&mut candidates, &mut winners);
+    }
+
+    candidates
 }
 
 impl<P, R> Selector<P> for Lexicase
@@ -37,51 +111,9 @@ where
     fn select<'pop>(
         &self,
         population: &'pop P,
-        rng: &mut ThreadRng,
+        rng: &mut dyn RngCore,
     ) -> Result<&'pop P::Individual> {
-        // Candidate set is initially the whole population.
-        // Shuffle the (indices of the) test cases.
-        // For each test in turn:
-        //   * Find the best score of any individual still in in the candidate set on
-        //     that test case.
-        //   * Remove any individual from the candidate set that is worse than that best
-        //     score on that test case.
-        // Go until you get to a single individual or you run
-        // out of test cases.
-        let mut case_indices: Vec<usize> = (0..self.num_test_cases).collect();
-        case_indices.shuffle(rng);
-
-        let mut candidates: Vec<_> = population.into_iter().collect();
-
-        let mut winners = Vec::with_capacity(candidates.len());
-        for test_case_index in case_indices {
-            assert!(
-                candidates.is_empty().not(),
-                "The set of lexicase candidates shouldn't be empty"
-            );
-            if candidates.len() == 1 {
-                break;
-            }
-            winners.clear();
-            winners.push(candidates[0]);
-            for c in &candidates[1..] {
-                // I find the `if-else` to be easier to read than Clippy's preferred
-                // use of `match`.
-                #[allow(clippy::comparison_chain)]
-                if c.test_results().results[test_case_index]
-                    > winners[0].test_results().results[test_case_index]
-                {
-                    winners.clear();
-                    winners.push(c);
-                } else if c.test_results().results[test_case_index]
-                    == winners[0].test_results().results[test_case_index]
-                {
-                    winners.push(c);
-                }
-            }
-            swap(&mut candidates, &mut winners);
-        }
-
+        let mut candidates = self.survivors(population, rng);
         candidates.shuffle(rng);
         candidates
             .first()
@@ -0,0 +1,90 @@
+use anyhow::Result;
+use rand::RngCore;
+
+use super::{lexicase::Lexicase, tournament::Tournament, Selector};
+use crate::{individual::Individual, population::Population, test_results::TestResults};
+
+/// A hybrid of lexicase and tournament selection.
+///
+/// This first uses lexicase filtering to find the surviving candidates, and
+/// then runs a tournament among those survivors instead of picking one of
+/// them at random. This adds some additional selection pressure towards
+/// individuals with better overall results.
+///
+/// If there are fewer survivors than `tournament_size`, then all the
+/// survivors are in the tournament, so the best of them is selected.
+pub struct LexicaseTournament {
+    lexicase: Lexicase,
+    tournament_size: usize,
+}
+
+impl LexicaseTournament {
+    #[must_use]
+    pub const fn new(num_test_cases: usize, tournament_size: usize) -> Self {
+        Self {
+            lexicase: Lexicase::new(num_test_cases),
+            tournament_size,
+        }
+    }
+}
+
+impl<P, R> Selector<P> for LexicaseTournament
+where
+    P: Population,
+    for<'pop> &'pop P: IntoIterator<Item = &'pop P::Individual>,
+    P::Individual: Individual<TestResults = TestResults<R>> + Ord,
+    R: Ord,
+{
+    fn select<'pop>(
+        &self,
+        population: &'pop P,
+        rng: &mut dyn RngCore,
+    ) -> Result<&'pop P::Individual> {
+        let survivors = self.lexicase.survivors(population, rng);
+        Tournament::new(self.tournament_size.min(survivors.len()))
+            .select(&survivors, rng)
+            .copied()
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "Selecting from the non-empty test population can't fail"
+)]
+mod tests {
+    use super::*;
+    use crate::individual::ec::EcIndividual;
+
+    #[test]
+    fn winner_is_a_lexicase_survivor() {
+        // "a" and "b" are the only individuals that are at least as good as
+        // everyone else on every test case, so they're always the only
+        // lexicase survivors whatever the order of the test cases.
+        let population: Vec<EcIndividual<&str, TestResults<i32>>> = vec![
+            EcIndividual::new("c", [1, 9, 1].into()),
+            EcIndividual::new("a", [5, 9, 5].into()),
+            EcIndividual::new("d", [4, 9, 5].into()),
+            EcIndividual::new("b", [5, 9, 5].into()),
+            EcIndividual::new("e", [5, 2, 5].into()),
+        ];
+        let mut rng = rand::thread_rng();
+        let lexicase = Lexicase::new(3);
+        for _ in 0..100 {
+            let mut survivors: Vec<_> = lexicase
+                .survivors(&population, &mut rng)
+                .into_iter()
+                .map(|individual| individual.genome)
+                .collect();
+            survivors.sort_unstable();
+            assert_eq!(survivors, ["a", "b"]);
+        }
+        for tournament_size in 1..=3 {
+            let selector = LexicaseTournament::new(3, tournament_size);
+            for _ in 0..100 {
+                let winner = selector.select(&population, &mut rng).unwrap();
+                assert!(["a", "b"].contains(&winner.genome));
+            }
+        }
+    }
+}
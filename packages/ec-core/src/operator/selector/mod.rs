@@ -1,14 +1,26 @@
 use anyhow::Result;
-use rand::rngs::ThreadRng;
+use rand::RngCore;
 
 use super::{Composable, Operator};
-use crate::population::Population;
+use crate::{individual::Individual, population::Population};
 
 pub mod best;
+pub mod counting;
+pub mod distinct;
+pub mod down_sampled_lexicase;
+pub mod epsilon_lexicase;
+pub mod filtered;
 pub mod lexicase;
+pub mod lexicase_tournament;
+pub mod nsga2;
 pub mod random;
+pub mod rank;
+pub mod roulette;
+pub mod top_k;
 pub mod tournament;
+pub mod track_parents;
 pub mod weighted;
+pub mod worst;
 
 pub trait Selector<P>
 where
@@ -18,10 +30,49 @@ where
     /// This will return an error if there's some problem selecting. That will
     /// usually be because the population is empty or not large enough for
     /// the desired selector.
-    fn select<'pop>(&self, population: &'pop P, rng: &mut ThreadRng)
-    -> Result<&'pop P::Individual>;
+    fn select<'pop>(
+        &self,
+        population: &'pop P,
+        rng: &mut dyn RngCore,
+    ) -> Result<&'pop P::Individual>;
+
+    /// Select an individual as with [`Selector::select`], also returning a
+    /// reference to its test results, which are what selectors compare
+    /// individuals by (e.g., the `total_result` for
+    /// [`Best`](best::Best)). This saves going back to the selected
+    /// individual for its results, e.g., to log the best total result.
+    ///
+    /// # Errors
+    /// This will return an error if [`Selector::select`] does.
+    fn select_with_key<'pop>(
+        &self,
+        population: &'pop P,
+        rng: &mut dyn RngCore,
+    ) -> Result<(
+        &'pop P::Individual,
+        &'pop <P::Individual as Individual>::TestResults,
+    )>
+    where
+        P::Individual: Individual,
+    {
+        let individual = self.select(population, rng)?;
+        Ok((individual, individual.test_results()))
+    }
 }
 
+/// The error returned by selectors that were asked to select from an empty
+/// population.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmptyPopulation;
+
+impl std::fmt::Display for EmptyPopulation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "The population was empty")
+    }
+}
+
+impl std::error::Error for EmptyPopulation {}
+
 #[derive(Clone)]
 pub struct Select<S> {
     selector: S,
@@ -41,7 +92,11 @@ where
     type Output = &'pop P::Individual;
     type Error = anyhow::Error;
 
-    fn apply(&self, population: &'pop P, rng: &mut ThreadRng) -> Result<Self::Output, Self::Error> {
+    fn apply(
+        &self,
+        population: &'pop P,
+        rng: &mut dyn RngCore,
+    ) -> Result<Self::Output, Self::Error> {
         self.selector.select(population, rng)
     }
 }
@@ -55,7 +110,7 @@ where
     fn select<'pop>(
         &self,
         population: &'pop P,
-        rng: &mut ThreadRng,
+        rng: &mut dyn RngCore,
     ) -> Result<&'pop P::Individual> {
         (*self).select(population, rng)
     }
@@ -0,0 +1,255 @@
+//! Multi-objective selection with NSGA-II.
+//!
+//! Instead of combining an individual's results into a single total, NSGA-II
+//! treats each result as a separate objective. Individuals are sorted into
+//! Pareto fronts (see [`non_dominated_sort`]), where no individual in a front
+//! is dominated by any other individual in that front or a later one, and
+//! individuals within a front are compared by their
+//! [crowding distances](crowding_distances), which favors individuals in
+//! sparsely populated parts of the front.
+
+mod sorting;
+
+use std::cmp::Ordering;
+
+use anyhow::{ensure, Context, Result};
+use rand::RngCore;
+
+pub use self::sorting::{crowding_distances, dominates, non_dominated_sort};
+use super::Selector;
+use crate::{
+    individual::Individual,
+    population::Population,
+    test_results::{NumericResult, TestResults},
+};
+
+/// Test results that can be treated as a vector of objectives, where (as
+/// with the results themselves) bigger is better.
+pub trait MultiObjective {
+    type Objective: PartialOrd + NumericResult;
+
+    fn objectives(&self) -> &[Self::Objective];
+}
+
+/// Each test case is a separate objective.
+impl<R> MultiObjective for TestResults<R>
+where
+    R: PartialOrd + NumericResult,
+{
+    type Objective = R;
+
+    fn objectives(&self) -> &[R] {
+        &self.results
+    }
+}
+
+/// NSGA-II's selection: a tournament where the winner is the contestant in
+/// the earliest Pareto front, with ties broken in favor of the larger
+/// crowding distance.
+///
+/// This sorts the population into fronts on every call to `select`, which
+/// takes time `O(m n²)` for a population of size `n` with `m` objectives.
+/// When selecting many times from the same population, build a [`Ranking`]
+/// once with [`Nsga2::ranking`] and use that as the selector instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Nsga2 {
+    tournament_size: usize,
+}
+
+impl Nsga2 {
+    /// The binary tournament used by NSGA-II.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::with_tournament_size(2)
+    }
+
+    #[must_use]
+    pub const fn with_tournament_size(tournament_size: usize) -> Self {
+        Self { tournament_size }
+    }
+
+    /// The front and crowding distance of every individual in `population`,
+    /// which can be used as a selector on this population.
+    #[must_use]
+    pub fn ranking<P, T>(&self, population: &P) -> Ranking
+    where
+        P: Population + AsRef<[P::Individual]>,
+        P::Individual: Individual<TestResults = T>,
+        T: MultiObjective,
+    {
+        let objectives: Vec<&[T::Objective]> = population
+            .as_ref()
+            .iter()
+            .map(|individual| individual.test_results().objectives())
+            .collect();
+        let mut front = vec![0; objectives.len()];
+        let mut crowding_distance = vec![0.0; objectives.len()];
+        for (rank, members) in non_dominated_sort(&objectives).into_iter().enumerate() {
+            let member_objectives: Vec<_> = members.iter().map(|&i| objectives[i]).collect();
+            for (&i, distance) in members.iter().zip(crowding_distances(&member_objectives)) {
+                front[i] = rank;
+                crowding_distance[i] = distance;
+            }
+        }
+        Ranking {
+            front,
+            crowding_distance,
+            tournament_size: self.tournament_size,
+        }
+    }
+}
+
+impl Default for Nsga2 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P, T> Selector<P> for Nsga2
+where
+    P: Population + AsRef<[P::Individual]>,
+    P::Individual: Individual<TestResults = T>,
+    T: MultiObjective,
+{
+    fn select<'pop>(
+        &self,
+        population: &'pop P,
+        rng: &mut dyn RngCore,
+    ) -> Result<&'pop P::Individual> {
+        self.ranking(population).select(population, rng)
+    }
+}
+
+/// The Pareto front (0 for the first front) and crowding distance of each
+/// individual in a population, in the same order as the population.
+///
+/// A `Ranking` built from a population with [`Nsga2::ranking`] is also a
+/// [`Selector`] for that population.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ranking {
+    front: Vec<usize>,
+    crowding_distance: Vec<f64>,
+    tournament_size: usize,
+}
+
+impl Ranking {
+    #[must_use]
+    pub fn front(&self, index: usize) -> Option<usize> {
+        self.front.get(index).copied()
+    }
+
+    #[must_use]
+    pub fn crowding_distance(&self, index: usize) -> Option<f64> {
+        self.crowding_distance.get(index).copied()
+    }
+
+    /// NSGA-II's "crowded comparison" of the individuals at indices `x` and
+    /// `y`, where `Greater` means that `x` is better.
+    fn crowded_cmp(&self, x: usize, y: usize) -> Ordering {
+        self.front[y]
+            .cmp(&self.front[x])
+            .then_with(|| self.crowding_distance[x].total_cmp(&self.crowding_distance[y]))
+    }
+}
+
+impl<P> Selector<P> for Ranking
+where
+    P: Population + AsRef<[P::Individual]>,
+{
+    fn select<'pop>(
+        &self,
+        population: &'pop P,
+        rng: &mut dyn RngCore,
+    ) -> Result<&'pop P::Individual> {
+        let individuals = population.as_ref();
+        ensure!(
+            individuals.len() == self.front.len(),
+            "The ranking was built for a population of size {}, but the population had size {}",
+            self.front.len(),
+            individuals.len()
+        );
+        ensure!(
+            individuals.len() >= self.tournament_size,
+            "The population had size {} and we wanted a tournament of size {}",
+            individuals.len(),
+            self.tournament_size
+        );
+        let winner = rand::seq::index::sample(rng, individuals.len(), self.tournament_size)
+            .into_iter()
+            .max_by(|&x, &y| self.crowded_cmp(x, y))
+            .with_context(|| {
+                format!(
+                    "The tournament was empty; should have been {}",
+                    self.tournament_size
+                )
+            })?;
+        individuals
+            .get(winner)
+            .context("The tournament winner wasn't in the population")
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The tests only unwrap selections from non-empty populations"
+)]
+mod tests {
+    use super::*;
+    use crate::{individual::ec::EcIndividual, test_results::Error};
+
+    fn population(points: &[[i32; 2]]) -> Vec<EcIndividual<usize, TestResults<Error<i32>>>> {
+        points
+            .iter()
+            .enumerate()
+            .map(|(index, &point)| EcIndividual::new(index, TestResults::from(point)))
+            .collect()
+    }
+
+    #[test]
+    fn ranking() {
+        let population = population(&[[1, 5], [3, 3], [5, 1], [2, 2], [4, 4]]);
+        let ranking = Nsga2::new().ranking(&population);
+        assert_eq!(
+            (0..5)
+                .map(|i| ranking.front(i).unwrap())
+                .collect::<Vec<_>>(),
+            [0, 1, 0, 0, 2]
+        );
+        assert_eq!(ranking.crowding_distance(0), Some(f64::INFINITY));
+        assert_eq!(ranking.crowding_distance(2), Some(f64::INFINITY));
+        assert_eq!(ranking.crowding_distance(3), Some(2.0));
+        assert_eq!(ranking.front(5), None);
+    }
+
+    #[test]
+    fn full_tournament_selects_extreme_point_of_first_front() {
+        let population = population(&[[1, 5], [3, 3], [5, 1], [2, 2], [4, 4]]);
+        let selector = Nsga2::with_tournament_size(population.len());
+        let mut rng = rand::thread_rng();
+        for _ in 0..20 {
+            let selected = selector.select(&population, &mut rng).unwrap();
+            assert!([0, 2].contains(&selected.genome), "{selected:?}");
+        }
+    }
+
+    #[test]
+    fn binary_tournament_never_selects_worst() {
+        // The last individual is dominated by everyone else, so it loses
+        // every tournament.
+        let population = population(&[[1, 2], [2, 1], [3, 3]]);
+        let ranking = Nsga2::new().ranking(&population);
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            assert_ne!(ranking.select(&population, &mut rng).unwrap().genome, 2);
+        }
+    }
+
+    #[test]
+    fn empty_population_is_an_error() {
+        let population = population(&[]);
+        assert!(Nsga2::new()
+            .select(&population, &mut rand::thread_rng())
+            .is_err());
+    }
+}
@@ -0,0 +1,180 @@
+use std::cmp::Ordering;
+
+use crate::test_results::NumericResult;
+
+/// Whether the objectives `x` dominate the objectives `y`, i.e., `x` is at
+/// least as good as `y` on every objective, and better on at least one.
+///
+/// As with test results, "bigger is better", so, e.g., a smaller
+/// [`Error`](crate::test_results::Error) is better. If any pair of
+/// objectives can't be compared, neither set dominates the other.
+#[must_use]
+pub fn dominates<R: PartialOrd>(x: &[R], y: &[R]) -> bool {
+    let mut better_somewhere = false;
+    for (a, b) in x.iter().zip(y) {
+        match a.partial_cmp(b) {
+            Some(Ordering::Greater) => better_somewhere = true,
+            Some(Ordering::Equal) => {}
+            Some(Ordering::Less) | None => return false,
+        }
+    }
+    better_somewhere
+}
+
+/// Sort a set of objective vectors into Pareto fronts.
+///
+/// The result is a list of fronts, each holding indices into `objectives`.
+/// The first front holds everything that isn't dominated by anything else,
+/// the second holds everything that's only dominated by members of the
+/// first front, and so on. Every index appears in exactly one front.
+///
+/// This is the "fast non-dominated sort" from the NSGA-II paper, which
+/// takes time `O(m n²)` for `n` vectors of `m` objectives.
+#[must_use]
+pub fn non_dominated_sort<R: PartialOrd>(objectives: &[&[R]]) -> Vec<Vec<usize>> {
+    // For each vector, the vectors it dominates and the number of vectors
+    // that dominate it.
+    let mut dominated: Vec<Vec<usize>> = vec![Vec::new(); objectives.len()];
+    let mut num_dominators = vec![0_usize; objectives.len()];
+    for (i, x) in objectives.iter().enumerate() {
+        for (j, y) in objectives.iter().enumerate().skip(i.saturating_add(1)) {
+            if dominates(x, y) {
+                dominated[i].push(j);
+                num_dominators[j] = num_dominators[j].saturating_add(1);
+            } else if dominates(y, x) {
+                dominated[j].push(i);
+                num_dominators[i] = num_dominators[i].saturating_add(1);
+            }
+        }
+    }
+
+    let mut fronts = Vec::new();
+    let mut front: Vec<usize> = (0..objectives.len())
+        .filter(|&i| num_dominators[i] == 0)
+        .collect();
+    while !front.is_empty() {
+        let mut next_front = Vec::new();
+        for &i in &front {
+            for &j in &dominated[i] {
+                num_dominators[j] = num_dominators[j].saturating_sub(1);
+                if num_dominators[j] == 0 {
+                    next_front.push(j);
+                }
+            }
+        }
+        fronts.push(front);
+        front = next_front;
+    }
+    fronts
+}
+
+/// The crowding distance of each member of a front, in the same order as
+/// `front`, which measures how far each member is from its neighbors in the
+/// front.
+///
+/// For each objective, the members with the smallest and largest values get
+/// an infinite distance, and every other member adds the distance between
+/// its two neighbors on that objective, divided by the range of that
+/// objective in the front. So members in sparse parts of the front have
+/// larger distances.
+#[must_use]
+pub fn crowding_distances<R: NumericResult>(front: &[&[R]]) -> Vec<f64> {
+    let mut distances = vec![0.0; front.len()];
+    let num_objectives = front.first().map_or(0, |objectives| objectives.len());
+    for objective in 0..num_objectives {
+        let values: Vec<f64> = front
+            .iter()
+            .map(|objectives| {
+                objectives
+                    .get(objective)
+                    .and_then(NumericResult::to_f64)
+                    .unwrap_or_default()
+            })
+            .collect();
+        let mut order: Vec<usize> = (0..front.len()).collect();
+        order.sort_by(|&x, &y| values[x].total_cmp(&values[y]));
+        let (Some(&lowest), Some(&highest)) = (order.first(), order.last()) else {
+            continue;
+        };
+        distances[lowest] = f64::INFINITY;
+        distances[highest] = f64::INFINITY;
+        let range = values[highest] - values[lowest];
+        if range > 0.0 {
+            for window in order.windows(3) {
+                if let &[previous, current, next] = window {
+                    distances[current] += (values[next] - values[previous]) / range;
+                }
+            }
+        }
+    }
+    distances
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::float_cmp,
+    reason = "The expected values are computed exactly, so exact comparison is correct"
+)]
+mod tests {
+    use super::*;
+    use crate::test_results::Error;
+
+    fn errors(points: &[[i32; 2]]) -> Vec<Vec<Error<i32>>> {
+        points
+            .iter()
+            .map(|point| point.iter().copied().map(Error::from).collect())
+            .collect()
+    }
+
+    fn as_slices(objectives: &[Vec<Error<i32>>]) -> Vec<&[Error<i32>]> {
+        objectives.iter().map(Vec::as_slice).collect()
+    }
+
+    #[test]
+    fn domination() {
+        let objectives = errors(&[[1, 2], [2, 2], [2, 1], [1, 2]]);
+        let objectives = as_slices(&objectives);
+        assert!(dominates(objectives[0], objectives[1]));
+        assert!(!dominates(objectives[1], objectives[0]));
+        // Neither is better on both objectives.
+        assert!(!dominates(objectives[0], objectives[2]));
+        assert!(!dominates(objectives[2], objectives[0]));
+        // Equal objectives don't dominate each other.
+        assert!(!dominates(objectives[0], objectives[3]));
+    }
+
+    #[test]
+    fn fronts() {
+        // Smaller errors are better, so the first front is the lower-left
+        // "staircase" of points.
+        let objectives = errors(&[[1, 5], [3, 3], [5, 1], [2, 2], [4, 4], [6, 6]]);
+        let mut fronts = non_dominated_sort(&as_slices(&objectives));
+        for front in &mut fronts {
+            front.sort_unstable();
+        }
+        assert_eq!(fronts, vec![vec![0, 2, 3], vec![1], vec![4], vec![5]]);
+    }
+
+    #[test]
+    fn empty_sort() {
+        assert!(non_dominated_sort::<Error<i32>>(&[]).is_empty());
+    }
+
+    #[test]
+    fn extreme_points_have_infinite_crowding_distance() {
+        let objectives = errors(&[[1, 5], [2, 2], [5, 1]]);
+        let distances = crowding_distances(&as_slices(&objectives));
+        assert_eq!(distances[0], f64::INFINITY);
+        assert_eq!(distances[2], f64::INFINITY);
+        // The middle point's neighbors span the whole range of both
+        // objectives.
+        assert_eq!(distances[1], 2.0);
+    }
+
+    #[test]
+    fn small_fronts_are_all_extreme() {
+        let objectives = errors(&[[1, 5], [2, 2]]);
+        let distances = crowding_distances(&as_slices(&objectives));
+        assert_eq!(distances, [f64::INFINITY, f64::INFINITY]);
+    }
+}
@@ -1,5 +1,5 @@
 use anyhow::{Context, Result};
-use rand::{prelude::IndexedRandom, rngs::ThreadRng};
+use rand::{prelude::IndexedRandom, RngCore};
 
 use super::Selector;
 use crate::population::Population;
@@ -13,7 +13,7 @@ where
     fn select<'pop>(
         &self,
         population: &'pop P,
-        rng: &mut ThreadRng,
+        rng: &mut dyn RngCore,
     ) -> Result<&'pop P::Individual> {
         population
             .as_ref()
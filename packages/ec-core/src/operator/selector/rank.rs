@@ -0,0 +1,223 @@
+use anyhow::Result;
+use num_traits::ToPrimitive;
+use rand::RngCore;
+
+use super::{roulette::Wheel, Selector};
+use crate::{individual::Individual, population::Population};
+
+/// Linear rank selection, where the probability of selecting an individual
+/// only depends on its rank in the population (ordered by test results).
+///
+/// How much better or worse an individual's results are than anyone else's
+/// doesn't matter.
+///
+/// With a population of size `n` and a selection pressure `s`, the
+/// individual with rank `i` (where the worst individual has rank 0 and the
+/// best has rank `n - 1`) is selected with probability
+///
+/// ```text
+/// (2 - s + 2 (s - 1) i / (n - 1)) / n
+/// ```
+///
+/// so `s = 1` selects uniformly, and `s = 2` makes the best individual twice
+/// as likely as average to be selected, and never selects the worst. Tied
+/// individuals share the average of their ranks, so if every individual
+/// ties they're all equally likely to be selected.
+///
+/// This ranks the population on every call to `select`, which takes time
+/// `O(n log n)`. When selecting many times from the same population (e.g.,
+/// for a whole generation), build a [`Wheel`] once with
+/// [`RankSelector::wheel`] and use that as the selector instead.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RankSelector {
+    selection_pressure: f64,
+}
+
+impl RankSelector {
+    /// The smallest selection pressure, which selects uniformly.
+    pub const MIN_PRESSURE: f64 = 1.0;
+    /// The largest selection pressure, which never selects the worst
+    /// individual.
+    pub const MAX_PRESSURE: f64 = 2.0;
+
+    /// Create a rank selector with the given selection pressure, which is
+    /// clamped to the range `[1.0, 2.0]` (with `NaN` treated as `1.0`).
+    #[must_use]
+    pub const fn new(selection_pressure: f64) -> Self {
+        let selection_pressure = if selection_pressure.is_nan() {
+            Self::MIN_PRESSURE
+        } else {
+            selection_pressure.clamp(Self::MIN_PRESSURE, Self::MAX_PRESSURE)
+        };
+        Self { selection_pressure }
+    }
+
+    #[must_use]
+    pub const fn selection_pressure(&self) -> f64 {
+        self.selection_pressure
+    }
+
+    /// The selection weight of each individual in `population`, in the same
+    /// order as the population. The weights sum to the size of the
+    /// population.
+    #[must_use]
+    pub fn weights<P, R>(&self, population: &P) -> Vec<f64>
+    where
+        P: Population + AsRef<[P::Individual]>,
+        P::Individual: Individual<TestResults = R>,
+        R: Ord,
+    {
+        let individuals = population.as_ref();
+        let mut order: Vec<usize> = (0..individuals.len()).collect();
+        order.sort_by(|&x, &y| {
+            individuals[x]
+                .test_results()
+                .cmp(individuals[y].test_results())
+        });
+
+        // The formula divides by the highest rank, so a population with fewer
+        // than two individuals has to be handled separately.
+        let Some(max_rank) = individuals
+            .len()
+            .checked_sub(1)
+            .filter(|&max_rank| max_rank > 0)
+            .and_then(|max_rank| max_rank.to_f64())
+        else {
+            return vec![1.0; individuals.len()];
+        };
+        let s = self.selection_pressure;
+        let mut weights = vec![0.0; individuals.len()];
+        let mut first: usize = 0;
+        for group in
+            order.chunk_by(|&x, &y| individuals[x].test_results() == individuals[y].test_results())
+        {
+            // The ranks of this group of tied individuals are `first` to
+            // `first + group.len() - 1`, so we give them all the average.
+            let rank = first.to_f64().unwrap_or_default()
+                + (group.len().to_f64().unwrap_or(1.0) - 1.0) / 2.0;
+            let weight = 2.0 - s + 2.0 * (s - 1.0) * rank / max_rank;
+            for &index in group {
+                weights[index] = weight;
+            }
+            first = first.saturating_add(group.len());
+        }
+        weights
+    }
+
+    /// A [`Wheel`] that selects individuals from `population` with the
+    /// probabilities given by their ranks. The wheel can be used as a
+    /// selector on this population, without re-ranking it each time.
+    ///
+    /// # Errors
+    /// This returns an error if the population is empty.
+    pub fn wheel<P, R>(&self, population: &P) -> Result<Wheel>
+    where
+        P: Population + AsRef<[P::Individual]>,
+        P::Individual: Individual<TestResults = R>,
+        R: Ord,
+    {
+        // The selection pressure is at most 2, so the weights are never
+        // negative and the only possible error is an empty population.
+        Ok(Wheel::new(self.weights(population))?)
+    }
+}
+
+impl<P, R> Selector<P> for RankSelector
+where
+    P: Population + AsRef<[P::Individual]>,
+    P::Individual: Individual<TestResults = R>,
+    R: Ord,
+{
+    fn select<'pop>(
+        &self,
+        population: &'pop P,
+        rng: &mut dyn RngCore,
+    ) -> Result<&'pop P::Individual> {
+        self.wheel(population)?.select(population, rng)
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The tests only unwrap selections from non-empty populations"
+)]
+#[expect(
+    clippy::float_cmp,
+    reason = "The expected values are computed exactly, so exact comparison is correct"
+)]
+mod tests {
+    use rand::{prelude::Distribution, rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{individual::ec::EcIndividual, test_results::TestResults};
+
+    fn population(scores: &[i32]) -> Vec<EcIndividual<usize, TestResults<i32>>> {
+        scores
+            .iter()
+            .enumerate()
+            .map(|(index, &score)| EcIndividual::new(index, TestResults::from([score])))
+            .collect()
+    }
+
+    #[test]
+    fn top_rank_beats_median_rank() {
+        // The best individual is at index 3 and the median is at index 2.
+        let population = population(&[0, 40, 20, 50, 10]);
+        let wheel = RankSelector::new(2.0).wheel(&population).unwrap();
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut counts = [0_u32; 5];
+        for _ in 0..10_000 {
+            counts[wheel.sample(&mut rng)] += 1;
+        }
+        // The expected proportions are 0, 0.1, 0.2, 0.3, and 0.4 from worst
+        // to best.
+        assert_eq!(counts[0], 0);
+        assert!(counts[3] > counts[2], "{counts:?}");
+        let ratio = f64::from(counts[3]) / f64::from(counts[2]);
+        assert!((1.8..2.2).contains(&ratio), "{counts:?}");
+    }
+
+    #[test]
+    fn weights_are_linear_in_rank() {
+        let population = population(&[1, 2, 3]);
+        assert_eq!(RankSelector::new(2.0).weights(&population), [0.0, 1.0, 2.0]);
+        assert_eq!(RankSelector::new(1.5).weights(&population), [0.5, 1.0, 1.5]);
+        assert_eq!(RankSelector::new(1.0).weights(&population), [1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn ties_share_ranks() {
+        let weights = RankSelector::new(2.0).weights(&population(&[7, 7, 7, 7]));
+        assert_eq!(weights, [1.0; 4]);
+
+        let weights = RankSelector::new(2.0).weights(&population(&[5, 1, 5]));
+        assert_eq!(weights, [1.5, 0.0, 1.5]);
+    }
+
+    #[test]
+    fn single_individual() {
+        let population = population(&[3]);
+        let selector = RankSelector::new(2.0);
+        assert_eq!(selector.weights(&population), [1.0]);
+        let selected = selector
+            .select(&population, &mut rand::thread_rng())
+            .unwrap();
+        assert_eq!(selected.genome, 0);
+    }
+
+    #[test]
+    fn empty_population_is_an_error() {
+        let population = population(&[]);
+        assert!(RankSelector::new(1.5)
+            .select(&population, &mut rand::thread_rng())
+            .is_err());
+    }
+
+    #[test]
+    fn pressure_is_clamped() {
+        assert_eq!(RankSelector::new(3.0).selection_pressure(), 2.0);
+        assert_eq!(RankSelector::new(0.0).selection_pressure(), 1.0);
+        assert_eq!(RankSelector::new(f64::NAN).selection_pressure(), 1.0);
+    }
+}
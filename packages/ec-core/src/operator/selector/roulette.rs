@@ -0,0 +1,271 @@
+use anyhow::{ensure, Context, Result};
+use num_traits::ToPrimitive;
+use rand::{prelude::Distribution, Rng, RngCore};
+
+use super::Selector;
+use crate::{
+    individual::Individual,
+    population::Population,
+    test_results::{Score, TestResults},
+};
+
+/// The error returned when a roulette wheel can't be built from a set of
+/// scores.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RouletteError {
+    /// There were no scores, so there's nothing to select.
+    EmptyPopulation,
+    /// The score at `index` was negative, so it can't be used as a
+    /// probability.
+    NegativeScore { index: usize, score: f64 },
+    /// The score at `index` couldn't be converted to a finite `f64`, or
+    /// made the total of the scores infinite.
+    InvalidScore { index: usize },
+}
+
+impl std::fmt::Display for RouletteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptyPopulation => write!(f, "The population was empty"),
+            Self::NegativeScore { index, score } => write!(
+                f,
+                "The individual at index {index} had the negative score {score}, but roulette \
+                 wheel selection requires non-negative scores"
+            ),
+            Self::InvalidScore { index } => write!(
+                f,
+                "The score of the individual at index {index} couldn't be used as a finite weight"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RouletteError {}
+
+/// Classic fitness-proportionate ("roulette wheel") selection, where the
+/// probability of selecting an individual is its total score divided by the
+/// sum of the total scores of the population.
+///
+/// This requires individuals whose total results are [`Score`]s (i.e., bigger
+/// is better), and all the scores must be non-negative. If every score is
+/// zero, individuals are selected uniformly at random.
+///
+/// This builds a new [`Wheel`] on every call to `select`, which takes time
+/// linear in the size of the population. When selecting many times from the
+/// same population, build a [`Wheel`] once with [`Wheel::from_population`]
+/// and use that as the selector instead.
+pub struct RouletteWheel;
+
+impl<P, T> Selector<P> for RouletteWheel
+where
+    P: Population + AsRef<[P::Individual]>,
+    P::Individual: Individual<TestResults = TestResults<Score<T>>>,
+    T: ToPrimitive,
+{
+    fn select<'pop>(
+        &self,
+        population: &'pop P,
+        rng: &mut dyn RngCore,
+    ) -> Result<&'pop P::Individual> {
+        Wheel::from_population(population)?.select(population, rng)
+    }
+}
+
+/// The cumulative distribution of a set of non-negative scores, which
+/// samples the index of a score with probability proportional to that
+/// score.
+///
+/// A `Wheel` built from a population with [`Wheel::from_population`] is also
+/// a [`Selector`] for that population.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Wheel {
+    // `cumulative[i]` is the sum of the first `i + 1` scores. This is never
+    // empty.
+    cumulative: Vec<f64>,
+}
+
+impl Wheel {
+    /// # Errors
+    /// This returns an error if `scores` is empty, if any of the scores is
+    /// negative or not finite, or if their total isn't finite.
+    pub fn new(scores: impl IntoIterator<Item = f64>) -> Result<Self, RouletteError> {
+        let mut total = 0.0;
+        let cumulative = scores
+            .into_iter()
+            .enumerate()
+            .map(|(index, score)| {
+                if score < 0.0 {
+                    return Err(RouletteError::NegativeScore { index, score });
+                }
+                total += score;
+                if total.is_finite() {
+                    Ok(total)
+                } else {
+                    Err(RouletteError::InvalidScore { index })
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if cumulative.is_empty() {
+            return Err(RouletteError::EmptyPopulation);
+        }
+        Ok(Self { cumulative })
+    }
+
+    /// Build a wheel from the total scores of the individuals in
+    /// `population`.
+    ///
+    /// # Errors
+    /// This returns an error if the population is empty, or if any of the
+    /// scores is negative or can't be converted to a finite `f64`.
+    pub fn from_population<P, T>(population: &P) -> Result<Self, RouletteError>
+    where
+        P: Population + AsRef<[P::Individual]>,
+        P::Individual: Individual<TestResults = TestResults<Score<T>>>,
+        T: ToPrimitive,
+    {
+        Self::new(population.as_ref().iter().map(|individual| {
+            individual
+                .test_results()
+                .total_result
+                .score
+                .to_f64()
+                .unwrap_or(f64::NAN)
+        }))
+    }
+
+    /// The number of scores on the wheel.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.cumulative.len()
+    }
+
+    /// A wheel is never empty, but this is here for the sake of `len`.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+impl Distribution<usize> for Wheel {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        let total = self.cumulative.last().copied().unwrap_or_default();
+        if total > 0.0 {
+            let spin = rng.gen_range(0.0..total);
+            // The first index whose cumulative score is past the spin; zero
+            // scores don't move the cumulative score, so they're never chosen.
+            self.cumulative.partition_point(|&c| c <= spin)
+        } else {
+            rng.gen_range(0..self.cumulative.len())
+        }
+    }
+}
+
+impl<P> Selector<P> for Wheel
+where
+    P: Population + AsRef<[P::Individual]>,
+{
+    fn select<'pop>(
+        &self,
+        population: &'pop P,
+        rng: &mut dyn RngCore,
+    ) -> Result<&'pop P::Individual> {
+        ensure!(
+            population.size() == self.len(),
+            "The wheel was built for a population of size {} but the population had size {}",
+            self.len(),
+            population.size()
+        );
+        population
+            .as_ref()
+            .get(self.sample(rng))
+            .context("The wheel selected an index past the end of the population")
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The tests unwrap wheels and selections built from valid scores"
+)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::individual::ec::EcIndividual;
+
+    #[test]
+    fn selection_is_proportional_to_score() {
+        let wheel = Wheel::new([1.0, 2.0, 0.0, 1.0]).unwrap();
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut counts = [0_u32; 4];
+        for _ in 0..40_000 {
+            counts[wheel.sample(&mut rng)] += 1;
+        }
+        assert_eq!(counts[2], 0);
+        let ratio = f64::from(counts[1]) / f64::from(counts[0]);
+        assert!((1.9..2.1).contains(&ratio), "{counts:?}");
+        let ratio = f64::from(counts[1]) / f64::from(counts[3]);
+        assert!((1.9..2.1).contains(&ratio), "{counts:?}");
+    }
+
+    #[test]
+    fn all_zero_scores_select_uniformly() {
+        let wheel = Wheel::new([0.0; 3]).unwrap();
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut counts = [0_u32; 3];
+        for _ in 0..3_000 {
+            counts[wheel.sample(&mut rng)] += 1;
+        }
+        assert!(
+            counts.iter().all(|&c| (900..1_100).contains(&c)),
+            "{counts:?}"
+        );
+    }
+
+    #[test]
+    fn rejects_bad_scores() {
+        assert_eq!(
+            Wheel::new([1.0, -2.0]),
+            Err(RouletteError::NegativeScore {
+                index: 1,
+                score: -2.0
+            })
+        );
+        assert_eq!(
+            Wheel::new([1.0, f64::NAN]),
+            Err(RouletteError::InvalidScore { index: 1 })
+        );
+        assert_eq!(Wheel::new([]), Err(RouletteError::EmptyPopulation));
+    }
+
+    #[test]
+    fn selects_from_population() {
+        let population: Vec<EcIndividual<char, TestResults<Score<i32>>>> =
+            [('a', [0, 0]), ('b', [1, 2]), ('c', [0, 0])]
+                .into_iter()
+                .map(|(genome, scores)| EcIndividual::new(genome, scores.into()))
+                .collect();
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            assert_eq!(
+                RouletteWheel.select(&population, &mut rng).unwrap().genome,
+                'b'
+            );
+        }
+    }
+
+    #[test]
+    fn negative_population_score_is_an_error() {
+        let population = vec![EcIndividual::new((), TestResults::<Score<i32>>::from([-1]))];
+        let error = RouletteWheel
+            .select(&population, &mut rand::thread_rng())
+            .unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<RouletteError>(),
+            Some(&RouletteError::NegativeScore {
+                index: 0,
+                score: -1.0
+            })
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use anyhow::{ensure, Result};
+use rand::RngCore;
+
+use crate::{
+    operator::{Composable, Operator},
+    population::Population,
+};
+
+/// Which end of the population [`TopK`] takes individuals from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Extreme {
+    /// The largest individuals, as with [`Best`](super::best::Best).
+    Best,
+    /// The smallest individuals.
+    Worst,
+}
+
+/// An operator that returns the `k` best (or worst) individuals in a
+/// population, ordered from the best (or worst) down.
+///
+/// This is useful for elitism, where the top individuals are carried over
+/// unchanged into the next generation along with the children of the rest;
+/// [`Generation::with_elitism`](crate::generation::Generation::with_elitism)
+/// does this as part of making each new generation.
+///
+/// Individuals are compared with their `Ord` implementation, which for
+/// [`EcIndividual`](crate::individual::ec::EcIndividual)s compares their
+/// `TestResults` totals. Equally good individuals keep their order in the
+/// population.
+///
+/// Unlike a [`Selector`](super::Selector), this returns several
+/// individuals, and it is an error for `k` to be larger than the population.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TopK {
+    k: usize,
+    extreme: Extreme,
+}
+
+impl TopK {
+    /// Return the `k` best individuals, best first.
+    #[must_use]
+    pub const fn best(k: usize) -> Self {
+        Self {
+            k,
+            extreme: Extreme::Best,
+        }
+    }
+
+    /// Return the `k` worst individuals, worst first.
+    #[must_use]
+    pub const fn worst(k: usize) -> Self {
+        Self {
+            k,
+            extreme: Extreme::Worst,
+        }
+    }
+
+    #[must_use]
+    pub const fn k(&self) -> usize {
+        self.k
+    }
+
+    #[must_use]
+    pub const fn extreme(&self) -> Extreme {
+        self.extreme
+    }
+
+    /// The indices of the `k` best (or worst) of `individuals`, in the same
+    /// order as [`TopK::apply`](Operator::apply) returns them. If there are
+    /// fewer than `k` individuals this returns all their indices instead of
+    /// an error.
+    pub(crate) fn indices<I: Ord>(&self, individuals: &[I]) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..individuals.len()).collect();
+        // `sort_by` is stable, so equal individuals keep their order.
+        indices.sort_by(|&i, &j| match self.extreme {
+            Extreme::Best => individuals[j].cmp(&individuals[i]),
+            Extreme::Worst => individuals[i].cmp(&individuals[j]),
+        });
+        indices.truncate(self.k);
+        indices
+    }
+}
+
+impl<'pop, P> Operator<&'pop P> for TopK
+where
+    P: Population,
+    &'pop P: IntoIterator<Item = &'pop P::Individual>,
+    P::Individual: Ord,
+{
+    type Output = Vec<&'pop P::Individual>;
+    type Error = anyhow::Error;
+
+    fn apply(&self, population: &'pop P, _: &mut dyn RngCore) -> Result<Self::Output> {
+        ensure!(
+            self.k <= population.size(),
+            "Can't select the top {} individuals from a population of size {}",
+            self.k,
+            population.size()
+        );
+        let individuals: Vec<&'pop P::Individual> = population.into_iter().collect();
+        Ok(self
+            .indices(&individuals)
+            .into_iter()
+            .map(|index| individuals[index])
+            .collect())
+    }
+}
+impl Composable for TopK {}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The tests unwrap selections from non-empty populations"
+)]
+mod tests {
+    use super::*;
+    use crate::{
+        individual::ec::EcIndividual,
+        test_results::{Score, TestResults},
+    };
+
+    #[test]
+    fn best_and_worst() {
+        let population = vec![5, 8, 9, 6, 3, 2, 0];
+        let mut rng = rand::thread_rng();
+        assert_eq!(
+            TopK::best(3).apply(&population, &mut rng).unwrap(),
+            [&9, &8, &6]
+        );
+        assert_eq!(
+            TopK::worst(2).apply(&population, &mut rng).unwrap(),
+            [&0, &2]
+        );
+        assert!(TopK::best(0)
+            .apply(&population, &mut rng)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn k_larger_than_population() {
+        let population = vec![1, 2];
+        let mut rng = rand::thread_rng();
+        assert!(TopK::best(3).apply(&population, &mut rng).is_err());
+    }
+
+    #[test]
+    fn selects_individuals_with_best_total_results() {
+        // The total scores are 3, 10, 0, 9, and 6.
+        let results: [&[i64]; 5] = [&[1, 2], &[5, 5], &[0, 0], &[9, 0], &[3, 3]];
+        let population: Vec<EcIndividual<usize, TestResults<Score<i64>>>> = results
+            .into_iter()
+            .enumerate()
+            .map(|(genome, results)| EcIndividual::new(genome, results.iter().copied().collect()))
+            .collect();
+        let mut rng = rand::thread_rng();
+        let top = TopK::best(3).apply(&population, &mut rng).unwrap();
+        let genomes: Vec<usize> = top.iter().map(|individual| individual.genome).collect();
+        assert_eq!(genomes, [1, 3, 4]);
+    }
+}
@@ -1,17 +1,40 @@
+use std::cmp::Ordering;
+
 use anyhow::{ensure, Context, Result};
-use rand::{prelude::IndexedRandom, rngs::ThreadRng};
+use rand::{prelude::IndexedRandom, RngCore};
 
 use super::Selector;
 use crate::population::Population;
 
+/// How a [`Tournament`] chooses a winner when several of the sampled
+/// individuals compare as equal to the best of the sample.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TieBreak {
+    /// The tied individual that comes first in the population wins.
+    First,
+    /// The tied individual that comes last in the population wins.
+    Last,
+    /// A tied individual is chosen uniformly at random.
+    #[default]
+    Random,
+}
+
 pub struct Tournament {
     size: usize,
+    tie_break: TieBreak,
 }
 
 impl Tournament {
+    /// Create a tournament selector of the given size that breaks ties at
+    /// random.
     #[must_use]
     pub const fn new(size: usize) -> Self {
-        Self { size }
+        Self::with_tie_breaking(size, TieBreak::Random)
+    }
+
+    #[must_use]
+    pub const fn with_tie_breaking(size: usize, tie_break: TieBreak) -> Self {
+        Self { size, tie_break }
     }
 }
 
@@ -23,7 +46,7 @@ where
     fn select<'pop>(
         &self,
         population: &'pop P,
-        rng: &mut ThreadRng,
+        rng: &mut dyn RngCore,
     ) -> Result<&'pop P::Individual> {
         ensure!(
             population.size() >= self.size,
@@ -31,10 +54,72 @@ where
             population.size(),
             self.size
         );
-        population
-            .as_ref()
-            .choose_multiple(rng, self.size)
-            .max()
-            .with_context(|| "The tournament was empty; should have been {size}")
+        let individuals = population.as_ref();
+        let contestants: Vec<_> = rand::seq::index::sample(rng, individuals.len(), self.size)
+            .into_iter()
+            .filter_map(|index| individuals.get(index).map(|individual| (index, individual)))
+            .collect();
+        let (_, best) = contestants
+            .iter()
+            .max_by(|(_, x), (_, y)| x.cmp(y))
+            .with_context(|| format!("The tournament was empty; should have been {}", self.size))?;
+        let ties = contestants
+            .iter()
+            .filter(|(_, individual)| individual.cmp(best) == Ordering::Equal);
+        let winner = match self.tie_break {
+            TieBreak::First => ties.min_by_key(|(index, _)| *index),
+            TieBreak::Last => ties.max_by_key(|(index, _)| *index),
+            TieBreak::Random => ties.collect::<Vec<_>>().choose(rng).copied(),
+        };
+        winner
+            .map(|&(_, individual)| individual)
+            .context("The best individual in the tournament wasn't tied with itself")
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "Tournaments over the non-empty test population can't fail"
+)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::{individual::ec::EcIndividual, test_results::TestResults};
+
+    fn winners(tie_break: TieBreak) -> HashSet<char> {
+        // "b", "c", and "d" all have the same (best) results, so with a
+        // tournament as large as the population they're always tied.
+        let population: Vec<EcIndividual<char, TestResults<i32>>> = [
+            ('a', [0, 1]),
+            ('b', [2, 3]),
+            ('c', [2, 3]),
+            ('d', [2, 3]),
+            ('e', [1, 0]),
+        ]
+        .into_iter()
+        .map(|(genome, results)| EcIndividual::new(genome, results.into()))
+        .collect();
+        let selector = Tournament::with_tie_breaking(population.len(), tie_break);
+        let mut rng = rand::thread_rng();
+        (0..100)
+            .map(|_| selector.select(&population, &mut rng).unwrap().genome)
+            .collect()
+    }
+
+    #[test]
+    fn first_wins() {
+        assert_eq!(winners(TieBreak::First), HashSet::from(['b']));
+    }
+
+    #[test]
+    fn last_wins() {
+        assert_eq!(winners(TieBreak::Last), HashSet::from(['d']));
+    }
+
+    #[test]
+    fn random_among_ties() {
+        assert_eq!(winners(TieBreak::Random), HashSet::from(['b', 'c', 'd']));
     }
 }
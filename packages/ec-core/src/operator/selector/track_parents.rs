@@ -0,0 +1,42 @@
+use anyhow::Result;
+use rand::RngCore;
+
+use super::Selector;
+use crate::{lineage, population::Population};
+
+/// A selector wrapper that reports the index of every individual selected by
+/// the wrapped selector to the current [lineage](crate::lineage) record.
+///
+/// This has no effect unless lineage recording has been enabled on the
+/// [`Generation`](crate::generation::Generation) that's using it.
+pub struct TrackParents<S> {
+    selector: S,
+}
+
+impl<S> TrackParents<S> {
+    pub const fn new(selector: S) -> Self {
+        Self { selector }
+    }
+}
+
+impl<P, S> Selector<P> for TrackParents<S>
+where
+    P: Population + AsRef<[P::Individual]>,
+    S: Selector<P>,
+{
+    fn select<'pop>(
+        &self,
+        population: &'pop P,
+        rng: &mut dyn RngCore,
+    ) -> Result<&'pop P::Individual> {
+        let selected = self.selector.select(population, rng)?;
+        if let Some(index) = population
+            .as_ref()
+            .iter()
+            .position(|individual| std::ptr::eq(individual, selected))
+        {
+            lineage::record_parent(index);
+        }
+        Ok(selected)
+    }
+}
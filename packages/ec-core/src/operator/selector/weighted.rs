@@ -1,5 +1,5 @@
-use anyhow::{Context, Result};
-use rand::{rngs::ThreadRng, seq::IndexedRandom};
+use anyhow::{ensure, Context, Result};
+use rand::{seq::IndexedRandom, RngCore};
 
 use super::Selector;
 use crate::population::Population;
@@ -30,6 +30,49 @@ impl<P: Population> Weighted<P> {
         self.selectors.push((Box::new(selector), weight));
         self
     }
+
+    /// Change the weight of the selector at `index` (in the order the
+    /// selectors were added, starting with the one passed to `new`).
+    ///
+    /// Setting a weight to zero effectively disables that selector; it will
+    /// never be used by `select`.
+    ///
+    /// # Errors
+    /// This returns an error if there's no selector at `index`.
+    pub fn set_weight(&mut self, index: usize, weight: usize) -> Result<()> {
+        let num_selectors = self.selectors.len();
+        let (_, w) = self.selectors.get_mut(index).with_context(|| {
+            format!("There is no selector at index {index}; there are only {num_selectors}")
+        })?;
+        *w = weight;
+        Ok(())
+    }
+
+    /// Remove the selector at `index` (in the order the selectors were
+    /// added, starting with the one passed to `new`). The selectors after it
+    /// move down one place, so their indices go down by one.
+    ///
+    /// # Errors
+    /// This returns an error if there's no selector at `index`, or if it's
+    /// the only selector, since there must always be at least one.
+    pub fn remove_selector(&mut self, index: usize) -> Result<()> {
+        let num_selectors = self.selectors.len();
+        ensure!(
+            index < num_selectors,
+            "There is no selector at index {index}; there are only {num_selectors}"
+        );
+        ensure!(
+            num_selectors > 1,
+            "Can't remove the only selector; use `set_weight` to change its weight instead"
+        );
+        self.selectors.remove(index);
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn num_selectors(&self) -> usize {
+        self.selectors.len()
+    }
 }
 
 impl<P> Selector<P> for Weighted<P>
@@ -39,8 +82,12 @@ where
     fn select<'pop>(
         &self,
         population: &'pop P,
-        rng: &mut ThreadRng,
+        rng: &mut dyn RngCore,
     ) -> Result<&'pop P::Individual> {
+        ensure!(
+            self.selectors.iter().any(|(_, w)| *w > 0),
+            "All the selector weights were zero"
+        );
         let (selector, _) = self
             .selectors
             .choose_weighted(rng, |(_, w)| *w)
@@ -48,3 +95,85 @@ where
         selector.select(population, rng)
     }
 }
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "Selecting from the non-empty test populations can't fail"
+)]
+mod tests {
+    use anyhow::bail;
+
+    use super::*;
+    use crate::operator::selector::{best::Best, worst::Worst};
+
+    struct Fails;
+
+    impl<P: Population> Selector<P> for Fails {
+        fn select<'pop>(&self, _: &'pop P, _: &mut dyn RngCore) -> Result<&'pop P::Individual> {
+            bail!("A selector with zero weight was used")
+        }
+    }
+
+    #[test]
+    fn zero_weight_selectors_are_never_used() {
+        let population = vec![5, 8, 9, 6, 3, 2, 0];
+        let mut selector = Weighted::new(Fails, 5)
+            .with_selector(Best, 1)
+            .with_selector(Fails, 3);
+        selector.set_weight(0, 0).unwrap();
+        selector.set_weight(2, 0).unwrap();
+        let mut rng = rand::thread_rng();
+        for _ in 0..1_000 {
+            assert_eq!(&9, selector.select(&population, &mut rng).unwrap());
+        }
+    }
+
+    #[test]
+    fn all_zero_weights_is_an_error() {
+        let population = vec![5, 8, 9, 6, 3, 2, 0];
+        let selector = Weighted::new(Best, 0).with_selector(Best, 0);
+        let mut rng = rand::thread_rng();
+        assert!(selector.select(&population, &mut rng).is_err());
+    }
+
+    // How many of 1,000 selections pick the best individual.
+    fn num_best(selector: &Weighted<Vec<i32>>, population: &Vec<i32>) -> usize {
+        let mut rng = rand::thread_rng();
+        (0..1_000)
+            .filter(|_| selector.select(population, &mut rng).unwrap() == &9)
+            .count()
+    }
+
+    #[test]
+    fn changing_weights_shifts_selection_frequencies() {
+        let population = vec![5, 8, 9, 6, 3, 2, 0];
+        let mut selector = Weighted::new(Best, 1).with_selector(Worst, 3);
+        // We expect 250 with a standard deviation of about 14.
+        assert!((150..350).contains(&num_best(&selector, &population)));
+
+        selector.set_weight(0, 3).unwrap();
+        selector.set_weight(1, 1).unwrap();
+        // We expect 750 with a standard deviation of about 14.
+        assert!((650..850).contains(&num_best(&selector, &population)));
+
+        selector.remove_selector(0).unwrap();
+        assert_eq!(selector.num_selectors(), 1);
+        assert_eq!(num_best(&selector, &population), 0);
+    }
+
+    #[test]
+    fn remove_selector_errors() {
+        let mut selector = Weighted::<Vec<i32>>::new(Best, 1).with_selector(Best, 2);
+        assert!(selector.remove_selector(2).is_err());
+        assert!(selector.remove_selector(1).is_ok());
+        assert!(selector.remove_selector(0).is_err());
+        assert_eq!(selector.num_selectors(), 1);
+    }
+
+    #[test]
+    fn set_weight_out_of_range() {
+        let mut selector = Weighted::<Vec<i32>>::new(Best, 1);
+        assert!(selector.set_weight(1, 3).is_err());
+    }
+}
@@ -0,0 +1,94 @@
+use std::cmp::Ordering;
+
+use anyhow::{Context, Result};
+use rand::RngCore;
+
+use super::Selector;
+use crate::population::Population;
+
+/// Selects the worst individual, i.e., the smallest one according to its
+/// [`Ord`] implementation. Use [`Worst::by`] to compare individuals some
+/// other way.
+pub struct Worst;
+
+impl Worst {
+    /// A selector that selects the worst individual according to `compare`,
+    /// i.e., the individual that `compare` says is less than (or equal to)
+    /// all the others.
+    #[must_use]
+    pub const fn by<F>(compare: F) -> WorstBy<F> {
+        WorstBy { compare }
+    }
+}
+
+impl<P> Selector<P> for Worst
+where
+    P: Population,
+    for<'pop> &'pop P: IntoIterator<Item = &'pop P::Individual>,
+    P::Individual: Ord,
+{
+    fn select<'pop>(
+        &self,
+        population: &'pop P,
+        _: &mut dyn RngCore,
+    ) -> Result<&'pop P::Individual> {
+        population
+            .into_iter()
+            .min()
+            .context("The population was empty")
+    }
+}
+
+/// A selector that selects the worst individual according to a custom
+/// comparison function; see [`Worst::by`].
+pub struct WorstBy<F> {
+    compare: F,
+}
+
+impl<P, F> Selector<P> for WorstBy<F>
+where
+    P: Population,
+    for<'pop> &'pop P: IntoIterator<Item = &'pop P::Individual>,
+    F: Fn(&P::Individual, &P::Individual) -> Ordering,
+{
+    fn select<'pop>(
+        &self,
+        population: &'pop P,
+        _: &mut dyn RngCore,
+    ) -> Result<&'pop P::Individual> {
+        population
+            .into_iter()
+            .min_by(|x, y| (self.compare)(x, y))
+            .context("The population was empty")
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "There's always a worst individual in the non-empty test populations"
+)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selects_the_smallest() {
+        let pop = vec![5, 8, 9, 6, 3, 2, 0];
+        let mut rng = rand::thread_rng();
+        assert_eq!(&0, Worst.select(&pop, &mut rng).unwrap());
+    }
+
+    #[test]
+    fn select_by_distance_from_five() {
+        let pop = vec![5, 8, 9, 6, 3, 2, 0];
+        let mut rng = rand::thread_rng();
+        let selector = Worst::by(|x: &i32, y: &i32| (x - 5).abs().cmp(&(y - 5).abs()));
+        assert_eq!(&5, selector.select(&pop, &mut rng).unwrap());
+    }
+
+    #[test]
+    fn empty_population_is_an_error() {
+        let pop: Vec<i32> = Vec::new();
+        assert!(Worst.select(&pop, &mut rand::thread_rng()).is_err());
+    }
+}
@@ -0,0 +1,154 @@
+//! Diversity statistics for populations, e.g., for spotting premature
+//! convergence from a [`GenerationObserver`](crate::observer::GenerationObserver).
+//!
+//! Genotypic diversity looks at the genomes ([`num_distinct_genomes`],
+//! [`mean_pairwise_distance`], and [`mean_hamming_distance`] for linear
+//! genomes), while phenotypic diversity looks at how the individuals
+//! behave, i.e., their test results ([`num_distinct_test_results`]).
+
+use std::{collections::HashSet, hash::Hash};
+
+use num_traits::ToPrimitive;
+
+use crate::individual::Individual;
+
+/// The number of distinct genomes in `population`.
+#[must_use]
+pub fn num_distinct_genomes<'pop, P, I>(population: &'pop P) -> usize
+where
+    P: ?Sized,
+    &'pop P: IntoIterator<Item = &'pop I>,
+    I: Individual + 'pop,
+    I::Genome: Eq + Hash,
+{
+    population
+        .into_iter()
+        .map(Individual::genome)
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+/// The number of distinct test results in `population`, i.e., the number of
+/// distinct vectors of results (scores or errors) on the test cases.
+#[must_use]
+pub fn num_distinct_test_results<'pop, P, I>(population: &'pop P) -> usize
+where
+    P: ?Sized,
+    &'pop P: IntoIterator<Item = &'pop I>,
+    I: Individual + 'pop,
+    I::TestResults: Eq + Hash,
+{
+    population
+        .into_iter()
+        .map(Individual::test_results)
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+/// The mean of `distance` over all pairs of (different) individuals' genomes
+/// in `population`, or `None` if the population has fewer than two
+/// individuals.
+///
+/// This compares every pair of genomes, so takes time quadratic in the size
+/// of the population; sampling the population first may be worthwhile for
+/// large populations.
+#[must_use]
+pub fn mean_pairwise_distance<'pop, P, I, D>(population: &'pop P, mut distance: D) -> Option<f64>
+where
+    P: ?Sized,
+    &'pop P: IntoIterator<Item = &'pop I>,
+    I: Individual + 'pop,
+    D: FnMut(&I::Genome, &I::Genome) -> usize,
+{
+    let genomes: Vec<&I::Genome> = population.into_iter().map(Individual::genome).collect();
+    let mut total_distance: usize = 0;
+    let mut num_pairs: usize = 0;
+    for (index, &first) in genomes.iter().enumerate() {
+        for &second in genomes.iter().skip(index.saturating_add(1)) {
+            total_distance = total_distance.saturating_add(distance(first, second));
+            num_pairs = num_pairs.saturating_add(1);
+        }
+    }
+    if num_pairs == 0 {
+        return None;
+    }
+    Some(total_distance.to_f64()? / num_pairs.to_f64()?)
+}
+
+/// The mean Hamming distance (see [`hamming_distance`]) between all pairs of
+/// linear genomes in `population`, or `None` if the population has fewer than
+/// two individuals. See [`mean_pairwise_distance`].
+#[must_use]
+pub fn mean_hamming_distance<'pop, P, I, T>(population: &'pop P) -> Option<f64>
+where
+    P: ?Sized,
+    &'pop P: IntoIterator<Item = &'pop I>,
+    I: Individual + 'pop,
+    I::Genome: AsRef<[T]>,
+    T: PartialEq,
+{
+    mean_pairwise_distance(population, |first, second| {
+        hamming_distance(first.as_ref(), second.as_ref())
+    })
+}
+
+/// The number of positions where `first` and `second` have different genes.
+/// If one is longer than the other, the extra genes all count as different.
+#[must_use]
+pub fn hamming_distance<T: PartialEq>(first: &[T], second: &[T]) -> usize {
+    let num_different = first
+        .iter()
+        .zip(second)
+        .filter(|(first_gene, second_gene)| first_gene != second_gene)
+        .count();
+    num_different.saturating_add(first.len().abs_diff(second.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{individual::ec::EcIndividual, test_results::TestResults};
+
+    fn individual(genome: &[u8], errors: &[i64]) -> EcIndividual<Vec<u8>, TestResults<i64>> {
+        EcIndividual::new(genome.to_vec(), errors.iter().copied().collect())
+    }
+
+    // Four individuals with three distinct genomes, but only two distinct
+    // behaviors.
+    fn population() -> Vec<EcIndividual<Vec<u8>, TestResults<i64>>> {
+        vec![
+            individual(&[0, 0, 0], &[1, 2]),
+            individual(&[0, 0, 0], &[1, 2]),
+            individual(&[0, 1, 1], &[1, 2]),
+            individual(&[1, 1, 1], &[0, 0]),
+        ]
+    }
+
+    #[test]
+    fn counts_distinct_genomes() {
+        assert_eq!(num_distinct_genomes(&population()), 3);
+        assert_eq!(
+            num_distinct_genomes(&Vec::<EcIndividual<Vec<u8>, TestResults<i64>>>::new()),
+            0
+        );
+    }
+
+    #[test]
+    fn counts_distinct_test_results() {
+        assert_eq!(num_distinct_test_results(&population()), 2);
+    }
+
+    #[test]
+    fn hamming_distance_counts_extra_genes() {
+        assert_eq!(hamming_distance(&[0, 1, 1], &[0, 0, 1]), 1);
+        assert_eq!(hamming_distance(&[0, 1], &[1, 1, 0, 0]), 3);
+        assert_eq!(hamming_distance::<u8>(&[], &[]), 0);
+    }
+
+    #[test]
+    fn mean_hamming_distance_of_known_population() {
+        // The six pairwise distances are 0, 2, 3, 2, 3, and 1.
+        assert_eq!(mean_hamming_distance(&population()), Some(11.0 / 6.0));
+        assert_eq!(mean_hamming_distance(&population()[..1].to_vec()), None);
+    }
+}
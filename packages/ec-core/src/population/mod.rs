@@ -0,0 +1,109 @@
+use std::collections::VecDeque;
+
+pub mod diversity;
+
+pub trait Population {
+    type Individual;
+
+    fn is_empty(&self) -> bool {
+        self.size() == 0
+    }
+
+    fn size(&self) -> usize;
+}
+
+impl<I> Population for Vec<I> {
+    type Individual = I;
+
+    fn size(&self) -> usize {
+        self.len()
+    }
+}
+
+// A `VecDeque` is handy for steady-state populations, where new individuals
+// are pushed onto one end and old ones popped off the other.
+impl<I> Population for VecDeque<I> {
+    type Individual = I;
+
+    fn size(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<I, const N: usize> Population for [I; N] {
+    type Individual = I;
+
+    fn size(&self) -> usize {
+        N
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "Selecting from the non-empty test populations can't fail"
+)]
+mod tests {
+    use core::ops::Range;
+    use std::collections::VecDeque;
+
+    use rand::{prelude::Distribution, thread_rng, Rng};
+
+    use crate::{
+        distributions::collection::ConvertToCollectionGenerator,
+        operator::selector::{best::Best, Selector},
+        population::Population,
+    };
+
+    struct RandValue {
+        val: i32,
+    }
+
+    impl Distribution<RandValue> for Range<i32> {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> RandValue {
+            RandValue {
+                val: rng.gen_range(self.clone()),
+            }
+        }
+    }
+
+    #[test]
+    fn generator_works() {
+        let mut rng = thread_rng();
+        let population_size = 10;
+        let range = -10..25;
+        let vec_pop = range
+            .to_collection_generator(population_size)
+            .sample(&mut rng);
+
+        assert_eq!(population_size, vec_pop.size());
+        for i in vec_pop {
+            assert!(range.contains(&i.val));
+        }
+    }
+
+    #[test]
+    fn vec_deque_selects_like_vec() {
+        let mut rng = thread_rng();
+        let vec = vec![5, 8, 9, 6, 3, 2, 0];
+        let mut deque: VecDeque<_> = vec.iter().copied().collect();
+        assert_eq!(deque.size(), vec.size());
+        assert_eq!(
+            Best.select(&deque, &mut rng).unwrap(),
+            Best.select(&vec, &mut rng).unwrap()
+        );
+
+        // Replace the oldest individual with a new one, steady-state style.
+        deque.pop_front();
+        deque.push_back(12);
+        assert_eq!(deque.size(), vec.size());
+        assert_eq!(Best.select(&deque, &mut rng).unwrap(), &12);
+    }
+
+    #[test]
+    fn arrays_are_populations() {
+        let array = [5, 8, 9, 6];
+        assert_eq!(array.size(), 4);
+        assert_eq!(Best.select(&array, &mut thread_rng()).unwrap(), &9);
+    }
+}
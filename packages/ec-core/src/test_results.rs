@@ -2,11 +2,11 @@ use std::{
     cmp::Ordering,
     fmt::{Debug, Display},
     iter::Sum,
+    ops::{Add, Div},
 };
 
-// TODO: We can probably use things in the `num` family of traits
-//   (https://github.com/rust-num/num) to genericize `Score` and
-//   `Error` so they're not tied to `i64`s anymore.
+use num_traits::{FromPrimitive, ToPrimitive, Zero};
+
 // TODO: I think that we want `Score` and `Error` to implement
 //   some common trait so that we can mixed vectors of `Score`s
 //   and `Error`s. Or maybe we already have that? Do (Partial)Ord,
@@ -24,7 +24,7 @@ use std::{
 //   closer to where they're actually needed.
 
 /// Score implicitly follows a "bigger is better" model.
-#[derive(Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Score<T> {
     pub score: T,
 }
@@ -41,8 +41,6 @@ impl<T: Display> Display for Score<T> {
     }
 }
 
-// TODO: Write tests for the `From` and `Sum` trait implementations.
-
 impl<T> From<T> for Score<T> {
     fn from(score: T) -> Self {
         Self { score }
@@ -79,7 +77,7 @@ where
 
 // TODO: Rewrite `Error` using the std::cmp::Reverse type
 //   to convert `Score` to `Error`.
-#[derive(Eq, PartialEq)]
+#[derive(Clone, Eq, PartialEq, Hash)]
 pub struct Error<T> {
     pub error: T,
 }
@@ -109,8 +107,6 @@ impl<T: Display> Display for Error<T> {
         write!(f, "Error (lower is better): {}", self.error)
     }
 }
-// TODO: Write tests for the `From` and `Sum` trait implementations.
-
 impl<T> From<T> for Error<T> {
     fn from(error: T) -> Self {
         Self { error }
@@ -145,6 +141,52 @@ where
     }
 }
 
+// `Add` is only here because `Zero` requires it; it lets
+// `TestResults::is_ideal` check for errors of zero.
+impl<T: Add<Output = T>> Add for Error<T> {
+    type Output = Self;
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "Overflow behavior is up to `T`'s `Add` implementation"
+    )]
+    fn add(self, other: Self) -> Self {
+        Self {
+            error: self.error + other.error,
+        }
+    }
+}
+
+impl<T: Zero> Zero for Error<T> {
+    fn zero() -> Self {
+        Self { error: T::zero() }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.error.is_zero()
+    }
+}
+
+/// A test result with an underlying numeric value, which lets us measure how
+/// far apart two results are (e.g., in epsilon-lexicase selection).
+pub trait NumericResult {
+    /// The value of this result as an `f64`, or `None` if it can't be
+    /// represented as one.
+    fn to_f64(&self) -> Option<f64>;
+}
+
+impl<T: ToPrimitive> NumericResult for Score<T> {
+    fn to_f64(&self) -> Option<f64> {
+        self.score.to_f64()
+    }
+}
+
+impl<T: ToPrimitive> NumericResult for Error<T> {
+    fn to_f64(&self) -> Option<f64> {
+        self.error.to_f64()
+    }
+}
+
 #[cfg(test)]
 mod score_error_tests {
     use super::*;
@@ -178,7 +220,7 @@ mod score_error_tests {
     }
 }
 
-#[derive(Eq, PartialEq)]
+#[derive(Clone, Eq, PartialEq)]
 pub enum TestResult<S, E> {
     Score(Score<S>),
     Error(Error<E>),
@@ -234,12 +276,33 @@ mod test_result_tests {
     }
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct TestResults<R> {
     pub results: Vec<R>,
     pub total_result: R,
 }
 
+impl<R> TestResults<R> {
+    /// The aggregate of all the results (their sum, unless this was built
+    /// with [`TestResults::with_aggregator`]). It's always computed when the
+    /// `TestResults` is built, so there's always a total, even if there are
+    /// no results.
+    #[must_use]
+    pub const fn total(&self) -> &R {
+        &self.total_result
+    }
+
+    /// Whether every result is zero, e.g., an individual that has no error on
+    /// any of the test cases. This is `true` if there are no results.
+    #[must_use]
+    pub fn is_ideal(&self) -> bool
+    where
+        R: Zero,
+    {
+        self.results.iter().all(Zero::is_zero)
+    }
+}
+
 impl<R: Ord> Ord for TestResults<R> {
     fn cmp(&self, other: &Self) -> Ordering {
         self.total_result.cmp(&other.total_result)
@@ -308,6 +371,99 @@ where
     }
 }
 
+/// How the individual results in a [`TestResults`] are combined into its
+/// `total_result`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Aggregator {
+    /// The sum of the results; this is what the `From` and `FromIterator`
+    /// implementations for [`TestResults`] use.
+    #[default]
+    Sum,
+    /// The largest of the underlying values, e.g., the worst case for errors.
+    Max,
+    /// The mean of the results. This uses the division of the underlying
+    /// type, so the mean of integer results is truncated.
+    Mean,
+}
+
+impl Aggregator {
+    /// Combine `values` into a single value using this aggregation strategy.
+    ///
+    /// An empty collection of values aggregates to the sum of no values
+    /// (typically zero) regardless of the strategy.
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "Overflow behavior is up to `T`'s arithmetic implementations"
+    )]
+    pub fn aggregate<T>(self, values: impl IntoIterator<Item = T>) -> T
+    where
+        T: Sum + PartialOrd + Div<Output = T> + FromPrimitive,
+    {
+        let values = values.into_iter();
+        match self {
+            Self::Sum => values.sum(),
+            Self::Max => values
+                .reduce(|max, value| if value > max { value } else { max })
+                .unwrap_or_else(|| std::iter::empty().sum()),
+            Self::Mean => {
+                let mut count = 0_usize;
+                let total: T = values.inspect(|_| count = count.saturating_add(1)).sum();
+                // There's nothing sensible to divide by if there are no values, or
+                // more than `T` can count.
+                match T::from_usize(count).filter(|_| count > 0) {
+                    Some(count) => total / count,
+                    None => total,
+                }
+            }
+        }
+    }
+}
+
+/// A test result that can be aggregated using any [`Aggregator`].
+pub trait Aggregate: Sized {
+    /// Combine `results` into a single result using `aggregator`.
+    fn aggregate(results: &[Self], aggregator: Aggregator) -> Self;
+}
+
+impl<T> Aggregate for Score<T>
+where
+    T: Clone + Sum + PartialOrd + Div<Output = T> + FromPrimitive,
+{
+    fn aggregate(results: &[Self], aggregator: Aggregator) -> Self {
+        aggregator
+            .aggregate(results.iter().map(|s| s.score.clone()))
+            .into()
+    }
+}
+
+impl<T> Aggregate for Error<T>
+where
+    T: Clone + Sum + PartialOrd + Div<Output = T> + FromPrimitive,
+{
+    fn aggregate(results: &[Self], aggregator: Aggregator) -> Self {
+        aggregator
+            .aggregate(results.iter().map(|e| e.error.clone()))
+            .into()
+    }
+}
+
+impl<R: Aggregate> TestResults<R> {
+    /// Build a `TestResults` from `values`, using `aggregator` to compute the
+    /// `total_result`. Converting with `From` or `collect` is the same as
+    /// using [`Aggregator::Sum`] here.
+    pub fn with_aggregator<V>(values: impl IntoIterator<Item = V>, aggregator: Aggregator) -> Self
+    where
+        R: From<V>,
+    {
+        let results: Vec<R> = values.into_iter().map(Into::into).collect();
+        let total_result = R::aggregate(&results, aggregator);
+        Self {
+            results,
+            total_result,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test_results_from_vec {
     use super::*;
@@ -359,4 +515,130 @@ mod test_results_from_vec {
         assert_eq!(test_results.results, results.collect::<Vec<_>>());
         assert_eq!(test_results.total_result, scores.into_iter().sum());
     }
+
+    #[test]
+    fn total_is_the_sum_of_the_results() {
+        let test_results: TestResults<Error<i32>> = vec![5, 8, 0, 9].into();
+        assert_eq!(test_results.total(), &Error::from(22));
+        assert_eq!(test_results.total(), &test_results.total_result);
+    }
+
+    #[test]
+    fn all_zero_errors_are_ideal() {
+        let test_results: TestResults<Error<i32>> = vec![0, 0, 0].into();
+        assert!(test_results.is_ideal());
+        let test_results: TestResults<Error<i32>> = vec![0, 3, 0].into();
+        assert!(!test_results.is_ideal());
+        // The total is zero, but not every error is.
+        let test_results: TestResults<Error<i32>> = vec![-3, 3].into();
+        assert!(!test_results.is_ideal());
+    }
+}
+
+#[cfg(test)]
+mod aggregator_tests {
+    use super::*;
+
+    #[test]
+    fn sum_matches_from() {
+        let errors = vec![5, 8, 0, 9];
+        let aggregated =
+            TestResults::<Error<i32>>::with_aggregator(errors.clone(), Aggregator::Sum);
+        let converted: TestResults<Error<i32>> = errors.into();
+        assert_eq!(aggregated, converted);
+    }
+
+    #[test]
+    fn max_is_largest_error() {
+        let test_results =
+            TestResults::<Error<i64>>::with_aggregator(vec![5, 8, 0, 9, 3], Aggregator::Max);
+        assert_eq!(test_results.total_result, Error::from(9));
+        assert_eq!(test_results.results.len(), 5);
+    }
+
+    #[test]
+    fn mean_of_scores() {
+        let test_results =
+            TestResults::<Score<f64>>::with_aggregator(vec![1.0, 2.0, 6.0], Aggregator::Mean);
+        assert!((test_results.total_result.score - 3.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn mean_of_integers_truncates() {
+        let test_results =
+            TestResults::<Error<i64>>::with_aggregator(vec![1, 2, 2], Aggregator::Mean);
+        assert_eq!(test_results.total_result, Error::from(1));
+    }
+
+    #[test]
+    fn empty_results_aggregate_to_zero() {
+        for aggregator in [Aggregator::Sum, Aggregator::Max, Aggregator::Mean] {
+            let test_results =
+                TestResults::<Error<i64>>::with_aggregator(Vec::<i64>::new(), aggregator);
+            assert_eq!(test_results.total_result, Error::from(0));
+        }
+    }
+
+    #[test]
+    fn ordering_uses_total_result() {
+        // Worst-case errors of 9 and 7, although the sums are 9 and 21.
+        let first = TestResults::<Error<i64>>::with_aggregator(vec![9, 0, 0], Aggregator::Max);
+        let second = TestResults::<Error<i64>>::with_aggregator(vec![7, 7, 7], Aggregator::Max);
+        // Smaller errors are better, so `second` is greater.
+        assert!(second > first);
+        assert_eq!(first.cmp(&second), Ordering::Less);
+    }
+}
+
+#[cfg(test)]
+mod generic_results {
+    use ordered_float::OrderedFloat;
+
+    use super::*;
+
+    #[test]
+    fn i64_errors() {
+        let test_results: TestResults<Error<i64>> = vec![-3, 8, 0].into();
+        assert_eq!(test_results.total(), &Error::from(5));
+        let better: TestResults<Error<i64>> = vec![1, 1, 1].into();
+        assert!(better > test_results);
+    }
+
+    #[test]
+    fn usize_errors() {
+        let test_results: TestResults<Error<usize>> = vec![3, 8, 0].into();
+        assert_eq!(test_results.total(), &Error::from(11));
+        let worse: TestResults<Error<usize>> = vec![4, 8, 0].into();
+        assert!(worse < test_results);
+    }
+
+    #[test]
+    fn ordered_float_errors() {
+        let errors = [0.5, 1.25, 0.0].map(OrderedFloat);
+        let test_results: TestResults<Error<OrderedFloat<f64>>> = errors.into();
+        assert_eq!(test_results.total(), &Error::from(OrderedFloat(1.75)));
+        let better: TestResults<Error<OrderedFloat<f64>>> = [0.5, 0.5].map(OrderedFloat).into();
+        assert!(better > test_results);
+        assert_eq!(
+            errors.iter().copied().map(Error::from).sum::<Error<_>>(),
+            errors.into_iter().sum::<Error<_>>()
+        );
+    }
+
+    #[test]
+    fn ordered_float_scores() {
+        let test_results: TestResults<Score<OrderedFloat<f64>>> =
+            [2.5, 1.0].map(OrderedFloat).into();
+        assert_eq!(test_results.total(), &Score::from(OrderedFloat(3.5)));
+        let better: TestResults<Score<OrderedFloat<f64>>> = [4.0].map(OrderedFloat).into();
+        assert!(better > test_results);
+    }
+
+    #[test]
+    fn sums_of_references() {
+        let errors: Vec<Error<usize>> = vec![3, 4].into_iter().map(Error::from).collect();
+        assert_eq!(errors.iter().sum::<Error<usize>>(), Error::from(7));
+        let scores: Vec<Score<i64>> = vec![-3, 4].into_iter().map(Score::from).collect();
+        assert_eq!(scores.iter().sum::<Score<i64>>(), Score::from(1));
+    }
 }
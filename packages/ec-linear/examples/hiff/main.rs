@@ -1,11 +1,17 @@
-#![allow(clippy::use_debug)]
-#![allow(clippy::arithmetic_side_effects)]
+#![expect(
+    clippy::use_debug,
+    reason = "The example prints its results using their `Debug` representation"
+)]
+#![expect(
+    clippy::arithmetic_side_effects,
+    reason = "The example's arithmetic is on small values that can't overflow"
+)]
 
 pub mod args;
 
 use std::{iter::once, ops::Not};
 
-use anyhow::{ensure, Result};
+use anyhow::{ensure, Context, Result};
 use clap::Parser;
 use ec_core::{
     distributions::collection::ConvertToCollectionGenerator,
@@ -18,7 +24,6 @@ use ec_core::{
         recombinator::Recombine,
         selector::{
             best::Best, lexicase::Lexicase, tournament::Tournament, weighted::Weighted, Select,
-            Selector,
         },
         Composable,
     },
@@ -28,7 +33,7 @@ use ec_linear::{
     genome::bitstring::Bitstring, mutator::with_one_over_length::WithOneOverLength,
     recombinator::two_point_xo::TwoPointXo,
 };
-use rand::{distributions::Standard, prelude::Distribution, thread_rng};
+use rand::{distributions::Standard, prelude::Distribution, rngs::StdRng, SeedableRng};
 
 use crate::args::{Args, RunModel};
 
@@ -49,9 +54,7 @@ fn hiff(bits: &[bool]) -> (bool, TestResults<Score<usize>>) {
                 .results
                 .into_iter()
                 .chain(right_score.results)
-                .chain(once(Score::from(
-                    all_same.then_some(len).unwrap_or_default(),
-                )))
+                .chain(once(Score::from(if all_same { len } else { 0 })))
                 .collect(),
         )
     }
@@ -63,9 +66,10 @@ fn main() -> Result<()> {
         population_size,
         bit_length,
         num_generations,
+        seed,
     } = Args::parse();
 
-    let mut rng = thread_rng();
+    let mut rng = seed.map_or_else(StdRng::from_entropy, StdRng::seed_from_u64);
 
     let scorer = FnScorer(|bitstring: &Bitstring| hiff(&bitstring.bits).1);
 
@@ -109,11 +113,14 @@ fn main() -> Result<()> {
 
     for generation_number in 0..num_generations {
         match run_model {
-            RunModel::Serial => generation.serial_next()?,
-            RunModel::Parallel => generation.par_next()?,
+            RunModel::Serial => generation.serial_next_with_rng(&mut rng)?,
+            RunModel::Parallel => match seed {
+                Some(seed) => generation.par_next_seeded(seed)?,
+                None => generation.par_next()?,
+            },
         }
 
-        let best = Best.select(generation.population(), &mut rng)?;
+        let best = generation.best().context("The population was empty")?;
 
         // TODO: Change 2 to be the smallest number of digits needed for
         //  num_generations-1.
@@ -5,7 +5,7 @@ use ec_core::{
     distributions::collection::{CollectionGenerator, ConvertToCollectionGenerator},
     genome::Genome,
 };
-use rand::{distributions::Standard, prelude::Distribution, rngs::ThreadRng, Rng};
+use rand::{distributions::Standard, prelude::Distribution, Rng, RngCore};
 
 use super::Linear;
 use crate::recombinator::crossover::Crossover;
@@ -30,7 +30,7 @@ impl Distribution<bool> for BoolGenerator {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Bitstring {
     pub bits: Vec<bool>,
 }
@@ -47,23 +47,54 @@ where
 }
 
 impl Bitstring {
-    pub fn random(num_bits: usize, rng: &mut ThreadRng) -> Self {
+    pub fn random(num_bits: usize, rng: &mut dyn RngCore) -> Self {
         Standard.into_collection_generator(num_bits).sample(rng)
     }
 
-    pub fn random_with_probability(num_bits: usize, probability: f64, rng: &mut ThreadRng) -> Self {
+    pub fn random_with_probability(
+        num_bits: usize,
+        probability: f64,
+        rng: &mut dyn RngCore,
+    ) -> Self {
         BoolGenerator::new(probability)
             .into_collection_generator(num_bits)
             .sample(rng)
     }
 
-    pub fn iter(&self) -> std::slice::Iter<bool> {
+    pub fn iter(&self) -> std::slice::Iter<'_, bool> {
         self.bits.iter()
     }
 
-    pub fn iter_mut(&mut self) -> std::slice::IterMut<bool> {
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, bool> {
         self.bits.iter_mut()
     }
+
+    /// Converts a bitstring holding a (most-significant bit first) binary
+    /// number into the corresponding Gray code, where consecutive numbers
+    /// differ in exactly one bit.
+    #[must_use]
+    pub fn to_gray(&self) -> Self {
+        self.iter()
+            .scan(false, |previous, &bit| {
+                let gray = *previous ^ bit;
+                *previous = bit;
+                Some(gray)
+            })
+            .collect()
+    }
+
+    /// Converts a bitstring holding a Gray code back into the corresponding
+    /// (most-significant bit first) binary number. This is the inverse of
+    /// [`Bitstring::to_gray`].
+    #[must_use]
+    pub fn from_gray(gray: &Self) -> Self {
+        gray.iter()
+            .scan(false, |previous, &bit| {
+                *previous ^= bit;
+                Some(*previous)
+            })
+            .collect()
+    }
 }
 
 impl Display for Bitstring {
@@ -115,6 +146,12 @@ impl<'a> IntoIterator for &'a mut Bitstring {
     }
 }
 
+impl AsRef<[bool]> for Bitstring {
+    fn as_ref(&self) -> &[bool] {
+        &self.bits
+    }
+}
+
 impl Genome for Bitstring {
     type Gene = bool;
 }
@@ -154,3 +191,40 @@ impl Crossover for Bitstring {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::iter::zip;
+
+    use super::*;
+
+    fn from_u8(n: u8) -> Bitstring {
+        (0..8).rev().map(|i| (n >> i) & 1 == 1).collect()
+    }
+
+    #[test]
+    fn gray_round_trip() {
+        for n in 0..=u8::MAX {
+            let bits = from_u8(n);
+            assert_eq!(Bitstring::from_gray(&bits.to_gray()), bits);
+            assert_eq!(Bitstring::from_gray(&bits).to_gray(), bits);
+        }
+    }
+
+    #[test]
+    fn known_gray_codes() {
+        assert_eq!(from_u8(0b0000_0101).to_gray(), from_u8(0b0000_0111));
+        assert_eq!(from_u8(0b1000_0000).to_gray(), from_u8(0b1100_0000));
+        assert_eq!(from_u8(0b1111_1111).to_gray(), from_u8(0b1000_0000));
+    }
+
+    #[test]
+    fn consecutive_values_differ_by_one_gray_bit() {
+        for n in 0..u8::MAX {
+            let gray = from_u8(n).to_gray();
+            let next_gray = from_u8(n + 1).to_gray();
+            let num_differences = zip(&gray, &next_gray).filter(|(x, y)| x != y).count();
+            assert_eq!(num_differences, 1, "{n} and {} as Gray codes", n + 1);
+        }
+    }
+}
@@ -8,3 +8,13 @@ pub trait Linear: Genome {
 
     fn gene_mut(&mut self, index: usize) -> Option<&mut Self::Gene>;
 }
+
+impl<T> Linear for Vec<T> {
+    fn size(&self) -> usize {
+        self.len()
+    }
+
+    fn gene_mut(&mut self, index: usize) -> Option<&mut Self::Gene> {
+        self.get_mut(index)
+    }
+}
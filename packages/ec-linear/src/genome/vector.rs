@@ -1,6 +1,8 @@
+use anyhow::bail;
 use ec_core::genome::Genome;
 
 use super::Linear;
+use crate::recombinator::crossover::Crossover;
 
 #[derive(Debug, Clone)]
 pub struct Vector<T> {
@@ -11,6 +13,12 @@ impl<T> Genome for Vector<T> {
     type Gene = T;
 }
 
+impl<T> AsRef<[T]> for Vector<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.genes
+    }
+}
+
 impl<T> Linear for Vector<T> {
     fn size(&self) -> usize {
         self.genes.len()
@@ -21,6 +29,21 @@ impl<T> Linear for Vector<T> {
     }
 }
 
+impl<T> Crossover for Vector<T> {
+    fn crossover_gene(&mut self, other: &mut Self, index: usize) -> anyhow::Result<()> {
+        if let (Some(lhs), Some(rhs)) = (self.gene_mut(index), other.gene_mut(index)) {
+            std::mem::swap(lhs, rhs);
+            Ok(())
+        } else {
+            bail!(
+                "Crossing vectors of lengths {} and {} at position {index} failed",
+                self.size(),
+                other.size()
+            )
+        }
+    }
+}
+
 impl<T> FromIterator<T> for Vector<T> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         Self {
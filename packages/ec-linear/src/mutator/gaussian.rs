@@ -0,0 +1,126 @@
+use std::f64::consts::TAU;
+
+use anyhow::Result;
+use ec_core::operator::mutator::Mutator;
+use num_traits::Float;
+use rand::{Rng, RngCore};
+
+use crate::genome::Linear;
+
+/// Mutates real-valued linear genomes by adding Gaussian noise, N(0, `sigma`),
+/// to each gene with probability `mutation_rate`. Genes that aren't chosen for
+/// mutation are left untouched.
+///
+/// If bounds have been set with [`GaussianMutation::with_bounds`], every
+/// mutated gene is clamped to `[min, max]`.
+pub struct GaussianMutation<T> {
+    mutation_rate: f64,
+    sigma: T,
+    bounds: Option<(T, T)>,
+}
+
+impl<T> GaussianMutation<T> {
+    /// `mutation_rate` should be in `[0, 1]`, and is clamped to that range.
+    pub const fn new(mutation_rate: f64, sigma: T) -> Self {
+        Self {
+            mutation_rate: mutation_rate.clamp(0.0, 1.0),
+            sigma,
+            bounds: None,
+        }
+    }
+
+    /// Clamp mutated genes to the range `[min, max]`, where `min` should be no
+    /// larger than `max`.
+    #[must_use]
+    pub fn with_bounds(mut self, min: T, max: T) -> Self {
+        self.bounds = Some((min, max));
+        self
+    }
+}
+
+impl<T: Float> GaussianMutation<T> {
+    fn mutate_gene(&self, gene: T, rng: &mut dyn RngCore) -> T {
+        let noise = T::from(standard_normal(rng)).unwrap_or_else(T::zero);
+        let mutated = noise.mul_add(self.sigma, gene);
+        match self.bounds {
+            Some((min, max)) => mutated.max(min).min(max),
+            None => mutated,
+        }
+    }
+}
+
+// A sample from the standard normal distribution, N(0, 1), using the
+// Box-Muller transform.
+fn standard_normal<R: Rng + ?Sized>(rng: &mut R) -> f64 {
+    // `gen` returns values in `[0, 1)`, so `u` is in `(0, 1]` and its
+    // logarithm is finite.
+    let u = 1.0 - rng.gen::<f64>();
+    let v = rng.gen::<f64>();
+    (-2.0 * u.ln()).sqrt() * (TAU * v).cos()
+}
+
+impl<G> Mutator<G> for GaussianMutation<G::Gene>
+where
+    G: Linear + FromIterator<G::Gene> + IntoIterator<Item = G::Gene>,
+    G::Gene: Float,
+{
+    fn mutate(&self, genome: G, rng: &mut dyn RngCore) -> Result<G> {
+        Ok(genome
+            .into_iter()
+            .map(|gene| {
+                if rng.gen_bool(self.mutation_rate) {
+                    self.mutate_gene(gene, rng)
+                } else {
+                    gene
+                }
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The test mutations can't fail, so unwrapping them is fine"
+)]
+mod tests {
+    use super::*;
+    use crate::genome::vector::Vector;
+
+    fn genome() -> Vector<f64> {
+        (0..100).map(f64::from).collect()
+    }
+
+    #[test]
+    fn zero_sigma_leaves_genome_unchanged() {
+        let mutator = GaussianMutation::new(1.0, 0.0);
+        let child = mutator.mutate(genome(), &mut rand::thread_rng()).unwrap();
+        assert_eq!(child.genes, genome().genes);
+    }
+
+    #[test]
+    fn zero_rate_leaves_genome_unchanged() {
+        let mutator = GaussianMutation::new(0.0, 10.0);
+        let child = mutator.mutate(genome(), &mut rand::thread_rng()).unwrap();
+        assert_eq!(child.genes, genome().genes);
+    }
+
+    #[test]
+    fn clamping_keeps_genes_in_bounds() {
+        let mutator = GaussianMutation::new(1.0, 1_000.0).with_bounds(10.0, 20.0);
+        let child = mutator.mutate(genome(), &mut rand::thread_rng()).unwrap();
+        assert!(
+            child.genes.iter().all(|gene| (10.0..=20.0).contains(gene)),
+            "{:?}",
+            child.genes
+        );
+    }
+
+    #[test]
+    fn mutates_f32_genes() {
+        let parent: Vector<f32> = [0.0; 100].into_iter().collect();
+        let mutator = GaussianMutation::new(1.0, 1.0_f32);
+        let child = mutator.mutate(parent, &mut rand::thread_rng()).unwrap();
+        assert!(child.genes.iter().any(|gene| gene.abs() > 0.0));
+    }
+}
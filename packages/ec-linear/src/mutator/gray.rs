@@ -0,0 +1,75 @@
+use anyhow::Result;
+use ec_core::operator::mutator::Mutator;
+use rand::RngCore;
+
+use crate::genome::bitstring::Bitstring;
+
+/// Applies a bitstring mutator in Gray code space.
+///
+/// The genome is assumed to hold (most-significant bit first) binary
+/// numbers. It's converted to Gray code, mutated with the wrapped mutator,
+/// and then converted back to binary. Since consecutive numbers differ in a
+/// single Gray code bit, small mutations are more likely to produce small
+/// changes in the encoded values than they would be with plain binary, where
+/// e.g., going from 127 to 128 requires flipping every bit.
+pub struct GrayMutation<M> {
+    mutator: M,
+}
+
+impl<M> GrayMutation<M> {
+    pub const fn new(mutator: M) -> Self {
+        Self { mutator }
+    }
+}
+
+impl<M> Mutator<Bitstring> for GrayMutation<M>
+where
+    M: Mutator<Bitstring>,
+{
+    fn mutate(&self, genome: Bitstring, rng: &mut dyn RngCore) -> Result<Bitstring> {
+        let gray = self.mutator.mutate(genome.to_gray(), rng)?;
+        Ok(Bitstring::from_gray(&gray))
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "Decoding a mutated test genome should never fail"
+)]
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "The decoded test values are only a few bits wide"
+)]
+mod tests {
+    use anyhow::Context;
+
+    use super::*;
+
+    // Flips the last (least significant) bit.
+    struct FlipLastBit;
+
+    impl Mutator<Bitstring> for FlipLastBit {
+        fn mutate(&self, mut genome: Bitstring, _: &mut dyn RngCore) -> Result<Bitstring> {
+            let last = genome.bits.last_mut().context("The genome was empty")?;
+            *last = !*last;
+            Ok(genome)
+        }
+    }
+
+    fn value(bits: &Bitstring) -> i32 {
+        bits.iter()
+            .fold(0, |value, &bit| 2 * value + i32::from(bit))
+    }
+
+    #[test]
+    fn single_gray_bit_flip_changes_value_by_one() {
+        let mutator = GrayMutation::new(FlipLastBit);
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let parent = Bitstring::random(16, &mut rng);
+            let child = mutator.mutate(parent.clone(), &mut rng).unwrap();
+            assert_eq!((value(&child) - value(&parent)).abs(), 1);
+        }
+    }
+}
@@ -1,3 +1,6 @@
+pub mod gaussian;
+pub mod gray;
+pub mod point;
 pub mod umad;
 pub mod with_one_over_length;
 pub mod with_rate;
@@ -0,0 +1,103 @@
+use anyhow::Result;
+use ec_core::operator::mutator::Mutator;
+use rand::{prelude::Distribution, Rng, RngCore};
+
+use crate::genome::Linear;
+
+/// Point mutation, which replaces each gene with probability `mutation_rate`
+/// by a new gene drawn from the gene generator.
+///
+/// Genes that aren't chosen are left untouched, and the length of the
+/// genome never changes.
+///
+/// A replacement gene can happen to be the same as the gene it replaces, so
+/// the number of genes that actually change can be less than the number
+/// chosen for mutation.
+pub struct PointMutation<GeneGenerator> {
+    mutation_rate: f64,
+    // Provides the new, random genes that replace the mutated ones.
+    gene_generator: GeneGenerator,
+}
+
+impl<GeneGenerator> PointMutation<GeneGenerator> {
+    /// `mutation_rate` should be in `[0, 1]`, and is clamped to that range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mutation_rate` is `NaN`, since there's no sensible rate to
+    /// clamp it to.
+    pub fn new(mutation_rate: f64, gene_generator: GeneGenerator) -> Self {
+        assert!(!mutation_rate.is_nan(), "The mutation rate can't be NaN");
+        Self {
+            mutation_rate: mutation_rate.clamp(0.0, 1.0),
+            gene_generator,
+        }
+    }
+}
+
+impl<G, GeneGenerator> Mutator<G> for PointMutation<GeneGenerator>
+where
+    G: Linear + FromIterator<G::Gene> + IntoIterator<Item = G::Gene>,
+    GeneGenerator: Distribution<G::Gene>,
+{
+    fn mutate(&self, genome: G, rng: &mut dyn RngCore) -> Result<G> {
+        Ok(genome
+            .into_iter()
+            .map(|gene| {
+                if rng.gen_bool(self.mutation_rate) {
+                    self.gene_generator.sample(rng)
+                } else {
+                    gene
+                }
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The test mutations can't fail, so unwrapping them is fine"
+)]
+mod tests {
+    use ec_core::uniform_distribution_of;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::genome::vector::Vector;
+
+    fn genome() -> Vector<char> {
+        std::iter::repeat_n('a', 1_000).collect()
+    }
+
+    #[test]
+    fn zero_rate_leaves_genome_unchanged() {
+        let mutator = PointMutation::new(0.0, uniform_distribution_of!['x']);
+        let child = mutator.mutate(genome(), &mut rand::thread_rng()).unwrap();
+        assert_eq!(child.genes, genome().genes);
+    }
+
+    #[test]
+    #[should_panic(expected = "The mutation rate can't be NaN")]
+    fn nan_rate_is_rejected() {
+        let _ = PointMutation::new(f64::NAN, uniform_distribution_of!['x']);
+    }
+
+    #[test]
+    fn mutates_about_rate_of_the_genes() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mutator = PointMutation::new(0.1, uniform_distribution_of!['x']);
+        let mut num_mutated = 0;
+        for _ in 0..100 {
+            let child = mutator.mutate(genome(), &mut rng).unwrap();
+            assert_eq!(child.size(), 1_000);
+            num_mutated += child.genes.iter().filter(|&&gene| gene == 'x').count();
+        }
+        // We expect 10,000 of the 100,000 genes to be mutated, with a
+        // standard deviation of about 95.
+        assert!(
+            (9_500..=10_500).contains(&num_mutated),
+            "Mutated {num_mutated} genes"
+        );
+    }
+}
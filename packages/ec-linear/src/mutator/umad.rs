@@ -1,18 +1,66 @@
 use ec_core::{genome::Genome, operator::mutator::Mutator};
-use rand::{prelude::Distribution, rngs::ThreadRng, Rng};
+use rand::{prelude::Distribution, Rng, RngCore};
 
 use crate::genome::Linear;
 
 /// UMAD = Uniform Mutation through random Addition and Deletion
+///
+/// Each gene in the parent has a new gene added after it with probability
+/// `addition_rate`, and is deleted with probability `deletion_rate`. Added
+/// genes are drawn from the gene generator, which doesn't have to be the
+/// distribution used to create the initial population. Deletions don't
+/// depend on the values of the genes.
+///
+/// Whether the genes added by the mutation can also be deleted is controlled
+/// by a [`DeletionBalance`], which also determines the relationship between
+/// the addition and deletion rates that leaves the expected size of a genome
+/// unchanged.
 pub struct Umad<GeneGenerator> {
     addition_rate: f64,
     deletion_rate: f64,
     empty_addition_rate: Option<f64>,
+    deletion_balance: DeletionBalance,
+    max_length: Option<usize>,
     // Provides the generator needed to generate a new, random gene
     // during the addition phase.
     gene_generator: GeneGenerator,
 }
 
+/// Which genes UMAD's deletion pass applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DeletionBalance {
+    /// Genes added by the mutation can be deleted as well as the parent's
+    /// genes. Each gene in the parent then becomes
+    /// `(1 + addition_rate) * (1 - deletion_rate)` genes on average, so the
+    /// expected size is unchanged when
+    /// `deletion_rate = addition_rate / (1 + addition_rate)`.
+    ///
+    /// This is the behavior of every constructor except
+    /// [`Umad::new_with_addition_generator`], where it's chosen explicitly.
+    #[default]
+    IncludeAddedGenes,
+    /// Only the parent's genes can be deleted. Each gene in the parent then
+    /// becomes `1 + addition_rate - deletion_rate` genes on average, so the
+    /// expected size is unchanged when `deletion_rate = addition_rate`.
+    ParentGenesOnly,
+}
+
+impl DeletionBalance {
+    /// The deletion rate that leaves the expected size of a (non-empty)
+    /// genome unchanged for the given addition rate.
+    #[must_use]
+    pub fn balanced_deletion_rate(self, addition_rate: f64) -> f64 {
+        match self {
+            Self::IncludeAddedGenes => addition_rate / (1.0 + addition_rate),
+            Self::ParentGenesOnly => addition_rate,
+        }
+    }
+
+    const fn deletes_added_genes(self) -> bool {
+        matches!(self, Self::IncludeAddedGenes)
+    }
+}
+
 impl<GeneGenerator> Umad<GeneGenerator> {
     pub const fn new(
         addition_rate: f64,
@@ -23,10 +71,32 @@ impl<GeneGenerator> Umad<GeneGenerator> {
             addition_rate,
             deletion_rate,
             empty_addition_rate: Some(addition_rate),
+            deletion_balance: DeletionBalance::IncludeAddedGenes,
+            max_length: None,
             gene_generator,
         }
     }
 
+    /// Create a UMAD mutator where added genes are drawn from
+    /// `addition_generator`, e.g., to favor different instructions than the
+    /// ones used to create the initial population, and where
+    /// `deletion_balance` says whether added genes can also be deleted.
+    pub const fn new_with_addition_generator(
+        addition_rate: f64,
+        deletion_rate: f64,
+        addition_generator: GeneGenerator,
+        deletion_balance: DeletionBalance,
+    ) -> Self {
+        Self {
+            addition_rate,
+            deletion_rate,
+            empty_addition_rate: Some(addition_rate),
+            deletion_balance,
+            max_length: None,
+            gene_generator: addition_generator,
+        }
+    }
+
     pub const fn new_with_empty_rate(
         addition_rate: f64,
         empty_addition_rate: f64,
@@ -37,6 +107,8 @@ impl<GeneGenerator> Umad<GeneGenerator> {
             addition_rate,
             deletion_rate,
             empty_addition_rate: Some(empty_addition_rate),
+            deletion_balance: DeletionBalance::IncludeAddedGenes,
+            max_length: None,
             gene_generator,
         }
     }
@@ -50,11 +122,26 @@ impl<GeneGenerator> Umad<GeneGenerator> {
             addition_rate,
             deletion_rate,
             empty_addition_rate: None,
+            deletion_balance: DeletionBalance::IncludeAddedGenes,
+            max_length: None,
             gene_generator,
         }
     }
 
-    fn new_gene<G>(&self, rng: &mut ThreadRng) -> G::Gene
+    /// Never create a child with more than `max_length` genes.
+    ///
+    /// A gene is only added if the child would still have at most
+    /// `max_length` genes even if none of the remaining parent genes are
+    /// deleted, so a parent that's no longer than `max_length` never loses
+    /// genes to the cap. A longer parent's child is truncated to `max_length`
+    /// genes.
+    #[must_use]
+    pub const fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    fn new_gene<G>(&self, rng: &mut dyn RngCore) -> G::Gene
     where
         G: Genome,
         GeneGenerator: Distribution<G::Gene>,
@@ -68,50 +155,79 @@ where
     G: Linear + IntoIterator<Item = G::Gene> + FromIterator<G::Gene>,
     GeneGenerator: Distribution<G::Gene>,
 {
-    fn mutate(&self, genome: G, rng: &mut ThreadRng) -> anyhow::Result<G> {
+    fn mutate(&self, genome: G, rng: &mut dyn RngCore) -> anyhow::Result<G> {
+        let max_length = self.max_length.unwrap_or(usize::MAX);
         if genome.size() == 0 {
             if let Some(addition_rate) = self.empty_addition_rate {
                 return Ok(rng
                     .gen_bool(addition_rate)
                     .then(|| self.new_gene::<G>(rng))
                     .into_iter()
+                    .take(max_length)
                     .collect());
             }
         }
+        // The number of genes in the child so far, and the number of parent
+        // genes that haven't been considered yet.
+        let mut child_size: usize = 0;
+        let mut num_remaining = genome.size();
         // Addition pass
         Ok(genome
             .into_iter()
             .flat_map(|gene| {
+                num_remaining = num_remaining.saturating_sub(1);
                 // The body of this closure is due to MizardX@Twitch;
                 // much nicer than my original approach.
                 let add_gene = rng.gen_bool(self.addition_rate);
                 let delete_gene = rng.gen_bool(self.deletion_rate);
-                // only called when `add_gene` is true
-                let delete_new_gene = add_gene && rng.gen_bool(self.deletion_rate);
+                // only called when `add_gene` is true, and added genes can be
+                // deleted
+                let delete_new_gene = add_gene
+                    && self.deletion_balance.deletes_added_genes()
+                    && rng.gen_bool(self.deletion_rate);
 
-                #[allow(clippy::match_bool)]
+                #[expect(
+                    clippy::match_bool,
+                    reason = "Matching on the two cases reads more clearly than an `if`/`else` here"
+                )]
                 let old_gene = match delete_gene {
                     false => Some(gene),
                     true => None,
                 };
+                child_size = child_size.saturating_add(usize::from(old_gene.is_some()));
 
+                // Only add a gene if there's room for it and all the
+                // remaining parent genes.
+                let has_room =
+                    child_size.saturating_add(1).saturating_add(num_remaining) <= max_length;
                 let new_gene = match (add_gene, delete_new_gene) {
-                    (true, false) => Some(self.new_gene::<G>(rng)),
+                    (true, false) if has_room => Some(self.new_gene::<G>(rng)),
                     _ => None,
                 };
+                child_size = child_size.saturating_add(usize::from(new_gene.is_some()));
 
                 [old_gene, new_gene]
             })
             .flatten()
+            .take(max_length)
             .collect::<G>())
     }
 }
 
 #[cfg(test)]
-#[allow(clippy::arithmetic_side_effects)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "UMAD on the test genomes can't fail, so the tests unwrap its results"
+)]
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "The test genomes are short, so the counts can't overflow"
+)]
 mod test {
     use ec_core::uniform_distribution_of;
-    use rand::thread_rng;
+    use rand::{rngs::StdRng, thread_rng, SeedableRng};
+
+    use num_traits::ToPrimitive;
 
     use super::*;
     use crate::genome::vector::Vector;
@@ -132,7 +248,6 @@ mod test {
     }
 
     #[test]
-    #[allow(clippy::unwrap_used)]
     #[ignore = "This is stochastic, and it will fail sometimes"]
     fn umad_test() {
         let mut rng = thread_rng();
@@ -172,4 +287,71 @@ mod test {
             "There should have been at least one character dropped from the parent in {child:?}"
         );
     }
+
+    // The fraction of the genes added to a long genome of 'o's that are 'x's.
+    fn fraction_of_added_xs(addition_generator: impl Distribution<char>) -> f64 {
+        let mut rng = StdRng::seed_from_u64(0);
+        let umad = Umad::new_with_addition_generator(
+            0.5,
+            0.0,
+            addition_generator,
+            DeletionBalance::ParentGenesOnly,
+        );
+        let parent = Vector {
+            genes: vec!['o'; 2_000],
+        };
+        let child = umad.mutate(parent, &mut rng).unwrap();
+        let added = child
+            .genes
+            .iter()
+            .filter(|&&c| c != 'o')
+            .collect::<Vec<_>>();
+        assert!(added.len() > 500, "Only {} genes were added", added.len());
+        let num_xs = added.iter().filter(|&&&c| c == 'x').count();
+        num_xs.to_f64().unwrap() / added.len().to_f64().unwrap()
+    }
+
+    #[test]
+    fn added_genes_come_from_addition_generator() {
+        let even = fraction_of_added_xs(uniform_distribution_of!['x', 'y']);
+        let mostly_x = fraction_of_added_xs(uniform_distribution_of!['x', 'x', 'x', 'y']);
+        assert!((0.45..0.55).contains(&even), "{even}");
+        assert!((0.7..0.8).contains(&mostly_x), "{mostly_x}");
+    }
+
+    #[test]
+    fn balanced_deletion_rates() {
+        assert!(
+            (DeletionBalance::IncludeAddedGenes.balanced_deletion_rate(0.1) - 0.1 / 1.1).abs()
+                < f64::EPSILON
+        );
+        assert!(
+            (DeletionBalance::ParentGenesOnly.balanced_deletion_rate(0.1) - 0.1).abs()
+                < f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn never_exceeds_max_length() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let umad = Umad::new(0.5, 0.1, uniform_distribution_of!['x']).with_max_length(30);
+        let mut genome = Vector {
+            genes: vec!['o'; 20],
+        };
+        for _ in 0..1_000 {
+            genome = umad.mutate(genome, &mut rng).unwrap();
+            assert!(genome.genes.len() <= 30, "{genome:?}");
+        }
+    }
+
+    #[test]
+    fn long_parent_is_truncated() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let umad = Umad::new(0.0, 0.0, uniform_distribution_of!['x']).with_max_length(5);
+        let parent = Vector {
+            genes: "abcdefgh".chars().collect(),
+        };
+        let child = umad.mutate(parent, &mut rng).unwrap();
+        assert_eq!(child.genes, ['a', 'b', 'c', 'd', 'e']);
+    }
 }
@@ -3,36 +3,19 @@ use std::ops::Not;
 use anyhow::{Context, Result};
 use ec_core::operator::mutator::Mutator;
 use num_traits::ToPrimitive;
-use rand::rngs::ThreadRng;
+use rand::RngCore;
 
 use super::with_rate::WithRate;
 use crate::genome::Linear;
 
 pub struct WithOneOverLength;
 
-impl<T> Mutator<Vec<T>> for WithOneOverLength
-where
-    T: Not<Output = T>,
-{
-    fn mutate(&self, genome: Vec<T>, rng: &mut ThreadRng) -> Result<Vec<T>> {
-        let genome_length = genome.len().to_f32().with_context(|| {
-            format!(
-                "The genome length {} couldn't be converted to an f32 value",
-                genome.len()
-            )
-        })?;
-        let mutation_rate = 1.0 / genome_length;
-        let mutator = WithRate::new(mutation_rate);
-        mutator.mutate(genome, rng)
-    }
-}
-
 impl<T> Mutator<T> for WithOneOverLength
 where
     T: Linear + FromIterator<T::Gene> + IntoIterator<Item = T::Gene>,
     T::Gene: Not<Output = T::Gene>,
 {
-    fn mutate(&self, genome: T, rng: &mut ThreadRng) -> Result<T> {
+    fn mutate(&self, genome: T, rng: &mut dyn RngCore) -> Result<T> {
         let genome_length = genome.size().to_f32().with_context(|| {
             format!(
                 "The genome length {} couldn't be converted to an f32 value",
@@ -46,6 +29,7 @@ where
 }
 
 #[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "Mutating the test genome can't fail")]
 mod tests {
     use std::iter::zip;
 
@@ -53,10 +37,8 @@ mod tests {
 
     use crate::{genome::bitstring::Bitstring, mutator::with_one_over_length::WithOneOverLength};
 
-    // This test is stochastic, so I'm going to ignore it most of the time.
     #[test]
-    #[ignore]
-    #[allow(clippy::unwrap_used)]
+    #[ignore = "This test is stochastic, so it's ignored most of the time"]
     fn mutate_one_over_does_not_change_much() {
         let mut rng = rand::thread_rng();
         let num_bits = 100;
@@ -2,7 +2,7 @@ use std::ops::Not;
 
 use anyhow::Result;
 use ec_core::operator::mutator::Mutator;
-use rand::{rngs::ThreadRng, Rng};
+use rand::{Rng, RngCore};
 
 use crate::genome::Linear;
 
@@ -10,23 +10,6 @@ pub struct WithRate {
     mutation_rate: f32,
 }
 
-// TODO: Get rid of this guy when we're just using the new
-//   struct-based `Bitstring` type.
-impl<T> Mutator<Vec<T>> for WithRate
-where
-    T: Not<Output = T>,
-{
-    fn mutate(&self, genome: Vec<T>, rng: &mut ThreadRng) -> Result<Vec<T>> {
-        Ok(genome
-            .into_iter()
-            .map(|bit| {
-                let r: f32 = rng.gen();
-                if r < self.mutation_rate { !bit } else { bit }
-            })
-            .collect())
-    }
-}
-
 // TODO: We should change this so that it mutates `genome` "in place".
 //   We own `genome`, so there's no need to make a new one every time.
 //   See the `Crossover` trait for the key idea.
@@ -35,12 +18,16 @@ where
     T: Linear + FromIterator<T::Gene> + IntoIterator<Item = T::Gene>,
     T::Gene: Not<Output = T::Gene>,
 {
-    fn mutate(&self, genome: T, rng: &mut ThreadRng) -> Result<T> {
+    fn mutate(&self, genome: T, rng: &mut dyn RngCore) -> Result<T> {
         Ok(genome
             .into_iter()
             .map(|bit| {
                 let r: f32 = rng.gen();
-                if r < self.mutation_rate { !bit } else { bit }
+                if r < self.mutation_rate {
+                    !bit
+                } else {
+                    bit
+                }
             })
             .collect())
     }
@@ -54,6 +41,7 @@ impl WithRate {
 }
 
 #[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "Mutating the test genomes can't fail")]
 mod tests {
     use std::iter::zip;
 
@@ -61,10 +49,8 @@ mod tests {
 
     use crate::{genome::bitstring::Bitstring, mutator::with_rate::WithRate};
 
-    // This test is stochastic, so I'm going to ignore it most of the time.
     #[test]
-    #[ignore]
-    #[allow(clippy::unwrap_used)]
+    #[ignore = "This test is stochastic, so it's ignored most of the time"]
     fn mutate_using_generator_with_rate_does_not_change_much() {
         let mutator = WithRate {
             mutation_rate: 0.05,
@@ -90,10 +76,8 @@ mod tests {
         );
     }
 
-    // This test is stochastic, so I'm going to ignore it most of the time.
     #[test]
-    #[ignore]
-    #[allow(clippy::unwrap_used)]
+    #[ignore = "This test is stochastic, so it's ignored most of the time"]
     fn mutate_bitstring_with_rate_does_not_change_much() {
         let mutator = WithRate {
             mutation_rate: 0.05,
@@ -1,5 +1,7 @@
 use std::ops::Range;
 
+use anyhow::bail;
+
 use crate::genome::Linear;
 
 // TODO: Does `Crossover` need to be visible outside
@@ -27,3 +29,92 @@ pub trait Crossover: Linear {
         Ok(())
     }
 }
+
+impl<T> Crossover for Vec<T> {
+    fn crossover_gene(&mut self, other: &mut Self, index: usize) -> anyhow::Result<()> {
+        if let (Some(lhs), Some(rhs)) = (self.get_mut(index), other.get_mut(index)) {
+            std::mem::swap(lhs, rhs);
+            Ok(())
+        } else {
+            bail!(
+                "Crossing vectors of lengths {} and {} at position {index} failed",
+                self.len(),
+                other.len()
+            )
+        }
+    }
+
+    fn crossover_segment(&mut self, other: &mut Self, range: Range<usize>) -> anyhow::Result<()> {
+        if let (Some(lhs), Some(rhs)) = (self.get_mut(range.clone()), other.get_mut(range.clone()))
+        {
+            lhs.swap_with_slice(rhs);
+            Ok(())
+        } else {
+            bail!(
+                "Crossing vectors of lengths {} and {} with range {range:?} failed",
+                self.len(),
+                other.len()
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The test recombinations can't fail, so unwrapping their results is fine"
+)]
+#[expect(
+    clippy::float_cmp,
+    reason = "The expected values are computed exactly, so exact comparison is correct"
+)]
+mod tests {
+    use ec_core::operator::recombinator::Recombinator;
+
+    use super::*;
+    use crate::recombinator::{
+        n_point_xo::NPointXo, two_point_xo::TwoPointXo, uniform_xo::UniformXo,
+    };
+
+    fn parents() -> [Vec<f64>; 2] {
+        [vec![0.0; 10], vec![1.0; 10]]
+    }
+
+    #[test]
+    fn crossover_segment_swaps_range() {
+        let [mut first, mut second] = parents();
+        first.crossover_segment(&mut second, 2..5).unwrap();
+        assert_eq!(first, [0.0, 0.0, 1.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(second, [1.0, 1.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0, 1.0]);
+        assert!(first.crossover_segment(&mut second, 8..11).is_err());
+        assert!(first.crossover_gene(&mut second, 10).is_err());
+    }
+
+    #[test]
+    fn two_point_xo_on_real_valued_vectors() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let child = TwoPointXo.recombine(parents(), &mut rng).unwrap();
+            assert_eq!(child.len(), 10);
+            // The child is the first parent with (possibly empty) segment
+            // from the second parent in the middle.
+            let start = child.iter().position(|&x| x == 1.0).unwrap_or(10);
+            let end = child
+                .iter()
+                .rposition(|&x| x == 1.0)
+                .map_or(start, |i| i + 1);
+            assert!(child[..start].iter().all(|&x| x == 0.0));
+            assert!(child[start..end].iter().all(|&x| x == 1.0));
+            assert!(child[end..].iter().all(|&x| x == 0.0));
+        }
+    }
+
+    #[test]
+    fn other_recombinators_on_real_valued_vectors() {
+        let mut rng = rand::thread_rng();
+        let child = NPointXo::<3>::new().recombine(parents(), &mut rng).unwrap();
+        assert_eq!(child.len(), 10);
+        let child = UniformXo.recombine(parents(), &mut rng).unwrap();
+        assert!(child.iter().all(|&x| x == 0.0 || x == 1.0));
+    }
+}
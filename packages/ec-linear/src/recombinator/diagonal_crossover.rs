@@ -0,0 +1,150 @@
+use anyhow::{bail, ensure, Result};
+use ec_core::operator::recombinator::Recombinator;
+use rand::{seq::index, Rng, RngCore};
+
+use super::crossover::Crossover;
+use crate::genome::Linear;
+
+/// Diagonal crossover, which recombines `K` parents into one child.
+///
+/// This picks `K - 1` distinct crossover points, which split the genomes
+/// into `K` non-empty segments, and a random starting parent `start`. The
+/// child then takes segment `i` from parent `(start + i) mod K`, so every
+/// parent contributes exactly one segment. `DiagonalCrossover<2>` is
+/// one-point crossover, except that either parent can come first.
+///
+/// All the parents must have the same length, and that length must be at
+/// least `K` so there is room for `K - 1` crossover points.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiagonalCrossover<const K: usize>;
+
+impl<G, const K: usize> Recombinator<[G; K]> for DiagonalCrossover<K>
+where
+    G: Crossover + Linear,
+{
+    type Output = G;
+
+    fn recombine(&self, parents: [G; K], rng: &mut dyn RngCore) -> Result<Self::Output> {
+        let sizes: Vec<usize> = parents.iter().map(Linear::size).collect();
+        let Some(&len) = sizes.first() else {
+            bail!("Attempted to perform DiagonalCrossover with no parents");
+        };
+        ensure!(
+            sizes.iter().all(|&size| size == len),
+            "Attempted to perform DiagonalCrossover on genomes of different lengths {sizes:?}"
+        );
+        ensure!(
+            K <= len,
+            "Attempted to perform DiagonalCrossover with {K} parents on genomes of length {len}, \
+             which need to have at least {K} genes"
+        );
+
+        // Points are in `1..len`, so every segment between them is non-empty.
+        let mut boundaries: Vec<usize> =
+            index::sample(rng, len.saturating_sub(1), K.saturating_sub(1))
+                .into_iter()
+                .map(|point| point.saturating_add(1))
+                .collect();
+        boundaries.sort_unstable();
+        boundaries.insert(0, 0);
+        boundaries.push(len);
+
+        // Rotating the parents by `start` puts them in the same order as the
+        // segments they contribute, so the child starts as the first parent
+        // and takes each later segment from the corresponding parent.
+        let mut parents = Vec::from(parents);
+        parents.rotate_left(rng.gen_range(0..K));
+        let (child, donors) = parents.split_at_mut(1);
+        for (donor, segment) in donors.iter_mut().zip(boundaries.windows(2).skip(1)) {
+            child[0].crossover_segment(donor, segment[0]..segment[1])?;
+        }
+
+        Ok(parents.swap_remove(0))
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The tests unwrap recombinations of equal-length parents"
+)]
+mod tests {
+    use super::*;
+    use crate::genome::{bitstring::Bitstring, vector::Vector};
+
+    // Three parents of length `len`, where every gene in parent `i` is `i`.
+    fn parents(len: usize) -> [Vector<usize>; 3] {
+        [0, 1, 2].map(|parent| std::iter::repeat_n(parent, len).collect())
+    }
+
+    // The runs of equal genes in `child`, as `(gene, length)` pairs.
+    fn segments(child: &Vector<usize>) -> Vec<(usize, usize)> {
+        child
+            .genes
+            .chunk_by(|x, y| x == y)
+            .map(|run| (run[0], run.len()))
+            .collect()
+    }
+
+    #[test]
+    fn each_segment_comes_from_the_next_parent() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let child = DiagonalCrossover::<3>
+                .recombine(parents(10), &mut rng)
+                .unwrap();
+            let segments = segments(&child);
+            assert_eq!(segments.len(), 3, "{:?}", child.genes);
+            let start = segments[0].0;
+            for (i, &(parent, _)) in segments.iter().enumerate() {
+                assert_eq!(parent, (start + i) % 3, "{:?}", child.genes);
+            }
+            assert_eq!(segments.iter().map(|&(_, len)| len).sum::<usize>(), 10);
+        }
+    }
+
+    #[test]
+    fn minimum_length_uses_one_gene_from_each_parent() {
+        let mut rng = rand::thread_rng();
+        let child = DiagonalCrossover::<3>
+            .recombine(parents(3), &mut rng)
+            .unwrap();
+        let segments = segments(&child);
+        assert_eq!(segments.len(), 3);
+        assert!(segments.iter().all(|&(_, len)| len == 1));
+    }
+
+    #[test]
+    fn rejects_short_genomes() {
+        let mut rng = rand::thread_rng();
+        assert!(DiagonalCrossover::<3>
+            .recombine(parents(2), &mut rng)
+            .is_err());
+    }
+
+    #[test]
+    fn rejects_different_lengths() {
+        let mut rng = rand::thread_rng();
+        let [first, second, _] = parents(5);
+        let third = std::iter::repeat_n(2, 6).collect();
+        assert!(DiagonalCrossover::<3>
+            .recombine([first, second, third], &mut rng)
+            .is_err());
+    }
+
+    #[test]
+    fn works_with_bitstrings() {
+        let mut rng = rand::thread_rng();
+        let parents: [Bitstring; 2] = [
+            std::iter::repeat_n(false, 4).collect(),
+            std::iter::repeat_n(true, 4).collect(),
+        ];
+        let child = DiagonalCrossover::<2>.recombine(parents, &mut rng).unwrap();
+        let num_switches = child
+            .bits
+            .windows(2)
+            .filter(|pair| pair[0] != pair[1])
+            .count();
+        assert_eq!(num_switches, 1);
+    }
+}
@@ -1,3 +1,6 @@
 pub mod crossover;
+pub mod diagonal_crossover;
+pub mod n_point_xo;
 pub mod two_point_xo;
 pub mod uniform_xo;
+pub mod variable_length_two_point_xo;
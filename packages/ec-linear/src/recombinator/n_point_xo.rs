@@ -0,0 +1,173 @@
+use anyhow::{ensure, Result};
+use ec_core::operator::recombinator::Recombinator;
+use rand::{seq::index, RngCore};
+
+use super::crossover::Crossover;
+
+/// N-point crossover, which picks `N` distinct crossover points and makes a
+/// child that alternates between the two parents at each point.
+///
+/// The child starts with the first parent.
+/// [`TwoPointXo`](super::two_point_xo::TwoPointXo) is similar to
+/// `NPointXo<2>`, except that its two points may coincide.
+///
+/// A crossover point is a position between two genes, so there are only
+/// `len - 1` possible points in a genome of length `len`. By default (with
+/// [`NPointXo::new`]) it is an error to recombine genomes with fewer than
+/// `N + 1` genes. With [`NPointXo::with_fallback`], such genomes are instead
+/// recombined using as many points as the length allows, i.e., `len - 1`
+/// points, so genomes of length 0 or 1 are returned unchanged (as the first
+/// parent). This is useful if a population occasionally contains tiny
+/// genomes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NPointXo<const N: usize> {
+    fallback: bool,
+}
+
+impl<const N: usize> NPointXo<N> {
+    /// Strict `N`-point crossover, which fails on genomes shorter than
+    /// `N + 1`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { fallback: false }
+    }
+
+    /// Use fewer crossover points (rather than failing) on genomes shorter
+    /// than `N + 1`; see [`NPointXo`].
+    #[must_use]
+    pub const fn with_fallback(mut self) -> Self {
+        self.fallback = true;
+        self
+    }
+
+    // The number of crossover points to use for genomes of length `len`.
+    fn num_points(self, len: usize) -> Result<usize> {
+        let max_points = len.saturating_sub(1);
+        ensure!(
+            self.fallback || N <= max_points,
+            "Attempted to perform {N}-point crossover on genomes of length {len}, which need to \
+             have at least {} genes",
+            N.saturating_add(1)
+        );
+        Ok(N.min(max_points))
+    }
+}
+
+impl<G, const N: usize> Recombinator<[G; 2]> for NPointXo<N>
+where
+    G: Crossover,
+{
+    type Output = G;
+
+    fn recombine(
+        &self,
+        [mut first_genome, mut second_genome]: [G; 2],
+        rng: &mut dyn RngCore,
+    ) -> Result<Self::Output> {
+        ensure!(
+            first_genome.size() == second_genome.size(),
+            "Attempted to perform NPointXo on genomes of different lengths {} and {}",
+            first_genome.size(),
+            second_genome.size()
+        );
+        let len = first_genome.size();
+        let num_points = self.num_points(len)?;
+        // Points are in `1..len`, so every segment between them is non-empty.
+        let mut points: Vec<usize> = index::sample(rng, len.saturating_sub(1), num_points)
+            .into_iter()
+            .map(|point| point.saturating_add(1))
+            .collect();
+        points.sort_unstable();
+        // The child takes the segments between the first and second points,
+        // the third and fourth, and so on, from the second parent. With an
+        // odd number of points the last of those runs to the end.
+        points.push(len);
+        for segment in points.chunks_exact(2) {
+            first_genome.crossover_segment(&mut second_genome, segment[0]..segment[1])?;
+        }
+
+        Ok(first_genome)
+    }
+}
+
+impl<G, const N: usize> Recombinator<(G, G)> for NPointXo<N>
+where
+    G: Crossover,
+{
+    type Output = G;
+
+    fn recombine(&self, genomes: (G, G), rng: &mut dyn RngCore) -> Result<Self::Output> {
+        self.recombine(<[G; 2]>::from(genomes), rng)
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The tests unwrap recombinations of genomes long enough for the crossover points"
+)]
+mod tests {
+    use super::*;
+    use crate::genome::bitstring::Bitstring;
+
+    fn parents(len: usize) -> [Bitstring; 2] {
+        [
+            std::iter::repeat_n(false, len).collect(),
+            std::iter::repeat_n(true, len).collect(),
+        ]
+    }
+
+    // The number of places where consecutive genes in `child` differ, which
+    // is the number of crossover points used to make it from `parents`.
+    fn num_switches(child: &Bitstring) -> usize {
+        child
+            .bits
+            .windows(2)
+            .filter(|pair| pair[0] != pair[1])
+            .count()
+    }
+
+    #[test]
+    fn uses_n_points() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let child = NPointXo::<3>::new()
+                .recombine(parents(10), &mut rng)
+                .unwrap();
+            assert_eq!(num_switches(&child), 3);
+            // The child always starts with the first parent.
+            assert!(!child.bits[0]);
+        }
+    }
+
+    #[test]
+    fn strict_rejects_short_genomes() {
+        let mut rng = rand::thread_rng();
+        assert!(NPointXo::<4>::new()
+            .recombine(parents(2), &mut rng)
+            .is_err());
+        assert!(NPointXo::<4>::new().recombine(parents(5), &mut rng).is_ok());
+    }
+
+    #[test]
+    fn fallback_uses_single_point_for_length_two() {
+        let mut rng = rand::thread_rng();
+        let child = NPointXo::<4>::new()
+            .with_fallback()
+            .recombine(parents(2), &mut rng)
+            .unwrap();
+        assert_eq!(child.bits, [false, true]);
+    }
+
+    #[test]
+    fn fallback_leaves_tiny_genomes_unchanged() {
+        let mut rng = rand::thread_rng();
+        for len in [0, 1] {
+            let child = NPointXo::<4>::new()
+                .with_fallback()
+                .recombine(parents(len), &mut rng)
+                .unwrap();
+            assert_eq!(child, parents(len)[0]);
+        }
+    }
+}
@@ -1,47 +1,21 @@
 use anyhow::{ensure, Result};
 use ec_core::operator::recombinator::Recombinator;
-use rand::{rngs::ThreadRng, Rng};
+use rand::{Rng, RngCore};
 
 use super::crossover::Crossover;
 
 pub struct TwoPointXo;
 
-// TODO: Remove the `Vec<T>` versions when we're done migrating
-//   to the struct-based version of `Bitstring`.
-impl<T> Recombinator<[Vec<T>; 2]> for TwoPointXo {
-    type Output = Vec<T>;
-
-    fn recombine(
-        &self,
-        [mut first_genome, mut second_genome]: [Vec<T>; 2],
-        rng: &mut ThreadRng,
-    ) -> Result<Self::Output> {
-        ensure!(
-            first_genome.len() == second_genome.len(),
-            "Attempted to perform TwoPointXo on genomes of different lengths {} and {}",
-            first_genome.len(),
-            second_genome.len()
-        );
-        let len = first_genome.len();
-
-        let mut first = rng.gen_range(0..len);
-        let mut second = rng.gen_range(0..len);
-        if second < first {
-            (first, second) = (second, first);
-        }
-        // We now know that first <= second
-        first_genome[first..second].swap_with_slice(&mut second_genome[first..second]);
-        Ok(first_genome)
-    }
-}
-
-impl<T> Recombinator<(Vec<T>, Vec<T>)> for TwoPointXo {
-    type Output = Vec<T>;
-
-    fn recombine(&self, genomes: (Vec<T>, Vec<T>), rng: &mut ThreadRng) -> Result<Self::Output> {
-        self.recombine(<[Vec<T>; 2]>::from(genomes), rng)
-    }
-}
+/// Two-point crossover that returns both children.
+///
+/// The children are the first parent with the crossover segment taken from
+/// the second, and the second parent with the segment taken from the first.
+/// The first child is the one [`TwoPointXo`] returns.
+///
+/// To use both children in a pipeline, follow this with
+/// [`then_map`](ec_core::operator::composable::Composable::then_map), e.g.,
+/// to mutate each child.
+pub struct TwoPointXoPair;
 
 // TODO: Note that `TwoPointXo` doesn't strictly need
 //   the two vectors to have the same length, but the
@@ -61,10 +35,33 @@ where
 {
     type Output = G;
 
+    fn recombine(&self, genomes: [G; 2], rng: &mut dyn RngCore) -> Result<Self::Output> {
+        let [child, _] = TwoPointXoPair.recombine(genomes, rng)?;
+        Ok(child)
+    }
+}
+
+impl<G> Recombinator<(G, G)> for TwoPointXo
+where
+    G: Crossover,
+{
+    type Output = G;
+
+    fn recombine(&self, genomes: (G, G), rng: &mut dyn RngCore) -> Result<Self::Output> {
+        self.recombine(<[G; 2]>::from(genomes), rng)
+    }
+}
+
+impl<G> Recombinator<[G; 2]> for TwoPointXoPair
+where
+    G: Crossover,
+{
+    type Output = [G; 2];
+
     fn recombine(
         &self,
         [mut first_genome, mut second_genome]: [G; 2],
-        rng: &mut ThreadRng,
+        rng: &mut dyn RngCore,
     ) -> Result<Self::Output> {
         ensure!(
             first_genome.size() == second_genome.size(),
@@ -80,17 +77,61 @@ where
         }
         first_genome.crossover_segment(&mut second_genome, first..second)?;
 
-        Ok(first_genome)
+        Ok([first_genome, second_genome])
     }
 }
 
-impl<G> Recombinator<(G, G)> for TwoPointXo
+impl<G> Recombinator<(G, G)> for TwoPointXoPair
 where
     G: Crossover,
 {
-    type Output = G;
+    type Output = [G; 2];
 
-    fn recombine(&self, genomes: (G, G), rng: &mut ThreadRng) -> Result<Self::Output> {
+    fn recombine(&self, genomes: (G, G), rng: &mut dyn RngCore) -> Result<Self::Output> {
         self.recombine(<[G; 2]>::from(genomes), rng)
     }
 }
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The tests unwrap the children, which must exist for the parents they build"
+)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::genome::vector::Vector;
+
+    #[test]
+    fn children_are_complementary() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let parents: [Vector<usize>; 2] = [(0..10).collect(), (100..110).collect()];
+            let [first_child, second_child] = TwoPointXoPair.recombine(parents, &mut rng).unwrap();
+            for (index, (&x, &y)) in first_child
+                .genes
+                .iter()
+                .zip(&second_child.genes)
+                .enumerate()
+            {
+                // Each position has one gene from each parent, with one in
+                // each child.
+                assert_eq!(x.min(y), index);
+                assert_eq!(x.max(y), index + 100);
+            }
+        }
+    }
+
+    #[test]
+    fn two_point_xo_returns_the_first_child() {
+        let parents: [Vector<usize>; 2] = [(0..10).collect(), (100..110).collect()];
+        let [expected, _] = TwoPointXoPair
+            .recombine(parents.clone(), &mut StdRng::seed_from_u64(0))
+            .unwrap();
+        let child = TwoPointXo
+            .recombine(parents, &mut StdRng::seed_from_u64(0))
+            .unwrap();
+        assert_eq!(child.genes, expected.genes);
+    }
+}
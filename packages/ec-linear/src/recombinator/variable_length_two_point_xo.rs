@@ -0,0 +1,132 @@
+use anyhow::Result;
+use ec_core::operator::recombinator::Recombinator;
+use rand::{Rng, RngCore};
+
+use crate::genome::Linear;
+
+/// Two-point crossover for genomes that can have different lengths.
+///
+/// This picks a segment of each parent independently, and the child is the
+/// first parent with its segment replaced by the second parent's segment.
+/// Since the segments can have different lengths, the child can be longer or
+/// shorter than both parents. Either segment can be empty, so this can also
+/// just insert part of the second parent into the first, or just delete part
+/// of the first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VariableLengthTwoPointXo {
+    max_length: Option<usize>,
+}
+
+impl VariableLengthTwoPointXo {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { max_length: None }
+    }
+
+    /// Never create a child with more than `max_length` genes; longer
+    /// children are truncated to `max_length` genes.
+    #[must_use]
+    pub const fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    // A random segment `start..end` of a genome with `len` genes.
+    fn segment(len: usize, rng: &mut dyn RngCore) -> (usize, usize) {
+        let first = rng.gen_range(0..=len);
+        let second = rng.gen_range(0..=len);
+        (first.min(second), first.max(second))
+    }
+}
+
+impl<G> Recombinator<[G; 2]> for VariableLengthTwoPointXo
+where
+    G: Linear + IntoIterator<Item = G::Gene> + FromIterator<G::Gene>,
+{
+    type Output = G;
+
+    fn recombine(
+        &self,
+        [first_genome, second_genome]: [G; 2],
+        rng: &mut dyn RngCore,
+    ) -> Result<Self::Output> {
+        let (first_start, first_end) = Self::segment(first_genome.size(), rng);
+        let (second_start, second_end) = Self::segment(second_genome.size(), rng);
+
+        let mut genes: Vec<G::Gene> = first_genome.into_iter().collect();
+        let tail = genes.split_off(first_end);
+        genes.truncate(first_start);
+        genes.extend(
+            second_genome
+                .into_iter()
+                .skip(second_start)
+                .take(second_end.saturating_sub(second_start)),
+        );
+        genes.extend(tail);
+        if let Some(max_length) = self.max_length {
+            genes.truncate(max_length);
+        }
+
+        Ok(genes.into_iter().collect())
+    }
+}
+
+impl<G> Recombinator<(G, G)> for VariableLengthTwoPointXo
+where
+    G: Linear + IntoIterator<Item = G::Gene> + FromIterator<G::Gene>,
+{
+    type Output = G;
+
+    fn recombine(&self, genomes: (G, G), rng: &mut dyn RngCore) -> Result<Self::Output> {
+        self.recombine(<[G; 2]>::from(genomes), rng)
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "An error from recombining the test parents should fail the test"
+)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::genome::vector::Vector;
+
+    // Parents of lengths 5 and 8, where the genes are their positions in the
+    // parent, offset by 100 in the second parent.
+    fn parents() -> [Vector<usize>; 2] {
+        [(0..5).collect(), (100..108).collect()]
+    }
+
+    #[test]
+    fn child_is_a_splice_of_the_parents() {
+        for seed in 0..100 {
+            let child = VariableLengthTwoPointXo::new()
+                .recombine(parents(), &mut StdRng::seed_from_u64(seed))
+                .unwrap();
+            // Replay the same random choices to find the cut points.
+            let mut rng = StdRng::seed_from_u64(seed);
+            let (first_start, first_end) = VariableLengthTwoPointXo::segment(5, &mut rng);
+            let (second_start, second_end) = VariableLengthTwoPointXo::segment(8, &mut rng);
+            // The child is a prefix of the first parent, followed by a
+            // segment of the second parent, followed by a suffix of the
+            // first parent.
+            let expected: Vec<_> = (0..first_start)
+                .chain((100 + second_start)..(100 + second_end))
+                .chain(first_end..5)
+                .collect();
+            assert_eq!(child.genes, expected, "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn respects_max_length() {
+        let mut rng = rand::thread_rng();
+        let xo = VariableLengthTwoPointXo::new().with_max_length(6);
+        for _ in 0..100 {
+            let child = xo.recombine(parents(), &mut rng).unwrap();
+            assert!(child.size() <= 6, "{:?}", child.genes);
+        }
+    }
+}
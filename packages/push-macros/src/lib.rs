@@ -2,7 +2,13 @@ use push_state::printing::generate_builder::generate_builder;
 use quote::quote;
 use syn::{spanned::Spanned, DeriveInput};
 
-use crate::push_state::{parsing::parse_fields, printing::derive_has_stack::derive_has_stack};
+use crate::push_state::{
+    parsing::parse_fields,
+    printing::{
+        derive_has_stack::derive_has_stack,
+        generate_common_instructions::generate_common_instructions,
+    },
+};
 
 mod doctest_tokenstream;
 mod push_state;
@@ -23,6 +29,11 @@ mod push_state;
 /// This derives the HasStack trait for all stacks in the state.
 /// You need to indicate which fields are stacks
 /// using the `#[stack]` attribute on the corresponding field.
+/// It also adds a `stack_summary()` method that returns the name (the field
+/// name) and current size of every stack, with the exec stack first, and
+/// `visit_stacks()` and `visit_stacks_mut()` methods that pass every stack
+/// (in the same order) to a `push::push_vm::stack::VisitStack` or
+/// `VisitStackMut`.
 ///
 /// ## Builder (disabled by default)
 /// This creates a builder for this state.
@@ -41,6 +52,21 @@ mod push_state;
 /// can use the `ignore_doctests` flag to annotate every code example of the
 /// stack with the `ignore` attribute.
 ///
+/// A field marked with `#[input_instructions]` holds the instructions for the
+/// input variables, set with the builder's `with_<stack>_input` methods. A
+/// field of type `push::push_vm::named_output::OutputChannels` marked with
+/// `#[output_channels]` adds `with_output_channel` and `with_max_output_size`
+/// methods to the builder.
+///
+/// ## CommonInstructions (disabled by default)
+/// This creates an enum named `<State>CommonInstruction` with a variant for
+/// each (non-exec) stack, named after the field in `PascalCase`, holding a
+/// `push::instruction::common::CommonInstruction` (`Dup`, `Pop`, `Swap`,
+/// `Flush`, `StackDepth`, and `IsEmpty`) for that stack, and implements
+/// `Instruction<State>` for it. This requires the HasStack feature, and the
+/// state must have `i64` and `bool` stacks for `StackDepth` and `IsEmpty`
+/// to push their results onto.
+///
 /// # Example
 /// ```ignore
 /// #[push_state::push_state(builder)]
@@ -94,11 +120,23 @@ pub fn push_state(
         .into());
     };
 
-    let (stacks, exec_stack, input_instructions) = parse_fields(fields, macro_span, &macro_flags)?;
+    let (stacks, exec_stack, special_fields) = parse_fields(fields, macro_span, &macro_flags)?;
+
+    if macro_flags.common_instructions && !macro_flags.has_stack {
+        return Err(syn::Error::new(
+            macro_span,
+            "The common_instructions feature requires the has_stack feature.",
+        )
+        .into());
+    }
 
     let has_stack_derives = macro_flags
         .has_stack
-        .then(|| derive_has_stack(struct_ident, &stacks, &exec_stack));
+        .then(|| derive_has_stack(struct_ident, struct_visibility, &stacks, &exec_stack));
+
+    let common_instructions = macro_flags
+        .common_instructions
+        .then(|| generate_common_instructions(struct_ident, struct_visibility, &stacks));
 
     let builder = macro_flags
         .builder
@@ -110,7 +148,7 @@ pub fn push_state(
                 struct_generics,
                 &stacks,
                 &exec_stack,
-                input_instructions,
+                special_fields,
             )
         })
         .transpose()?;
@@ -118,6 +156,7 @@ pub fn push_state(
     Ok(quote! {
         #struct_defn
         #has_stack_derives
+        #common_instructions
         #builder
     })
 }
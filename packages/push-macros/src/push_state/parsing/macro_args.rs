@@ -6,6 +6,7 @@ use super::FlagsValue;
 pub struct PushStateFlags {
     pub builder: bool,
     pub has_stack: bool,
+    pub common_instructions: bool,
 }
 
 impl Default for PushStateFlags {
@@ -13,15 +14,18 @@ impl Default for PushStateFlags {
         Self {
             builder: false,
             has_stack: true,
+            common_instructions: false,
         }
     }
 }
 syn::custom_keyword!(builder);
 syn::custom_keyword!(has_stack);
+syn::custom_keyword!(common_instructions);
 
 pub enum PushStateFlagsKw {
     Builder(builder),
     HasStack(has_stack),
+    CommonInstructions(common_instructions),
 }
 
 impl ToTokens for PushStateFlagsKw {
@@ -29,6 +33,7 @@ impl ToTokens for PushStateFlagsKw {
         match self {
             Self::Builder(t) => t.to_tokens(tokens),
             Self::HasStack(t) => t.to_tokens(tokens),
+            Self::CommonInstructions(t) => t.to_tokens(tokens),
         }
     }
 }
@@ -39,6 +44,8 @@ impl Parse for PushStateFlagsKw {
             PushStateFlagsKw::Builder(input.parse()?)
         } else if input.peek(has_stack) {
             PushStateFlagsKw::HasStack(input.parse()?)
+        } else if input.peek(common_instructions) {
+            PushStateFlagsKw::CommonInstructions(input.parse()?)
         } else {
             return Err(input.error("Expected flag"));
         })
@@ -53,6 +60,7 @@ impl Parse for PushStateFlags {
 
         let mut builder_flag_set = false;
         let mut has_stack_flag_set = false;
+        let mut common_instructions_flag_set = false;
 
         let mut current_flags = PushStateFlags::default();
         for flag in parsed_flags_list {
@@ -87,6 +95,22 @@ impl Parse for PushStateFlags {
                     has_stack_flag_set = true;
                     current_flags.has_stack = set_to;
                 }
+                PushStateFlagsKw::CommonInstructions(_)
+                    if default_flags.common_instructions == set_to =>
+                {
+                    return Err(syn::Error::new_spanned(
+                        flag,
+                        "Redundant flag, this is set by default. Maybe you meant to use !flag to \
+                         disable it?",
+                    ));
+                }
+                PushStateFlagsKw::CommonInstructions(_) if common_instructions_flag_set => {
+                    return Err(syn::Error::new_spanned(flag, "Flag already set."));
+                }
+                PushStateFlagsKw::CommonInstructions(_) => {
+                    common_instructions_flag_set = true;
+                    current_flags.common_instructions = set_to;
+                }
             }
         }
 
@@ -47,7 +47,14 @@ impl<T: Parse> Parse for FlagsValue<T> {
 
 pub type StacksInput = BTreeMap<Ident, (StackMarkerFlags, Type)>;
 pub type ExecStackInput = Option<(Ident, StackMarkerFlags, Type)>;
-pub type InputInstructionsInput = Option<Ident>;
+
+/// The fields marked with `#[input_instructions]` and `#[output_channels]`,
+/// if there are any.
+#[derive(Default)]
+pub struct SpecialFields {
+    pub input_instructions: Option<Ident>,
+    pub output_channels: Option<Ident>,
+}
 
 pub fn parse_fields(
     fields: &mut Punctuated<Field, Token![,]>,
@@ -55,12 +62,13 @@ pub fn parse_fields(
     PushStateFlags {
         builder: generate_builder,
         has_stack: derive_has_stack,
+        ..
     }: &PushStateFlags,
-) -> syn::Result<(StacksInput, ExecStackInput, InputInstructionsInput)> {
+) -> syn::Result<(StacksInput, ExecStackInput, SpecialFields)> {
     let mut stacks: BTreeMap<Ident, (StackMarkerFlags, Type)> = BTreeMap::new();
     let mut exec_stack: Option<(Ident, StackMarkerFlags, Type)> = None;
 
-    let mut input_instructions: Option<Ident> = None;
+    let mut special_fields = SpecialFields::default();
 
     for Field {
         attrs, ident, ty, ..
@@ -75,16 +83,13 @@ pub fn parse_fields(
         })?;
         let mut i = 0;
         // Consume elements that are for our macro from the input to not return them
-        loop {
-            let Some(to_compare) = attrs.get(i) else {
-                break;
-            };
+        while let Some(to_compare) = attrs.get(i) {
             if to_compare.meta.path() == &syn::parse_quote!(stack) {
                 matching_attrs.push(attrs.remove(i));
                 continue;
             }
             if to_compare.meta.path() == &syn::parse_quote!(input_instructions) {
-                if input_instructions.is_some() {
+                if special_fields.input_instructions.is_some() {
                     return Err(syn::Error::new_spanned(
                         to_compare,
                         "Only one input instructions field is supported",
@@ -97,7 +102,24 @@ pub fn parse_fields(
                     ));
                 }
                 attrs.remove(i);
-                input_instructions = Some(ident.clone());
+                special_fields.input_instructions = Some(ident.clone());
+                continue;
+            }
+            if to_compare.meta.path() == &syn::parse_quote!(output_channels) {
+                if special_fields.output_channels.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        to_compare,
+                        "Only one output channels field is supported",
+                    ));
+                }
+                if !matches!(to_compare.meta, syn::Meta::Path(_)) {
+                    return Err(syn::Error::new_spanned(
+                        to_compare,
+                        "This attribute does not support any arguments",
+                    ));
+                }
+                attrs.remove(i);
+                special_fields.output_channels = Some(ident.clone());
                 continue;
             }
             i += 1;
@@ -264,5 +286,5 @@ pub fn parse_fields(
             stacks.insert(ident, (stack_marker_flags, ty.clone()));
         }
     }
-    Ok((stacks, exec_stack, input_instructions))
+    Ok((stacks, exec_stack, special_fields))
 }
@@ -1,11 +1,44 @@
 use proc_macro2::TokenStream;
-use quote::quote;
-use syn::Ident;
+use quote::{quote, ToTokens};
+use syn::{GenericArgument, Ident, PathArguments, Type, Visibility};
 
 use crate::push_state::parsing::{ExecStackInput, StacksInput};
 
+/// The type of the values in a stack of type `ty`.
+///
+/// For a stack written as `Stack<T>` this is just `T`. The
+/// `<#ty as StackType>::Type` projection would work everywhere, but outside of
+/// the `push` crate the compiler can't tell that two such projections are
+/// different types, so the `HasStack` implementations for a state with more
+/// than one stack would conflict. The projection is only used as a fallback,
+/// e.g., for type aliases.
+fn stack_element_type(ty: &Type) -> TokenStream {
+    let element_type = match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => type_path
+            .path
+            .segments
+            .last()
+            .filter(|segment| segment.ident == "Stack")
+            .and_then(|segment| match &segment.arguments {
+                PathArguments::AngleBracketed(arguments) if arguments.args.len() == 1 => {
+                    match arguments.args.first() {
+                        Some(GenericArgument::Type(element_type)) => Some(element_type),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }),
+        _ => None,
+    };
+    element_type.map_or_else(
+        || quote! { <#ty as ::push::push_vm::stack::StackType>::Type },
+        ToTokens::to_token_stream,
+    )
+}
+
 pub fn derive_has_stack(
     struct_ident: &Ident,
+    struct_visibility: &Visibility,
     stacks: &StacksInput,
     exec_stack: &ExecStackInput,
 ) -> TokenStream {
@@ -22,33 +55,97 @@ pub fn derive_has_stack(
         stacks_to_derive_for.push((ident, ty));
     }
 
-    stacks_to_derive_for
+    // The summary (and the visitors) list the exec stack (if there is one)
+    // first, followed by the other stacks in order of their field names.
+    let ordered_stacks = exec_stack
+        .iter()
+        .map(|(ident, _, ty)| (ident, ty))
+        .chain(stacks.iter().map(|(ident, (_, ty))| (ident, ty)))
+        .collect::<Vec<_>>();
+    let summary_entries = ordered_stacks.iter().map(|(ident, _)| {
+        let name = ident.to_string();
+        quote! { (#name, self.#ident.size()) }
+    });
+    let names = ordered_stacks
+        .iter()
+        .map(|(ident, _)| ident.to_string())
+        .collect::<Vec<_>>();
+    let idents = ordered_stacks
+        .iter()
+        .map(|(ident, _)| ident)
+        .collect::<Vec<_>>();
+    let element_types = ordered_stacks
+        .iter()
+        .map(|(_, ty)| stack_element_type(ty))
+        .collect::<Vec<_>>();
+    let stack_summary = quote! {
+        #[automatically_derived]
+        impl #struct_ident {
+            /// The name (i.e., the field name) and current size of each of
+            /// the stacks in this state, e.g., for logging.
+            #[must_use]
+            #struct_visibility fn stack_summary(&self) -> ::std::vec::Vec<(&'static str, usize)> {
+                ::std::vec![#(#summary_entries),*]
+            }
+
+            /// Calls `visitor` with the name and a reference to each of the
+            /// stacks in this state, in the same order as `stack_summary`.
+            #struct_visibility fn visit_stacks<V>(&self, visitor: &mut V)
+            where
+                #(V: ::push::push_vm::stack::VisitStack<#element_types>,)*
+            {
+                #(
+                    ::push::push_vm::stack::VisitStack::<#element_types>::visit(
+                        visitor,
+                        #names,
+                        &self.#idents,
+                    );
+                )*
+            }
+
+            /// Calls `visitor` with the name and a mutable reference to each
+            /// of the stacks in this state, in the same order as
+            /// `stack_summary`.
+            #struct_visibility fn visit_stacks_mut<V>(&mut self, visitor: &mut V)
+            where
+                #(V: ::push::push_vm::stack::VisitStackMut<#element_types>,)*
+            {
+                #(
+                    ::push::push_vm::stack::VisitStackMut::<#element_types>::visit_mut(
+                        visitor,
+                        #names,
+                        &mut self.#idents,
+                    );
+                )*
+            }
+        }
+    };
+
+    let has_stack_impls = stacks_to_derive_for
         .into_iter()
         .map(|(ident, ty)| {
+            let element_type = stack_element_type(ty);
             quote! {
                 #[automatically_derived]
-                impl
-                    ::push::push_vm::stack::HasStack<
-                        <#ty as ::push::push_vm::stack::StackType>::Type
-                    >
-                for
-                    #struct_ident
-                {
+                impl ::push::push_vm::stack::HasStack<#element_type> for #struct_ident {
                     fn stack<
-                        U: ::push::push_vm::stack::TypeEq<
-                            This = <#ty as ::push::push_vm::stack::StackType>::Type>
+                        U: ::push::push_vm::stack::TypeEq<This = #element_type>
                     >(&self) -> &#ty {
                         &self.#ident
                     }
 
                     fn stack_mut<
-                        U: ::push::push_vm::stack::TypeEq<
-                            This = <#ty as ::push::push_vm::stack::StackType>::Type>
+                        U: ::push::push_vm::stack::TypeEq<This = #element_type>
                     >(&mut self) -> &mut #ty {
                         &mut self.#ident
                     }
                 }
             }
         })
-        .collect::<proc_macro2::TokenStream>()
+        .collect::<proc_macro2::TokenStream>();
+
+    quote! {
+        #has_stack_impls
+        #stack_summary
+    }
 }
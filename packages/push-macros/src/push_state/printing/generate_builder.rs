@@ -6,7 +6,7 @@ use syn::{ext::IdentExt, Generics, Ident, Visibility};
 use crate::{
     doctest_tokenstream::{doctest, Import},
     push_state::parsing::{
-        stack_attribute_args::StackMarkerFlags, ExecStackInput, InputInstructionsInput, StacksInput,
+        stack_attribute_args::StackMarkerFlags, ExecStackInput, SpecialFields, StacksInput,
     },
 };
 
@@ -42,6 +42,14 @@ macro_rules! derived_ident {
     }
 }
 
+/// The name of the builder's typestate generic for the stack in the field
+/// `field`. This is prefixed so it can't shadow a type used in the state,
+/// like `String` for a field named `string`.
+fn stack_generic(field: &Ident) -> Ident {
+    let name = field.unraw().to_pascal_case_spanned(Span::mixed_site());
+    Ident::new(&format!("__S{}", name.unraw()), Span::mixed_site())
+}
+
 pub fn generate_builder(
     macro_span: Span,
     struct_ident: &Ident,
@@ -49,9 +57,12 @@ pub fn generate_builder(
     struct_generics: &Generics,
     stacks: &StacksInput,
     exec_stack: &ExecStackInput,
-    input_instructions: InputInstructionsInput,
+    SpecialFields {
+        input_instructions,
+        output_channels,
+    }: SpecialFields,
 ) -> syn::Result<TokenStream> {
-    let Some((exec_stack_ident, _, exec_stack_ty)) = exec_stack else {
+    let Some((exec_stack_ident, exec_stack_flags, exec_stack_ty)) = exec_stack else {
         return Err(syn::Error::new(
             macro_span,
             "Need to declare exactly one exec stack using #[stack(exec)] to use the builder \
@@ -77,13 +88,7 @@ pub fn generate_builder(
     let fields = stacks.keys().collect::<Vec<_>>();
 
     // Generic bounds for stacks, like `Int: StackState, Bool: StackState`
-    let stack_generics = fields
-        .iter()
-        .map(|i| {
-            i.unraw()
-                .to_pascal_case_spanned(proc_macro2::Span::mixed_site())
-        })
-        .collect::<Vec<_>>();
+    let stack_generics = fields.iter().map(|i| stack_generic(i)).collect::<Vec<_>>();
     let stack_generics_with_state_bounds = stack_generics
         .iter()
         .map(|g| quote! {#g: #utilities_mod_ident::StackState})
@@ -135,6 +140,14 @@ pub fn generate_builder(
                 });
 
                 let fn_ident = derived_ident!("with_", stack_ident, "_input");
+                let plural_fn_ident = derived_ident!("with_", stack_ident, "_inputs");
+                let plural_doc = format!(
+                    " Adds several input instructions to the current state's set of\n \
+                     instructions, exactly as if `{fn_ident}()` had been called with\n \
+                     each `(input_name, input_value)` pair in turn. In particular, if\n \
+                     a name is repeated, the last value for that name is the one\n \
+                     that's used."
+                );
 
                 quote! {
                     /// Adds a input instruction to the current current state's set
@@ -160,6 +173,21 @@ pub fn generate_builder(
                         );
                         self
                     }
+
+                    #[doc = #plural_doc]
+                    #[must_use]
+                    pub fn #plural_fn_ident<'a>(
+                            self,
+                            inputs: impl ::std::iter::IntoIterator<
+                                Item = (&'a str, <#ty as ::push::push_vm::stack::StackType>::Type)
+                            >
+                    ) -> Self {
+                        inputs
+                            .into_iter()
+                            .fold(self, |builder, (input_name, input_value)| {
+                                builder.#fn_ident(input_name, input_value)
+                            })
+                    }
                 }
             },
         );
@@ -173,6 +201,36 @@ pub fn generate_builder(
         }
     });
 
+    let with_output_channels_impl = output_channels.map(|output_channels_field| {
+        quote! {
+            impl<__Exec: #utilities_mod_ident::StackState, #(#stack_generics_with_state_bounds),*>
+                #builder_name<__Exec, #(#stack_generics),*>
+            {
+                /// Adds an (empty) output channel named `channel`, which instructions
+                /// like `OutputInstruction::PrintInt` can print to. Adding a channel
+                /// that already exists leaves it unchanged.
+                #[must_use]
+                pub fn with_output_channel(
+                    mut self,
+                    channel: impl ::std::convert::Into<::std::string::String>,
+                ) -> Self {
+                    self.partial_state.#output_channels_field.add_channel(channel);
+                    self
+                }
+
+                /// Limits every output channel to holding at most `max_size` bytes.
+                /// Printing more than that to a channel is a fatal
+                /// [`PushInstructionError::OutputOverflow`](::push::instruction::instruction_error::PushInstructionError::OutputOverflow)
+                /// error. There is no limit by default.
+                #[must_use]
+                pub fn with_max_output_size(mut self, max_size: usize) -> Self {
+                    self.partial_state.#output_channels_field.set_max_size(max_size);
+                    self
+                }
+            }
+        }
+    });
+
     let with_values_impl = stacks
         .iter()
         .map(
@@ -198,9 +256,7 @@ pub fn generate_builder(
                 // Where bounds where the current stack is required to be SizeSet
                 //  and every other stack can be in any state
                 let where_bounds = stacks.keys().map(|ident| {
-                    let generic_name = ident
-                        .unraw()
-                        .to_pascal_case_spanned(proc_macro2::Span::mixed_site());
+                    let generic_name = stack_generic(ident);
                     if ident == field {
                         quote! {#generic_name: #utilities_mod_ident::SizeSet}
                     } else {
@@ -214,9 +270,7 @@ pub fn generate_builder(
                     if ident == field {
                         quote! {#utilities_mod_ident::WithSizeAndData}
                     } else {
-                        let generic_name = ident
-                            .unraw()
-                            .to_pascal_case_spanned(proc_macro2::Span::mixed_site());
+                        let generic_name = stack_generic(ident);
                         quote! {#generic_name}
                     }
                 });
@@ -265,6 +319,17 @@ pub fn generate_builder(
                         #doctest_tokenstream
                 });
 
+                let clear_where_bounds = where_bounds.clone();
+                let clear_stack_generics_or_type = stacks.keys().map(|ident| {
+                    if ident == field {
+                        quote! {#utilities_mod_ident::WithSize}
+                    } else {
+                        let generic_name = stack_generic(ident);
+                        quote! {#generic_name}
+                    }
+                });
+                let clear_fn_ident = derived_ident!("with_cleared_", stack_ident, "_values").unraw();
+
                 quote! {
                     impl<
                         __Exec: #utilities_mod_ident::StackState,
@@ -303,10 +368,35 @@ pub fn generate_builder(
 
                             ::std::result::Result::Ok(#builder_name {
                                 partial_state: self.partial_state,
+                                max_stack_size: self.max_stack_size,
                                 _p: ::std::marker::PhantomData,
                             })
                         }
                     }
+
+                    impl<
+                        __Exec: #utilities_mod_ident::StackState,
+                         #(#clear_where_bounds),*
+                    >
+                        #builder_name<__Exec, #(#stack_generics),*>
+                    {
+                        /// Removes any values previously added to this
+                        /// stack for the state you're building, keeping its
+                        /// maximum size. This is useful for reusing a base
+                        /// builder configuration with different stack contents.
+                        #[must_use]
+                        pub fn #clear_fn_ident(
+                            mut self
+                        ) -> #builder_name<__Exec, #(#clear_stack_generics_or_type),*> {
+                            self.partial_state.#field.clear();
+
+                            #builder_name {
+                                partial_state: self.partial_state,
+                                max_stack_size: self.max_stack_size,
+                                _p: ::std::marker::PhantomData,
+                            }
+                        }
+                    }
                 }
             },
         )
@@ -336,9 +426,7 @@ pub fn generate_builder(
                 // Where bounds where the current stack is required
                 // to be SizeSet and every other stack can be in any state
                 let where_bounds = stacks.keys().map(|ident| {
-                    let generic_name = ident
-                        .unraw()
-                        .to_pascal_case_spanned(proc_macro2::Span::mixed_site());
+                    let generic_name = stack_generic(ident);
 
                     if ident == field {
                         quote! {#generic_name: #utilities_mod_ident::Dataless}
@@ -353,9 +441,7 @@ pub fn generate_builder(
                     if ident == field {
                         quote! {#utilities_mod_ident::WithSize}
                     } else {
-                        let generic_name = ident
-                            .unraw()
-                            .to_pascal_case_spanned(proc_macro2::Span::mixed_site());
+                        let generic_name = stack_generic(ident);
                         quote! {#generic_name}
                     }
                 });
@@ -381,6 +467,7 @@ pub fn generate_builder(
 
                             #builder_name {
                                 partial_state: self.partial_state,
+                                max_stack_size: self.max_stack_size,
                                 _p: ::std::marker::PhantomData,
                             }
                         }
@@ -390,6 +477,17 @@ pub fn generate_builder(
         )
         .collect::<proc_macro2::TokenStream>();
 
+    let exec_stack_name = exec_stack_flags
+        .builder_name
+        .as_ref()
+        .unwrap_or(exec_stack_ident)
+        .unraw()
+        .to_snake_case();
+    let set_exec_max_size_ident = derived_ident!("with_max_", exec_stack_name, "_stack_size");
+    let get_exec_max_size_ident = derived_ident!("max_", exec_stack_name, "_stack_size");
+    let exec_max_size_setter_doc =
+        format!(" [`{set_exec_max_size_ident}`](Self::{set_exec_max_size_ident}),");
+
     let with_max_stack_size_examples = stacks
         .iter()
         .filter_map(|(_, (StackMarkerFlags { ignore_doctests, .. }, ty))|
@@ -467,23 +565,32 @@ pub fn generate_builder(
                 pub trait SealedMarker {}
             }
 
-            pub trait StackState: sealed::SealedMarker {}
+            pub trait StackState: sealed::SealedMarker {
+                /// Whether the maximum size of stacks in this state has been set.
+                const SIZE_SET: bool;
+            }
             pub trait Dataless: StackState {}
             pub trait SizeSet: StackState {}
 
             impl sealed::SealedMarker for () {}
-            impl StackState for () {}
+            impl StackState for () {
+                const SIZE_SET: bool = false;
+            }
             impl Dataless for () {}
 
             pub struct WithSize;
             impl sealed::SealedMarker for WithSize {}
-            impl StackState for WithSize {}
+            impl StackState for WithSize {
+                const SIZE_SET: bool = true;
+            }
             impl Dataless for WithSize {}
             impl SizeSet for WithSize {}
 
             pub struct WithSizeAndData;
             impl sealed::SealedMarker for WithSizeAndData {}
-            impl StackState for WithSizeAndData {}
+            impl StackState for WithSizeAndData {
+                const SIZE_SET: bool = true;
+            }
             impl SizeSet for WithSizeAndData {}
         }
 
@@ -492,13 +599,37 @@ pub fn generate_builder(
             #(#stack_generics_with_state_bounds),*
         > {
             partial_state: #struct_ident,
+            max_stack_size: ::std::option::Option<usize>,
             _p: std::marker::PhantomData<(__Exec, #(#stack_generics),*)>
         }
 
+        impl<
+            __Exec: #utilities_mod_ident::StackState,
+            #(#stack_generics_with_state_bounds),*
+        > #builder_name<__Exec, #(#stack_generics),*> {
+            /// The maximum stack size set by [`with_max_stack_size`](Self::with_max_stack_size),
+            /// or `None` if it hasn't been called yet.
+            #[must_use]
+            pub const fn max_stack_size(&self) -> ::std::option::Option<usize> {
+                self.max_stack_size
+            }
+
+            /// The maximum size of the exec stack, set by
+            /// [`with_max_stack_size`](Self::with_max_stack_size) or
+            #[doc = #exec_max_size_setter_doc]
+            /// or `None` if neither has been called yet.
+            #[must_use]
+            pub fn #get_exec_max_size_ident(&self) -> ::std::option::Option<usize> {
+                <__Exec as #utilities_mod_ident::StackState>::SIZE_SET
+                    .then(|| self.partial_state.#exec_stack_ident.max_stack_size())
+            }
+        }
+
         impl ::std::default::Default for #builder_name<(), #(#default_states),*> {
             fn default() -> Self {
                 #builder_name {
                     partial_state: ::std::default::Default::default(),
+                    max_stack_size: ::std::option::Option::None,
                     _p: ::std::marker::PhantomData,
                 }
             }
@@ -529,6 +660,43 @@ pub fn generate_builder(
 
                 #builder_name {
                     partial_state: self.partial_state,
+                    max_stack_size: ::std::option::Option::Some(max_size),
+                    _p: ::std::marker::PhantomData,
+                }
+            }
+        }
+
+        impl<
+            __Exec: #utilities_mod_ident::Dataless,
+            #(#stack_generics_with_state_bounds),*
+        > #builder_name<__Exec, #(#stack_generics),*> {
+            /// Sets the maximum stack size for just the exec stack. Programs
+            /// that keep pushing blocks onto the exec stack can use a lot of
+            /// memory before they hit the step limit, so this lets the exec
+            /// stack be smaller than the other stacks.
+            ///
+            /// Like the setters for the other individual stacks, this has to be
+            /// called after [`with_max_stack_size`](Self::with_max_stack_size),
+            /// which would otherwise replace it. Performing an instruction that
+            /// would grow the exec stack past this size is a fatal
+            /// [`StackError::Overflow`](::push::push_vm::stack::StackError::Overflow)
+            /// error.
+            ///
+            /// # Arguments
+            ///
+            /// * `max_stack_size` - A `usize` specifying the maximum stack size
+            #[must_use]
+            pub fn #set_exec_max_size_ident(
+                mut self,
+                max_stack_size: usize,
+            ) -> #builder_name<#utilities_mod_ident::WithSize, #(#stack_generics),*> {
+                self.partial_state
+                    .#exec_stack_ident
+                    .set_max_stack_size(max_stack_size);
+
+                #builder_name {
+                    partial_state: self.partial_state,
+                    max_stack_size: self.max_stack_size,
                     _p: ::std::marker::PhantomData,
                 }
             }
@@ -545,11 +713,15 @@ pub fn generate_builder(
             ///
             /// # Arguments
             /// - `program` - The program you wish to execute
+            ///
+            /// # Errors
+            /// Returns [`ProgramTooLong`](::push::push_vm::program::ProgramTooLong)
+            /// if the program has more items than the maximum size of the exec stack.
             #[must_use]
             pub fn with_program<P>(mut self, program: P)
                 -> ::std::result::Result<
                     #builder_name<#utilities_mod_ident::WithSizeAndData, #(#stack_generics),*>,
-                    ::push::push_vm::stack::StackError
+                    ::push::push_vm::program::ProgramTooLong
                 >
             where
                 P: ::std::iter::IntoIterator,
@@ -561,13 +733,17 @@ pub fn generate_builder(
                         <#exec_stack_ty as ::push::push_vm::stack::StackType>::Type
                     >
             {
+                let program = ::std::iter::IntoIterator::into_iter(program);
+                let length = ::std::iter::ExactSizeIterator::len(&program);
+                let max = self.partial_state.#exec_stack_ident.max_stack_size();
                 self
                     .partial_state
                     .#exec_stack_ident
-                    .try_extend(::std::iter::IntoIterator::into_iter(program)
-                    .map(::std::convert::Into::into))?;
+                    .try_extend(program.map(::std::convert::Into::into))
+                    .map_err(|_| ::push::push_vm::program::ProgramTooLong { length, max })?;
                 ::std::result::Result::Ok(#builder_name {
                     partial_state: self.partial_state,
+                    max_stack_size: self.max_stack_size,
                     _p: ::std::marker::PhantomData,
                 })
             }
@@ -579,6 +755,7 @@ pub fn generate_builder(
             {
                 #builder_name {
                     partial_state: self.partial_state,
+                    max_stack_size: self.max_stack_size,
                     _p: ::std::marker::PhantomData,
                 }
             }
@@ -615,6 +792,7 @@ pub fn generate_builder(
         }
 
         #with_inputs_impl
+        #with_output_channels_impl
         #with_values_impl
         #set_max_size_impl
 
@@ -0,0 +1,88 @@
+use ident_case_conversions::CaseConversions;
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{ext::IdentExt, Ident, Visibility};
+
+use crate::push_state::parsing::StacksInput;
+
+/// Generates an enum with one variant for each (non-exec) stack in the state,
+/// each holding a `::push::instruction::common::CommonInstruction` for that
+/// stack's type, along with an `Instruction` implementation for the state
+/// that dispatches to the wrapped instruction.
+///
+/// For a state `MyState` with stacks `int: Stack<i64>` and
+/// `bool: Stack<bool>` this generates
+/// ```ignore
+/// pub enum MyStateCommonInstruction {
+///     Bool(CommonInstruction<bool>),
+///     Int(CommonInstruction<i64>),
+/// }
+/// ```
+pub fn generate_common_instructions(
+    struct_ident: &Ident,
+    struct_visibility: &Visibility,
+    stacks: &StacksInput,
+) -> TokenStream {
+    let enum_ident = format_ident!("{}CommonInstruction", struct_ident.unraw());
+
+    let variants = stacks
+        .keys()
+        .map(|ident| ident.unraw().to_pascal_case_spanned(Span::mixed_site()))
+        .collect::<Vec<_>>();
+    let instruction_types = stacks
+        .values()
+        .map(|(_, ty)| {
+            quote! {
+                ::push::instruction::common::CommonInstruction<
+                    <#ty as ::push::push_vm::stack::StackType>::Type
+                >
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let doc = format!(
+        "The common stack instructions (`Dup`, `Pop`, `Swap`, `Flush`, `StackDepth`, and \
+         `IsEmpty`) for every stack in [`{struct_ident}`]."
+    );
+
+    quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #struct_visibility enum #enum_ident {
+            #(#variants(#instruction_types)),*
+        }
+
+        #[automatically_derived]
+        impl #enum_ident {
+            /// Every common instruction for every stack.
+            #[must_use]
+            pub fn all() -> ::std::vec::Vec<Self> {
+                let mut instructions = ::std::vec::Vec::new();
+                #(
+                    instructions.extend(
+                        <#instruction_types>::all().into_iter().map(Self::#variants)
+                    );
+                )*
+                instructions
+            }
+        }
+
+        #[automatically_derived]
+        impl ::push::instruction::Instruction<#struct_ident> for #enum_ident {
+            type Error = ::push::instruction::instruction_error::PushInstructionError;
+
+            fn perform(
+                &self,
+                state: #struct_ident,
+            ) -> ::push::error::InstructionResult<#struct_ident, Self::Error> {
+                match *self {
+                    #(
+                        Self::#variants(ref instruction) => {
+                            ::push::instruction::Instruction::perform(instruction, state)
+                        }
+                    )*
+                }
+            }
+        }
+    }
+}
@@ -1,2 +1,3 @@
 pub mod derive_has_stack;
 pub mod generate_builder;
+pub mod generate_common_instructions;
@@ -0,0 +1,37 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ec_core::{distributions::collection::ConvertToCollectionGenerator, uniform_distribution_of};
+use push::{
+    genome::plushy::{ConvertToGeneGenerator, Plushy},
+    instruction::{BoolInstruction, ExecInstruction, IntInstruction, PushInstruction},
+    push_vm::program::PushProgram,
+};
+use rand::{distributions::Distribution, thread_rng};
+
+const GENOME_LENGTH: usize = 10_000;
+
+// Compare converting a long genome into a program by cloning the genome
+// first with converting it from a reference.
+pub fn plushy_to_program(c: &mut Criterion) {
+    let plushy: Plushy = uniform_distribution_of![<PushInstruction>
+        IntInstruction::Add,
+        IntInstruction::Multiply,
+        BoolInstruction::And,
+        ExecInstruction::if_else(),
+        ExecInstruction::dup_block(),
+    ]
+    .into_gene_generator()
+    .into_collection_generator(GENOME_LENGTH)
+    .sample(&mut thread_rng());
+
+    let mut group = c.benchmark_group("Convert Plushy to program");
+    group.bench_function("clone then convert", |b| {
+        b.iter(|| Vec::<PushProgram>::from(black_box(&plushy).clone()));
+    });
+    group.bench_function("convert from reference", |b| {
+        b.iter(|| black_box(&plushy).to_program());
+    });
+    group.finish();
+}
+
+criterion_group!(benches, plushy_to_program);
+criterion_main!(benches);
@@ -29,4 +29,8 @@ pub struct Args {
     /// Number of generations to run
     #[clap(short, long, value_parser, default_value_t = 100)]
     pub num_generations: usize,
+
+    /// Seed for the random number generator, to make runs reproducible
+    #[clap(long, value_parser)]
+    pub seed: Option<u64>,
 }
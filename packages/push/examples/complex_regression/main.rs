@@ -1,11 +1,13 @@
-#![allow(clippy::use_debug)]
-#![allow(clippy::arithmetic_side_effects)]
+#![expect(
+    clippy::arithmetic_side_effects,
+    reason = "The example's arithmetic is on small values that can't overflow"
+)]
 
 pub mod args;
 
 use std::ops::Not;
 
-use anyhow::{ensure, Result};
+use anyhow::{ensure, Context, Result};
 use clap::Parser;
 use ec_core::{
     distributions::collection::ConvertToCollectionGenerator,
@@ -21,16 +23,22 @@ use ec_core::{
     test_results::{self, TestResults},
     uniform_distribution_of,
 };
-use ec_linear::mutator::umad::Umad;
 use num_traits::Float;
 use ordered_float::OrderedFloat;
 use push::{
-    evaluation::cases::{Case, Cases, WithTargetFn},
+    evaluation::{
+        cases::{Case, Cases, WithTargetFn},
+        penalty::Penalty,
+    },
     genome::plushy::{ConvertToGeneGenerator, Plushy},
     instruction::{variable_name::VariableName, FloatInstruction, PushInstruction},
-    push_vm::{program::PushProgram, push_state::PushState, HasStack, State},
+    push_vm::{
+        program::{ProgramTooLong, PushProgram},
+        push_state::PushState,
+        State,
+    },
 };
-use rand::{prelude::Distribution, thread_rng};
+use rand::{prelude::Distribution, rngs::StdRng, SeedableRng};
 
 use crate::args::{Args, RunModel};
 
@@ -42,10 +50,10 @@ use crate::args::{Args, RunModel};
 
 // The penalty value to use when an evolved program doesn't have an expected
 // "return" value on the appropriate stack at the end of its execution.
-const PENALTY_VALUE: f64 = 1_000.0;
-
 type Of64 = OrderedFloat<f64>;
 
+const PENALTY: Penalty<Of64> = Penalty::new(OrderedFloat(1_000.0));
+
 /// The target polynomial is (x^3 + 1)^3 + 1
 /// i.e., x^9 + 3x^6 + 3x^3 + 2
 fn target_fn(input: Of64) -> Of64 {
@@ -55,43 +63,32 @@ fn target_fn(input: Of64) -> Of64 {
 fn build_push_state(
     program: impl DoubleEndedIterator<Item = PushProgram> + ExactSizeIterator,
     input: Of64,
-) -> PushState {
-    #[allow(clippy::unwrap_used)]
-    PushState::builder()
+) -> Result<PushState, ProgramTooLong> {
+    Ok(PushState::builder()
         .with_max_stack_size(1000)
-        .with_program(program)
-        // This will return an error if the program is longer than the allowed
-        // max stack size.
-        // We arguably should check that and return an error here.
-        .unwrap()
+        .with_program(program)?
         .with_float_input("x", input)
-        .build()
+        .build())
 }
 
 fn score_program(
     program: impl DoubleEndedIterator<Item = PushProgram> + ExactSizeIterator,
     Case { input, output }: Case<Of64>,
 ) -> Of64 {
-    let state = build_push_state(program, input);
-    #[allow(clippy::option_if_let_else)]
-    match state.run_to_completion() {
-        Ok(final_state) => final_state
-            .stack::<Of64>()
-            .top()
-            .map_or(Of64::from(PENALTY_VALUE), |answer| (answer - output).abs()),
-
-        Err(_) => {
-            // Do some logging, perhaps?
-            Of64::from(PENALTY_VALUE)
-        }
-    }
+    let Ok(state) = build_push_state(program, input) else {
+        // The program is too long to fit on the exec stack.
+        return *PENALTY.value();
+    };
+    PENALTY.score(state.run_to_completion(), |answer: &Of64| {
+        (answer - output).abs()
+    })
 }
 
 fn score_genome(
     genome: &Plushy,
     training_cases: &Cases<Of64>,
 ) -> TestResults<test_results::Error<Of64>> {
-    let program: Vec<PushProgram> = genome.clone().into();
+    let program = genome.to_program();
 
     training_cases
         .iter()
@@ -100,16 +97,16 @@ fn score_genome(
 }
 
 fn main() -> Result<()> {
-    // FIXME: Respect the max_genome_length input
     let Args {
         run_model,
         population_size,
         max_initial_instructions,
+        max_genome_length,
         num_generations,
-        ..
+        seed,
     } = Args::parse();
 
-    let mut rng = thread_rng();
+    let mut rng = seed.map_or_else(StdRng::from_entropy, StdRng::seed_from_u64);
 
     // Inputs from -4 (inclusive) to 4 (exclusive) in increments of 0.25.
     let training_cases = (-4 * 4..4 * 4)
@@ -145,7 +142,8 @@ fn main() -> Result<()> {
         FloatInstruction::Push(OrderedFloat(1.0)),
         VariableName::from("x")
     ]
-    .into_gene_generator();
+    .into_gene_generator()
+    .with_max_length(max_genome_length);
 
     let population = gene_generator
         .to_collection_generator(max_initial_instructions)
@@ -158,7 +156,7 @@ fn main() -> Result<()> {
     let best = Best.select(&population, &mut rng)?;
     println!("Best initial individual is {best}");
 
-    let umad = Umad::new(0.1, 0.1, &gene_generator);
+    let umad = gene_generator.umad(0.1, 0.1);
 
     let make_new_individual = Select::new(selector)
         .then(GenomeExtractor)
@@ -172,16 +170,19 @@ fn main() -> Result<()> {
 
     for generation_number in 0..num_generations {
         match run_model {
-            RunModel::Serial => generation.serial_next()?,
-            RunModel::Parallel => generation.par_next()?,
+            RunModel::Serial => generation.serial_next_with_rng(&mut rng)?,
+            RunModel::Parallel => match seed {
+                Some(seed) => generation.par_next_seeded(seed)?,
+                None => generation.par_next()?,
+            },
         }
 
-        let best = Best.select(generation.population(), &mut rng)?;
+        let best = generation.best().context("The population was empty")?;
         // TODO: Change 2 to be the smallest number of digits needed for
         // num_generations-1.
         println!("Generation {generation_number:2} best is {best}");
 
-        if best.test_results.total_result.error == OrderedFloat(0.0) {
+        if best.test_results.is_ideal() {
             println!("SUCCESS");
             break;
         }
@@ -52,4 +52,8 @@ pub struct CliArgs {
     /// have a value on the expected "return" stack.
     #[clap(short = 'v', long, default_value_t = 1_000)]
     pub penalty_value: i128,
+
+    /// Seed for the random number generator, to make runs reproducible
+    #[clap(long, value_parser)]
+    pub seed: Option<u64>,
 }
@@ -2,7 +2,7 @@ pub mod args;
 
 use std::ops::Not;
 
-use anyhow::{ensure, Result};
+use anyhow::{ensure, Context, Result};
 use clap::Parser;
 use ec_core::{
     distributions::{collection::ConvertToCollectionGenerator, conversion::IntoDistribution},
@@ -17,19 +17,26 @@ use ec_core::{
     },
     test_results::{self, TestResults},
 };
-use ec_linear::mutator::umad::Umad;
 use push::{
-    evaluation::cases::{Case, Cases, WithTargetFn},
+    evaluation::{
+        cases::{Case, Cases, WithTargetFn},
+        penalty::Penalty,
+    },
     genome::plushy::{GeneGenerator, Plushy},
     instruction::{
-        variable_name::VariableName, BoolInstruction, ExecInstruction, IntInstruction,
-        PushInstruction,
+        variable_name::variable_instructions, ArithmeticMode, BoolInstruction, ExecInstruction,
+        IntInstruction, PushInstruction,
+    },
+    push_vm::{
+        program::{ProgramTooLong, PushProgram},
+        push_state::PushState,
+        State,
     },
-    push_vm::{program::PushProgram, push_state::PushState, stack::StackError, HasStack, State},
 };
 use rand::{
     distributions::{Distribution, Uniform},
-    thread_rng,
+    rngs::StdRng,
+    SeedableRng,
 };
 use strum::IntoEnumIterator;
 
@@ -42,7 +49,10 @@ struct Input([i64; 4]);
 impl Input {
     fn smallest(&self) -> Output {
         let Self(input) = self;
-        #[allow(clippy::unwrap_used)]
+        #[expect(
+            clippy::unwrap_used,
+            reason = "The generated inputs are never empty, so there's always a minimum"
+        )]
         Output(*input.iter().min().unwrap())
     }
 }
@@ -76,15 +86,16 @@ fn main() -> Result<()> {
         run_model,
         population_size,
         max_initial_instructions,
+        max_genome_length,
         max_generations,
         num_training_cases,
         lower_input_bound,
         upper_input_bound,
         penalty_value,
-        ..
+        seed,
     } = CliArgs::parse();
 
-    let mut rng = thread_rng();
+    let mut rng = seed.map_or_else(StdRng::from_entropy, StdRng::seed_from_u64);
 
     let training_cases = Uniform::new(lower_input_bound, upper_input_bound)?
         .sample_iter(&mut rng)
@@ -98,7 +109,8 @@ fn main() -> Result<()> {
     let instruction_set = instructions().collect::<Vec<_>>();
 
     let gene_generator =
-        GeneGenerator::with_uniform_close_probability(instruction_set.into_distribution()?);
+        GeneGenerator::with_uniform_close_probability(instruction_set.into_distribution()?)
+            .with_max_length(max_genome_length);
 
     let population = gene_generator
         .to_collection_generator(max_initial_instructions)
@@ -111,7 +123,7 @@ fn main() -> Result<()> {
     let best = Best.select(&population, &mut rng)?;
     println!("Best initial individual is {best}");
 
-    let umad = Umad::new(0.1, 0.1, &gene_generator);
+    let umad = gene_generator.umad(0.1, 0.1);
 
     let make_new_individual = Select::new(lexicase)
         .then(GenomeExtractor)
@@ -122,14 +134,17 @@ fn main() -> Result<()> {
 
     for generation_number in 0..max_generations {
         match run_model {
-            RunModel::Serial => generation.serial_next()?,
-            RunModel::Parallel => generation.par_next()?,
+            RunModel::Serial => generation.serial_next_with_rng(&mut rng)?,
+            RunModel::Parallel => match seed {
+                Some(seed) => generation.par_next_seeded(seed)?,
+                None => generation.par_next()?,
+            },
         }
 
-        let best = Best.select(generation.population(), &mut rng)?;
+        let best = generation.best().context("The population was empty")?;
         println!("Generation {generation_number:4} best is {best}");
 
-        if best.test_results.total_result.error == 0 {
+        if best.test_results.is_ideal() {
             println!("SUCCESS");
             break;
         }
@@ -143,7 +158,7 @@ fn score_genome(
     training_cases: &Cases<Input, Output>,
     penalty_value: i128,
 ) -> TestResults<test_results::Error<i128>> {
-    let program = Vec::<PushProgram>::from(genome.clone());
+    let program = genome.to_program();
     training_cases
         .iter()
         .map(|&case: &Case<Input, Output>| run_case(case, &program, penalty_value))
@@ -163,41 +178,34 @@ fn run_case(
         // stack size. I think the "Push way" here would be to take whatever
         // value is on top of the relevant stack and go with it, but we instead
         // return the penalty value.
-        start_state
-            .run_to_completion()
-            .map_or(penalty_value, |final_state| {
-                compute_error(&final_state, penalty_value, expected)
-            })
+        Penalty::new(penalty_value).score(start_state.run_to_completion(), |answer: &i64| {
+            i128::from(*answer)
+                .saturating_sub(i128::from(expected))
+                .abs()
+        })
     })
 }
 
 fn build_state(
     program: &[PushProgram],
     Input([a, b, c, d]): Input,
-) -> Result<PushState, StackError> {
+) -> Result<PushState, ProgramTooLong> {
     Ok(PushState::builder()
         .with_max_stack_size(1000)
         .with_program(program.to_vec())?
-        .with_int_input("a", a)
-        .with_int_input("b", b)
-        .with_int_input("c", c)
-        .with_int_input("d", d)
+        .with_int_inputs([("a", a), ("b", b), ("c", c), ("d", d)])
         .build())
 }
 
-fn compute_error(final_state: &PushState, penalty_value: i128, expected: i64) -> i128 {
-    final_state
-        .stack::<i64>()
-        .top()
-        .map_or(penalty_value, |answer| {
-            i128::from(*answer)
-                .saturating_sub(i128::from(expected))
-                .abs()
-        })
-}
-
 fn instructions() -> impl Iterator<Item = PushInstruction> {
     let int_instructions = IntInstruction::iter()
+        // Only use the checked versions of the arithmetic instructions, as
+        // the saturating and wrapping versions are rarely useful here.
+        .filter(|instruction| {
+            instruction
+                .arithmetic_mode()
+                .is_none_or(|mode| mode == ArithmeticMode::Checked)
+        })
         // Restore this line to remove `Min` from the instruction set.
         // .filter(|&i| i != IntInstruction::Min)
         .map(Into::into);
@@ -209,10 +217,7 @@ fn instructions() -> impl Iterator<Item = PushInstruction> {
         // .filter(|&i| i != ExecInstruction::dup_block())
         .map(Into::into);
 
-    let variables = ["a", "b", "c", "d"]
-        .into_iter()
-        .map(VariableName::from)
-        .map(Into::into);
+    let variables = variable_instructions(["a", "b", "c", "d"]);
 
     int_instructions
         .chain(bool_instructions)
@@ -47,6 +47,18 @@ impl<S, E, Severity: ErrorSeverity> StatefulError<S, E, Severity> {
             _p: PhantomData,
         }
     }
+
+    pub const fn state(&self) -> &S {
+        &self.state
+    }
+
+    pub const fn error(&self) -> &E {
+        &self.error
+    }
+    /// The state and the error, taking ownership of both.
+    pub fn into_parts(self) -> (S, E) {
+        (*self.state, self.error)
+    }
 }
 
 impl<S, E, Severity: ErrorSeverity> IntoState<S> for StatefulError<S, E, Severity> {
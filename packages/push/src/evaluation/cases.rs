@@ -1,3 +1,5 @@
+use rand::{seq::SliceRandom, RngCore};
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct Case<Input, Output = Input> {
     pub input: Input,
@@ -59,23 +61,75 @@ impl<Input, Output> Cases<Input, Output> {
         self
     }
 
-    pub fn iter(&self) -> std::slice::Iter<Case<Input, Output>> {
+    pub fn iter(&self) -> std::slice::Iter<'_, Case<Input, Output>> {
         self.cases.iter()
     }
 
-    pub fn iter_mut(&mut self) -> std::slice::IterMut<Case<Input, Output>> {
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, Case<Input, Output>> {
         self.cases.iter_mut()
     }
 
     #[must_use]
-    pub fn is_empty(&self) -> bool {
+    pub const fn is_empty(&self) -> bool {
         self.cases.is_empty()
     }
 
     #[must_use]
-    pub fn len(&self) -> usize {
+    pub const fn len(&self) -> usize {
         self.cases.len()
     }
+
+    /// Split these cases into two sets, the first containing the first `n`
+    /// cases and the second containing the rest. The cases are moved (not
+    /// cloned), and keep their order within each set. If `n` is larger than
+    /// the number of cases, the second set is empty.
+    ///
+    /// This is useful for separating training and testing cases; call
+    /// [`Cases::shuffle`] first to get a random split.
+    #[must_use]
+    pub fn split_at(mut self, n: usize) -> (Self, Self) {
+        let rest = self.cases.split_off(n.min(self.cases.len()));
+        (self, Self { cases: rest })
+    }
+
+    /// Randomly reorder these cases using `rng`, so using a seeded `rng`
+    /// gives a reproducible order.
+    pub fn shuffle(&mut self, rng: &mut dyn RngCore) {
+        self.cases.shuffle(rng);
+    }
+
+    /// Convert these cases into a `Vec` of the individual cases.
+    #[must_use]
+    pub fn into_cases(self) -> Vec<Case<Input, Output>> {
+        self.cases
+    }
+
+    /// Convert these cases into cases with the same outputs, but with each
+    /// input replaced by the result of calling `f` on it. This can change
+    /// the type of the inputs, e.g., to reuse a dataset for a problem that
+    /// represents the inputs differently. The cases keep their order.
+    #[must_use]
+    pub fn map_inputs<NewInput>(
+        self,
+        mut f: impl FnMut(Input) -> NewInput,
+    ) -> Cases<NewInput, Output> {
+        self.into_iter()
+            .map(|Case { input, output }| Case::new(f(input), output))
+            .collect()
+    }
+
+    /// Convert these cases into cases with the same inputs, but with each
+    /// output replaced by the result of calling `f` on it. The cases keep
+    /// their order.
+    #[must_use]
+    pub fn map_outputs<NewOutput>(
+        self,
+        mut f: impl FnMut(Output) -> NewOutput,
+    ) -> Cases<Input, NewOutput> {
+        self.into_iter()
+            .map(|Case { input, output }| Case::new(input, f(output)))
+            .collect()
+    }
 }
 
 pub trait WithTargetFn<Input> {
@@ -139,3 +193,78 @@ impl<'a, Input, Output> IntoIterator for &'a mut Cases<Input, Output> {
         self.cases.iter_mut()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn split_at_keeps_order() {
+        let cases = (0..10).with_target_fn(|x| x * 2);
+        let (first, second) = cases.split_at(3);
+        assert_eq!(first.len() + second.len(), 10);
+        assert_eq!(
+            first.into_cases(),
+            (0..3).map(|x| Case::new(x, x * 2)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            second.into_cases(),
+            (3..10).map(|x| Case::new(x, x * 2)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn split_past_end() {
+        let cases = (0..4).with_target_fn(|x| x + 1);
+        let (first, second) = cases.split_at(10);
+        assert_eq!(first.len(), 4);
+        assert!(second.is_empty());
+    }
+
+    #[test]
+    fn seeded_shuffle_is_deterministic() {
+        let shuffled = |seed| {
+            let mut cases = (0..100).with_target_fn(|x| x * x);
+            cases.shuffle(&mut StdRng::seed_from_u64(seed));
+            cases.into_cases()
+        };
+        let first = shuffled(42);
+        assert_eq!(first, shuffled(42));
+        assert_ne!(
+            first,
+            (0..100).map(|x| Case::new(x, x * x)).collect::<Vec<_>>()
+        );
+
+        let mut inputs = first.iter().map(|case| case.input).collect::<Vec<_>>();
+        inputs.sort_unstable();
+        assert_eq!(inputs, (0..100).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn map_inputs_and_outputs() {
+        let cases: Cases<i32, i32> = (1..4).with_target_fn(|x| x * 10);
+        let cases = cases.map_inputs(|x| x.to_string());
+        assert_eq!(
+            cases
+                .iter()
+                .map(|case| case.input.as_str())
+                .collect::<Vec<_>>(),
+            ["1", "2", "3"]
+        );
+        assert_eq!(
+            cases.iter().map(|case| case.output).collect::<Vec<_>>(),
+            [10, 20, 30]
+        );
+        let cases = cases.map_outputs(|y| y % 3 == 0);
+        assert_eq!(
+            cases.into_cases(),
+            [
+                Case::new("1".to_string(), false),
+                Case::new("2".to_string(), false),
+                Case::new("3".to_string(), true)
+            ]
+        );
+    }
+}
@@ -0,0 +1,117 @@
+//! Reading [`Cases`] from CSV files, for benchmark problems whose training
+//! and testing cases come from a dataset rather than a target function. It
+//! requires the `csv` feature.
+//!
+//! The first line of the CSV is treated as a header and skipped. Cases are
+//! returned in the same order as the rows, and an empty file (or one with
+//! only a header) gives empty [`Cases`].
+
+use std::{error::Error, io::Read, str::FromStr};
+
+use csv::{ReaderBuilder, StringRecord};
+
+use super::cases::{Case, Cases};
+
+/// The error returned when reading [`Cases`] from a CSV fails. Rows are
+/// numbered from 1, not counting the header.
+#[derive(thiserror::Error, Debug)]
+pub enum CsvCasesError {
+    #[error("Failed to read row {row} of the CSV")]
+    Read {
+        row: usize,
+        #[source]
+        source: csv::Error,
+    },
+    #[error("Failed to parse the {part} of row {row} of the CSV: {source}")]
+    Parse {
+        row: usize,
+        part: CasePart,
+        #[source]
+        source: Box<dyn Error + Send + Sync>,
+    },
+}
+
+/// Which part of a [`Case`] couldn't be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum_macros::Display)]
+#[strum(serialize_all = "lowercase")]
+pub enum CasePart {
+    Input,
+    Output,
+}
+
+impl<Input, Output> Cases<Input, Output> {
+    /// Read cases from the CSV in `reader`, making the input and output for
+    /// each row with `parse_input` and `parse_output`. Each of those is
+    /// given the whole record, so they can use as many columns as they need.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error, with the number of the row, if reading a row
+    /// fails or if `parse_input` or `parse_output` fails on a row.
+    pub fn from_csv_reader<E>(
+        reader: impl Read,
+        parse_input: impl Fn(&StringRecord) -> Result<Input, E>,
+        parse_output: impl Fn(&StringRecord) -> Result<Output, E>,
+    ) -> Result<Self, CsvCasesError>
+    where
+        E: Into<Box<dyn Error + Send + Sync>>,
+    {
+        ReaderBuilder::new()
+            .has_headers(true)
+            .from_reader(reader)
+            .records()
+            .zip(1..)
+            .map(|(record, row)| {
+                let record = record.map_err(|source| CsvCasesError::Read { row, source })?;
+                let parse_error = |part| {
+                    move |source: E| CsvCasesError::Parse {
+                        row,
+                        part,
+                        source: source.into(),
+                    }
+                };
+                let input = parse_input(&record).map_err(parse_error(CasePart::Input))?;
+                let output = parse_output(&record).map_err(parse_error(CasePart::Output))?;
+                Ok(Case::new(input, output))
+            })
+            .collect()
+    }
+
+    /// Read cases from the CSV in `reader`, where the input and output of
+    /// each case are in the columns with (0-based) indices `input_column`
+    /// and `output_column`, and are parsed with [`FromStr`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error, with the number of the row, if reading a row
+    /// fails, if a row doesn't have one of the columns, or if parsing the
+    /// input or output fails.
+    pub fn from_csv_columns(
+        reader: impl Read,
+        input_column: usize,
+        output_column: usize,
+    ) -> Result<Self, CsvCasesError>
+    where
+        Input: FromStr<Err: Error + Send + Sync + 'static>,
+        Output: FromStr<Err: Error + Send + Sync + 'static>,
+    {
+        Self::from_csv_reader(
+            reader,
+            |record| parse_column(record, input_column),
+            |record| parse_column(record, output_column),
+        )
+    }
+}
+
+fn parse_column<T>(record: &StringRecord, column: usize) -> Result<T, Box<dyn Error + Send + Sync>>
+where
+    T: FromStr<Err: Error + Send + Sync + 'static>,
+{
+    let field = record.get(column).ok_or_else(|| {
+        format!(
+            "There is no column {column} in a row with {} columns",
+            record.len()
+        )
+    })?;
+    Ok(field.trim().parse()?)
+}
@@ -0,0 +1,136 @@
+use crate::push_vm::HasStack;
+
+/// What to do when the stack holding a program's answer is empty once the
+/// program has finished running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissingOutputPolicy<T, E> {
+    /// Use the given error value (usually something large) for this case.
+    Penalty(E),
+    /// Act as if the program had returned the given answer, and compute the
+    /// error for that as usual.
+    UseDefault(T),
+    /// Treat the missing answer as an error.
+    Error,
+}
+
+#[derive(thiserror::Error, Debug, Eq, PartialEq)]
+#[error("The program finished with an empty {stack_type} stack, so there was no answer")]
+pub struct MissingOutputError {
+    pub stack_type: &'static str,
+}
+
+impl<T, E> MissingOutputPolicy<T, E> {
+    /// Compute the error of `answer` using `compute_error`, applying this
+    /// policy if there is no answer.
+    ///
+    /// # Errors
+    ///
+    /// This returns a [`MissingOutputError`] if there's no answer and the
+    /// policy is [`MissingOutputPolicy::Error`].
+    pub fn error_for(
+        &self,
+        answer: Option<&T>,
+        compute_error: impl FnOnce(&T) -> E,
+    ) -> Result<E, MissingOutputError>
+    where
+        E: Clone,
+    {
+        match (answer, self) {
+            (Some(answer), _) | (None, Self::UseDefault(answer)) => Ok(compute_error(answer)),
+            (None, Self::Penalty(penalty)) => Ok(penalty.clone()),
+            (None, Self::Error) => Err(MissingOutputError {
+                stack_type: std::any::type_name::<T>(),
+            }),
+        }
+    }
+
+    /// Compute the error of the answer on top of the `T` stack in `state`
+    /// using `compute_error`, applying this policy if that stack is empty.
+    ///
+    /// # Errors
+    ///
+    /// This returns a [`MissingOutputError`] if the `T` stack is empty and the
+    /// policy is [`MissingOutputPolicy::Error`].
+    pub fn state_error<S>(
+        &self,
+        state: &S,
+        compute_error: impl FnOnce(&T) -> E,
+    ) -> Result<E, MissingOutputError>
+    where
+        S: HasStack<T>,
+        E: Clone,
+    {
+        self.error_for(state.stack::<T>().top().ok(), compute_error)
+    }
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "Building the test states can't fail")]
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "The expected and actual test values are small"
+)]
+mod tests {
+    use super::*;
+    use crate::{
+        instruction::{BoolInstruction, PushInstruction},
+        list_into::vec_into,
+        push_vm::{program::PushProgram, push_state::PushState, State},
+    };
+
+    // Runs a program that leaves the `i64` (answer) stack empty.
+    fn run_without_answer() -> PushState {
+        let program: Vec<PushProgram> = vec_into![
+            PushInstruction::push_bool(true),
+            PushInstruction::push_bool(false),
+            BoolInstruction::And,
+        ];
+        PushState::builder()
+            .with_max_stack_size(10)
+            .with_program(program)
+            .unwrap()
+            .build()
+            .run_to_completion()
+            .unwrap()
+    }
+
+    fn abs_error(expected: i64) -> impl FnOnce(&i64) -> i128 {
+        move |answer| (i128::from(*answer) - i128::from(expected)).abs()
+    }
+
+    #[test]
+    fn penalty() {
+        let state = run_without_answer();
+        let policy = MissingOutputPolicy::Penalty(1_000);
+        assert_eq!(policy.state_error(&state, abs_error(5)).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn use_default() {
+        let state = run_without_answer();
+        let policy = MissingOutputPolicy::UseDefault(0);
+        assert_eq!(policy.state_error(&state, abs_error(5)).unwrap(), 5);
+    }
+
+    #[test]
+    fn error() {
+        let state = run_without_answer();
+        let policy = MissingOutputPolicy::<i64, i128>::Error;
+        assert_eq!(
+            policy.state_error(&state, abs_error(5)).unwrap_err(),
+            MissingOutputError { stack_type: "i64" }
+        );
+    }
+
+    #[test]
+    fn answer_present_ignores_policy() {
+        let state = PushState::builder()
+            .with_max_stack_size(10)
+            .with_int_values([8])
+            .unwrap()
+            .with_no_program()
+            .build();
+        let policy = MissingOutputPolicy::<i64, i128>::Error;
+        assert_eq!(policy.state_error(&state, abs_error(5)).unwrap(), 3);
+    }
+}
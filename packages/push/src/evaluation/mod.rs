@@ -1 +1,5 @@
 pub mod cases;
+#[cfg(feature = "csv")]
+pub mod csv_cases;
+pub mod missing_output;
+pub mod penalty;
@@ -0,0 +1,107 @@
+use crate::push_vm::HasStack;
+
+/// A penalty value (usually something large) to use as the error when
+/// running a program fails or leaves no answer, so scorers don't each have to
+/// handle those cases by hand.
+///
+/// This is the same as a [`Penalty`](super::missing_output::MissingOutputPolicy::Penalty)
+/// policy for missing outputs, but also covers runs that fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Penalty<E> {
+    value: E,
+}
+
+impl<E> Penalty<E> {
+    #[must_use]
+    pub const fn new(value: E) -> Self {
+        Self { value }
+    }
+
+    #[must_use]
+    pub const fn value(&self) -> &E {
+        &self.value
+    }
+
+    /// The error of the result of running a program: the penalty value if
+    /// `final_state` is an error or its `T` stack is empty, and otherwise the
+    /// error `compute_error` gives for the answer on top of the `T` stack.
+    pub fn score<S, T, Err>(
+        &self,
+        final_state: Result<S, Err>,
+        compute_error: impl FnOnce(&T) -> E,
+    ) -> E
+    where
+        S: HasStack<T>,
+        E: Clone,
+    {
+        final_state.map_or_else(
+            |_| self.value.clone(),
+            |state| {
+                state
+                    .stack::<T>()
+                    .top()
+                    .map_or_else(|_| self.value.clone(), compute_error)
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "Building the test states can't fail")]
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "The expected and actual test values are small"
+)]
+mod tests {
+    use super::*;
+    use crate::{
+        instruction::{IntInstruction, PushInstruction},
+        list_into::vec_into,
+        push_vm::{program::PushProgram, push_state::PushState, State},
+    };
+
+    const PENALTY: Penalty<i128> = Penalty::new(1_000);
+
+    fn abs_error(expected: i64) -> impl FnOnce(&i64) -> i128 {
+        move |answer| (i128::from(*answer) - i128::from(expected)).abs()
+    }
+
+    fn run(program: Vec<PushProgram>, max_int_stack_size: usize) -> i128 {
+        let final_state = PushState::builder()
+            .with_max_stack_size(10)
+            .with_int_max_size(max_int_stack_size)
+            .with_program(program)
+            .unwrap()
+            .build()
+            .run_to_completion();
+        PENALTY.score(final_state, abs_error(5))
+    }
+
+    #[test]
+    fn answer_is_scored() {
+        let program = vec_into![
+            PushInstruction::push_int(3),
+            PushInstruction::push_int(4),
+            IntInstruction::Add,
+        ];
+        assert_eq!(run(program, 10), 2);
+    }
+
+    #[test]
+    fn empty_answer_stack_is_penalized() {
+        let program = vec_into![PushInstruction::push_bool(true)];
+        assert_eq!(run(program, 10), 1_000);
+    }
+
+    #[test]
+    fn failed_run_is_penalized() {
+        // Pushing a third value onto an `i64` stack that can only hold two
+        // is a fatal error.
+        let program = vec_into![
+            PushInstruction::push_int(3),
+            PushInstruction::push_int(4),
+            PushInstruction::push_int(5),
+        ];
+        assert_eq!(run(program, 2), 1_000);
+    }
+}
@@ -0,0 +1,199 @@
+use std::f64::consts::TAU;
+
+use anyhow::Result;
+use ec_core::operator::recombinator::Recombinator;
+use num_traits::ToPrimitive;
+use rand::{Rng, RngCore};
+
+use super::plushy::{Plushy, PushGene};
+
+/// The standard Push "alternation" recombinator for variable-length genomes.
+///
+/// This walks along the two parents' genes, copying genes from the current
+/// parent into the child. Before copying each gene, it switches to the other
+/// parent with probability `alternation_rate`. When it switches, the position
+/// it continues from in the other parent is shifted by a random offset, drawn
+/// from a normal distribution with standard deviation `alignment_deviation`
+/// (and rounded to the nearest integer), so the parents don't have to line up
+/// exactly.
+///
+/// Copying always starts with the first parent, and stops when the current
+/// parent has no genes left, or when the child has `max_genome_length` genes.
+/// With an `alternation_rate` of 0 the child is therefore a copy of (a prefix
+/// of) the first parent.
+pub struct AlternationRecombinator {
+    alternation_rate: f64,
+    alignment_deviation: f64,
+    max_genome_length: usize,
+}
+
+impl AlternationRecombinator {
+    /// `alternation_rate` is clamped to `[0, 1]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alternation_rate` is `NaN` or infinite. There's no sensible
+    /// rate to clamp `NaN` to, and catching it here is better than having
+    /// `recombine` panic later on.
+    #[must_use]
+    pub const fn new(
+        alternation_rate: f64,
+        alignment_deviation: f64,
+        max_genome_length: usize,
+    ) -> Self {
+        assert!(
+            alternation_rate.is_finite(),
+            "The alternation rate must be finite"
+        );
+        Self {
+            alternation_rate: alternation_rate.clamp(0.0, 1.0),
+            alignment_deviation,
+            max_genome_length,
+        }
+    }
+
+    // Shift `index` by a random offset, staying non-negative.
+    fn realign(&self, index: usize, rng: &mut dyn RngCore) -> usize {
+        let offset = (self.alignment_deviation * standard_normal(rng)).round();
+        let magnitude = offset.abs().to_usize().unwrap_or(usize::MAX);
+        if offset < 0.0 {
+            index.saturating_sub(magnitude)
+        } else {
+            index.saturating_add(magnitude)
+        }
+    }
+}
+
+// A sample from the standard normal distribution, N(0, 1), using the
+// Box-Muller transform.
+fn standard_normal(rng: &mut dyn RngCore) -> f64 {
+    // `gen` returns values in `[0, 1)`, so `u` is in `(0, 1]` and its
+    // logarithm is finite.
+    let u = 1.0 - rng.gen::<f64>();
+    let v = rng.gen::<f64>();
+    (-2.0 * u.ln()).sqrt() * (TAU * v).cos()
+}
+
+impl Recombinator<[Plushy; 2]> for AlternationRecombinator {
+    type Output = Plushy;
+
+    fn recombine(&self, parents: [Plushy; 2], rng: &mut dyn RngCore) -> Result<Plushy> {
+        let [first, second] = parents.map(|parent| parent.into_iter().collect::<Vec<_>>());
+        let mut child: Vec<PushGene> = Vec::new();
+        let mut use_first = true;
+        let mut index = 0;
+        // Every iteration either copies a gene or switches parents, so this
+        // bounds the number of switches between copies.
+        let mut iteration_budget = first.len().saturating_add(second.len());
+        while child.len() < self.max_genome_length && iteration_budget > 0 {
+            let current = if use_first { &first } else { &second };
+            let Some(gene) = current.get(index) else {
+                break;
+            };
+            if rng.gen_bool(self.alternation_rate) {
+                use_first = !use_first;
+                index = self.realign(index, rng);
+            } else {
+                child.push(gene.clone());
+                index = index.saturating_add(1);
+            }
+            iteration_budget = iteration_budget.saturating_sub(1);
+        }
+        Ok(Plushy::new(child))
+    }
+}
+
+impl Recombinator<(Plushy, Plushy)> for AlternationRecombinator {
+    type Output = Plushy;
+
+    fn recombine(&self, parents: (Plushy, Plushy), rng: &mut dyn RngCore) -> Result<Plushy> {
+        self.recombine(<[Plushy; 2]>::from(parents), rng)
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "Recombining the test parents can't fail"
+)]
+mod tests {
+    use super::*;
+    use crate::instruction::{BoolInstruction, IntInstruction, PushInstruction};
+
+    fn parents() -> [Plushy; 2] {
+        [
+            (0..20)
+                .map(|i| PushInstruction::push_int(i).into())
+                .collect(),
+            (0..7)
+                .map(|i| PushInstruction::push_bool(i % 2 == 0).into())
+                .collect(),
+        ]
+    }
+
+    #[test]
+    fn no_alternation_copies_first_parent() {
+        let mut rng = rand::thread_rng();
+        let [first, second] = parents();
+
+        let recombinator = AlternationRecombinator::new(0.0, 10.0, 100);
+        let child = recombinator
+            .recombine([first.clone(), second.clone()], &mut rng)
+            .unwrap();
+        assert_eq!(child, first);
+
+        let recombinator = AlternationRecombinator::new(0.0, 10.0, 5);
+        let child = recombinator
+            .recombine([first.clone(), second], &mut rng)
+            .unwrap();
+        assert_eq!(child.get_genes(), first.get_genes()[..5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "The alternation rate must be finite")]
+    fn nan_rate_is_rejected() {
+        let _ = AlternationRecombinator::new(f64::NAN, 10.0, 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "The alternation rate must be finite")]
+    fn infinite_rate_is_rejected() {
+        let _ = AlternationRecombinator::new(f64::INFINITY, 10.0, 100);
+    }
+
+    #[test]
+    fn genes_come_from_parents() {
+        let mut rng = rand::thread_rng();
+        let [first, second] = parents();
+        let parent_genes: Vec<PushGene> = first
+            .get_genes()
+            .into_iter()
+            .chain(second.get_genes())
+            .collect();
+        let recombinator = AlternationRecombinator::new(0.3, 2.0, 15);
+        for _ in 0..100 {
+            let child = recombinator
+                .recombine((first.clone(), second.clone()), &mut rng)
+                .unwrap();
+            assert!(child.get_genes().len() <= 15);
+            assert!(child
+                .get_genes()
+                .iter()
+                .all(|gene| parent_genes.contains(gene)));
+        }
+    }
+
+    #[test]
+    fn handles_empty_parents() {
+        let mut rng = rand::thread_rng();
+        let recombinator = AlternationRecombinator::new(0.5, 1.0, 10);
+        let empty = Plushy::new([]);
+        let other = Plushy::new([IntInstruction::Add.into(), BoolInstruction::And.into()]);
+        let child = recombinator
+            .recombine([empty.clone(), other.clone()], &mut rng)
+            .unwrap();
+        assert!(child.get_genes().is_empty());
+        let child = recombinator.recombine([other, empty], &mut rng).unwrap();
+        assert!(child.get_genes().len() <= 2);
+    }
+}
@@ -0,0 +1,156 @@
+use std::num::NonZeroUsize;
+
+use ec_core::distributions::choices::ChoicesDistribution;
+use rand::{prelude::Distribution, Rng};
+
+use crate::instruction::{CharInstruction, PushInstruction};
+
+/// A set of characters to sample literal `char`s from, e.g., for
+/// [`CharInstruction::Push`] ERCs (ephemeral random constants).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharClass {
+    // Never empty.
+    chars: Vec<char>,
+}
+
+impl CharClass {
+    /// The ASCII letters, `a-z` and `A-Z`.
+    #[must_use]
+    pub fn ascii_letters() -> Self {
+        Self {
+            chars: ('a'..='z').chain('A'..='Z').collect(),
+        }
+    }
+
+    /// The ASCII digits `0-9`.
+    #[must_use]
+    pub fn ascii_digits() -> Self {
+        Self {
+            chars: ('0'..='9').collect(),
+        }
+    }
+
+    /// The printable ASCII characters, from space (`' '`) through `'~'`.
+    #[must_use]
+    pub fn ascii_printable() -> Self {
+        Self {
+            chars: (' '..='~').collect(),
+        }
+    }
+
+    /// A custom set of characters, or `None` if `chars` is empty. Characters
+    /// that appear more than once are proportionally more likely to be
+    /// sampled.
+    #[must_use]
+    pub fn custom(chars: impl IntoIterator<Item = char>) -> Option<Self> {
+        let chars: Vec<_> = chars.into_iter().collect();
+        (!chars.is_empty()).then_some(Self { chars })
+    }
+
+    #[must_use]
+    pub fn contains(&self, c: char) -> bool {
+        self.chars.contains(&c)
+    }
+}
+
+impl Distribution<char> for CharClass {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
+        self.chars[rng.gen_range(0..self.chars.len())]
+    }
+}
+
+/// Generates [`CharInstruction::Push`] instructions whose `char` is sampled
+/// from a [`CharClass`].
+///
+/// This can be used (or combined with other distributions) as the
+/// instruction distribution of a
+/// [`GeneGenerator`](super::plushy::GeneGenerator).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharPushGenerator {
+    char_class: CharClass,
+}
+
+impl CharPushGenerator {
+    #[must_use]
+    pub const fn new(char_class: CharClass) -> Self {
+        Self { char_class }
+    }
+}
+
+impl Distribution<CharInstruction> for CharPushGenerator {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> CharInstruction {
+        CharInstruction::Push(self.char_class.sample(rng))
+    }
+}
+
+impl Distribution<PushInstruction> for CharPushGenerator {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PushInstruction {
+        Distribution::<CharInstruction>::sample(self, rng).into()
+    }
+}
+
+impl ChoicesDistribution for CharPushGenerator {
+    /// All the `Push` instructions count as a single choice, so a
+    /// [`GeneGenerator`](super::plushy::GeneGenerator) with a uniform close
+    /// probability closes as often as it emits a `Push`.
+    fn num_choices(&self) -> NonZeroUsize {
+        NonZeroUsize::MIN
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The character classes in the tests are never empty"
+)]
+#[expect(
+    clippy::panic,
+    reason = "Panicking is how these tests report a failure"
+)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::genome::plushy::{ConvertToGeneGenerator, PushGene};
+
+    #[test]
+    fn generated_chars_are_in_class() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for char_class in [
+            CharClass::ascii_letters(),
+            CharClass::ascii_digits(),
+            CharClass::ascii_printable(),
+            CharClass::custom("xyz!".chars()).unwrap(),
+        ] {
+            let generator = CharPushGenerator::new(char_class.clone());
+            for _ in 0..100 {
+                let instruction: CharInstruction = generator.sample(&mut rng);
+                let CharInstruction::Push(c) = instruction else {
+                    panic!("Expected a CharInstruction::Push");
+                };
+                assert!(char_class.contains(c), "{c:?} isn't in {char_class:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn gene_generator_emits_pushes_and_closes() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let gene_generator =
+            CharPushGenerator::new(CharClass::ascii_digits()).into_gene_generator();
+        for _ in 0..100 {
+            match gene_generator.sample(&mut rng) {
+                PushGene::Close => {}
+                PushGene::Instruction(PushInstruction::CharInstruction(CharInstruction::Push(
+                    c,
+                ))) => assert!(c.is_ascii_digit()),
+                gene @ PushGene::Instruction(_) => panic!("Unexpected gene {gene}"),
+            }
+        }
+    }
+
+    #[test]
+    fn empty_custom_class() {
+        assert_eq!(CharClass::custom(std::iter::empty()), None);
+    }
+}
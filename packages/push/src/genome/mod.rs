@@ -1 +1,4 @@
+pub mod alternation;
+pub mod char_class;
 pub mod plushy;
+pub mod weighted;
@@ -1,16 +1,23 @@
-use std::fmt::Display;
+use std::{fmt::Display, ops::RangeInclusive};
 
 use easy_cast::ConvApprox;
 use ec_core::{
     distributions::{choices::ChoicesDistribution, collection::CollectionGenerator},
     genome::Genome,
 };
-use ec_linear::genome::Linear;
+use ec_linear::{
+    genome::Linear,
+    mutator::{point::PointMutation, umad::Umad},
+};
 use rand::{prelude::Distribution, Rng};
 
-use crate::instruction::{NumOpens, PushInstruction};
+use crate::{
+    instruction::{NumOpens, PushInstruction},
+    push_vm::program::PushProgram,
+};
 
-#[derive(Clone, Eq, PartialEq, Debug)]
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PushGene {
     Close,
     Instruction(PushInstruction),
@@ -25,7 +32,7 @@ impl Display for PushGene {
             Self::Instruction(i) => {
                 i.fmt(f)?;
 
-                for bracket in std::iter::repeat(" {").take(i.num_opens()) {
+                for bracket in std::iter::repeat_n(" {", i.num_opens()) {
                     f.write_str(bracket)?;
                 }
             }
@@ -51,6 +58,7 @@ where
 {
     close_probability: f32,
     instruction_distribution: T,
+    max_length: Option<usize>,
 }
 
 impl<T> GeneGenerator<T>
@@ -62,6 +70,74 @@ where
         Self {
             close_probability,
             instruction_distribution: instructions_distribution,
+            max_length: None,
+        }
+    }
+
+    /// Limit the genomes built from this generator to `max_length` genes.
+    ///
+    /// This doesn't change the genes that are generated, but genomes sampled
+    /// from a collection generator (e.g., from `to_collection_generator`) or
+    /// from [`GeneGenerator::to_variable_length_generator`] are no longer
+    /// than `max_length`, and neither are the genomes made by the mutators
+    /// from [`GeneGenerator::umad`] (and [`GeneGenerator::point_mutation`],
+    /// which never changes the length of a genome).
+    #[must_use]
+    pub const fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    #[must_use]
+    pub const fn max_length(&self) -> Option<usize> {
+        self.max_length
+    }
+
+    /// A UMAD mutator that adds genes from this generator, and respects its
+    /// maximum length (if there is one).
+    #[must_use]
+    pub const fn umad(&self, addition_rate: f64, deletion_rate: f64) -> Umad<&Self> {
+        let umad = Umad::new(addition_rate, deletion_rate, self);
+        match self.max_length {
+            Some(max_length) => umad.with_max_length(max_length),
+            None => umad,
+        }
+    }
+
+    /// A point mutator that replaces genes with genes from this generator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mutation_rate` is `NaN`.
+    #[must_use]
+    pub fn point_mutation(&self, mutation_rate: f64) -> PointMutation<&Self> {
+        PointMutation::new(mutation_rate, self)
+    }
+
+    /// A generator for initial genomes whose lengths are drawn uniformly
+    /// from `lengths` (capped at this generator's maximum length, if there
+    /// is one), so an initial population has a spread of program sizes
+    /// instead of every genome having the same length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lengths` is empty.
+    #[must_use]
+    pub fn to_variable_length_generator(
+        &self,
+        lengths: RangeInclusive<usize>,
+    ) -> VariableLengthGenerator<&Self> {
+        assert!(
+            !lengths.is_empty(),
+            "The range of lengths must not be empty"
+        );
+        let (start, end) = lengths.into_inner();
+        let end = self
+            .max_length
+            .map_or(end, |max_length| end.min(max_length));
+        VariableLengthGenerator {
+            gene_generator: self,
+            lengths: start.min(end)..=end,
         }
     }
 }
@@ -164,7 +240,8 @@ where
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Plushy {
     genes: Vec<PushGene>,
 }
@@ -174,7 +251,7 @@ impl Display for Plushy {
         let mut iter = self.genes.iter();
         if let Some(gene) = iter.next() {
             gene.fmt(f)?;
-        };
+        }
 
         for gene in iter {
             f.write_str(" ")?;
@@ -199,6 +276,15 @@ impl Plushy {
     pub fn get_genes(&self) -> Vec<PushGene> {
         self.genes.clone()
     }
+
+    /// The program represented by this genome. This is the same as
+    /// `Vec::<PushProgram>::from(plushy.clone())`, but it only clones the
+    /// instructions as they're added to the program, without cloning the
+    /// genome first.
+    #[must_use]
+    pub fn to_program(&self) -> Vec<PushProgram> {
+        PushProgram::from_genes(self.genes.iter().cloned())
+    }
 }
 
 impl Genome for Plushy {
@@ -215,14 +301,60 @@ impl Linear for Plushy {
     }
 }
 
-impl<GG> Distribution<Plushy> for CollectionGenerator<GG>
+impl<T> GeneGenerator<T>
+where
+    T: Distribution<PushInstruction>,
+{
+    // A genome of `length` genes, or of this generator's maximum length if
+    // that's shorter.
+    fn sample_genome<R: Rng + ?Sized>(&self, length: usize, rng: &mut R) -> Plushy {
+        let length = self
+            .max_length
+            .map_or(length, |max_length| length.min(max_length));
+        self.sample_iter(rng).take(length).collect()
+    }
+}
+
+/// Genomes of `size` genes, or of the gene generator's maximum length (see
+/// [`GeneGenerator::with_max_length`]) if that's shorter.
+impl<T> Distribution<Plushy> for CollectionGenerator<GeneGenerator<T>>
+where
+    T: Distribution<PushInstruction>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Plushy {
+        self.element_generator.sample_genome(self.size, rng)
+    }
+}
+
+/// Genomes of `size` genes, or of the gene generator's maximum length (see
+/// [`GeneGenerator::with_max_length`]) if that's shorter.
+impl<T> Distribution<Plushy> for CollectionGenerator<&GeneGenerator<T>>
+where
+    T: Distribution<PushInstruction>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Plushy {
+        self.element_generator.sample_genome(self.size, rng)
+    }
+}
+
+/// Generates [`Plushy`] genomes whose lengths are drawn uniformly from a
+/// range; see [`GeneGenerator::to_variable_length_generator`].
+#[derive(Debug, Clone)]
+pub struct VariableLengthGenerator<GG> {
+    gene_generator: GG,
+    lengths: RangeInclusive<usize>,
+}
+
+impl<GG> Distribution<Plushy> for VariableLengthGenerator<GG>
 where
     GG: Distribution<PushGene>,
 {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Plushy {
-        Plushy {
-            genes: rng.sample(self),
-        }
+        let length = rng.gen_range(self.lengths.clone());
+        (&self.gene_generator)
+            .sample_iter(rng)
+            .take(length)
+            .collect()
     }
 }
 
@@ -244,24 +376,31 @@ impl FromIterator<PushGene> for Plushy {
     }
 }
 
-#[allow(clippy::unwrap_used)]
 #[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The test genomes and operators are valid, so sampling and mutating them can't fail"
+)]
 mod test {
+    use std::collections::HashSet;
+
     use ec_core::{
         distributions::collection::ConvertToCollectionGenerator, operator::mutator::Mutator,
         uniform_distribution_of,
     };
-    use ec_linear::mutator::umad::Umad;
-    use rand::thread_rng;
+    use ordered_float::OrderedFloat;
+    use rand::{rngs::StdRng, thread_rng, SeedableRng};
 
     use super::*;
     use crate::{
-        instruction::{variable_name::VariableName, BoolInstruction, IntInstruction},
+        instruction::{
+            fold_stack::FoldStack, variable_name::VariableName, BoolInstruction, ExecInstruction,
+            IntInstruction, OutputInstruction,
+        },
         list_into::vec_into,
     };
 
     #[test]
-    #[allow(clippy::unwrap_used)]
     fn generator() {
         let mut rng = thread_rng();
         let plushy: Plushy = uniform_distribution_of![<PushInstruction>
@@ -277,6 +416,101 @@ mod test {
         assert_eq!(10, plushy.genes.len());
     }
 
+    #[test]
+    fn collection_generator_respects_max_length() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let gene_generator = uniform_distribution_of![<PushInstruction>
+            IntInstruction::Add,
+            BoolInstruction::And,
+        ]
+        .into_gene_generator()
+        .with_max_length(25);
+        let short: Plushy = gene_generator.to_collection_generator(10).sample(&mut rng);
+        assert_eq!(short.size(), 10);
+        let capped: Plushy = gene_generator
+            .into_collection_generator(100)
+            .sample(&mut rng);
+        assert_eq!(capped.size(), 25);
+    }
+
+    #[test]
+    fn umad_respects_max_length() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let gene_generator = uniform_distribution_of![<PushInstruction>
+            IntInstruction::Add,
+            BoolInstruction::And,
+        ]
+        .into_gene_generator()
+        .with_max_length(25);
+        let umad = gene_generator.umad(0.3, 0.1);
+        let mut plushy: Plushy = gene_generator.to_collection_generator(10).sample(&mut rng);
+        for _ in 0..500 {
+            plushy = umad.mutate(plushy, &mut rng).unwrap();
+            assert!(plushy.size() <= 25, "{plushy}");
+        }
+    }
+
+    #[test]
+    fn point_mutation_preserves_length() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let gene_generator = uniform_distribution_of![<PushInstruction>
+            IntInstruction::Add,
+            BoolInstruction::And,
+        ]
+        .into_gene_generator();
+        let parent: Plushy = gene_generator.to_collection_generator(50).sample(&mut rng);
+
+        let unchanged = gene_generator
+            .point_mutation(0.0)
+            .mutate(parent.clone(), &mut rng)
+            .unwrap();
+        assert_eq!(unchanged, parent);
+
+        let mutator = gene_generator.point_mutation(0.5);
+        let mut child = parent;
+        for _ in 0..100 {
+            child = mutator.mutate(child, &mut rng).unwrap();
+            assert_eq!(child.size(), 50, "{child}");
+        }
+    }
+
+    #[test]
+    fn variable_length_generator_spreads_lengths() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let gene_generator = uniform_distribution_of![<PushInstruction>
+            IntInstruction::Add,
+            BoolInstruction::And,
+        ]
+        .into_gene_generator()
+        .with_max_length(40);
+        let population: Vec<Plushy> = gene_generator
+            .to_variable_length_generator(5..=60)
+            .into_collection_generator(100)
+            .sample(&mut rng);
+        let lengths: HashSet<usize> = population.iter().map(Linear::size).collect();
+        // The lengths are capped by the generator's maximum length.
+        assert!(lengths.iter().all(|length| (5..=40).contains(length)));
+        assert!(lengths.len() > 10);
+    }
+
+    #[test]
+    fn hashing_is_consistent_with_equality() {
+        let genome = |x: i64| -> Plushy {
+            Plushy::new(vec_into![
+                IntInstruction::Push(x),
+                PushInstruction::push_float(OrderedFloat(1.5)),
+                OutputInstruction::print_int("out"),
+                IntInstruction::yank(),
+                IntInstruction::SumAll(FoldStack::new()),
+                ExecInstruction::dup_block(),
+                PushGene::Close,
+            ])
+        };
+        let genomes: HashSet<Plushy> = [genome(1), genome(1), genome(2)].into_iter().collect();
+        assert_eq!(genomes.len(), 2);
+        assert!(genomes.contains(&genome(2)));
+    }
+
     #[ignore = "this has about a 2.665% chance on failing at least once across the three test \
                 runners in ci"]
     #[test]
@@ -314,7 +548,6 @@ mod test {
 
         let child = umad.mutate(parent, &mut rng);
 
-        #[allow(clippy::unwrap_used)]
         let num_inputs = child
             .unwrap()
             .genes
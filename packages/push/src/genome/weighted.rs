@@ -0,0 +1,173 @@
+use std::num::NonZeroUsize;
+
+use ec_core::distributions::choices::ChoicesDistribution;
+use rand::{
+    distributions::{WeightError, WeightedIndex},
+    prelude::Distribution,
+    Rng,
+};
+
+use crate::instruction::PushInstruction;
+
+/// Samples instructions from a fixed set, with each instruction chosen in
+/// proportion to its weight, e.g., to generate `Add` more often than
+/// `Power`.
+///
+/// Like the uniform distributions (e.g., from `uniform_distribution_of!`),
+/// this can be used as the instruction distribution of a
+/// [`GeneGenerator`](super::plushy::GeneGenerator), such as with
+/// `into_gene_generator()`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeightedInstructionDistribution {
+    instructions: Vec<PushInstruction>,
+    weights: WeightedIndex<f64>,
+    // The number of instructions with a non-zero weight.
+    num_choices: NonZeroUsize,
+}
+
+impl WeightedInstructionDistribution {
+    /// Create a distribution from `(instruction, weight)` pairs. The weights
+    /// don't need to sum to 1, and an instruction with a weight of 0 is never
+    /// sampled.
+    ///
+    /// # Errors
+    ///
+    /// - [`WeightError::InvalidInput`] if there are no instructions.
+    /// - [`WeightError::InvalidWeight`] if any weight is negative or NaN.
+    /// - [`WeightError::InsufficientNonZero`] if all the weights are 0.
+    /// - [`WeightError::Overflow`] if the weights sum to more than `f64` can
+    ///   represent.
+    pub fn new<I>(
+        weighted_instructions: impl IntoIterator<Item = (I, f64)>,
+    ) -> Result<Self, WeightError>
+    where
+        I: Into<PushInstruction>,
+    {
+        let (instructions, weights): (Vec<PushInstruction>, Vec<f64>) = weighted_instructions
+            .into_iter()
+            .map(|(instruction, weight)| (instruction.into(), weight))
+            .unzip();
+        let num_choices = weights.iter().filter(|&&weight| weight > 0.0).count();
+        let weights = WeightedIndex::new(weights)?;
+        let num_choices = NonZeroUsize::new(num_choices).ok_or(WeightError::InsufficientNonZero)?;
+        Ok(Self {
+            instructions,
+            weights,
+            num_choices,
+        })
+    }
+}
+
+impl Distribution<PushInstruction> for WeightedInstructionDistribution {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PushInstruction {
+        // `WeightedIndex` only returns indices of the weights it was made
+        // from, so this index is always in bounds.
+        self.instructions[self.weights.sample(rng)].clone()
+    }
+}
+
+impl ChoicesDistribution for WeightedInstructionDistribution {
+    /// Instructions with a weight of 0 are never sampled, so they don't count
+    /// as choices (e.g., when computing a uniform close probability).
+    fn num_choices(&self) -> NonZeroUsize {
+        self.num_choices
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The tests unwrap distributions built from valid weights"
+)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{
+        genome::plushy::{ConvertToGeneGenerator, PushGene},
+        instruction::IntInstruction,
+    };
+
+    #[test]
+    fn frequencies_match_weights() {
+        let weights = [
+            (IntInstruction::Add, 1.0),
+            (IntInstruction::Power, 2.0),
+            (IntInstruction::Abs, 7.0),
+        ];
+        let distribution = WeightedInstructionDistribution::new(weights).unwrap();
+        let mut rng = StdRng::seed_from_u64(42);
+        let num_samples = 100_000;
+        let mut counts = [0_u32; 3];
+        for _ in 0..num_samples {
+            let instruction = distribution.sample(&mut rng);
+            let index = weights
+                .iter()
+                .position(|(i, _)| PushInstruction::from(*i) == instruction)
+                .unwrap();
+            counts[index] += 1;
+        }
+        for ((_, weight), count) in weights.iter().zip(counts) {
+            let frequency = f64::from(count) / f64::from(num_samples);
+            assert!(
+                (frequency - weight / 10.0).abs() < 0.01,
+                "Expected frequency {} but got {frequency}",
+                weight / 10.0
+            );
+        }
+    }
+
+    #[test]
+    fn zero_weights_are_never_sampled() {
+        let distribution = WeightedInstructionDistribution::new([
+            (IntInstruction::Add, 0.0),
+            (IntInstruction::Abs, 1.0),
+        ])
+        .unwrap();
+        assert_eq!(distribution.num_choices().get(), 1);
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..100 {
+            assert_eq!(
+                distribution.sample(&mut rng),
+                PushInstruction::from(IntInstruction::Abs)
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_weights() {
+        assert_eq!(
+            WeightedInstructionDistribution::new([
+                (IntInstruction::Add, 0.0),
+                (IntInstruction::Abs, 0.0)
+            ]),
+            Err(WeightError::InsufficientNonZero)
+        );
+        assert_eq!(
+            WeightedInstructionDistribution::new([
+                (IntInstruction::Add, -1.0),
+                (IntInstruction::Abs, 2.0)
+            ]),
+            Err(WeightError::InvalidWeight)
+        );
+        assert_eq!(
+            WeightedInstructionDistribution::new(Vec::<(IntInstruction, f64)>::new()),
+            Err(WeightError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn gene_generator() {
+        let gene_generator = WeightedInstructionDistribution::new([(IntInstruction::Add, 3.0)])
+            .unwrap()
+            .into_gene_generator();
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..100 {
+            let gene: PushGene = gene_generator.sample(&mut rng);
+            assert!(matches!(
+                gene,
+                PushGene::Close | PushGene::Instruction(PushInstruction::IntInstruction(_))
+            ));
+        }
+    }
+}
@@ -1,14 +1,19 @@
 use std::ops::Not;
 
+use rand::Rng;
 use strum_macros::EnumIter;
 
 use super::{Instruction, PushInstruction, PushInstructionError};
 use crate::{
     error::{InstructionResult, MapInstructionError},
-    push_vm::stack::{HasStack, PushOnto},
+    push_vm::{
+        stack::{HasStack, PushOnto},
+        HasRng,
+    },
 };
 
-#[derive(Debug, strum_macros::Display, Clone, PartialEq, Eq, EnumIter)]
+#[derive(Debug, strum_macros::Display, Clone, PartialEq, Eq, Hash, EnumIter)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum BoolInstruction {
     Push(bool),
@@ -16,17 +21,26 @@ pub enum BoolInstruction {
     Or,
     And,
     Xor,
+    /// Pushes `false` only if the top two values are both `true`.
+    Nand,
+    /// Pushes `true` only if the top two values are both `false`.
+    Nor,
+    /// Pushes `false` only if the top value is `true` and the second value is
+    /// `false`, i.e., "top implies second".
     Implies,
     // Do we really want either of these? Do they get used?
     // BooleanInvertFirstThenAnd,
     // BoolInvertSecondThenAnd,
     FromInt,
     // BoolFromFloat,
+    /// Pushes a random boolean (a "coin flip") using the state's random
+    /// number generator.
+    Random,
 }
 
 impl<S> Instruction<S> for BoolInstruction
 where
-    S: Clone + HasStack<bool> + HasStack<i64>,
+    S: Clone + HasStack<bool> + HasStack<i64> + HasRng,
 {
     type Error = PushInstructionError;
 
@@ -87,6 +101,8 @@ where
             Self::And => bool_stack.pop2().map(|(x, y)| x && y).push_onto(state),
             Self::Or => bool_stack.pop2().map(|(x, y)| x || y).push_onto(state),
             Self::Xor => bool_stack.pop2().map(|(x, y)| x != y).push_onto(state),
+            Self::Nand => bool_stack.pop2().map(|(x, y)| !(x && y)).push_onto(state),
+            Self::Nor => bool_stack.pop2().map(|(x, y)| !(x || y)).push_onto(state),
             Self::Implies => bool_stack.pop2().map(|(x, y)| !x || y).push_onto(state),
             Self::FromInt => {
                 let mut state = state.not_full::<bool>().map_err_into()?;
@@ -96,6 +112,10 @@ where
                     .map(|i| i != 0)
                     .push_onto(state)
             }
+            Self::Random => {
+                let b = state.using_rng(|rng| rng.gen::<bool>());
+                state.with_push(b).map_err_into()
+            }
         }
     }
 }
@@ -107,14 +127,17 @@ impl From<BoolInstruction> for PushInstruction {
 }
 
 #[cfg(test)]
-#[allow(clippy::unwrap_used, clippy::ignored_unit_patterns)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The generated states always have room for the values the tests push"
+)]
 mod property_tests {
     use proptest::prop_assert_eq;
     use strum::IntoEnumIterator;
     use test_strategy::proptest;
 
     use crate::{
-        instruction::{BoolInstruction, Instruction},
+        instruction::{BoolInstruction, Instruction, PushInstruction},
         push_vm::push_state::PushState,
     };
 
@@ -168,4 +191,86 @@ mod property_tests {
         prop_assert_eq!(result_state.bool.size(), 1);
         prop_assert_eq!(*result_state.bool.top().unwrap(), !x || y);
     }
+
+    fn perform_on(instr: &BoolInstruction, x: bool, y: bool) -> bool {
+        let state = PushState::builder()
+            .with_max_stack_size(2)
+            .with_no_program()
+            .with_bool_values([x, y])
+            .unwrap()
+            .build();
+        let result_state = instr.perform(state).unwrap();
+        assert_eq!(result_state.bool.size(), 1);
+        *result_state.bool.top().unwrap()
+    }
+
+    #[test]
+    fn gate_truth_tables() {
+        // The rows are `(x, y, x xor y, x nand y, x nor y)`.
+        let rows = [
+            (false, false, false, true, true),
+            (false, true, true, true, false),
+            (true, false, true, true, false),
+            (true, true, false, false, false),
+        ];
+        for (x, y, xor, nand, nor) in rows {
+            assert_eq!(perform_on(&BoolInstruction::Xor, x, y), xor);
+            assert_eq!(perform_on(&BoolInstruction::Nand, x, y), nand);
+            assert_eq!(perform_on(&BoolInstruction::Nor, x, y), nor);
+        }
+    }
+
+    #[test]
+    fn from_int_is_true_for_nonzero() {
+        for (i, expected) in [(0, false), (1, true), (-7, true), (i64::MIN, true)] {
+            let state = PushState::builder()
+                .with_max_stack_size(1)
+                .with_no_program()
+                .with_int_values([i])
+                .unwrap()
+                .build();
+            let result_state = BoolInstruction::FromInt.perform(state).unwrap();
+            assert!(result_state.int.is_empty());
+            assert_eq!(result_state.bool, vec![expected]);
+        }
+    }
+
+    #[test]
+    fn from_int_with_full_bool_stack_fails() {
+        let state = PushState::builder()
+            .with_max_stack_size(1)
+            .with_no_program()
+            .with_bool_values([true])
+            .unwrap()
+            .with_int_values([0])
+            .unwrap()
+            .build();
+        let error = BoolInstruction::FromInt.perform(state).unwrap_err();
+        assert!(error.is_fatal());
+        assert_eq!(error.state().int, vec![0]);
+    }
+
+    #[test]
+    fn display_names_are_distinct() {
+        let names: Vec<String> = [
+            BoolInstruction::Xor,
+            BoolInstruction::Nand,
+            BoolInstruction::Nor,
+            BoolInstruction::Implies,
+            BoolInstruction::FromInt,
+        ]
+        .into_iter()
+        .map(|instr| PushInstruction::from(instr).to_string())
+        .collect();
+        assert_eq!(
+            names,
+            [
+                "Bool-Xor",
+                "Bool-Nand",
+                "Bool-Nor",
+                "Bool-Implies",
+                "Bool-FromInt"
+            ]
+        );
+    }
 }
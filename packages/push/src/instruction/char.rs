@@ -0,0 +1,94 @@
+use strum_macros::EnumIter;
+
+use super::{Instruction, PushInstruction, PushInstructionError};
+use crate::{
+    error::{InstructionResult, MapInstructionError},
+    push_vm::stack::{HasStack, PushOnto},
+};
+
+#[derive(Debug, strum_macros::Display, Copy, Clone, PartialEq, Eq, Hash, EnumIter)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+#[must_use]
+pub enum CharInstruction {
+    Push(char),
+    /// Pops the top `char` and pushes whether it's alphabetic onto the `bool`
+    /// stack.
+    IsLetter,
+    /// Pops the top `char` and pushes whether it's an ASCII digit onto the
+    /// `bool` stack.
+    IsDigit,
+    /// Pops the top `char` and pushes whether it's whitespace onto the `bool`
+    /// stack.
+    IsWhitespace,
+    /// Replaces the top `char` with its uppercase equivalent. Characters
+    /// without one (like digits), and those whose uppercase form is more than
+    /// one character (like `'ß'`), are left unchanged.
+    ToUppercase,
+    /// Replaces the top `char` with its lowercase equivalent. Characters
+    /// without one, and those whose lowercase form is more than one
+    /// character, are left unchanged.
+    ToLowercase,
+}
+
+impl From<CharInstruction> for PushInstruction {
+    fn from(instr: CharInstruction) -> Self {
+        Self::CharInstruction(instr)
+    }
+}
+
+impl<S> Instruction<S> for CharInstruction
+where
+    S: Clone + HasStack<char> + HasStack<bool>,
+{
+    type Error = PushInstructionError;
+
+    fn perform(&self, state: S) -> InstructionResult<S, Self::Error> {
+        match self {
+            Self::Push(c) => state.with_push(*c).map_err_into(),
+            Self::IsLetter => Self::predicate(state, char::is_alphabetic),
+            Self::IsDigit => Self::predicate(state, |c| c.is_ascii_digit()),
+            Self::IsWhitespace => Self::predicate(state, char::is_whitespace),
+            Self::ToUppercase => Self::convert(state, char::to_uppercase),
+            Self::ToLowercase => Self::convert(state, char::to_lowercase),
+        }
+    }
+}
+
+impl CharInstruction {
+    fn predicate<S>(
+        state: S,
+        op: impl FnOnce(char) -> bool,
+    ) -> InstructionResult<S, PushInstructionError>
+    where
+        S: HasStack<char> + HasStack<bool>,
+    {
+        // This doesn't pop anything off the boolean stack, so we need to make
+        // sure there's room for the result before we pop the `char`.
+        let mut state = state.not_full::<bool>().map_err_into()?;
+        state.stack_mut::<char>().pop().map(op).push_onto(state)
+    }
+
+    fn convert<S, I>(
+        state: S,
+        op: impl FnOnce(char) -> I,
+    ) -> InstructionResult<S, PushInstructionError>
+    where
+        S: HasStack<char>,
+        I: ExactSizeIterator<Item = char>,
+    {
+        state
+            .stack::<char>()
+            .top()
+            .copied()
+            .map(|c| {
+                let mut converted = op(c);
+                if converted.len() == 1 {
+                    converted.next().unwrap_or(c)
+                } else {
+                    c
+                }
+            })
+            .replace_on(1, state)
+    }
+}
@@ -0,0 +1,482 @@
+//! Instructions that work the same way on a stack of any type.
+//!
+//! `Yank`, `YankDup`, and `Shove` move values to or from a depth in a stack,
+//! where the depth is taken from the top of the `i64` stack. The depth wraps
+//! around the size of the stack (after the depth itself has been popped), so
+//! any depth is valid on a non-empty stack, and a negative depth counts up
+//! from the bottom.
+//!
+//! `Dup`, `Pop`, `Swap`, `Flush`, `StackDepth`, and `IsEmpty` are the basic
+//! stack manipulation instructions, and [`CommonInstruction`] collects them
+//! for a single stack type. The `common_instructions` flag of the
+//! [`push_state`](crate::push_state) macro uses that to make an instruction
+//! enum covering every stack in a state:
+//!
+//! ```
+//! use push::{
+//!     instruction::{common::CommonInstruction, Instruction},
+//!     push_state,
+//!     push_vm::{stack::Stack, HasStack},
+//! };
+//!
+//! #[push_state(common_instructions)]
+//! #[derive(Debug, Default)]
+//! struct MyState {
+//!     #[stack]
+//!     int: Stack<i64>,
+//!     #[stack]
+//!     bool: Stack<bool>,
+//! }
+//!
+//! let mut state = MyState::default();
+//! state.stack_mut::<i64>().push(1).unwrap();
+//! state.stack_mut::<i64>().push(2).unwrap();
+//! state.stack_mut::<bool>().push(true).unwrap();
+//!
+//! let state = MyStateCommonInstruction::Int(CommonInstruction::swap())
+//!     .perform(state)
+//!     .unwrap();
+//! assert_eq!(state.stack::<i64>().top2().unwrap(), (&1, &2));
+//! let state = MyStateCommonInstruction::Bool(CommonInstruction::dup())
+//!     .perform(state)
+//!     .unwrap();
+//! assert_eq!(state.stack::<bool>().size(), 2);
+//! let state = MyStateCommonInstruction::Bool(CommonInstruction::stack_depth())
+//!     .perform(state)
+//!     .unwrap();
+//! assert_eq!(state.stack::<i64>().top().unwrap(), &2);
+//! let state = MyStateCommonInstruction::Int(CommonInstruction::flush())
+//!     .perform(state)
+//!     .unwrap();
+//! let state = MyStateCommonInstruction::Int(CommonInstruction::is_empty())
+//!     .perform(state)
+//!     .unwrap();
+//! assert!(state.stack::<i64>().is_empty());
+//! assert_eq!(state.stack::<bool>().top().unwrap(), &true);
+//!
+//! // Six instructions for each of the two stacks.
+//! assert_eq!(MyStateCommonInstruction::all().len(), 12);
+//! ```
+
+use std::marker::PhantomData;
+
+use super::{Instruction, PushInstructionError};
+use crate::{
+    error::{Error, InstructionResult, MapInstructionError},
+    push_vm::{
+        stack::{Stack, StackError, StackPush},
+        HasStack,
+    },
+};
+
+macro_rules! stack_instruction {
+    ($(#[$attr:meta])* $name:ident) => {
+        $(#[$attr])*
+        #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+        pub struct $name<T> {
+            _p: PhantomData<fn() -> T>,
+        }
+
+        impl<T> $name<T> {
+            #[must_use]
+            pub const fn new() -> Self {
+                Self { _p: PhantomData }
+            }
+        }
+
+        // These carry no data, so they're (de)serialized like unit structs
+        // (as with `FoldStack`).
+        #[cfg(feature = "serde")]
+        impl<T> serde::Serialize for $name<T> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_unit_struct(stringify!($name))
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, T> serde::Deserialize<'de> for $name<T> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                <() as serde::Deserialize>::deserialize(deserializer)?;
+                Ok(Self::new())
+            }
+        }
+    };
+}
+
+stack_instruction! {
+    /// Moves the value at the given depth of the `T` stack to the top.
+    ///
+    /// # Inputs
+    ///
+    /// A depth from the `i64` stack, and at least one value on the `T` stack.
+    ///
+    /// # Behavior
+    ///
+    /// Pops the depth, and moves the value that many places below the top of
+    /// the `T` stack to the top. A depth of 0 leaves the `T` stack unchanged.
+    ///
+    /// # Errors
+    ///
+    /// If either stack is empty this returns a recoverable
+    /// [`StackError::Underflow`] error, leaving the state unchanged.
+    Yank
+}
+
+stack_instruction! {
+    /// Pushes a copy of the value at the given depth of the `T` stack.
+    ///
+    /// # Inputs
+    ///
+    /// A depth from the `i64` stack, and at least one value on the `T` stack.
+    ///
+    /// # Behavior
+    ///
+    /// Pops the depth, and pushes a copy of the value that many places below
+    /// the top of the `T` stack. A depth of 0 duplicates the top value.
+    ///
+    /// # Errors
+    ///
+    /// If either stack is empty this returns a recoverable
+    /// [`StackError::Underflow`] error, and if the `T` stack is full this
+    /// returns a fatal [`StackError::Overflow`] error, leaving the state
+    /// unchanged in both cases.
+    YankDup
+}
+
+stack_instruction! {
+    /// Moves the top value of the `T` stack down to the given depth.
+    ///
+    /// # Inputs
+    ///
+    /// A depth from the `i64` stack, and at least one value on the `T` stack.
+    ///
+    /// # Behavior
+    ///
+    /// Pops the depth, and moves the top value of the `T` stack down so that
+    /// there are that many values above it. This undoes a [`Yank`] with the
+    /// same depth.
+    ///
+    /// # Errors
+    ///
+    /// If either stack is empty this returns a recoverable
+    /// [`StackError::Underflow`] error, leaving the state unchanged.
+    Shove
+}
+
+impl<S, T> Instruction<S> for Yank<T>
+where
+    S: HasStack<T> + HasStack<i64>,
+{
+    type Error = PushInstructionError;
+
+    fn perform(&self, state: S) -> InstructionResult<S, Self::Error> {
+        with_depth(state, Stack::<T>::move_to_top)
+    }
+}
+
+impl<S, T> Instruction<S> for YankDup<T>
+where
+    S: HasStack<T> + HasStack<i64>,
+    T: Clone,
+{
+    type Error = PushInstructionError;
+
+    fn perform(&self, state: S) -> InstructionResult<S, Self::Error> {
+        with_depth(state, |stack: &mut Stack<T>, depth| {
+            let value = stack.nth(depth)?.clone();
+            stack.push(value)
+        })
+    }
+}
+
+impl<S, T> Instruction<S> for Shove<T>
+where
+    S: HasStack<T> + HasStack<i64>,
+{
+    type Error = PushInstructionError;
+
+    fn perform(&self, state: S) -> InstructionResult<S, Self::Error> {
+        with_depth(state, Stack::<T>::move_top_down)
+    }
+}
+
+stack_instruction! {
+    /// Pushes a copy of the top value of the `T` stack.
+    ///
+    /// # Errors
+    ///
+    /// If the `T` stack is empty this returns a recoverable
+    /// [`StackError::Underflow`] error, and if it's full this returns a fatal
+    /// [`StackError::Overflow`] error, leaving the state unchanged in both
+    /// cases.
+    Dup
+}
+
+stack_instruction! {
+    /// Removes the top value of the `T` stack.
+    ///
+    /// # Errors
+    ///
+    /// If the `T` stack is empty this returns a recoverable
+    /// [`StackError::Underflow`] error, leaving the state unchanged.
+    Pop
+}
+
+stack_instruction! {
+    /// Swaps the top two values of the `T` stack.
+    ///
+    /// # Errors
+    ///
+    /// If the `T` stack has fewer than two values this returns a recoverable
+    /// [`StackError::Underflow`] error, leaving the state unchanged.
+    Swap
+}
+
+stack_instruction! {
+    /// Removes every value from the `T` stack. This never fails.
+    Flush
+}
+
+stack_instruction! {
+    /// Pushes the number of values on the `T` stack (before this instruction
+    /// is performed) onto the `i64` stack.
+    ///
+    /// # Errors
+    ///
+    /// If the `i64` stack is full this returns a fatal
+    /// [`StackError::Overflow`] error, leaving the state unchanged.
+    StackDepth
+}
+
+stack_instruction! {
+    /// Pushes `true` onto the `bool` stack if the `T` stack is empty, and
+    /// `false` otherwise.
+    ///
+    /// # Errors
+    ///
+    /// If the `bool` stack is full this returns a fatal
+    /// [`StackError::Overflow`] error, leaving the state unchanged.
+    IsEmpty
+}
+
+impl<S, T> Instruction<S> for Dup<T>
+where
+    S: HasStack<T>,
+    T: Clone,
+{
+    type Error = PushInstructionError;
+
+    fn perform(&self, state: S) -> InstructionResult<S, Self::Error> {
+        let state = state.not_full::<T>().map_err_into()?;
+        match state.stack::<T>().top().cloned() {
+            Ok(value) => Ok(state).with_stack_push(value),
+            Err(error) => Err(Error::recoverable(state, error)),
+        }
+    }
+}
+
+impl<S, T> Instruction<S> for Pop<T>
+where
+    S: HasStack<T>,
+{
+    type Error = PushInstructionError;
+
+    fn perform(&self, mut state: S) -> InstructionResult<S, Self::Error> {
+        match state.stack_mut::<T>().discard(1) {
+            Ok(()) => Ok(state),
+            Err(error) => Err(Error::recoverable(state, error)),
+        }
+    }
+}
+
+impl<S, T> Instruction<S> for Swap<T>
+where
+    S: HasStack<T>,
+{
+    type Error = PushInstructionError;
+
+    fn perform(&self, mut state: S) -> InstructionResult<S, Self::Error> {
+        let stack = state.stack_mut::<T>();
+        let result = if stack.size() < 2 {
+            Err(StackError::Underflow {
+                num_requested: 2,
+                num_present: stack.size(),
+            })
+        } else {
+            stack.move_to_top(1)
+        };
+        match result {
+            Ok(()) => Ok(state),
+            Err(error) => Err(Error::recoverable(state, error)),
+        }
+    }
+}
+
+impl<S, T> Instruction<S> for Flush<T>
+where
+    S: HasStack<T>,
+{
+    type Error = PushInstructionError;
+
+    fn perform(&self, mut state: S) -> InstructionResult<S, Self::Error> {
+        state.stack_mut::<T>().clear();
+        Ok(state)
+    }
+}
+
+impl<S, T> Instruction<S> for StackDepth<T>
+where
+    S: HasStack<T> + HasStack<i64>,
+{
+    type Error = PushInstructionError;
+
+    fn perform(&self, state: S) -> InstructionResult<S, Self::Error> {
+        // Stacks can't realistically have more than `i64::MAX` values.
+        let depth = i64::try_from(state.stack::<T>().size()).unwrap_or(i64::MAX);
+        state
+            .not_full::<i64>()
+            .map_err_into()
+            .with_stack_push(depth)
+    }
+}
+
+impl<S, T> Instruction<S> for IsEmpty<T>
+where
+    S: HasStack<T> + HasStack<bool>,
+{
+    type Error = PushInstructionError;
+
+    fn perform(&self, state: S) -> InstructionResult<S, Self::Error> {
+        let is_empty = state.stack::<T>().is_empty();
+        state
+            .not_full::<bool>()
+            .map_err_into()
+            .with_stack_push(is_empty)
+    }
+}
+
+/// The basic stack manipulation instructions for the `T` stack.
+///
+/// This lets a state's instruction set include them all for a stack with a
+/// single enum variant. See the [module documentation](self) for an example.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = "")
+)]
+pub enum CommonInstruction<T> {
+    Dup(Dup<T>),
+    Pop(Pop<T>),
+    Swap(Swap<T>),
+    Flush(Flush<T>),
+    StackDepth(StackDepth<T>),
+    IsEmpty(IsEmpty<T>),
+}
+
+impl<T> CommonInstruction<T> {
+    #[must_use]
+    pub const fn dup() -> Self {
+        Self::Dup(Dup::new())
+    }
+
+    #[must_use]
+    pub const fn pop() -> Self {
+        Self::Pop(Pop::new())
+    }
+
+    #[must_use]
+    pub const fn swap() -> Self {
+        Self::Swap(Swap::new())
+    }
+
+    #[must_use]
+    pub const fn flush() -> Self {
+        Self::Flush(Flush::new())
+    }
+
+    #[must_use]
+    pub const fn stack_depth() -> Self {
+        Self::StackDepth(StackDepth::new())
+    }
+
+    #[must_use]
+    pub const fn is_empty() -> Self {
+        Self::IsEmpty(IsEmpty::new())
+    }
+
+    /// Every common instruction for the `T` stack.
+    #[must_use]
+    pub const fn all() -> [Self; 6] {
+        [
+            Self::dup(),
+            Self::pop(),
+            Self::swap(),
+            Self::flush(),
+            Self::stack_depth(),
+            Self::is_empty(),
+        ]
+    }
+}
+
+impl<S, T> Instruction<S> for CommonInstruction<T>
+where
+    S: HasStack<T> + HasStack<i64> + HasStack<bool>,
+    T: Clone,
+{
+    type Error = PushInstructionError;
+
+    fn perform(&self, state: S) -> InstructionResult<S, Self::Error> {
+        match self {
+            Self::Dup(dup) => dup.perform(state),
+            Self::Pop(pop) => pop.perform(state),
+            Self::Swap(swap) => swap.perform(state),
+            Self::Flush(flush) => flush.perform(state),
+            Self::StackDepth(stack_depth) => stack_depth.perform(state),
+            Self::IsEmpty(is_empty) => is_empty.perform(state),
+        }
+    }
+}
+
+// Pops a depth off the `i64` stack, and applies `op` to the `T` stack with
+// that depth wrapped to the size of the `T` stack. `op` must either succeed
+// or leave the stack unchanged; if it fails, the depth is pushed back so the
+// whole state is unchanged.
+fn with_depth<S, T>(
+    mut state: S,
+    op: impl FnOnce(&mut Stack<T>, usize) -> Result<(), StackError>,
+) -> InstructionResult<S, PushInstructionError>
+where
+    S: HasStack<T> + HasStack<i64>,
+{
+    let depth = match state.stack_mut::<i64>().pop() {
+        Ok(depth) => depth,
+        Err(error) => return Err(Error::recoverable(state, error)),
+    };
+    let stack = state.stack_mut::<T>();
+    match wrap_depth(depth, stack.size()).and_then(|depth| op(stack, depth)) {
+        Ok(()) => Ok(state),
+        Err(error) => {
+            // The depth was just popped, so there's room to push it back.
+            let _ = state.stack_mut::<i64>().push(depth);
+            match error {
+                StackError::Overflow { .. } => Err(Error::fatal(state, error)),
+                StackError::Underflow { .. } => Err(Error::recoverable(state, error)),
+            }
+        }
+    }
+}
+
+// `depth` modulo `size`, or an underflow error if the stack is empty.
+fn wrap_depth(depth: i64, size: usize) -> Result<usize, StackError> {
+    let underflow = StackError::Underflow {
+        num_requested: 1,
+        num_present: 0,
+    };
+    let size = i64::try_from(size)
+        .ok()
+        .filter(|&size| size > 0)
+        .ok_or(underflow)?;
+    // `rem_euclid` is always in `0..size`, so the conversion can't fail.
+    Ok(usize::try_from(depth.rem_euclid(size)).unwrap_or_default())
+}
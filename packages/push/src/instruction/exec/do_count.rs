@@ -0,0 +1,227 @@
+use crate::{
+    error::{Error, InstructionResult},
+    instruction::{
+        instruction_error::PushInstructionError, ExecInstruction, Instruction, NumOpens,
+        PushInstruction,
+    },
+    push_vm::{
+        program::PushProgram,
+        stack::{StackDiscard, StackError, StackPush},
+        HasStack,
+    },
+};
+
+/// A bounded loop that performs the top block of the `Exec` stack a given
+/// number of times.
+///
+/// # Inputs
+///
+/// The `DoCount` instruction takes the following inputs:
+///    - `Int` stack
+///      - One integer, the number of times to perform the block
+///    - `Exec` stack
+///      - One code block, the body of the loop
+///
+/// # Behavior
+///
+/// The `DoCount` instruction pops the count `n` and performs the code block
+/// on the `Exec` stack `n` times. If `n` is zero or negative, the block is
+/// discarded and not performed at all. It has an implied "open" to create
+/// a block that runs from this instruction to the first `Close` in a Plushy
+/// genome.
+///
+/// Rather than copying the block `n` times, this leaves the block on top of
+/// the `Exec` stack with a block below it that pushes `n - 1` and then
+/// performs `DoCount` again. So each iteration takes steps when the program
+/// is run, and a huge count will hit the step limit (if there is one)
+/// instead of filling the `Exec` stack. Unlike `Exec::DoRange`, the loop
+/// index isn't pushed onto the `Int` stack.
+///
+/// ## Action Table
+///
+/// | Int stack  | Code block | Exec stack full | Success | Note |
+/// | ------------- | ------------- | ------------- | ------------- | ------------- |
+/// | `n > 1`  | exists | false | ✅ | The block is performed, followed by `n - 1` more iterations |
+/// | `n == 1` | exists | irrelevant | ✅ | The block is performed once |
+/// | `n < 1`  | exists | irrelevant | ✅ | The block is discarded |
+/// | `n > 1`  | exists | true | [‼️..](StackError::Overflow) | Program is terminated |
+/// | missing | irrelevant | irrelevant | [❗…](StackError::Underflow) | State is unchanged |
+/// | exists | missing | irrelevant | [❗…](StackError::Underflow) | State is unchanged |
+///
+/// # Errors
+///
+/// If either of the stacks is empty this returns a recoverable
+/// [`StackError::Underflow`] error, and if there isn't room on the `Exec`
+/// stack for the rest of the loop this returns a fatal
+/// [`StackError::Overflow`] error.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DoCount;
+
+impl NumOpens for DoCount {
+    fn num_opens(&self) -> usize {
+        1
+    }
+}
+
+impl<S> Instruction<S> for DoCount
+where
+    S: Clone + HasStack<PushProgram> + HasStack<i64>,
+{
+    type Error = PushInstructionError;
+
+    fn perform(&self, state: S) -> InstructionResult<S, Self::Error> {
+        let (count, body) = match (
+            state.stack::<i64>().top(),
+            state.stack::<PushProgram>().top(),
+        ) {
+            (Ok(&count), Ok(body)) => (count, body),
+            (Err(error), _) | (_, Err(error)) => return Err(Error::recoverable(state, error)),
+        };
+        match count {
+            // Performing the block zero times means discarding it.
+            ..=0 => Ok(state)
+                .with_stack_discard::<i64>(1)
+                .with_stack_discard::<PushProgram>(1),
+            // Leave the block to be performed (once) next.
+            1 => Ok(state).with_stack_discard::<i64>(1),
+            _ => {
+                // We replace the block with the rest of the loop and the
+                // block, so we need room for one more item.
+                if state.stack::<PushProgram>().is_full() {
+                    return Err(Error::fatal(
+                        state,
+                        StackError::Overflow { stack_type: "exec" },
+                    ));
+                }
+                let body = body.clone();
+                let rest_of_loop = PushProgram::Block(vec![
+                    PushInstruction::push_int(count.saturating_sub(1)).into(),
+                    ExecInstruction::do_count().into(),
+                    body.clone(),
+                ]);
+                Ok(state)
+                    .with_stack_discard::<i64>(1)
+                    .with_stack_discard::<PushProgram>(1)
+                    .with_stack_push(rest_of_loop)
+                    .with_stack_push(body)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The test states fit their stacks, so building them can't fail"
+)]
+mod tests {
+    use super::DoCount;
+    use crate::{
+        instruction::{
+            ExecInstruction, Instruction, IntInstruction, PushInstruction, PushInstructionError,
+        },
+        push_vm::{program::PushProgram, push_state::PushState, stack::StackError, State},
+    };
+
+    fn run_do_count(count: i64) -> PushState {
+        PushState::builder()
+            .with_max_stack_size(10)
+            .with_program([
+                PushProgram::from(ExecInstruction::do_count()),
+                PushProgram::Block(vec![IntInstruction::Inc.into()]),
+            ])
+            .unwrap()
+            .with_int_values([count, 10])
+            .unwrap()
+            .build()
+            .run_to_completion()
+            .unwrap()
+    }
+
+    #[test]
+    fn performs_block_count_times() {
+        let state = run_do_count(3);
+        assert_eq!(state.int, vec![13]);
+        assert!(state.exec.is_empty());
+    }
+
+    #[test]
+    fn count_of_one() {
+        assert_eq!(run_do_count(1).int, vec![11]);
+    }
+
+    #[test]
+    fn zero_or_negative_count_skips_block() {
+        assert_eq!(run_do_count(0).int, vec![10]);
+        assert_eq!(run_do_count(-5).int, vec![10]);
+        assert_eq!(run_do_count(i64::MIN).int, vec![10]);
+    }
+
+    #[test]
+    fn huge_count_hits_step_limit() {
+        let result = PushState::builder()
+            .with_max_stack_size(10)
+            .with_program([
+                PushProgram::from(ExecInstruction::do_count()),
+                PushProgram::Block(vec![ExecInstruction::noop().into()]),
+            ])
+            .unwrap()
+            .with_int_values([i64::MAX])
+            .unwrap()
+            .build()
+            .with_step_limit(1_000)
+            .run_to_completion()
+            .unwrap_err();
+        assert_eq!(
+            result.error(),
+            &PushInstructionError::StepLimitExceeded { step_limit: 1_000 }
+        );
+    }
+
+    #[test]
+    fn missing_count() {
+        let state = PushState::builder()
+            .with_max_stack_size(1)
+            .with_program([PushInstruction::push_int(1)])
+            .unwrap()
+            .build();
+        let result_error = DoCount.perform(state.clone()).unwrap_err();
+        assert!(result_error.is_recoverable());
+        assert_eq!(result_error.state(), &state);
+        assert!(matches!(
+            result_error.error(),
+            PushInstructionError::StackError(StackError::Underflow { .. })
+        ));
+    }
+
+    #[test]
+    fn missing_block() {
+        let state = PushState::builder()
+            .with_max_stack_size(1)
+            .with_no_program()
+            .with_int_values([3])
+            .unwrap()
+            .build();
+        let result_error = DoCount.perform(state.clone()).unwrap_err();
+        assert!(result_error.is_recoverable());
+        assert_eq!(result_error.state(), &state);
+    }
+
+    #[test]
+    fn exec_full() {
+        let state = PushState::builder()
+            .with_max_stack_size(1)
+            .with_program([ExecInstruction::noop()])
+            .unwrap()
+            .with_int_values([3])
+            .unwrap()
+            .build();
+        let result_error = DoCount.perform(state).unwrap_err();
+        assert!(result_error.is_fatal());
+        assert!(matches!(
+            result_error.error(),
+            PushInstructionError::StackError(StackError::Overflow { .. })
+        ));
+    }
+}
@@ -0,0 +1,190 @@
+use crate::{
+    error::{Error, InstructionResult},
+    instruction::{
+        instruction_error::PushInstructionError, ExecInstruction, Instruction, NumOpens,
+        PushInstruction,
+    },
+    push_vm::{
+        program::PushProgram,
+        stack::{PushOnto, StackDiscard, StackError, StackPush},
+        HasStack,
+    },
+};
+
+/// A counted loop that performs the top block of the `Exec` stack once for
+/// each index in a range, pushing the index onto the `Int` stack before
+/// each iteration (like Clojush's `exec_do*range`).
+///
+/// # Inputs
+///
+/// The `DoRange` instruction takes the following inputs:
+///    - `Int` stack
+///      - Two integers, the destination index (top) and the current index
+///    - `Exec` stack
+///      - One code block, the body of the loop
+///
+/// # Behavior
+///
+/// The `DoRange` instruction pops the destination and current indices and
+/// pushes the current index onto the `Int` stack. If the current index is
+/// the destination, the block is left to be performed one last time.
+/// Otherwise the block is performed followed by the rest of the loop, which
+/// is a block that pushes the next index (one closer to the destination)
+/// and the destination, and then performs `DoRange` again. So the block is
+/// performed once for each index from the current index to the destination
+/// (inclusive), counting up or down as needed. It has an implied "open" to
+/// create a block that runs from this instruction to the first `Close` in a
+/// Plushy genome.
+///
+/// As with `Exec::DoCount`, each iteration takes steps when the program is
+/// run, so a huge range will hit the step limit (if there is one).
+///
+/// ## Action Table
+///
+/// | Int stack  | Code block | Exec stack full | Success | Note |
+/// | ------------- | ------------- | ------------- | ------------- | ------------- |
+/// | current == destination | exists | irrelevant | ✅ | The block is performed once more |
+/// | current != destination | exists | false | ✅ | The block is performed, followed by the rest of the loop |
+/// | current != destination | exists | true | [‼️..](StackError::Overflow) | Program is terminated |
+/// | fewer than two | irrelevant | irrelevant | [❗…](StackError::Underflow) | State is unchanged |
+/// | exists | missing | irrelevant | [❗…](StackError::Underflow) | State is unchanged |
+///
+/// # Errors
+///
+/// If there are fewer than two integers or no code block this returns a
+/// recoverable [`StackError::Underflow`] error, and if there isn't room on
+/// the `Exec` stack for the rest of the loop this returns a fatal
+/// [`StackError::Overflow`] error.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DoRange;
+
+impl NumOpens for DoRange {
+    fn num_opens(&self) -> usize {
+        1
+    }
+}
+
+impl<S> Instruction<S> for DoRange
+where
+    S: Clone + HasStack<PushProgram> + HasStack<i64>,
+{
+    type Error = PushInstructionError;
+
+    fn perform(&self, state: S) -> InstructionResult<S, Self::Error> {
+        let ((destination, current), body) = match (
+            state.stack::<i64>().top2(),
+            state.stack::<PushProgram>().top(),
+        ) {
+            (Ok((&destination, &current)), Ok(body)) => ((destination, current), body),
+            (Err(error), _) | (_, Err(error)) => return Err(Error::recoverable(state, error)),
+        };
+        if current == destination {
+            // Leave the block to be performed one last time.
+            return Ok::<_, PushInstructionError>(current).replace_on(2, state);
+        }
+        // We replace the block with the rest of the loop and the block, so
+        // we need room for one more item.
+        if state.stack::<PushProgram>().is_full() {
+            return Err(Error::fatal(
+                state,
+                StackError::Overflow { stack_type: "exec" },
+            ));
+        }
+        let next = if current < destination {
+            current.saturating_add(1)
+        } else {
+            current.saturating_sub(1)
+        };
+        let body = body.clone();
+        let rest_of_loop = PushProgram::Block(vec![
+            PushInstruction::push_int(next).into(),
+            PushInstruction::push_int(destination).into(),
+            ExecInstruction::do_range().into(),
+            body.clone(),
+        ]);
+        Ok::<_, PushInstructionError>(current)
+            .replace_on(2, state)
+            .with_stack_discard::<PushProgram>(1)
+            .with_stack_push(rest_of_loop)
+            .with_stack_push(body)
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The test states fit their stacks, so building them can't fail"
+)]
+mod tests {
+    use super::DoRange;
+    use crate::{
+        instruction::{ExecInstruction, Instruction, PushInstructionError},
+        push_vm::{program::PushProgram, push_state::PushState, stack::StackError, State},
+    };
+
+    // Runs `DoRange` with an empty body from `current` to `destination`,
+    // returning the `Int` stack (bottom first).
+    fn run_do_range(current: i64, destination: i64) -> Vec<i64> {
+        let state = PushState::builder()
+            .with_max_stack_size(10)
+            .with_program([
+                PushProgram::from(ExecInstruction::do_range()),
+                PushProgram::Block(vec![]),
+            ])
+            .unwrap()
+            .with_int_values([destination, current])
+            .unwrap()
+            .build()
+            .run_to_completion()
+            .unwrap();
+        assert!(state.exec.is_empty());
+        state.int.iter().rev().copied().collect()
+    }
+
+    #[test]
+    fn counts_up() {
+        assert_eq!(run_do_range(0, 3), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn counts_down() {
+        assert_eq!(run_do_range(2, -1), vec![2, 1, 0, -1]);
+    }
+
+    #[test]
+    fn single_index() {
+        assert_eq!(run_do_range(5, 5), vec![5]);
+    }
+
+    #[test]
+    fn too_few_ints() {
+        let state = PushState::builder()
+            .with_max_stack_size(1)
+            .with_program([ExecInstruction::noop()])
+            .unwrap()
+            .with_int_values([3])
+            .unwrap()
+            .build();
+        let result_error = DoRange.perform(state.clone()).unwrap_err();
+        assert!(result_error.is_recoverable());
+        assert_eq!(result_error.state(), &state);
+        assert!(matches!(
+            result_error.error(),
+            PushInstructionError::StackError(StackError::Underflow { .. })
+        ));
+    }
+
+    #[test]
+    fn exec_full() {
+        let state = PushState::builder()
+            .with_max_stack_size(2)
+            .with_program([ExecInstruction::noop(), ExecInstruction::noop()])
+            .unwrap()
+            .with_int_values([3, 0])
+            .unwrap()
+            .build();
+        let result_error = DoRange.perform(state).unwrap_err();
+        assert!(result_error.is_fatal());
+    }
+}
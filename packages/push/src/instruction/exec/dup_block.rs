@@ -56,7 +56,8 @@ use crate::{
 /// [`StackError::Underflow`](crate::push_vm::stack::StackError::Underflow)
 /// then this returns that as a [`Error::Fatal`](crate::error::Error::Fatal)
 /// error.
-#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DupBlock;
 
 impl NumOpens for DupBlock {
@@ -82,7 +83,10 @@ where
 }
 
 #[cfg(test)]
-#[allow(clippy::unwrap_used)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The test states fit their stacks, so building them can't fail"
+)]
 mod tests {
     use super::DupBlock;
     use crate::{
@@ -70,7 +70,8 @@ use crate::{
 /// If either of the stack accesses returns any error other than a
 /// [`StackError::Underflow`] then this returns that as a [`Error::Fatal`]
 /// error.
-#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IfElse;
 
 impl NumOpens for IfElse {
@@ -150,7 +151,10 @@ where
 }
 
 #[cfg(test)]
-#[allow(clippy::unwrap_used)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The test states fit their stacks, so building them can't fail"
+)]
 mod tests {
     use super::IfElse;
     use crate::{
@@ -0,0 +1,106 @@
+use crate::{
+    error::{Error, InstructionResult},
+    instruction::{instruction_error::PushInstructionError, Instruction, NumOpens},
+    push_vm::{
+        program::PushProgram,
+        stack::{StackDiscard, StackPush},
+        HasStack,
+    },
+};
+
+/// The K combinator from combinatory logic, which keeps the top item of the
+/// `Exec` stack and discards the one below it.
+///
+/// # Inputs
+///
+/// The `Exec::K` instruction takes the following inputs:
+///    - `Exec` stack
+///      - Two code blocks
+///
+/// # Behavior
+///
+/// The `Exec::K` instruction removes the second item on the `Exec` stack,
+/// leaving the top item to be performed next. It has two implied "opens",
+/// one for each of its inputs, in a Plushy genome.
+///
+/// ## Action Table
+///
+/// | Exec stack  | Success | Note |
+/// | ------------- | ------------- | ------------- |
+/// | at least two items | ✅ | The second item is discarded |
+/// | fewer than two items | [❗…](crate::push_vm::stack::StackError::Underflow) | State is unchanged |
+///
+/// # Errors
+///
+/// If the `Exec` stack has fewer than two items this returns a recoverable
+/// [`StackError::Underflow`](crate::push_vm::stack::StackError::Underflow)
+/// error.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KCombinator;
+
+impl NumOpens for KCombinator {
+    fn num_opens(&self) -> usize {
+        2
+    }
+}
+
+impl<S> Instruction<S> for KCombinator
+where
+    S: Clone + HasStack<PushProgram>,
+{
+    type Error = PushInstructionError;
+
+    fn perform(&self, state: S) -> InstructionResult<S, Self::Error> {
+        let top = match state.stack::<PushProgram>().top2() {
+            Ok((top, _)) => top.clone(),
+            Err(error) => return Err(Error::recoverable(state, error)),
+        };
+        // Discarding two items makes room to push the top one back.
+        Ok(state)
+            .with_stack_discard::<PushProgram>(2)
+            .with_stack_push(top)
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The test states fit their stacks, so building them can't fail"
+)]
+mod tests {
+    use super::KCombinator;
+    use crate::{
+        instruction::{ExecInstruction, Instruction, IntInstruction, PushInstruction},
+        push_vm::{program::PushProgram, push_state::PushState, State},
+    };
+
+    #[test]
+    fn discards_second_item() {
+        let state = PushState::builder()
+            .with_max_stack_size(10)
+            .with_program([
+                PushProgram::from(ExecInstruction::k()),
+                PushInstruction::push_int(1).into(),
+                PushInstruction::push_int(2).into(),
+                IntInstruction::Inc.into(),
+            ])
+            .unwrap()
+            .build()
+            .run_to_completion()
+            .unwrap();
+        assert_eq!(state.int, vec![2]);
+    }
+
+    #[test]
+    fn single_item_is_unchanged() {
+        let state = PushState::builder()
+            .with_max_stack_size(10)
+            .with_program([ExecInstruction::noop()])
+            .unwrap()
+            .build();
+        let result_error = KCombinator.perform(state.clone()).unwrap_err();
+        assert!(result_error.is_recoverable());
+        assert_eq!(result_error.state(), &state);
+    }
+}
@@ -1,19 +1,28 @@
+mod do_count;
+mod do_range;
 mod dup_block;
 mod ifelse;
+mod k;
 mod noop;
+mod s;
 mod unless;
 mod when;
+mod y;
 
 use strum_macros::EnumIter;
 
-use self::{dup_block::DupBlock, ifelse::IfElse, noop::Noop, unless::Unless, when::When};
+use self::{
+    do_count::DoCount, do_range::DoRange, dup_block::DupBlock, ifelse::IfElse, k::KCombinator,
+    noop::Noop, s::SCombinator, unless::Unless, when::When, y::YCombinator,
+};
 use super::{instruction_error::PushInstructionError, Instruction, NumOpens, PushInstruction};
 use crate::{
     error::InstructionResult,
     push_vm::{program::PushProgram, HasStack},
 };
 
-#[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, EnumIter)]
+#[derive(Debug, strum_macros::Display, Copy, Clone, Eq, PartialEq, Hash, EnumIter)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[must_use]
 pub enum ExecInstruction {
     Noop(Noop),
@@ -21,6 +30,11 @@ pub enum ExecInstruction {
     When(When),
     Unless(Unless),
     IfElse(IfElse),
+    DoCount(DoCount),
+    DoRange(DoRange),
+    K(KCombinator),
+    S(SCombinator),
+    Y(YCombinator),
 }
 
 impl ExecInstruction {
@@ -43,6 +57,26 @@ impl ExecInstruction {
     pub const fn if_else() -> Self {
         Self::IfElse(IfElse)
     }
+
+    pub const fn do_count() -> Self {
+        Self::DoCount(DoCount)
+    }
+
+    pub const fn do_range() -> Self {
+        Self::DoRange(DoRange)
+    }
+
+    pub const fn k() -> Self {
+        Self::K(KCombinator)
+    }
+
+    pub const fn s() -> Self {
+        Self::S(SCombinator)
+    }
+
+    pub const fn y() -> Self {
+        Self::Y(YCombinator)
+    }
 }
 impl From<ExecInstruction> for PushInstruction {
     fn from(instr: ExecInstruction) -> Self {
@@ -58,13 +92,18 @@ impl NumOpens for ExecInstruction {
             Self::When(when) => when.num_opens(),
             Self::Unless(unless) => unless.num_opens(),
             Self::IfElse(if_else) => if_else.num_opens(),
+            Self::DoCount(do_count) => do_count.num_opens(),
+            Self::DoRange(do_range) => do_range.num_opens(),
+            Self::K(k) => k.num_opens(),
+            Self::S(s) => s.num_opens(),
+            Self::Y(y) => y.num_opens(),
         }
     }
 }
 
 impl<S> Instruction<S> for ExecInstruction
 where
-    S: Clone + HasStack<PushProgram> + HasStack<bool>,
+    S: Clone + HasStack<PushProgram> + HasStack<bool> + HasStack<i64>,
 {
     type Error = PushInstructionError;
 
@@ -75,6 +114,11 @@ where
             Self::Unless(unless) => unless.perform(state),
             Self::IfElse(if_else) => if_else.perform(state),
             Self::DupBlock(dup) => dup.perform(state),
+            Self::DoCount(do_count) => do_count.perform(state),
+            Self::DoRange(do_range) => do_range.perform(state),
+            Self::K(k) => k.perform(state),
+            Self::S(s) => s.perform(state),
+            Self::Y(y) => y.perform(state),
         }
     }
 }
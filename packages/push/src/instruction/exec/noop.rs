@@ -10,7 +10,8 @@ use crate::instruction::{instruction_error::PushInstructionError, Instruction, N
 /// # Behavior
 ///
 /// This always succeeds and makes no changes to any of the stacks.
-#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Noop;
 
 impl NumOpens for Noop {
@@ -28,7 +29,10 @@ impl<S> Instruction<S> for Noop {
 }
 
 #[cfg(test)]
-#[allow(clippy::unwrap_used)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The test state is empty, so building it can't fail"
+)]
 mod tests {
     use super::Noop;
     use crate::{
@@ -0,0 +1,116 @@
+use crate::{
+    error::{Error, InstructionResult},
+    instruction::{instruction_error::PushInstructionError, Instruction, NumOpens},
+    push_vm::{
+        program::PushProgram,
+        stack::{StackDiscard, StackPush},
+        HasStack,
+    },
+};
+
+/// The S combinator from combinatory logic, which distributes the third item
+/// of the `Exec` stack to the first two.
+///
+/// # Inputs
+///
+/// The `Exec::S` instruction takes the following inputs:
+///    - `Exec` stack
+///      - Three code blocks
+///
+/// # Behavior
+///
+/// The `Exec::S` instruction pops three items `a`, `b`, and `c` (with `a` on
+/// top) from the `Exec` stack, and then pushes the block `(b c)`, then `c`,
+/// and then `a`. So `a` is performed next, followed by `c` and then `b` and
+/// `c` again. It has three implied "opens", one for each of its inputs, in a
+/// Plushy genome.
+///
+/// This leaves the same number of items on the `Exec` stack, so it can't
+/// overflow, but `c` is copied, so the program on the `Exec` stack grows.
+///
+/// ## Action Table
+///
+/// | Exec stack  | Success | Note |
+/// | ------------- | ------------- | ------------- |
+/// | at least three items | ✅ | `a`, `c`, and `(b c)` replace `a`, `b`, and `c` |
+/// | fewer than three items | [❗…](crate::push_vm::stack::StackError::Underflow) | State is unchanged |
+///
+/// # Errors
+///
+/// If the `Exec` stack has fewer than three items this returns a recoverable
+/// [`StackError::Underflow`](crate::push_vm::stack::StackError::Underflow)
+/// error.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SCombinator;
+
+impl NumOpens for SCombinator {
+    fn num_opens(&self) -> usize {
+        3
+    }
+}
+
+impl<S> Instruction<S> for SCombinator
+where
+    S: Clone + HasStack<PushProgram>,
+{
+    type Error = PushInstructionError;
+
+    fn perform(&self, state: S) -> InstructionResult<S, Self::Error> {
+        let exec = state.stack::<PushProgram>();
+        let (a, b, c) = match (exec.nth(0), exec.nth(1), exec.nth(2)) {
+            (Ok(a), Ok(b), Ok(c)) => (a.clone(), b.clone(), c.clone()),
+            (Err(error), _, _) | (_, Err(error), _) | (_, _, Err(error)) => {
+                return Err(Error::recoverable(state, error))
+            }
+        };
+        Ok(state)
+            .with_stack_discard::<PushProgram>(3)
+            .with_stack_push(PushProgram::Block(vec![b, c.clone()]))
+            .with_stack_push(c)
+            .with_stack_push(a)
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The test states fit their stacks, so building them can't fail"
+)]
+mod tests {
+    use super::SCombinator;
+    use crate::{
+        instruction::{ExecInstruction, Instruction, IntInstruction, PushInstruction},
+        push_vm::{program::PushProgram, push_state::PushState},
+    };
+
+    #[test]
+    fn distributes_third_item() {
+        let a = PushProgram::from(PushInstruction::push_int(1));
+        let b = PushProgram::from(IntInstruction::Inc);
+        let c = PushProgram::from(IntInstruction::Dec);
+        let state = PushState::builder()
+            .with_max_stack_size(10)
+            .with_program([a.clone(), b.clone(), c.clone()])
+            .unwrap()
+            .build();
+        let result_state = SCombinator.perform(state).unwrap();
+        assert_eq!(
+            result_state.exec,
+            // The bottom of the stack is first.
+            vec![PushProgram::Block(vec![b, c.clone()]), c, a]
+        );
+    }
+
+    #[test]
+    fn two_items_is_unchanged() {
+        let state = PushState::builder()
+            .with_max_stack_size(10)
+            .with_program([ExecInstruction::noop(), ExecInstruction::noop()])
+            .unwrap()
+            .build();
+        let result_error = SCombinator.perform(state.clone()).unwrap_err();
+        assert!(result_error.is_recoverable());
+        assert_eq!(result_error.state(), &state);
+    }
+}
@@ -0,0 +1,174 @@
+use crate::{
+    error::{Error, InstructionResult},
+    instruction::{
+        instruction_error::PushInstructionError, ExecInstruction, Instruction, NumOpens,
+    },
+    push_vm::{
+        program::PushProgram,
+        stack::{StackDiscard, StackError, StackPush},
+        HasStack,
+    },
+};
+
+/// The Y (fixed-point) combinator from combinatory logic, which performs the
+/// top block of the `Exec` stack over and over.
+///
+/// # Inputs
+///
+/// The `Exec::Y` instruction takes the following inputs:
+///    - `Exec` stack
+///      - One code block
+///
+/// # Behavior
+///
+/// The `Exec::Y` instruction pops the top item `a` from the `Exec` stack, and
+/// then pushes the block `(Exec::Y a)` followed by `a`. So `a` is performed
+/// next, followed by `Exec::Y` on `a` again, and so on. It has an implied
+/// "open" to create a block that runs from this instruction to the first
+/// `Close` in a Plushy genome.
+///
+/// The recursion only stops if `a` removes the `(Exec::Y a)` block below it,
+/// e.g., by ending with an `Exec::When` whose condition is false. Otherwise
+/// each repetition takes steps when the program is run, so it will hit the
+/// step limit (if there is one).
+///
+/// ## Action Table
+///
+/// | Code block | Exec stack full | Success | Note |
+/// | ------------- | ------------- | ------------- | ------------- |
+/// | exists | false | ✅ | The block is performed, followed by `Exec::Y` on it again |
+/// | exists | true | [‼️..](StackError::Overflow) | Program is terminated |
+/// | missing | irrelevant | [❗…](StackError::Underflow) | State is unchanged |
+///
+/// # Errors
+///
+/// If the `Exec` stack is empty this returns a recoverable
+/// [`StackError::Underflow`] error, and if there isn't room on the `Exec`
+/// stack for the recursive call this returns a fatal
+/// [`StackError::Overflow`] error.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct YCombinator;
+
+impl NumOpens for YCombinator {
+    fn num_opens(&self) -> usize {
+        1
+    }
+}
+
+impl<S> Instruction<S> for YCombinator
+where
+    S: Clone + HasStack<PushProgram>,
+{
+    type Error = PushInstructionError;
+
+    fn perform(&self, state: S) -> InstructionResult<S, Self::Error> {
+        let body = match state.stack::<PushProgram>().top() {
+            Ok(body) => body.clone(),
+            Err(error) => return Err(Error::recoverable(state, error)),
+        };
+        // We replace the block with the recursive call and the block, so we
+        // need room for one more item.
+        if state.stack::<PushProgram>().is_full() {
+            return Err(Error::fatal(
+                state,
+                StackError::Overflow { stack_type: "exec" },
+            ));
+        }
+        let recursive_call = PushProgram::Block(vec![ExecInstruction::y().into(), body.clone()]);
+        Ok(state)
+            .with_stack_discard::<PushProgram>(1)
+            .with_stack_push(recursive_call)
+            .with_stack_push(body)
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The test states fit their stacks, so building them can't fail"
+)]
+mod tests {
+    use super::YCombinator;
+    use crate::{
+        instruction::{
+            ExecInstruction, Instruction, IntInstruction, PushInstruction, PushInstructionError,
+        },
+        push_vm::{program::PushProgram, push_state::PushState, stack::StackError, State},
+    };
+
+    #[test]
+    fn recursion_stops_when_the_block_discards_the_call() {
+        // Decrement the top integer until it's no longer positive, using a
+        // final `When` to discard the recursive call.
+        let body = PushProgram::Block(vec![
+            IntInstruction::Dec.into(),
+            PushInstruction::push_int(0).into(),
+            IntInstruction::yank_dup().into(),
+            IntInstruction::IsPositive.into(),
+            ExecInstruction::when().into(),
+        ]);
+        let state = PushState::builder()
+            .with_max_stack_size(10)
+            .with_program([PushProgram::from(ExecInstruction::y()), body])
+            .unwrap()
+            .with_int_values([3])
+            .unwrap()
+            .build()
+            .with_step_limit(1_000)
+            .run_to_completion()
+            .unwrap();
+        assert_eq!(state.int, vec![0]);
+        assert!(state.bool.is_empty());
+        assert!(state.exec.is_empty());
+    }
+
+    #[test]
+    fn unbounded_recursion_hits_step_limit() {
+        let result = PushState::builder()
+            .with_max_stack_size(10)
+            .with_program([
+                PushProgram::from(ExecInstruction::y()),
+                IntInstruction::Inc.into(),
+            ])
+            .unwrap()
+            .with_int_values([0])
+            .unwrap()
+            .build()
+            .with_step_limit(1_000)
+            .run_to_completion()
+            .unwrap_err();
+        assert_eq!(
+            result.error(),
+            &PushInstructionError::StepLimitExceeded { step_limit: 1_000 }
+        );
+        // The recursion doesn't grow the `Exec` stack.
+        assert!(result.state().exec.size() <= 2);
+    }
+
+    #[test]
+    fn empty_exec_is_unchanged() {
+        let state = PushState::builder()
+            .with_max_stack_size(10)
+            .with_no_program()
+            .build();
+        let result_error = YCombinator.perform(state.clone()).unwrap_err();
+        assert!(result_error.is_recoverable());
+        assert_eq!(result_error.state(), &state);
+        assert!(matches!(
+            result_error.error(),
+            PushInstructionError::StackError(StackError::Underflow { .. })
+        ));
+    }
+
+    #[test]
+    fn full_exec_is_fatal() {
+        let state = PushState::builder()
+            .with_max_stack_size(1)
+            .with_program([ExecInstruction::noop()])
+            .unwrap()
+            .build();
+        let result_error = YCombinator.perform(state).unwrap_err();
+        assert!(result_error.is_fatal());
+    }
+}
@@ -1,3 +1,4 @@
+use num_traits::ToPrimitive;
 use ordered_float::OrderedFloat;
 use strum_macros::EnumIter;
 
@@ -10,7 +11,8 @@ use crate::{
     },
 };
 
-#[derive(Debug, strum_macros::Display, Copy, Clone, EnumIter, Eq, PartialEq)]
+#[derive(Debug, strum_macros::Display, Copy, Clone, EnumIter, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum FloatInstruction {
     Push(OrderedFloat<f64>),
@@ -18,6 +20,20 @@ pub enum FloatInstruction {
     Subtract,
     Multiply,
     ProtectedDivide,
+    /// Replaces the top two values `x` (the top) and `y` with `x` raised to
+    /// the power `y`.
+    Power,
+    Sqrt,
+    /// The natural logarithm.
+    Log,
+    /// The sine of the top value (in radians).
+    Sin,
+    /// The cosine of the top value (in radians).
+    Cos,
+    /// The tangent of the top value (in radians).
+    Tan,
+    /// `e` raised to the power of the top value.
+    Exp,
     Equal,
     NotEqual,
     GreaterThan,
@@ -25,6 +41,10 @@ pub enum FloatInstruction {
     GreaterThanOrEqual,
     LessThanOrEqual,
     Dup,
+    /// Pops the top value off the int stack and pushes it onto the float
+    /// stack. Integers too large to be represented exactly are rounded to
+    /// the nearest float.
+    FromInt,
 }
 
 impl From<FloatInstruction> for PushInstruction {
@@ -35,7 +55,7 @@ impl From<FloatInstruction> for PushInstruction {
 
 impl<S> Instruction<S> for FloatInstruction
 where
-    S: Clone + HasStack<OrderedFloat<f64>> + HasStack<bool>,
+    S: Clone + HasStack<OrderedFloat<f64>> + HasStack<bool> + HasStack<i64>,
 {
     type Error = PushInstructionError;
 
@@ -46,13 +66,27 @@ where
             // All these instructions pop at least one value from the float stack, so we're
             // guaranteed that there will be space for the result. So we don't have to check that
             // any stacks are full before we start.
-            Self::Add => Self::binary_arithmetic(state, std::ops::Add::add),
-            Self::Subtract => Self::binary_arithmetic(state, std::ops::Sub::sub),
-            Self::Multiply => Self::binary_arithmetic(state, std::ops::Mul::mul),
-            Self::ProtectedDivide => Self::binary_arithmetic(state, |x, y| {
-                #[allow(clippy::arithmetic_side_effects)]
+            //
+            // Any of these can produce a non-finite value (e.g., adding two very large numbers
+            // overflows to infinity), which returns a recoverable `NonFinite` error instead of
+            // pushing that value.
+            Self::Add => self.binary_arithmetic(state, std::ops::Add::add),
+            Self::Subtract => self.binary_arithmetic(state, std::ops::Sub::sub),
+            Self::Multiply => self.binary_arithmetic(state, std::ops::Mul::mul),
+            Self::ProtectedDivide => self.binary_arithmetic(state, |x, y| {
+                #[expect(
+                    clippy::arithmetic_side_effects,
+                    reason = "Float division can't overflow, and dividing by zero is handled"
+                )]
                 if y == 0.0 { OrderedFloat(1.0) } else { x / y }
             }),
+            Self::Power => self.binary_arithmetic(state, |x, y| OrderedFloat(x.powf(*y))),
+            Self::Sqrt => self.unary_arithmetic(state, |x| OrderedFloat(x.sqrt())),
+            Self::Log => self.unary_arithmetic(state, |x| OrderedFloat(x.ln())),
+            Self::Sin => self.unary_arithmetic(state, |x| OrderedFloat(x.sin())),
+            Self::Cos => self.unary_arithmetic(state, |x| OrderedFloat(x.cos())),
+            Self::Tan => self.unary_arithmetic(state, |x| OrderedFloat(x.tan())),
+            Self::Exp => self.unary_arithmetic(state, |x| OrderedFloat(x.exp())),
 
             // None of these instructions pop anything off the boolean stack, but
             // they will push a result onto that stack. Thus before we start performing
@@ -82,12 +116,71 @@ where
                     .cloned()
                     .push_onto(state)
             }
+
+            Self::FromInt => {
+                // This doesn't pop anything off the float stack, so we need to
+                // check that there's room for the result before we start.
+                if state.stack::<OrderedFloat<f64>>().is_full() {
+                    return Err(Error::fatal(
+                        state,
+                        StackError::Overflow {
+                            stack_type: "float",
+                        },
+                    ));
+                }
+                state
+                    .stack::<i64>()
+                    .top()
+                    .map_err(PushInstructionError::from)
+                    // Every `i64` can be converted to an `f64`, possibly with
+                    // rounding, so this never uses the default.
+                    .map(|&x| OrderedFloat(x.to_f64().unwrap_or_default()))
+                    .push_onto(state)
+                    .with_stack_discard::<i64>(1)
+            }
         }
     }
 }
 
 impl FloatInstruction {
+    /// Create a `Push` instruction for the given `f64` constant.
+    ///
+    /// NaN constants are rejected: this returns `None` if `f` is NaN.
+    /// `OrderedFloat` treats NaN as equal to itself and greater than every
+    /// other value, so a NaN constant would quietly distort every comparison
+    /// (and ultimately selection) that it's involved in. Infinite values are
+    /// accepted since they're ordered as expected.
+    #[must_use]
+    pub fn push_f64(f: f64) -> Option<Self> {
+        (!f.is_nan()).then_some(Self::Push(OrderedFloat(f)))
+    }
+
+    fn finite(self, value: OrderedFloat<f64>) -> Result<OrderedFloat<f64>, PushInstructionError> {
+        if value.is_finite() {
+            Ok(value)
+        } else {
+            Err(FloatInstructionError::NonFinite { op: self }.into())
+        }
+    }
+
+    fn unary_arithmetic<S>(
+        self,
+        mut state: S,
+        op: impl FnOnce(OrderedFloat<f64>) -> OrderedFloat<f64>,
+    ) -> Result<S, Error<S, PushInstructionError>>
+    where
+        S: Clone + HasStack<OrderedFloat<f64>>,
+    {
+        let float_stack = state.stack_mut::<OrderedFloat<f64>>();
+        float_stack
+            .top()
+            .map_err(PushInstructionError::from)
+            .and_then(|&x| self.finite(op(x)))
+            .replace_on(1, state)
+    }
+
     fn binary_arithmetic<S>(
+        self,
         mut state: S,
         op: impl FnOnce(OrderedFloat<f64>, OrderedFloat<f64>) -> OrderedFloat<f64>,
     ) -> Result<S, Error<S, PushInstructionError>>
@@ -98,7 +191,7 @@ impl FloatInstruction {
         float_stack
             .top2()
             .map_err(PushInstructionError::from)
-            .map(|(&x, &y)| op(x, y))
+            .and_then(|(&x, &y)| self.finite(op(x, y)))
             .replace_on(2, state)
     }
 
@@ -124,3 +217,10 @@ impl FloatInstruction {
             .with_stack_discard::<OrderedFloat<f64>>(1)
     }
 }
+
+#[derive(thiserror::Error, Debug, Eq, PartialEq)]
+pub enum FloatInstructionError {
+    /// The instruction would have produced a `NaN` or infinite value.
+    #[error("Non-finite float result for instruction {op}")]
+    NonFinite { op: FloatInstruction },
+}
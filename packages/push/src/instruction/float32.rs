@@ -0,0 +1,210 @@
+use num_traits::ToPrimitive;
+use ordered_float::OrderedFloat;
+use strum_macros::EnumIter;
+
+use super::{Instruction, PushInstruction, PushInstructionError};
+use crate::{
+    error::{Error, InstructionResult, MapInstructionError},
+    push_vm::{
+        stack::{PushOnto, Stack, StackDiscard, StackError},
+        HasStack,
+    },
+};
+
+/// Instructions on the `f32` float stack. These mirror the `f64`
+/// [`FloatInstruction`](super::FloatInstruction)s, for problems where
+/// single-precision floats are enough (e.g., to save memory).
+#[derive(Debug, strum_macros::Display, Copy, Clone, EnumIter, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Float32Instruction {
+    Push(OrderedFloat<f32>),
+    Add,
+    Subtract,
+    Multiply,
+    ProtectedDivide,
+    /// Replaces the top two values `x` (the top) and `y` with `x` raised to
+    /// the power `y`.
+    Power,
+    Sqrt,
+    /// The natural logarithm.
+    Log,
+    Equal,
+    NotEqual,
+    GreaterThan,
+    LessThan,
+    GreaterThanOrEqual,
+    LessThanOrEqual,
+    Dup,
+    /// Pops the top value off the int stack and pushes it onto the `f32`
+    /// stack, rounded to the nearest `f32`. Every `i64` is within the range
+    /// of `f32`, so this never produces an infinite value.
+    FromInt,
+}
+
+impl From<Float32Instruction> for PushInstruction {
+    fn from(instr: Float32Instruction) -> Self {
+        Self::Float32Instruction(instr)
+    }
+}
+
+impl<S> Instruction<S> for Float32Instruction
+where
+    S: Clone + HasStack<OrderedFloat<f32>> + HasStack<bool> + HasStack<i64>,
+{
+    type Error = PushInstructionError;
+
+    fn perform(&self, mut state: S) -> InstructionResult<S, Self::Error> {
+        match self {
+            Self::Push(f) => state.with_push(*f).map_err_into(),
+
+            // As with `FloatInstruction`, these all pop at least one value, so
+            // there's always room for the result, and non-finite results
+            // return a recoverable `NonFinite` error.
+            Self::Add => self.binary_arithmetic(state, std::ops::Add::add),
+            Self::Subtract => self.binary_arithmetic(state, std::ops::Sub::sub),
+            Self::Multiply => self.binary_arithmetic(state, std::ops::Mul::mul),
+            Self::ProtectedDivide => self.binary_arithmetic(state, |x, y| {
+                #[expect(
+                    clippy::arithmetic_side_effects,
+                    reason = "Float division can't overflow, and dividing by zero is handled"
+                )]
+                if y == 0.0 {
+                    OrderedFloat(1.0)
+                } else {
+                    x / y
+                }
+            }),
+            Self::Power => self.binary_arithmetic(state, |x, y| OrderedFloat(x.powf(*y))),
+            Self::Sqrt => self.unary_arithmetic(state, |x| OrderedFloat(x.sqrt())),
+            Self::Log => self.unary_arithmetic(state, |x| OrderedFloat(x.ln())),
+
+            Self::Equal => Self::binary_predicate(state, std::cmp::PartialEq::eq),
+            Self::NotEqual => Self::binary_predicate(state, std::cmp::PartialEq::ne),
+            Self::GreaterThan => Self::binary_predicate(state, std::cmp::PartialOrd::gt),
+            Self::LessThan => Self::binary_predicate(state, std::cmp::PartialOrd::lt),
+            Self::GreaterThanOrEqual => Self::binary_predicate(state, std::cmp::PartialOrd::ge),
+            Self::LessThanOrEqual => Self::binary_predicate(state, std::cmp::PartialOrd::le),
+
+            Self::Dup => {
+                if state.stack::<OrderedFloat<f32>>().is_full() {
+                    return Err(Error::fatal(
+                        state,
+                        StackError::Overflow {
+                            stack_type: "float32",
+                        },
+                    ));
+                }
+                let float_stack: &mut Stack<OrderedFloat<f32>> =
+                    state.stack_mut::<OrderedFloat<f32>>();
+                float_stack
+                    .top()
+                    .map_err(PushInstructionError::from)
+                    .cloned()
+                    .push_onto(state)
+            }
+
+            Self::FromInt => {
+                // This doesn't pop anything off the `f32` stack, so we need to
+                // check that there's room for the result before we start.
+                if state.stack::<OrderedFloat<f32>>().is_full() {
+                    return Err(Error::fatal(
+                        state,
+                        StackError::Overflow {
+                            stack_type: "float32",
+                        },
+                    ));
+                }
+                state
+                    .stack::<i64>()
+                    .top()
+                    .map_err(PushInstructionError::from)
+                    // Every `i64` can be converted to an `f32`, possibly with
+                    // rounding, so this never uses the default.
+                    .map(|&x| OrderedFloat(x.to_f32().unwrap_or_default()))
+                    .push_onto(state)
+                    .with_stack_discard::<i64>(1)
+            }
+        }
+    }
+}
+
+impl Float32Instruction {
+    /// Create a `Push` instruction for the given `f32` constant.
+    ///
+    /// As with [`FloatInstruction::push_f64`](super::FloatInstruction::push_f64),
+    /// NaN constants are rejected: this returns `None` if `f` is NaN.
+    #[must_use]
+    pub fn push_f32(f: f32) -> Option<Self> {
+        (!f.is_nan()).then_some(Self::Push(OrderedFloat(f)))
+    }
+
+    fn finite(self, value: OrderedFloat<f32>) -> Result<OrderedFloat<f32>, PushInstructionError> {
+        if value.is_finite() {
+            Ok(value)
+        } else {
+            Err(Float32InstructionError::NonFinite { op: self }.into())
+        }
+    }
+
+    fn unary_arithmetic<S>(
+        self,
+        mut state: S,
+        op: impl FnOnce(OrderedFloat<f32>) -> OrderedFloat<f32>,
+    ) -> Result<S, Error<S, PushInstructionError>>
+    where
+        S: Clone + HasStack<OrderedFloat<f32>>,
+    {
+        let float_stack = state.stack_mut::<OrderedFloat<f32>>();
+        float_stack
+            .top()
+            .map_err(PushInstructionError::from)
+            .and_then(|&x| self.finite(op(x)))
+            .replace_on(1, state)
+    }
+
+    fn binary_arithmetic<S>(
+        self,
+        mut state: S,
+        op: impl FnOnce(OrderedFloat<f32>, OrderedFloat<f32>) -> OrderedFloat<f32>,
+    ) -> Result<S, Error<S, PushInstructionError>>
+    where
+        S: Clone + HasStack<OrderedFloat<f32>>,
+    {
+        let float_stack = state.stack_mut::<OrderedFloat<f32>>();
+        float_stack
+            .top2()
+            .map_err(PushInstructionError::from)
+            .and_then(|(&x, &y)| self.finite(op(x, y)))
+            .replace_on(2, state)
+    }
+
+    fn binary_predicate<S>(
+        mut state: S,
+        op: impl FnOnce(&OrderedFloat<f32>, &OrderedFloat<f32>) -> bool,
+    ) -> Result<S, Error<S, PushInstructionError>>
+    where
+        S: Clone + HasStack<OrderedFloat<f32>> + HasStack<bool>,
+    {
+        if state.stack::<bool>().is_full() {
+            return Err(Error::fatal(
+                state,
+                StackError::Overflow { stack_type: "bool" },
+            ));
+        }
+        let float_stack: &mut Stack<OrderedFloat<f32>> = state.stack_mut::<OrderedFloat<f32>>();
+        float_stack
+            .top2()
+            .map_err(PushInstructionError::from)
+            .map(|(x, y)| op(x, y))
+            .push_onto(state)
+            .with_stack_discard::<OrderedFloat<f32>>(1)
+    }
+}
+
+#[derive(thiserror::Error, Debug, Eq, PartialEq)]
+pub enum Float32InstructionError {
+    /// The instruction would have produced a `NaN` or infinite value.
+    #[error("Non-finite f32 result for instruction {op}")]
+    NonFinite { op: Float32Instruction },
+}
@@ -0,0 +1,124 @@
+use std::marker::PhantomData;
+
+use super::{Instruction, PushInstructionError};
+use crate::{
+    error::InstructionResult,
+    push_vm::{
+        stack::{PushOnto, StackError},
+        HasStack,
+    },
+};
+
+/// A binary operation used by [`FoldStack`] to combine all the values on a
+/// stack into a single value.
+pub trait FoldOperation<T> {
+    /// The result of folding an empty stack, or `None` if folding an empty
+    /// stack should fail (e.g., there's no sensible maximum of no values).
+    fn empty() -> Option<T>;
+
+    /// Combine two values from the stack.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the values can't be combined, e.g., if adding
+    /// them overflows.
+    fn combine(x: T, y: T) -> Result<T, PushInstructionError>;
+}
+
+/// Folds an entire stack of `T` values into a single value using `Op`.
+///
+/// # Inputs
+///
+/// All the values on the `T` stack.
+///
+/// # Behavior
+///
+/// The `FoldStack` instruction combines all the values on the `T` stack
+/// using `Op`, clears the stack, and pushes the single result.
+///
+/// If the stack is empty, the result is [`FoldOperation::empty`] if that
+/// exists (e.g., 0 for a sum). Otherwise (e.g., for a maximum) this returns
+/// a recoverable [`StackError::Underflow`] error, leaving the state unchanged.
+///
+/// # Errors
+///
+/// If combining any of the values fails (e.g., integer overflow) this returns
+/// that as a recoverable error, leaving the state unchanged.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct FoldStack<T, Op> {
+    _p: PhantomData<fn() -> (T, Op)>,
+}
+
+impl<T, Op> FoldStack<T, Op> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { _p: PhantomData }
+    }
+}
+
+// `FoldStack` carries no data, so it's (de)serialized like a unit struct
+// (e.g., `null` in JSON) instead of exposing the `PhantomData` field.
+#[cfg(feature = "serde")]
+impl<T, Op> serde::Serialize for FoldStack<T, Op> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_unit_struct("FoldStack")
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, Op> serde::Deserialize<'de> for FoldStack<T, Op> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <() as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(Self::new())
+    }
+}
+
+impl<S, T, Op> Instruction<S> for FoldStack<T, Op>
+where
+    S: Clone + HasStack<T>,
+    T: Clone,
+    Op: FoldOperation<T>,
+{
+    type Error = PushInstructionError;
+
+    fn perform(&self, state: S) -> InstructionResult<S, Self::Error> {
+        let stack = state.stack::<T>();
+        let num_values = stack.size();
+        // The result has to be computed before `replace_on` takes `state`,
+        // since the iterator over the stack borrows from it.
+        let result = {
+            let mut values = stack.iter_top(num_values).cloned();
+            values
+                .next()
+                .or_else(Op::empty)
+                .ok_or_else(|| {
+                    PushInstructionError::from(StackError::Underflow {
+                        num_requested: 1,
+                        num_present: 0,
+                    })
+                })
+                .and_then(|first| values.try_fold(first, Op::combine))
+        };
+        result.replace_on(num_values, state)
+    }
+}
+
+/// Sum all the values on a stack.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sum;
+
+/// Multiply all the values on a stack.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Product;
+
+/// Find the smallest value on a stack.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Min;
+
+/// Find the largest value on a stack.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Max;
@@ -1,4 +1,4 @@
-use super::IntInstructionError;
+use super::{Float32InstructionError, FloatInstructionError, IntInstructionError, PushInstruction};
 use crate::push_vm::stack::StackError;
 
 /// An error that can occur when performing a `PushInstruction`.
@@ -9,7 +9,55 @@ pub enum PushInstructionError {
     StackError(#[from] StackError),
     #[error("Exceeded the maximum step limit {step_limit}")]
     StepLimitExceeded { step_limit: usize },
+    /// An instruction tried to print to an output channel that the state
+    /// doesn't have.
+    #[error("There is no output channel named {channel:?}")]
+    UnknownOutputChannel { channel: String },
+    /// An instruction tried to print more to an output channel than the
+    /// state's maximum output size allows.
+    #[error(
+        "Printing to output channel {channel:?} would exceed the maximum output size {max_size}"
+    )]
+    OutputOverflow { channel: String, max_size: usize },
     /// Int errors can be things like integer overflows.
     #[error(transparent)]
     Int(#[from] IntInstructionError),
+    /// Float errors are non-finite results like `NaN` or infinity.
+    #[error(transparent)]
+    Float(#[from] FloatInstructionError),
+    /// Non-finite results from the `f32` float instructions.
+    #[error(transparent)]
+    Float32(#[from] Float32InstructionError),
+    /// Adds the instruction that was being performed to an error, which
+    /// makes it easier to see what went wrong when running a program. This
+    /// wraps every error returned by `PushInstruction::perform()`.
+    ///
+    /// Both fields are boxed to keep this error (and thus every
+    /// `InstructionResult`) small.
+    #[error("Instruction {instruction} failed: {source}")]
+    InstructionFailed {
+        instruction: Box<PushInstruction>,
+        source: Box<Self>,
+    },
+}
+
+impl PushInstructionError {
+    /// The error without any [`InstructionFailed`](Self::InstructionFailed)
+    /// context, i.e., the error that the instruction itself returned.
+    #[must_use]
+    pub fn root_cause(&self) -> &Self {
+        match self {
+            Self::InstructionFailed { source, .. } => source.root_cause(),
+            _ => self,
+        }
+    }
+    /// Like [`PushInstructionError::root_cause`], but takes ownership of the
+    /// error.
+    #[must_use]
+    pub fn into_root_cause(self) -> Self {
+        match self {
+            Self::InstructionFailed { source, .. } => source.into_root_cause(),
+            _ => self,
+        }
+    }
 }
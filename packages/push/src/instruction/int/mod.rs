@@ -1,15 +1,23 @@
+mod from_float;
 mod negate;
+mod random;
 
 use strum_macros::EnumIter;
 
 use self::negate::Negate;
-use super::{Instruction, PushInstruction, PushInstructionError};
+pub use self::{from_float::IntFromFloatInstruction, random::IntRandomInstruction};
+use super::{
+    common::{Shove, Yank, YankDup},
+    fold_stack::{FoldOperation, FoldStack, Max, Min, Product, Sum},
+    Instruction, PushInstruction, PushInstructionError,
+};
 use crate::{
     error::{Error, InstructionResult, MapInstructionError},
     push_vm::stack::{HasStack, PushOnto, Stack, StackDiscard, StackError},
 };
 
-#[derive(Debug, strum_macros::Display, Copy, Clone, PartialEq, Eq, EnumIter)]
+#[derive(Debug, strum_macros::Display, Copy, Clone, PartialEq, Eq, Hash, EnumIter)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 #[must_use]
 pub enum IntInstruction {
@@ -28,10 +36,45 @@ pub enum IntInstruction {
     Mod,
     Power,
     Square,
-    // We can't easily convert from i64 to f64, etc.,
-    // so we might need to do the log(n) integer
-    // implementation of sqrt.
-    // Sqrt,
+    /// Like `Add`, but saturates at `i64::MIN` or `i64::MAX` instead of
+    /// failing on overflow.
+    AddSaturating,
+    /// Like `Subtract`, but saturates at `i64::MIN` or `i64::MAX` instead of
+    /// failing on overflow.
+    SubtractSaturating,
+    /// Like `Multiply`, but saturates at `i64::MIN` or `i64::MAX` instead of
+    /// failing on overflow.
+    MultiplySaturating,
+    /// Like `Square`, but saturates at `i64::MAX` instead of failing on
+    /// overflow.
+    SquareSaturating,
+    /// Like `Add`, but wraps around (two's complement) instead of failing on
+    /// overflow.
+    AddWrapping,
+    /// Like `Subtract`, but wraps around (two's complement) instead of
+    /// failing on overflow.
+    SubtractWrapping,
+    /// Like `Multiply`, but wraps around (two's complement) instead of
+    /// failing on overflow.
+    MultiplyWrapping,
+    /// Like `Square`, but wraps around (two's complement) instead of failing
+    /// on overflow.
+    SquareWrapping,
+    /// Replaces the top value with the integer square root (rounded down) of
+    /// its absolute value, so this never fails.
+    Sqrt,
+    /// Bitwise and of the top two values.
+    And,
+    /// Bitwise or of the top two values.
+    Or,
+    /// Bitwise exclusive or of the top two values.
+    Xor,
+    /// Shifts the top value left by the second value (modulo 64) bits. Bits
+    /// shifted past the most significant bit are lost, so this never fails.
+    ShiftLeft,
+    /// Shifts the top value right by the second value (modulo 64) bits. This
+    /// is an arithmetic shift, so the sign is preserved.
+    ShiftRight,
     IsZero,
     IsPositive,
     IsNegative,
@@ -45,12 +88,165 @@ pub enum IntInstruction {
     GreaterThanEqual,
 
     FromBoolean,
+
+    /// Replaces all the values on the `i64` stack with their sum, which is
+    /// 0 if the stack is empty.
+    SumAll(FoldStack<i64, Sum>),
+    /// Replaces all the values on the `i64` stack with their product, which
+    /// is 1 if the stack is empty.
+    ProductAll(FoldStack<i64, Product>),
+    /// Replaces all the values on the `i64` stack with the smallest of them.
+    /// If the stack is empty, this returns a recoverable
+    /// [`StackError::Underflow`] error, leaving the state unchanged.
+    MinAll(FoldStack<i64, Min>),
+    /// Replaces all the values on the `i64` stack with the largest of them.
+    /// If the stack is empty, this returns a recoverable
+    /// [`StackError::Underflow`] error, leaving the state unchanged.
+    MaxAll(FoldStack<i64, Max>),
+
+    /// Moves the value at a depth (taken from the top of the `i64` stack)
+    /// to the top of the `i64` stack.
+    Yank(Yank<i64>),
+    /// Pushes a copy of the value at a depth (taken from the top of the
+    /// `i64` stack) onto the `i64` stack.
+    YankDup(YankDup<i64>),
+    /// Moves the top of the `i64` stack down to a depth (taken from the top
+    /// of the `i64` stack).
+    Shove(Shove<i64>),
+}
+
+/// How an [`IntInstruction`] handles arithmetic overflow.
+///
+/// `Add`, `Subtract`, `Multiply`, and `Square` come in all three modes
+/// (e.g., `Add`, `AddSaturating`, and `AddWrapping`), so an instruction set
+/// can use just one mode by filtering on [`IntInstruction::arithmetic_mode`]:
+///
+/// ```
+/// # use push::instruction::{ArithmeticMode, IntInstruction};
+/// # use strum::IntoEnumIterator;
+/// let instructions: Vec<IntInstruction> = IntInstruction::iter()
+///     .filter(|instruction| {
+///         instruction
+///             .arithmetic_mode()
+///             .is_none_or(|mode| mode == ArithmeticMode::Wrapping)
+///     })
+///     .collect();
+/// assert!(instructions.contains(&IntInstruction::AddWrapping));
+/// assert!(!instructions.contains(&IntInstruction::Add));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ArithmeticMode {
+    /// Fail with [`IntInstructionError::Overflow`] on overflow.
+    Checked,
+    /// Saturate at `i64::MIN` or `i64::MAX` on overflow.
+    Saturating,
+    /// Wrap around (two's complement) on overflow.
+    Wrapping,
 }
 
 impl IntInstruction {
+    /// The overflow mode of this instruction if it's one of the arithmetic
+    /// instructions that come in all three [`ArithmeticMode`]s, or `None`
+    /// otherwise.
+    #[must_use]
+    pub const fn arithmetic_mode(&self) -> Option<ArithmeticMode> {
+        match self {
+            Self::Add | Self::Subtract | Self::Multiply | Self::Square => {
+                Some(ArithmeticMode::Checked)
+            }
+            Self::AddSaturating
+            | Self::SubtractSaturating
+            | Self::MultiplySaturating
+            | Self::SquareSaturating => Some(ArithmeticMode::Saturating),
+            Self::AddWrapping
+            | Self::SubtractWrapping
+            | Self::MultiplyWrapping
+            | Self::SquareWrapping => Some(ArithmeticMode::Wrapping),
+            _ => None,
+        }
+    }
+
     pub const fn negate() -> Self {
         Self::Negate(Negate)
     }
+
+    pub const fn sum_all() -> Self {
+        Self::SumAll(FoldStack::new())
+    }
+
+    pub const fn product_all() -> Self {
+        Self::ProductAll(FoldStack::new())
+    }
+
+    pub const fn min_all() -> Self {
+        Self::MinAll(FoldStack::new())
+    }
+
+    pub const fn max_all() -> Self {
+        Self::MaxAll(FoldStack::new())
+    }
+
+    pub const fn yank() -> Self {
+        Self::Yank(Yank::new())
+    }
+
+    pub const fn yank_dup() -> Self {
+        Self::YankDup(YankDup::new())
+    }
+
+    pub const fn shove() -> Self {
+        Self::Shove(Shove::new())
+    }
+}
+
+impl FoldOperation<i64> for Sum {
+    fn empty() -> Option<i64> {
+        Some(0)
+    }
+
+    fn combine(x: i64, y: i64) -> Result<i64, PushInstructionError> {
+        x.checked_add(y).ok_or_else(|| {
+            IntInstructionError::Overflow {
+                op: IntInstruction::sum_all(),
+            }
+            .into()
+        })
+    }
+}
+
+impl FoldOperation<i64> for Product {
+    fn empty() -> Option<i64> {
+        Some(1)
+    }
+
+    fn combine(x: i64, y: i64) -> Result<i64, PushInstructionError> {
+        x.checked_mul(y).ok_or_else(|| {
+            IntInstructionError::Overflow {
+                op: IntInstruction::product_all(),
+            }
+            .into()
+        })
+    }
+}
+
+impl FoldOperation<i64> for Min {
+    fn empty() -> Option<i64> {
+        None
+    }
+
+    fn combine(x: i64, y: i64) -> Result<i64, PushInstructionError> {
+        Ok(x.min(y))
+    }
+}
+
+impl FoldOperation<i64> for Max {
+    fn empty() -> Option<i64> {
+        None
+    }
+
+    fn combine(x: i64, y: i64) -> Result<i64, PushInstructionError> {
+        Ok(x.max(y))
+    }
 }
 
 impl From<IntInstruction> for PushInstruction {
@@ -77,16 +273,39 @@ where
 {
     type Error = PushInstructionError;
 
-    #[allow(clippy::too_many_lines, clippy::cognitive_complexity)]
-    #[allow(unreachable_code, clippy::let_unit_value)] // Remove this
+    #[expect(
+        clippy::too_many_lines,
+        reason = "`perform` has one short arm per instruction"
+    )]
     fn perform(&self, mut state: S) -> InstructionResult<S, Self::Error> {
         match self {
             Self::Negate(negate) => negate.perform(state),
+            Self::SumAll(fold) => fold.perform(state),
+            Self::ProductAll(fold) => fold.perform(state),
+            Self::MinAll(fold) => fold.perform(state),
+            Self::MaxAll(fold) => fold.perform(state),
+            Self::Yank(yank) => yank.perform(state),
+            Self::YankDup(yank_dup) => yank_dup.perform(state),
+            Self::Shove(shove) => shove.perform(state),
             Self::Push(_)
             | Self::Abs
             | Self::Inc
             | Self::Dec
             | Self::Square
+            | Self::AddSaturating
+            | Self::SubtractSaturating
+            | Self::MultiplySaturating
+            | Self::SquareSaturating
+            | Self::AddWrapping
+            | Self::SubtractWrapping
+            | Self::MultiplyWrapping
+            | Self::SquareWrapping
+            | Self::Sqrt
+            | Self::And
+            | Self::Or
+            | Self::Xor
+            | Self::ShiftLeft
+            | Self::ShiftRight
             | Self::Add
             | Self::Subtract
             | Self::Multiply
@@ -137,6 +356,56 @@ where
                         })
                         .replace_on(1, state),
 
+                    Self::SquareSaturating => int_stack
+                        .top()
+                        .map_err(PushInstructionError::from)
+                        .map(|&x| x.saturating_mul(x))
+                        .replace_on(1, state),
+
+                    Self::SquareWrapping => int_stack
+                        .top()
+                        .map_err(PushInstructionError::from)
+                        .map(|&x| x.wrapping_mul(x))
+                        .replace_on(1, state),
+
+                    // The square root of `i64::MIN.unsigned_abs()` is less than
+                    // `i64::MAX`, so converting back can't fail.
+                    Self::Sqrt => int_stack
+                        .top()
+                        .map_err(PushInstructionError::from)
+                        .map(|&x| i64::try_from(x.unsigned_abs().isqrt()).unwrap_or(i64::MAX))
+                        .replace_on(1, state),
+
+                    Self::And => int_stack
+                        .top2()
+                        .map_err(PushInstructionError::from)
+                        .map(|(&x, &y)| x & y)
+                        .replace_on(2, state),
+
+                    Self::Or => int_stack
+                        .top2()
+                        .map_err(PushInstructionError::from)
+                        .map(|(&x, &y)| x | y)
+                        .replace_on(2, state),
+
+                    Self::Xor => int_stack
+                        .top2()
+                        .map_err(PushInstructionError::from)
+                        .map(|(&x, &y)| x ^ y)
+                        .replace_on(2, state),
+
+                    Self::ShiftLeft => int_stack
+                        .top2()
+                        .map_err(PushInstructionError::from)
+                        .map(|(&x, &y)| x.wrapping_shl(shift_amount(y)))
+                        .replace_on(2, state),
+
+                    Self::ShiftRight => int_stack
+                        .top2()
+                        .map_err(PushInstructionError::from)
+                        .map(|(&x, &y)| x.wrapping_shr(shift_amount(y)))
+                        .replace_on(2, state),
+
                     Self::Add => int_stack
                         .top2()
                         .map_err(PushInstructionError::from)
@@ -167,6 +436,42 @@ where
                         })
                         .replace_on(2, state),
 
+                    Self::AddSaturating => int_stack
+                        .top2()
+                        .map_err(PushInstructionError::from)
+                        .map(|(&x, &y)| x.saturating_add(y))
+                        .replace_on(2, state),
+
+                    Self::SubtractSaturating => int_stack
+                        .top2()
+                        .map_err(PushInstructionError::from)
+                        .map(|(&x, &y)| x.saturating_sub(y))
+                        .replace_on(2, state),
+
+                    Self::MultiplySaturating => int_stack
+                        .top2()
+                        .map_err(PushInstructionError::from)
+                        .map(|(&x, &y)| x.saturating_mul(y))
+                        .replace_on(2, state),
+
+                    Self::AddWrapping => int_stack
+                        .top2()
+                        .map_err(PushInstructionError::from)
+                        .map(|(&x, &y)| x.wrapping_add(y))
+                        .replace_on(2, state),
+
+                    Self::SubtractWrapping => int_stack
+                        .top2()
+                        .map_err(PushInstructionError::from)
+                        .map(|(&x, &y)| x.wrapping_sub(y))
+                        .replace_on(2, state),
+
+                    Self::MultiplyWrapping => int_stack
+                        .top2()
+                        .map_err(PushInstructionError::from)
+                        .map(|(&x, &y)| x.wrapping_mul(y))
+                        .replace_on(2, state),
+
                     Self::ProtectedDivide => int_stack
                         .top2()
                         .map_err(PushInstructionError::from)
@@ -192,7 +497,7 @@ where
                         .map_err(PushInstructionError::from)
                         .map(|(&x, &y)| {
                             let y: u32 = y.try_into().ok()?;
-                            x.checked_pow(y).map(i64::from)
+                            x.checked_pow(y)
                         })
                         .and_then(|v| {
                             v.ok_or(IntInstructionError::Overflow { op: *self })
@@ -331,3 +636,10 @@ where
         }
     }
 }
+
+/// The number of bits to shift by for `ShiftLeft` and `ShiftRight`, which is
+/// `amount` modulo 64 (the number of bits in an `i64`).
+fn shift_amount(amount: i64) -> u32 {
+    // `rem_euclid` is always in `0..64`, so the conversion can't fail.
+    u32::try_from(amount.rem_euclid(i64::from(i64::BITS))).unwrap_or_default()
+}
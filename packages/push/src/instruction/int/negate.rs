@@ -68,11 +68,12 @@ use crate::{
 /// error when the `i64` stack is empty.
 ///
 /// # Differences
-#[allow(clippy::doc_markdown)]
+#[expect(clippy::doc_markdown, reason = "PyshGP is a project name, not code")]
 /// Implementations of integer negation instructions in Clojure (e.g., Clojush
 /// or Propeller) or Python (e.g., PyshGP) won't have the wrapping issue because
 /// they act on arbitrary precision integers.
-#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Negate;
 
 impl<S> Instruction<S> for Negate
@@ -91,7 +92,10 @@ where
 }
 
 #[cfg(test)]
-#[allow(clippy::unwrap_used)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The test states fit their stacks, so building them can't fail"
+)]
 mod tests {
 
     use proptest::prop_assert_eq;
@@ -2,19 +2,35 @@ use ordered_float::OrderedFloat;
 
 pub use self::{
     bool::BoolInstruction,
+    char::CharInstruction,
     exec::ExecInstruction,
-    float::FloatInstruction,
-    int::{IntInstruction, IntInstructionError},
+    float::{FloatInstruction, FloatInstructionError},
+    float32::{Float32Instruction, Float32InstructionError},
+    int::{
+        ArithmeticMode, IntFromFloatInstruction, IntInstruction, IntInstructionError,
+        IntRandomInstruction,
+    },
+    output::OutputInstruction,
+    string::StringInstruction,
+    vector_int::VectorIntInstruction,
 };
 use self::{instruction_error::PushInstructionError, variable_name::VariableName};
 use crate::{error::InstructionResult, push_vm::push_state::PushState};
 
 mod bool;
+mod char;
+pub mod common;
 mod exec;
 mod float;
+mod float32;
+pub mod fold_stack;
 pub mod instruction_error;
 mod int;
+mod output;
+pub mod print_to;
+mod string;
 pub mod variable_name;
+mod vector_int;
 
 /*
  * exec_if requires a boolean and two (additional) values on the exec stack.
@@ -55,14 +71,22 @@ impl<S, E> Instruction<S> for Box<dyn Instruction<S, Error = E>> {
     }
 }
 
-#[derive(Clone, Eq, PartialEq, Debug)]
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum PushInstruction {
     InputVar(VariableName),
     Exec(ExecInstruction),
     BoolInstruction(BoolInstruction),
     IntInstruction(IntInstruction),
+    IntFromFloatInstruction(IntFromFloatInstruction),
+    IntRandomInstruction(IntRandomInstruction),
     FloatInstruction(FloatInstruction),
+    Float32Instruction(Float32Instruction),
+    StringInstruction(StringInstruction),
+    CharInstruction(CharInstruction),
+    VectorIntInstruction(VectorIntInstruction),
+    OutputInstruction(OutputInstruction),
 }
 
 impl PushInstruction {
@@ -80,23 +104,58 @@ impl PushInstruction {
     pub fn push_float(f: OrderedFloat<f64>) -> Self {
         FloatInstruction::Push(f).into()
     }
+
+    #[must_use]
+    pub fn push_float32(f: OrderedFloat<f32>) -> Self {
+        Float32Instruction::Push(f).into()
+    }
+
+    #[must_use]
+    pub fn push_string(s: String) -> Self {
+        StringInstruction::Push(s).into()
+    }
+
+    #[must_use]
+    pub fn push_char(c: char) -> Self {
+        CharInstruction::Push(c).into()
+    }
+
+    #[must_use]
+    pub fn push_int_vector(v: Vec<i64>) -> Self {
+        VectorIntInstruction::Push(v).into()
+    }
 }
 
 impl Instruction<PushState> for PushInstruction {
     type Error = PushInstructionError;
 
     fn perform(&self, state: PushState) -> InstructionResult<PushState, Self::Error> {
-        match self {
+        let result = match self {
+            // The input's instruction is itself a `PushInstruction`, so its
+            // `perform()` has already added the instruction to any error.
             Self::InputVar(var_name) => {
                 // TODO: Should `push_input` return the new state?
                 // Or add a `with_input` that returns the new state and keep `push_input`?
-                state.with_input(var_name)
+                return state.with_input(var_name);
             }
             Self::Exec(i) => i.perform(state),
             Self::BoolInstruction(i) => i.perform(state),
             Self::IntInstruction(i) => i.perform(state),
+            Self::IntFromFloatInstruction(i) => i.perform(state),
+            Self::IntRandomInstruction(i) => i.perform(state),
             Self::FloatInstruction(i) => i.perform(state),
-        }
+            Self::Float32Instruction(i) => i.perform(state),
+            Self::StringInstruction(i) => i.perform(state),
+            Self::CharInstruction(i) => i.perform(state),
+            Self::VectorIntInstruction(i) => i.perform(state),
+            Self::OutputInstruction(i) => i.perform(state),
+        };
+        result.map_err(|error| {
+            error.map_inner_err(|source| PushInstructionError::InstructionFailed {
+                instruction: Box::new(self.clone()),
+                source: Box::new(source),
+            })
+        })
     }
 }
 
@@ -122,7 +181,14 @@ impl std::fmt::Display for PushInstruction {
             Self::Exec(instruction) => write!(f, "Exec-{instruction}"),
             Self::BoolInstruction(instruction) => write!(f, "Bool-{instruction}"),
             Self::IntInstruction(instruction) => write!(f, "Int-{instruction}"),
+            Self::IntFromFloatInstruction(instruction) => write!(f, "Int-{instruction}"),
+            Self::IntRandomInstruction(instruction) => write!(f, "Int-{instruction}"),
             Self::FloatInstruction(instruction) => write!(f, "Float-{instruction}"),
+            Self::Float32Instruction(instruction) => write!(f, "Float32-{instruction}"),
+            Self::StringInstruction(instruction) => write!(f, "String-{instruction}"),
+            Self::CharInstruction(instruction) => write!(f, "Char-{instruction}"),
+            Self::VectorIntInstruction(instruction) => write!(f, "VectorInt-{instruction}"),
+            Self::OutputInstruction(instruction) => write!(f, "Output-{instruction}"),
         }
     }
 }
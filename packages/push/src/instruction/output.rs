@@ -0,0 +1,71 @@
+use ordered_float::OrderedFloat;
+use strum_macros::EnumIter;
+
+use super::{print_to::PrintTo, Instruction, PushInstruction, PushInstructionError};
+use crate::{
+    error::InstructionResult,
+    push_vm::{named_output::HasNamedOutput, HasStack},
+};
+
+/// Instructions that print values to the state's named output channels.
+#[derive(Debug, strum_macros::Display, Clone, PartialEq, Eq, Hash, EnumIter)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+#[must_use]
+pub enum OutputInstruction {
+    PrintInt(PrintTo<i64>),
+    PrintFloat(PrintTo<OrderedFloat<f64>>),
+    PrintBool(PrintTo<bool>),
+    PrintString(PrintTo<String>),
+    PrintChar(PrintTo<char>),
+}
+
+impl OutputInstruction {
+    pub fn print_int(channel: impl Into<String>) -> Self {
+        Self::PrintInt(PrintTo::new(channel))
+    }
+
+    pub fn print_float(channel: impl Into<String>) -> Self {
+        Self::PrintFloat(PrintTo::new(channel))
+    }
+
+    pub fn print_bool(channel: impl Into<String>) -> Self {
+        Self::PrintBool(PrintTo::new(channel))
+    }
+
+    pub fn print_string(channel: impl Into<String>) -> Self {
+        Self::PrintString(PrintTo::new(channel))
+    }
+
+    pub fn print_char(channel: impl Into<String>) -> Self {
+        Self::PrintChar(PrintTo::new(channel))
+    }
+}
+
+impl From<OutputInstruction> for PushInstruction {
+    fn from(instr: OutputInstruction) -> Self {
+        Self::OutputInstruction(instr)
+    }
+}
+
+impl<S> Instruction<S> for OutputInstruction
+where
+    S: HasNamedOutput
+        + HasStack<i64>
+        + HasStack<OrderedFloat<f64>>
+        + HasStack<bool>
+        + HasStack<String>
+        + HasStack<char>,
+{
+    type Error = PushInstructionError;
+
+    fn perform(&self, state: S) -> InstructionResult<S, Self::Error> {
+        match self {
+            Self::PrintInt(print) => print.perform(state),
+            Self::PrintFloat(print) => print.perform(state),
+            Self::PrintBool(print) => print.perform(state),
+            Self::PrintString(print) => print.perform(state),
+            Self::PrintChar(print) => print.perform(state),
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use std::{fmt::Display, marker::PhantomData};
+
+use super::{Instruction, PushInstructionError};
+use crate::{
+    error::{Error, InstructionResult},
+    push_vm::{named_output::HasNamedOutput, stack::StackDiscard, HasStack},
+};
+
+/// Prints the top value of the `T` stack to a named output channel.
+///
+/// # Inputs
+///
+/// The top value of the `T` stack.
+///
+/// # Behavior
+///
+/// The `PrintTo` instruction pops the top value of the `T` stack and appends
+/// its `Display` representation to the output channel named `channel`.
+///
+/// # Errors
+///
+/// If the `T` stack is empty, this returns a recoverable
+/// [`StackError::Underflow`](crate::push_vm::stack::StackError::Underflow)
+/// error. If the state has no channel named `channel`, this returns a
+/// recoverable [`PushInstructionError::UnknownOutputChannel`] error. Either
+/// way the state is left unchanged.
+///
+/// If printing the value would make the channel longer than the state's
+/// maximum output size, this returns a fatal
+/// [`PushInstructionError::OutputOverflow`] error, just as growing a stack
+/// past its maximum size is fatal.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct PrintTo<T> {
+    channel: String,
+    _p: PhantomData<fn() -> T>,
+}
+
+impl<T> PrintTo<T> {
+    #[must_use]
+    pub fn new(channel: impl Into<String>) -> Self {
+        Self {
+            channel: channel.into(),
+            _p: PhantomData,
+        }
+    }
+
+    #[must_use]
+    pub fn channel(&self) -> &str {
+        &self.channel
+    }
+}
+
+// Only the channel name is (de)serialized, like a newtype struct, instead of
+// exposing the `PhantomData` field.
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for PrintTo<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct("PrintTo", &self.channel)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for PrintTo<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <String as serde::Deserialize>::deserialize(deserializer).map(Self::new)
+    }
+}
+
+impl<S, T> Instruction<S> for PrintTo<T>
+where
+    S: HasStack<T> + HasNamedOutput,
+    T: Display,
+{
+    type Error = PushInstructionError;
+
+    fn perform(&self, mut state: S) -> InstructionResult<S, Self::Error> {
+        let text = match state.stack::<T>().top() {
+            Ok(value) => value.to_string(),
+            Err(error) => return Err(Error::recoverable(state, error)),
+        };
+        match state.print_to_named_output(&self.channel, &text) {
+            Ok(()) => Ok(state).with_stack_discard::<T>(1),
+            Err(error @ PushInstructionError::OutputOverflow { .. }) => {
+                Err(Error::fatal(state, error))
+            }
+            Err(error) => Err(Error::recoverable(state, error)),
+        }
+    }
+}
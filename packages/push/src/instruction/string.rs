@@ -0,0 +1,134 @@
+use strum_macros::EnumIter;
+
+use super::{Instruction, PushInstruction, PushInstructionError};
+use crate::{
+    error::{Error, InstructionResult, MapInstructionError},
+    push_vm::stack::{HasStack, PushOnto, StackDiscard, StackError},
+};
+
+#[derive(Debug, strum_macros::Display, Clone, PartialEq, Eq, Hash, EnumIter)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+#[must_use]
+pub enum StringInstruction {
+    Push(String),
+    /// Replaces the top two strings with the second string followed by the
+    /// top string. If the result would be longer (in `char`s) than the
+    /// maximum stack size, this is a recoverable `StackError::Overflow` and
+    /// the state is left unchanged.
+    Concat,
+    /// Pops the top string and pushes its length (in `char`s) onto the `i64`
+    /// stack.
+    Length,
+    Reverse,
+    /// Replaces the top string with its first `n` `char`s, where `n` is
+    /// popped from the `i64` stack. Negative values of `n` are treated as 0,
+    /// and values larger than the length of the string keep the whole string.
+    Take,
+    /// Replaces the top string with everything but its first `n` `char`s,
+    /// where `n` is popped from the `i64` stack. Negative values of `n` are
+    /// treated as 0, and values larger than the length of the string leave the
+    /// empty string.
+    Drop,
+    /// Pops the top two strings and pushes onto the `bool` stack whether the
+    /// top string contains the second string.
+    Contains,
+}
+
+impl From<StringInstruction> for PushInstruction {
+    fn from(instr: StringInstruction) -> Self {
+        Self::StringInstruction(instr)
+    }
+}
+
+impl<S> Instruction<S> for StringInstruction
+where
+    S: Clone + HasStack<String> + HasStack<i64> + HasStack<bool>,
+{
+    type Error = PushInstructionError;
+
+    fn perform(&self, mut state: S) -> InstructionResult<S, Self::Error> {
+        match self {
+            Self::Push(s) => state.with_push(s.clone()).map_err_into(),
+
+            Self::Concat => {
+                let string_stack = state.stack_mut::<String>();
+                let max_length = string_stack.max_stack_size();
+                string_stack
+                    .top2()
+                    .map_err(PushInstructionError::from)
+                    .and_then(|(x, y)| {
+                        if x.chars().count().saturating_add(y.chars().count()) > max_length {
+                            Err(StackError::Overflow {
+                                stack_type: "string",
+                            }
+                            .into())
+                        } else {
+                            Ok(format!("{y}{x}"))
+                        }
+                    })
+                    .replace_on(2, state)
+            }
+
+            Self::Reverse => state
+                .stack_mut::<String>()
+                .top()
+                .map_err(PushInstructionError::from)
+                .map(|s| s.chars().rev().collect::<String>())
+                .replace_on(1, state),
+
+            Self::Take => Self::with_count(state, |s, n| s.chars().take(n).collect()),
+            Self::Drop => Self::with_count(state, |s, n| s.chars().skip(n).collect()),
+
+            // Neither of these pop anything off the stack they push onto, so we
+            // need to make sure there's room for the result before we start.
+            Self::Length => {
+                let mut state = state.not_full::<i64>().map_err_into()?;
+                state
+                    .stack_mut::<String>()
+                    .top()
+                    .map_err(PushInstructionError::from)
+                    .map(|s| i64::try_from(s.chars().count()).unwrap_or(i64::MAX))
+                    .push_onto(state)
+                    .with_stack_discard::<String>(1)
+            }
+
+            Self::Contains => {
+                let mut state = state.not_full::<bool>().map_err_into()?;
+                state
+                    .stack_mut::<String>()
+                    .top2()
+                    .map_err(PushInstructionError::from)
+                    .map(|(x, y)| x.contains(y.as_str()))
+                    .push_onto(state)
+                    .with_stack_discard::<String>(2)
+            }
+        }
+    }
+}
+
+impl StringInstruction {
+    // Replaces the top string with `op(string, n)`, where `n` is the top of the
+    // `i64` stack, saturated to the range of `usize`. Both arguments are
+    // checked before anything is removed, so an underflow on either stack
+    // leaves the state unchanged.
+    fn with_count<S>(
+        mut state: S,
+        op: impl FnOnce(&str, usize) -> String,
+    ) -> InstructionResult<S, PushInstructionError>
+    where
+        S: HasStack<String> + HasStack<i64>,
+    {
+        let count = match state.stack::<i64>().top() {
+            Ok(&count) => usize::try_from(count.max(0)).unwrap_or(usize::MAX),
+            Err(error) => return Err(Error::recoverable(state, error)),
+        };
+        state
+            .stack_mut::<String>()
+            .top()
+            .map_err(PushInstructionError::from)
+            .map(|s| op(s, count))
+            .replace_on(1, state)
+            .with_stack_discard::<i64>(1)
+    }
+}
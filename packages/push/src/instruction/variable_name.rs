@@ -3,6 +3,7 @@ use std::{fmt::Display, sync::Arc};
 use super::PushInstruction;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VariableName(Arc<str>);
 
 impl From<&str> for VariableName {
@@ -17,6 +18,20 @@ impl From<VariableName> for PushInstruction {
     }
 }
 
+/// The input instructions ([`PushInstruction::InputVar`]) for each of
+/// `names`, e.g., to add all of a problem's inputs to an instruction set
+/// without converting each name by hand.
+pub fn variable_instructions<N>(
+    names: impl IntoIterator<Item = N>,
+) -> impl Iterator<Item = PushInstruction>
+where
+    N: AsRef<str>,
+{
+    names
+        .into_iter()
+        .map(|name| VariableName::from(name.as_ref()).into())
+}
+
 impl Display for VariableName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.0)
@@ -24,13 +39,16 @@ impl Display for VariableName {
 }
 
 #[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "Every name the test looks up was inserted into the map"
+)]
 pub(crate) mod variable_name_test {
     use std::collections::HashMap;
 
     use super::*;
 
     #[test]
-    #[allow(clippy::unwrap_used)]
     fn variable_name() {
         let x = VariableName::from("x");
         let x2 = VariableName::from("x");
@@ -51,4 +69,24 @@ pub(crate) mod variable_name_test {
         let z = VariableName::from("z");
         assert_eq!(map.get(&z), None);
     }
+
+    #[test]
+    fn variable_instructions_match_input_vars() {
+        let instructions: Vec<_> = variable_instructions(["a", "b", "c"]).collect();
+        assert_eq!(
+            instructions,
+            [
+                PushInstruction::InputVar(VariableName::from("a")),
+                PushInstruction::InputVar(VariableName::from("b")),
+                PushInstruction::InputVar(VariableName::from("c")),
+            ]
+        );
+
+        let many: Vec<_> = variable_instructions((0..100).map(|i| format!("x{i}"))).collect();
+        assert_eq!(many.len(), 100);
+        assert_eq!(
+            many[42],
+            PushInstruction::InputVar(VariableName::from("x42"))
+        );
+    }
 }
@@ -0,0 +1,140 @@
+use strum_macros::EnumIter;
+
+use super::{Instruction, PushInstruction, PushInstructionError};
+use crate::{
+    error::{Error, InstructionResult, MapInstructionError},
+    push_vm::stack::{HasStack, PushOnto, StackDiscard, StackError},
+};
+
+/// Instructions on the stack of integer vectors (`Vec<i64>`).
+///
+/// No vector can have more elements than the maximum stack size; instructions
+/// that would create a longer vector return a recoverable
+/// `StackError::Overflow` and leave the state unchanged.
+#[derive(Debug, strum_macros::Display, Clone, PartialEq, Eq, Hash, EnumIter)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+#[must_use]
+pub enum VectorIntInstruction {
+    Push(Vec<i64>),
+    /// Replaces the top two vectors with the second vector followed by the
+    /// top vector.
+    Concat,
+    /// Pops the top vector and an index from the `i64` stack, and pushes the
+    /// element at that index onto the `i64` stack. The index wraps around
+    /// (modulo the length of the vector), so any index is valid as long as
+    /// the vector isn't empty.
+    Nth,
+    /// Pops the top vector and pushes its length onto the `i64` stack.
+    Length,
+    /// Pops a value from the `i64` stack and appends it to the top vector.
+    Append,
+    /// Pushes an empty vector.
+    EmptyVector,
+}
+
+impl From<VectorIntInstruction> for PushInstruction {
+    fn from(instr: VectorIntInstruction) -> Self {
+        Self::VectorIntInstruction(instr)
+    }
+}
+
+impl<S> Instruction<S> for VectorIntInstruction
+where
+    S: Clone + HasStack<Vec<i64>> + HasStack<i64>,
+{
+    type Error = PushInstructionError;
+
+    fn perform(&self, mut state: S) -> InstructionResult<S, Self::Error> {
+        match self {
+            Self::Push(v) => state.with_push(v.clone()).map_err_into(),
+            Self::EmptyVector => state.with_push(Vec::new()).map_err_into(),
+
+            Self::Concat => {
+                let vector_stack = state.stack_mut::<Vec<i64>>();
+                let max_length = vector_stack.max_stack_size();
+                vector_stack
+                    .top2()
+                    .map_err(PushInstructionError::from)
+                    .and_then(|(x, y)| {
+                        if x.len().saturating_add(y.len()) > max_length {
+                            Err(vector_overflow())
+                        } else {
+                            Ok([y.as_slice(), x.as_slice()].concat())
+                        }
+                    })
+                    .replace_on(2, state)
+            }
+
+            Self::Append => {
+                let max_length = state.stack::<Vec<i64>>().max_stack_size();
+                let value = match state.stack::<i64>().top() {
+                    Ok(&value) => value,
+                    Err(error) => return Err(Error::recoverable(state, error)),
+                };
+                state
+                    .stack_mut::<Vec<i64>>()
+                    .top()
+                    .map_err(PushInstructionError::from)
+                    .and_then(|v| {
+                        if v.len() >= max_length {
+                            Err(vector_overflow())
+                        } else {
+                            let mut v = v.clone();
+                            v.push(value);
+                            Ok(v)
+                        }
+                    })
+                    .replace_on(1, state)
+                    .with_stack_discard::<i64>(1)
+            }
+
+            Self::Nth => {
+                let index = match state.stack::<i64>().top() {
+                    Ok(&index) => index,
+                    Err(error) => return Err(Error::recoverable(state, error)),
+                };
+                state
+                    .stack_mut::<Vec<i64>>()
+                    .top()
+                    .map_err(PushInstructionError::from)
+                    .and_then(|v| {
+                        i64::try_from(v.len())
+                            .ok()
+                            .and_then(|len| index.checked_rem_euclid(len))
+                            .and_then(|i| usize::try_from(i).ok())
+                            .and_then(|i| v.get(i).copied())
+                            .ok_or_else(|| {
+                                StackError::Underflow {
+                                    num_requested: 1,
+                                    num_present: 0,
+                                }
+                                .into()
+                            })
+                    })
+                    .replace_on(1, state)
+                    .with_stack_discard::<Vec<i64>>(1)
+            }
+
+            // This doesn't pop anything off the `i64` stack, so we need to
+            // make sure there's room for the result before we start.
+            Self::Length => {
+                let mut state = state.not_full::<i64>().map_err_into()?;
+                state
+                    .stack_mut::<Vec<i64>>()
+                    .top()
+                    .map_err(PushInstructionError::from)
+                    .map(|v| i64::try_from(v.len()).unwrap_or(i64::MAX))
+                    .push_onto(state)
+                    .with_stack_discard::<Vec<i64>>(1)
+            }
+        }
+    }
+}
+
+fn vector_overflow() -> PushInstructionError {
+    StackError::Overflow {
+        stack_type: "int_vector",
+    }
+    .into()
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "bincode")]
+pub mod binary;
 pub mod error;
 pub mod evaluation;
 pub mod genome;
@@ -0,0 +1,88 @@
+use std::{num::NonZeroUsize, time::Duration};
+
+/// Limits on how much work [`State::run_with_budget`](super::State::run_with_budget)
+/// can do before it stops running a program.
+///
+/// A budget can limit the number of steps (as counted by the state's
+/// [`StepCost`](super::step_cost::StepCost)), the wall-clock time, both, or
+/// neither. Checking the time isn't free, so the elapsed time is only checked
+/// once every [`Budget::time_check_interval`] items performed; a run can
+/// therefore go a little over its time limit.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[must_use]
+pub struct Budget {
+    max_steps: Option<usize>,
+    max_duration: Option<Duration>,
+    time_check_interval: NonZeroUsize,
+}
+
+impl Budget {
+    /// The default number of items performed between checks of the elapsed
+    /// time.
+    pub const DEFAULT_TIME_CHECK_INTERVAL: NonZeroUsize = match NonZeroUsize::new(100) {
+        Some(interval) => interval,
+        None => NonZeroUsize::MIN,
+    };
+
+    /// A budget with no limits, so programs run until they complete.
+    pub const fn unlimited() -> Self {
+        Self {
+            max_steps: None,
+            max_duration: None,
+            time_check_interval: Self::DEFAULT_TIME_CHECK_INTERVAL,
+        }
+    }
+
+    /// Stop once more than `max_steps` steps have been taken.
+    pub const fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = Some(max_steps);
+        self
+    }
+
+    /// Stop once more than `max_duration` has elapsed.
+    pub const fn with_max_duration(mut self, max_duration: Duration) -> Self {
+        self.max_duration = Some(max_duration);
+        self
+    }
+
+    /// Check the elapsed time once every `time_check_interval` items
+    /// performed, instead of every
+    /// [`DEFAULT_TIME_CHECK_INTERVAL`](Self::DEFAULT_TIME_CHECK_INTERVAL).
+    pub const fn with_time_check_interval(mut self, time_check_interval: NonZeroUsize) -> Self {
+        self.time_check_interval = time_check_interval;
+        self
+    }
+
+    #[must_use]
+    pub const fn max_steps(&self) -> Option<usize> {
+        self.max_steps
+    }
+
+    #[must_use]
+    pub const fn max_duration(&self) -> Option<Duration> {
+        self.max_duration
+    }
+
+    #[must_use]
+    pub const fn time_check_interval(&self) -> NonZeroUsize {
+        self.time_check_interval
+    }
+}
+
+impl Default for Budget {
+    fn default() -> Self {
+        Self::unlimited()
+    }
+}
+
+/// Why [`State::run_with_budget`](super::State::run_with_budget) stopped
+/// running a program.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Termination {
+    /// The program ran to completion, i.e., the `Exec` stack is empty.
+    Normal,
+    /// The budget's step limit was exceeded.
+    StepLimit,
+    /// The budget's time limit was exceeded.
+    TimeLimit,
+}
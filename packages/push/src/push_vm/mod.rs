@@ -1,13 +1,24 @@
+use self::budget::{Budget, Termination};
 use crate::{
     error::{stateful::FatalError, InstructionResult},
     instruction::Instruction,
 };
 
+pub mod budget;
+pub mod named_output;
 pub mod program;
 pub mod push_state;
+pub mod rng;
+pub mod run_outcome;
 pub mod stack;
+pub mod step_cost;
+pub mod trace;
 
-pub use self::stack::HasStack;
+pub use self::{named_output::HasNamedOutput, rng::HasRng, stack::HasStack};
+
+/// The fatal error (along with the state at the time) that running a state
+/// `S` can fail with.
+pub type StateFatalError<S> = FatalError<S, <<S as State>::Instruction as Instruction<S>>::Error>;
 
 // Need an associated error trait
 pub trait State: Sized {
@@ -23,12 +34,56 @@ pub trait State: Sized {
         instruction.perform(self)
     }
 
+    /// Pop the top item off the `Exec` stack and perform it, returning the
+    /// new state along with the item that was performed. If the `Exec` stack
+    /// is empty, nothing is performed and this returns the unchanged state
+    /// with `None`.
+    ///
+    /// As with running a whole program, recoverable errors from performing
+    /// the item are ignored, leaving the state as the instruction left it.
+    ///
+    /// # Errors
+    ///
+    /// Fails if performing the item fails with a fatal error.
+    fn step(self) -> Result<(Self, Option<Self::Instruction>), StateFatalError<Self>>;
+
+    /// Perform up to `n` items from the `Exec` stack (see [`State::step`]),
+    /// stopping early if the `Exec` stack becomes empty. This returns the
+    /// new state along with the number of items that were performed.
+    ///
+    /// This doesn't count against the state's step limit or any budget, so
+    /// it can be used to interleave running a program with other work.
+    ///
+    /// # Errors
+    ///
+    /// Fails if any of the performed instructions fails with a fatal error.
+    fn run_n_steps(mut self, n: usize) -> Result<(Self, usize), StateFatalError<Self>> {
+        for num_performed in 0..n {
+            let (state, performed) = self.step()?;
+            self = state;
+            if performed.is_none() {
+                return Ok((self, num_performed));
+            }
+        }
+        Ok((self, n))
+    }
+
     /// # Errors
     ///
     /// Fails if any of the performed instructions fails.
     fn run_to_completion(
         self,
     ) -> Result<Self, FatalError<Self, <Self::Instruction as Instruction<Self>>::Error>>;
+
+    /// Run the program until it completes or exceeds the given `budget`,
+    /// returning the final state along with the reason the run stopped. If
+    /// the budget is exceeded, the returned state is the (partial) state at
+    /// that point, with the unperformed items still on the `Exec` stack.
+    ///
+    /// # Errors
+    ///
+    /// Fails if any of the performed instructions fails.
+    fn run_with_budget(self, budget: Budget) -> Result<(Self, Termination), StateFatalError<Self>>;
 }
 
 /*
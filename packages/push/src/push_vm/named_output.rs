@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use crate::instruction::instruction_error::PushInstructionError;
+
+/// A state with named output channels that instructions can "print" to,
+/// e.g., with [`PrintTo`](crate::instruction::print_to::PrintTo).
+///
+/// Each channel is a `String` buffer, identified by its name. Only channels
+/// that have been registered (e.g., with the `with_output_channel` method of
+/// [`PushStateBuilder`](super::push_state::PushStateBuilder)) exist; there's
+/// no way for a program to create a new channel.
+pub trait HasNamedOutput {
+    /// Everything printed to `channel` so far, or `None` if there's no
+    /// channel with that name.
+    fn named_output_string(&self, channel: &str) -> Option<&str>;
+
+    /// Appends `text` to the buffer for `channel`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PushInstructionError::UnknownOutputChannel`] if there's no
+    /// channel with that name, and [`PushInstructionError::OutputOverflow`]
+    /// if appending `text` would make the buffer longer than the maximum
+    /// output size. Either way the buffer is left unchanged.
+    fn print_to_named_output(
+        &mut self,
+        channel: &str,
+        text: &str,
+    ) -> Result<(), PushInstructionError>;
+}
+
+/// The named output channels of a state, along with the maximum number of
+/// bytes each channel can hold.
+///
+/// A state marks its field of this type with `#[output_channels]` so that
+/// the [`push_state`](crate::push_state) macro adds `with_output_channel`
+/// and `with_max_output_size` methods to the state's builder.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct OutputChannels {
+    channels: HashMap<String, String>,
+    max_size: Option<usize>,
+}
+
+impl OutputChannels {
+    /// Adds an (empty) channel named `channel`. Adding a channel that
+    /// already exists leaves it unchanged.
+    pub fn add_channel(&mut self, channel: impl Into<String>) {
+        self.channels.entry(channel.into()).or_default();
+    }
+
+    /// Limits each channel to holding at most `max_size` bytes. There is no
+    /// limit by default.
+    pub const fn set_max_size(&mut self, max_size: usize) {
+        self.max_size = Some(max_size);
+    }
+
+    /// The maximum number of bytes each channel can hold, if any.
+    #[must_use]
+    pub const fn max_size(&self) -> Option<usize> {
+        self.max_size
+    }
+
+    /// Everything printed to `channel` so far, or `None` if there's no
+    /// channel with that name.
+    #[must_use]
+    pub fn get(&self, channel: &str) -> Option<&str> {
+        self.channels.get(channel).map(String::as_str)
+    }
+
+    /// Appends `text` to `channel`; see
+    /// [`HasNamedOutput::print_to_named_output`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PushInstructionError::UnknownOutputChannel`] if there's no
+    /// channel with that name, and [`PushInstructionError::OutputOverflow`]
+    /// if the channel would end up longer than the maximum size.
+    pub fn print(&mut self, channel: &str, text: &str) -> Result<(), PushInstructionError> {
+        let buffer = self.channels.get_mut(channel).ok_or_else(|| {
+            PushInstructionError::UnknownOutputChannel {
+                channel: channel.to_string(),
+            }
+        })?;
+        if let Some(max_size) = self.max_size {
+            if buffer.len().saturating_add(text.len()) > max_size {
+                return Err(PushInstructionError::OutputOverflow {
+                    channel: channel.to_string(),
+                    max_size,
+                });
+            }
+        }
+        buffer.push_str(text);
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+use std::fmt::{Display, Formatter, Result};
+
+use super::PushProgram;
+
+/// The indentation for each level of nesting in [`PushProgram::to_pretty`].
+const INDENT: &str = "  ";
+
+/// Programs are displayed on a single line, with each block wrapped in
+/// parentheses, e.g., `Exec-DupBlock (Int-Add Int-Multiply)`. Use
+/// [`PushProgram::to_pretty`] for a more readable, indented layout.
+impl Display for PushProgram {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::Instruction(instruction) => instruction.fmt(f),
+            Self::Block(block) => {
+                f.write_str("(")?;
+                let mut iter = block.iter();
+                if let Some(program) = iter.next() {
+                    program.fmt(f)?;
+                }
+                for program in iter {
+                    f.write_str(" ")?;
+                    program.fmt(f)?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+impl PushProgram {
+    /// Render this program with one instruction per line, and the contents
+    /// of each block between `(` and `)` lines and indented one more level
+    /// than the block itself. A block follows the instruction that opened
+    /// it, e.g., an `Exec-IfElse` is followed by its two blocks.
+    #[must_use]
+    pub fn to_pretty(&self) -> String {
+        Pretty(std::slice::from_ref(self)).to_string()
+    }
+
+    /// Render a sequence of programs (such as the top level of a program
+    /// converted from a [`Plushy`](crate::genome::plushy::Plushy)) as in
+    /// [`PushProgram::to_pretty`], without wrapping them in a block.
+    #[must_use]
+    pub fn sequence_to_pretty(programs: &[Self]) -> String {
+        Pretty(programs).to_string()
+    }
+
+    fn fmt_pretty(&self, f: &mut Formatter<'_>, depth: usize) -> Result {
+        let indent = INDENT.repeat(depth);
+        match self {
+            Self::Instruction(instruction) => writeln!(f, "{indent}{instruction}"),
+            Self::Block(block) if block.is_empty() => writeln!(f, "{indent}()"),
+            Self::Block(block) => {
+                writeln!(f, "{indent}(")?;
+                for program in block {
+                    program.fmt_pretty(f, depth.saturating_add(1))?;
+                }
+                writeln!(f, "{indent})")
+            }
+        }
+    }
+}
+
+struct Pretty<'a>(&'a [PushProgram]);
+
+impl Display for Pretty<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        self.0
+            .iter()
+            .try_for_each(|program| program.fmt_pretty(f, 0))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::PushProgram;
+    use crate::{
+        instruction::{ExecInstruction, IntInstruction},
+        list_into::vec_into,
+    };
+
+    fn program() -> Vec<PushProgram> {
+        vec_into![
+            IntInstruction::Add,
+            ExecInstruction::if_else(),
+            PushProgram::Block(vec_into![IntInstruction::Multiply]),
+            PushProgram::Block(vec_into![
+                ExecInstruction::dup_block(),
+                PushProgram::Block(vec_into![IntInstruction::Subtract, IntInstruction::Inc]),
+            ]),
+            PushProgram::Block(vec![]),
+        ]
+    }
+
+    #[test]
+    fn pretty_nested_blocks() {
+        let expected = "\
+Int-Add
+Exec-IfElse
+(
+  Int-Multiply
+)
+(
+  Exec-DupBlock
+  (
+    Int-Subtract
+    Int-Inc
+  )
+)
+()
+";
+        assert_eq!(PushProgram::sequence_to_pretty(&program()), expected);
+    }
+
+    #[test]
+    fn display_is_one_line() {
+        let program = PushProgram::Block(program());
+        assert_eq!(
+            program.to_string(),
+            "(Int-Add Exec-IfElse (Int-Multiply) (Exec-DupBlock (Int-Subtract Int-Inc)) ())"
+        );
+    }
+
+    #[test]
+    fn pretty_single_instruction() {
+        let program = PushProgram::from(IntInstruction::Add);
+        assert_eq!(program.to_pretty(), "Int-Add\n");
+    }
+}
@@ -1,3 +1,5 @@
+mod display;
+
 use super::{push_state::PushState, stack::StackError, HasStack};
 use crate::{
     error::{Error, InstructionResult},
@@ -7,18 +9,16 @@ use crate::{
     },
 };
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PushProgram {
     Instruction(PushInstruction),
-    Block(Vec<PushProgram>),
+    Block(Vec<Self>),
 }
 
 impl From<Plushy> for Vec<PushProgram> {
     fn from(plushy: Plushy) -> Self {
-        let mut genes = plushy.into_iter();
-        let mut program = Self::new();
-        PushProgram::parse_from_plushy(true, &mut genes, &mut program);
-        program
+        PushProgram::from_genes(plushy)
     }
 }
 
@@ -32,6 +32,28 @@ where
 }
 
 impl PushProgram {
+    /// The number of instructions in this program, including the
+    /// instructions in any nested blocks. Blocks themselves aren't counted,
+    /// so an empty block has size 0.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        match self {
+            Self::Instruction(_) => 1,
+            Self::Block(block) => block.iter().map(Self::size).sum(),
+        }
+    }
+
+    /// Build a program from a sequence of Plushy genes, where each
+    /// instruction is followed by a block for each of its
+    /// [`num_opens`](NumOpens::num_opens), and each block continues up to the
+    /// matching `Close`.
+    pub(crate) fn from_genes(genes: impl IntoIterator<Item = PushGene>) -> Vec<Self> {
+        let mut genes = genes.into_iter();
+        let mut program = Vec::new();
+        Self::parse_from_plushy(true, &mut genes, &mut program);
+        program
+    }
+
     // Take a vector of genes, parse out the next complete Push program and
     // return that program and the remaining slice of genes.
     fn parse_from_plushy(
@@ -85,6 +107,31 @@ where
     }
 }
 
+/// The error returned when building a state with a program that doesn't fit
+/// on the `Exec` stack, e.g., from
+/// [`with_program`](crate::push_vm::push_state::PushStateBuilder::with_program).
+///
+/// Having a dedicated error for this lets scorers treat programs that are too
+/// long to run differently from programs that fail while running, e.g., by
+/// assigning them a penalty value.
+#[derive(thiserror::Error, Debug, Clone, Copy, Eq, PartialEq)]
+#[error("The program has length {length}, which is more than the maximum exec stack size {max}")]
+pub struct ProgramTooLong {
+    /// The number of top-level items in the program.
+    pub length: usize,
+    /// The maximum size of the `Exec` stack.
+    pub max: usize,
+}
+
+impl From<ProgramTooLong> for StackError {
+    /// A program that is too long overflows the `Exec` stack. This is
+    /// mostly useful for functions that return `StackError`s from other parts
+    /// of building a state as well.
+    fn from(_: ProgramTooLong) -> Self {
+        Self::Overflow { stack_type: "exec" }
+    }
+}
+
 impl Instruction<PushState> for PushProgram {
     type Error = PushInstructionError;
 
@@ -97,7 +144,10 @@ impl Instruction<PushState> for PushProgram {
 }
 
 #[cfg(test)]
-#[allow(clippy::unwrap_used)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The test programs fit within the stack limits"
+)]
 mod test {
     use super::PushProgram;
     use crate::{
@@ -120,7 +170,8 @@ mod test {
             IntInstruction::Subtract,
         ];
         let plushy: Plushy = genes.into_iter().collect();
-        let program: Vec<PushProgram> = plushy.into();
+        let program: Vec<PushProgram> = plushy.clone().into();
+        assert_eq!(plushy.to_program(), program);
         // [Instruction(Int-Add), Instruction(Exec-IfElse),
         // Block([Instruction(Int-Multiply)]), Block([Instruction(Exec-Dup),
         // Block([Instruction(Int-Subtract)])])]
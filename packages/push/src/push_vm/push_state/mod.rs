@@ -0,0 +1,904 @@
+pub mod random;
+#[cfg(any(test, feature = "debug"))]
+pub mod snapshot;
+
+use std::{collections::HashMap, time::Instant};
+
+pub use ordered_float::OrderedFloat;
+
+use crate::{
+    error::{
+        into_state::IntoState, stateful::FatalError, try_recover::TryRecover, Error,
+        InstructionResult,
+    },
+    instruction::{
+        instruction_error::PushInstructionError, variable_name::VariableName, Instruction,
+        PushInstruction,
+    },
+    push_vm::{
+        budget::{Budget, Termination},
+        named_output::{HasNamedOutput, OutputChannels},
+        program::PushProgram,
+        rng::{HasRng, OptionalRng},
+        run_outcome::RunOutcome,
+        stack::Stack,
+        step_cost::StepCost,
+        trace::{Trace, TraceEntry},
+        State,
+    },
+};
+
+// TODO: It might make sense to separate out the specification of
+// a Push implementation (i.e., the relevant traits) into its
+// own package, and have the implementation of those traits in
+// its own package as well. We could, for example, do a FFI
+// implementation that just forwards to the a Clojure implementation
+// or Python implementation for comparison/testing purposes.
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+#[push_macros::push_state(builder)]
+pub struct PushState {
+    #[stack(exec)]
+    pub(crate) exec: Stack<PushProgram>,
+    #[stack(sample_values = [4, 5, 7])]
+    pub(crate) int: Stack<i64>,
+    #[stack(sample_values = [OrderedFloat(4.3), OrderedFloat(5.1), OrderedFloat(2.1)])]
+    pub(crate) float: Stack<OrderedFloat<f64>>,
+    #[stack(sample_values = [OrderedFloat(4.3_f32), OrderedFloat(5.1_f32)])]
+    pub(crate) float32: Stack<OrderedFloat<f32>>,
+    #[stack(sample_values = [true, false, true, true])]
+    pub(crate) bool: Stack<bool>,
+    #[stack(sample_values = [String::from("hello"), String::from("world")])]
+    pub(crate) string: Stack<String>,
+    #[stack(sample_values = ['a', 'b', 'c'])]
+    pub(crate) char: Stack<char>,
+    #[stack(sample_values = [vec![1, 2], vec![3]])]
+    pub(crate) int_vector: Stack<Vec<i64>>,
+    // The Internet suggests that when you have fewer than 15 entries,
+    // linear search on `Vec` is faster than `HashMap`. I found that
+    // using `HashMap` here did slow things down, mostly
+    // through substantially increased allocation time for `HashMap` vs.
+    // `Vec`. When I substantially increased the size of the programs,
+    // however, the difference pretty much disappeared, presumably
+    // because the execution of long programs swamps the cost of
+    // initialization of `PushState`.
+    #[input_instructions]
+    pub(super) input_instructions: HashMap<VariableName, PushInstruction>,
+    // The source of randomness for instructions like `IntRandomInstruction::RandomInRange`.
+    // This defaults to using `thread_rng()`, but can be seeded for reproducible runs.
+    pub(crate) rng: OptionalRng,
+    // The maximum number of steps `run_to_completion` can take before the
+    // program is terminated, if any, and how much each step costs.
+    pub(crate) step_limit: Option<usize>,
+    pub(crate) step_cost: StepCost,
+    // The number of steps (as counted by `step_cost`) taken by the most
+    // recent run.
+    pub(crate) steps_taken: usize,
+    // The number of items that failed with a recoverable error (and were
+    // skipped) during the most recent run.
+    pub(crate) recoverable_errors: usize,
+    // The named output channels (and everything printed to them so far) that
+    // instructions like `OutputInstruction::PrintInt` can print to.
+    #[output_channels]
+    pub(crate) output_channels: OutputChannels,
+    // The most recent items performed from the `Exec` stack in the most
+    // recent run, if tracing is turned on; when it's off (the default)
+    // nothing is recorded.
+    pub(crate) trace: Option<Trace>,
+}
+
+impl PushState {
+    /// Sets the random number generator used by instructions that generate
+    /// random values, such as `IntRandomInstruction::RandomInRange`.
+    #[must_use]
+    pub fn with_rng(mut self, rng: impl Into<OptionalRng>) -> Self {
+        self.rng = rng.into();
+        self
+    }
+
+    /// Seeds the random number generator used by instructions that generate
+    /// random values, so that running the same program on the same inputs
+    /// gives the same results every time.
+    #[must_use]
+    pub fn with_rng_seed(self, seed: u64) -> Self {
+        self.with_rng(OptionalRng::seeded(seed))
+    }
+
+    /// Limits the number of steps `run_to_completion` can take before the
+    /// program is terminated with a
+    /// [`StepLimitExceeded`](PushInstructionError::StepLimitExceeded) error.
+    /// There is no limit by default.
+    ///
+    /// To stop at a limit without it being an error, and to find out which
+    /// limit (if any) was hit, use [`State::run_with_budget`] instead.
+    #[must_use]
+    pub const fn with_step_limit(mut self, step_limit: usize) -> Self {
+        self.step_limit = Some(step_limit);
+        self
+    }
+
+    /// The step limit set with [`PushState::with_step_limit`], if any.
+    #[must_use]
+    pub const fn step_limit(&self) -> Option<usize> {
+        self.step_limit
+    }
+
+    /// The number of steps taken by the most recent call to
+    /// `run_to_completion` or [`State::run_with_budget`], as counted by the
+    /// [`StepCost`]; with the default [`StepCost::Flat`], that's the number
+    /// of items performed from the `Exec` stack. This is zero if the state
+    /// hasn't been run.
+    ///
+    /// If the run stopped because it exceeded a step limit, this includes
+    /// the step that went over the limit, so it will be more than the limit.
+    #[must_use]
+    pub const fn steps_taken(&self) -> usize {
+        self.steps_taken
+    }
+
+    /// The number of items performed by the most recent call to
+    /// `run_to_completion` or [`State::run_with_budget`] (along with any
+    /// performed since by [`State::step`]) that failed with a recoverable
+    /// error. Those errors don't stop the run (the item is just skipped), so
+    /// this is the only record of them.
+    #[must_use]
+    pub const fn recoverable_errors(&self) -> usize {
+        self.recoverable_errors
+    }
+
+    /// Run the program to completion, like `run_to_completion`, but return
+    /// the final state (even if the run failed) along with a [`RunOutcome`]
+    /// saying how the run ended.
+    #[must_use]
+    pub fn run_to_outcome(self) -> (Self, RunOutcome) {
+        match self.run_to_completion() {
+            Ok(state) => (state, RunOutcome::Completed),
+            Err(error) => {
+                let (state, error) = error.into_parts();
+                let outcome = match error.into_root_cause() {
+                    PushInstructionError::StepLimitExceeded { step_limit } => {
+                        RunOutcome::StepLimitReached { step_limit }
+                    }
+                    error => RunOutcome::FatalError(error),
+                };
+                (state, outcome)
+            }
+        }
+    }
+
+    /// Sets how many steps each item performed by `run_to_completion` counts
+    /// against the step limit. The default is [`StepCost::Flat`].
+    #[must_use]
+    pub const fn with_step_cost(mut self, step_cost: StepCost) -> Self {
+        self.step_cost = step_cost;
+        self
+    }
+
+    /// Turns on tracing, so that each item performed from the `Exec` stack
+    /// is recorded (in order, along with the size of every stack) and can
+    /// be retrieved with [`PushState::trace`]. Only the most recent
+    /// `max_entries` items are kept, so tracing a long run doesn't use an
+    /// unbounded amount of memory. This is for analyzing how a program
+    /// produced its results; tracing is off by default, in which case
+    /// nothing is recorded.
+    #[must_use]
+    pub fn with_tracing(mut self, max_entries: usize) -> Self {
+        self.trace = Some(Trace::new(max_entries));
+        self
+    }
+
+    /// The (most recent) items performed by the most recent call to
+    /// `run_to_completion` or [`State::run_with_budget`] (along with any
+    /// performed since by [`State::step`]), or `None` if tracing is off.
+    ///
+    /// If the run failed with a fatal error, the last entry in the trace of
+    /// the error's state is the item that failed.
+    #[must_use]
+    pub const fn trace(&self) -> Option<&Trace> {
+        self.trace.as_ref()
+    }
+
+    // /// # Panics
+    // ///
+    // /// This panics if we try to access a variable whose `var_index` isn't in the
+    // /// variable map.
+    // pub fn push_input(&mut self, var_name: &VariableName) {
+    //     let instruction = self
+    //         .input_instructions
+    //         .iter()
+    //         .find_map(|(n, v)| if n == var_name { Some(v) } else { None })
+    //         .unwrap_or_else(|| panic!(
+    //              "Failed to get an instruction for the input \
+    //               variable '{var_name}' that hadn't been defined"
+    //         ))
+    //         .clone();
+    //     instruction.perform(self);
+    // }
+
+    /// # Errors
+    ///
+    /// This returns an error if the `PushInstruction` returns an error,
+    /// which really shouldn't happen.
+    ///
+    /// # Panics
+    ///
+    /// This panics if there is no instruction associated with `var_name`, i.e.,
+    /// we have not yet added that variable name to the map of names to
+    /// instructions.
+    #[expect(
+        clippy::panic,
+        reason = "Using an input variable that was never added is a programming error"
+    )]
+    pub fn with_input(
+        self,
+        var_name: &VariableName,
+    ) -> InstructionResult<Self, <PushInstruction as Instruction<Self>>::Error> {
+        // TODO: This `panic` here is icky, and we really should deal with it better.
+        // I wonder if the fact that this index might not be there should be telling
+        // us something... - see issue #172
+        let instruction = self
+            .input_instructions
+            .iter()
+            .find_map(|(n, v)| (n == var_name).then_some(v))
+            .unwrap_or_else(|| {
+                panic!(
+                    "Failed to get an instruction for the input variable '{var_name}' that hadn't \
+                     been defined"
+                )
+            })
+            .clone();
+        instruction.perform(self)
+    }
+}
+
+impl HasRng for PushState {
+    fn using_rng<T>(&mut self, f: impl FnOnce(&mut dyn rand::RngCore) -> T) -> T {
+        self.rng.using_rng(f)
+    }
+}
+
+impl HasNamedOutput for PushState {
+    fn named_output_string(&self, channel: &str) -> Option<&str> {
+        self.output_channels.get(channel)
+    }
+
+    fn print_to_named_output(
+        &mut self,
+        channel: &str,
+        text: &str,
+    ) -> Result<(), PushInstructionError> {
+        self.output_channels.print(channel, text)
+    }
+}
+
+impl State for PushState {
+    type Instruction = PushProgram;
+
+    fn run_to_completion(self) -> Result<Self, FatalError<Self, PushInstructionError>> {
+        let Some(step_limit) = self.step_limit else {
+            return self
+                .run_with_budget(Budget::unlimited())
+                .map(|(state, _)| state);
+        };
+        match self.run_with_budget(Budget::unlimited().with_max_steps(step_limit))? {
+            (state, Termination::StepLimit) => Err(FatalError::new(
+                state,
+                PushInstructionError::StepLimitExceeded { step_limit },
+            )),
+            (state, _) => Ok(state),
+        }
+    }
+
+    fn step(
+        mut self,
+    ) -> Result<(Self, Option<PushProgram>), FatalError<Self, PushInstructionError>> {
+        // The `pop()` call can only return a `StackError`, which is either underflow or
+        // overflow, with the latter not possible when just popping. So I'm not going to
+        // bother capturing the error here.
+        let Ok(program) = self.exec.pop() else {
+            return Ok((self, None));
+        };
+        // The entry is recorded before the item is performed so that it's
+        // in the state's trace even if the item fails with a fatal error.
+        if self.trace.is_some() {
+            let stack_sizes = self.stack_summary();
+            if let Some(trace) = &mut self.trace {
+                trace.push(TraceEntry {
+                    item: program.clone(),
+                    failed: false,
+                    stack_sizes,
+                });
+            }
+        }
+        let state = match self.perform(&program) {
+            Err(Error::Recoverable(error)) => {
+                let mut state = error.into_state();
+                state.recoverable_errors = state.recoverable_errors.saturating_add(1);
+                if let Some(entry) = state.trace.as_mut().and_then(|trace| trace.last_mut()) {
+                    entry.failed = true;
+                }
+                state
+            }
+            result => result.try_recover()?,
+        };
+        Ok((state, Some(program)))
+    }
+
+    fn run_with_budget(
+        mut self,
+        budget: Budget,
+    ) -> Result<(Self, Termination), FatalError<Self, PushInstructionError>> {
+        let start = budget.max_duration().map(|_| Instant::now());
+        self.steps_taken = 0;
+        self.recoverable_errors = 0;
+        if let Some(trace) = &mut self.trace {
+            trace.clear();
+        }
+        let mut items_performed: usize = 0;
+        loop {
+            // The size of the `Exec` stack once the next item has been popped.
+            let exec_size = self.exec.size().saturating_sub(1);
+            let (state, performed) = self.step()?;
+            self = state;
+            if performed.is_none() {
+                break;
+            }
+            self.steps_taken = self
+                .steps_taken
+                .saturating_add(self.step_cost.cost(exec_size, &self.exec));
+            if budget
+                .max_steps()
+                .is_some_and(|max_steps| self.steps_taken > max_steps)
+            {
+                return Ok((self, Termination::StepLimit));
+            }
+            items_performed = items_performed.saturating_add(1);
+            if let (Some(start), Some(max_duration)) = (start, budget.max_duration()) {
+                if items_performed.checked_rem(budget.time_check_interval().get()) == Some(0)
+                    && start.elapsed() > max_duration
+                {
+                    return Ok((self, Termination::TimeLimit));
+                }
+            }
+        }
+        Ok((self, Termination::Normal))
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The test states are built with values that fit their stacks"
+)]
+mod simple_check {
+    use std::{num::NonZeroUsize, time::Duration};
+
+    use ordered_float::OrderedFloat;
+
+    use super::State;
+    use crate::{
+        error::stateful::FatalError,
+        genome::plushy::{Plushy, PushGene},
+        instruction::{
+            instruction_error::PushInstructionError, variable_name::VariableName, BoolInstruction,
+            ExecInstruction, FloatInstruction, IntInstruction, IntRandomInstruction,
+            PushInstruction,
+        },
+        list_into::vec_into,
+        push_vm::{
+            budget::{Budget, Termination},
+            program::{ProgramTooLong, PushProgram},
+            push_state::PushState,
+            run_outcome::RunOutcome,
+            stack::StackError,
+            step_cost::StepCost,
+        },
+    };
+
+    #[test]
+    fn run_simple_program() {
+        fn push_bool(b: bool) -> PushInstruction {
+            PushInstruction::push_bool(b)
+        }
+
+        fn push_int(i: i64) -> PushInstruction {
+            PushInstruction::push_int(i)
+        }
+
+        fn push_float(f: f64) -> PushInstruction {
+            PushInstruction::push_float(OrderedFloat(f))
+        }
+
+        let genes: Vec<PushGene> = vec_into![
+            VariableName::from("x"),    // [5]
+            VariableName::from("y"),    // [8, 5]
+            push_bool(true),            // [true]
+            VariableName::from("a"),    // [true, true]
+            push_int(9),                // [9, 8, 5]
+            BoolInstruction::Or,        // [true]
+            IntInstruction::Add,        // [17, 5]
+            push_int(6),                // [6, 17, 5]
+            IntInstruction::IsEven,     // [17, 5], [true, true]
+            BoolInstruction::And,       // [true]
+            VariableName::from("b"),    // [false, true]
+            push_float(3.5),            // [3.5]
+            FloatInstruction::Dup,      // [3.5, 3.5]
+            FloatInstruction::Multiply, // [12.25]
+            VariableName::from("f"),    // [12.25, 0.75]
+            FloatInstruction::Add,      // [13.0]
+        ];
+
+        let plushy = Plushy::new(genes);
+        let state = PushState::builder()
+            .with_max_stack_size(16)
+            .with_program(Vec::<PushProgram>::from(plushy))
+            .unwrap()
+            .with_bool_input("a", true)
+            .with_bool_input("b", false)
+            // I'm reversing the order of the variables on purpose here to make sure
+            // that order doesn't matter.
+            .with_int_input("y", 8)
+            .with_int_input("x", 5)
+            .with_float_input("f", OrderedFloat(0.75))
+            .build();
+
+        let state = state.run_to_completion().unwrap();
+
+        assert!(state.exec.is_empty());
+        assert_eq!(&state.int, &vec![5, 17]);
+        assert_eq!(&state.bool, &vec![true, false]);
+        assert_eq!(&state.float, &vec![OrderedFloat(13.0)]);
+    }
+
+    #[test]
+    fn plural_inputs_match_singular_inputs() {
+        let singular = PushState::builder()
+            .with_max_stack_size(4)
+            .with_no_program()
+            .with_int_input("x", 5)
+            .with_int_input("y", 8)
+            .with_int_input("x", 13)
+            .with_float_input("f", OrderedFloat(0.75))
+            .with_float_input("g", OrderedFloat(-2.5))
+            .build();
+        let plural = PushState::builder()
+            .with_max_stack_size(4)
+            .with_no_program()
+            .with_int_inputs([("x", 5), ("y", 8), ("x", 13)])
+            .with_float_inputs([("f", OrderedFloat(0.75)), ("g", OrderedFloat(-2.5))])
+            .build();
+        assert_eq!(plural.input_instructions, singular.input_instructions);
+        assert_eq!(
+            plural.input_instructions[&VariableName::from("x")],
+            PushInstruction::push_int(13)
+        );
+    }
+
+    #[test]
+    fn seeded_rng_is_deterministic() {
+        fn run_with_seed(seed: u64) -> PushState {
+            let program: Vec<PushProgram> = vec_into![
+                PushInstruction::push_int(-1_000_000),
+                PushInstruction::push_int(1_000_000),
+                IntRandomInstruction::RandomInRange,
+                PushInstruction::push_int(0),
+                PushInstruction::push_int(1_000_000),
+                IntRandomInstruction::RandomInRange,
+                BoolInstruction::Random,
+                BoolInstruction::Random,
+                BoolInstruction::Random,
+            ];
+            PushState::builder()
+                .with_max_stack_size(16)
+                .with_program(program)
+                .unwrap()
+                .build()
+                .with_rng_seed(seed)
+                .run_to_completion()
+                .unwrap()
+        }
+
+        let first = run_with_seed(1234);
+        let second = run_with_seed(1234);
+
+        assert_eq!(first.int.size(), 2);
+        assert_eq!(first.bool.size(), 3);
+        assert_eq!(first.int, second.int);
+        assert_eq!(first.bool, second.bool);
+    }
+
+    #[test]
+    fn exec_growth_step_limit_is_hit_before_flat_limit() {
+        fn run_with_step_cost(
+            step_cost: StepCost,
+        ) -> Result<PushState, FatalError<PushState, PushInstructionError>> {
+            // Duplicating the block of 100 `Noop`s costs 1 step with a flat cost
+            // model, but 101 steps if we count the instructions pushed onto `Exec`.
+            let program = vec![
+                ExecInstruction::dup_block().into(),
+                PushProgram::Block(vec![ExecInstruction::noop().into(); 100]),
+            ];
+            PushState::builder()
+                .with_max_stack_size(1_000)
+                .with_program(program)
+                .unwrap()
+                .build()
+                .with_step_limit(300)
+                .with_step_cost(step_cost)
+                .run_to_completion()
+        }
+
+        // 1 (`DupBlock`) + 2 * (1 (block) + 100 (`Noop`s)) = 203 steps
+        assert!(run_with_step_cost(StepCost::Flat).unwrap().exec.is_empty());
+        // 101 (`DupBlock`) + 2 * (101 (block) + 100 (`Noop`s)) = 503 steps
+        assert_eq!(
+            run_with_step_cost(StepCost::ExecGrowth)
+                .unwrap_err()
+                .error(),
+            &PushInstructionError::StepLimitExceeded { step_limit: 300 }
+        );
+    }
+
+    #[test]
+    fn no_step_limit_by_default() {
+        let program = vec![
+            ExecInstruction::dup_block().into(),
+            PushProgram::Block(vec![ExecInstruction::noop().into(); 100]),
+        ];
+        let state = PushState::builder()
+            .with_max_stack_size(1_000)
+            .with_program(program)
+            .unwrap()
+            .build()
+            .with_step_cost(StepCost::ExecGrowth)
+            .run_to_completion()
+            .unwrap();
+        assert!(state.exec.is_empty());
+    }
+
+    fn noop_loop_state() -> PushState {
+        let program = vec![
+            ExecInstruction::dup_block().into(),
+            PushProgram::Block(vec![ExecInstruction::noop().into(); 100]),
+        ];
+        PushState::builder()
+            .with_max_stack_size(1_000)
+            .with_program(program)
+            .unwrap()
+            .build()
+    }
+
+    #[test]
+    fn unlimited_budget_terminates_normally() {
+        let (state, termination) = noop_loop_state()
+            .run_with_budget(Budget::unlimited())
+            .unwrap();
+        assert_eq!(termination, Termination::Normal);
+        assert!(state.exec.is_empty());
+    }
+
+    #[test]
+    fn step_budget_returns_partial_state() {
+        let (state, termination) = noop_loop_state()
+            .run_with_budget(Budget::unlimited().with_max_steps(50))
+            .unwrap();
+        assert_eq!(termination, Termination::StepLimit);
+        assert!(!state.exec.is_empty());
+    }
+
+    #[test]
+    fn time_budget_returns_partial_state() {
+        let budget = Budget::unlimited()
+            .with_max_duration(Duration::ZERO)
+            .with_time_check_interval(NonZeroUsize::MIN);
+        let (state, termination) = noop_loop_state().run_with_budget(budget).unwrap();
+        assert_eq!(termination, Termination::TimeLimit);
+        assert!(!state.exec.is_empty());
+    }
+
+    #[test]
+    fn steps_taken_counts_performed_items() {
+        let state = noop_loop_state();
+        assert_eq!(state.steps_taken(), 0);
+        let state = state.run_to_completion().unwrap();
+        // 1 (`DupBlock`) + 2 * (1 (block) + 100 (`Noop`s)) = 203 items
+        assert_eq!(state.steps_taken(), 203);
+
+        let state = noop_loop_state()
+            .with_step_cost(StepCost::ExecGrowth)
+            .run_to_completion()
+            .unwrap();
+        // 101 (`DupBlock`) + 2 * (101 (block) + 100 (`Noop`s)) = 503 steps
+        assert_eq!(state.steps_taken(), 503);
+    }
+
+    #[test]
+    fn steps_taken_goes_past_the_step_limit() {
+        let state = noop_loop_state().with_step_limit(50);
+        assert_eq!(state.step_limit(), Some(50));
+        let (state, termination) = state
+            .run_with_budget(Budget::unlimited().with_max_steps(50))
+            .unwrap();
+        assert_eq!(termination, Termination::StepLimit);
+        assert_eq!(state.steps_taken(), 51);
+    }
+
+    #[test]
+    fn single_stepping_a_program() {
+        let program: Vec<PushProgram> = vec![
+            PushInstruction::push_int(2).into(),
+            PushInstruction::push_int(3).into(),
+            IntInstruction::Add.into(),
+        ];
+        let state = PushState::builder()
+            .with_max_stack_size(4)
+            .with_program(program.clone())
+            .unwrap()
+            .build();
+
+        let (state, performed) = state.step().unwrap();
+        assert_eq!(performed.as_ref(), program.first());
+        assert_eq!(state.int, vec![2]);
+        let (state, performed) = state.step().unwrap();
+        assert_eq!(performed.as_ref(), program.get(1));
+        assert_eq!(state.int, vec![2, 3]);
+        let (state, performed) = state.step().unwrap();
+        assert_eq!(performed.as_ref(), program.get(2));
+        assert_eq!(state.int, vec![5]);
+        assert!(state.exec.is_empty());
+
+        let (state, performed) = state.step().unwrap();
+        assert_eq!(performed, None);
+        assert_eq!(state.int, vec![5]);
+    }
+
+    #[test]
+    fn run_n_steps_stops_when_exec_is_empty() {
+        let (state, num_performed) = noop_loop_state().run_n_steps(2).unwrap();
+        assert_eq!(num_performed, 2);
+        // `DupBlock` and the first copy of the block have been performed,
+        // leaving the 100 `Noop`s and the second copy of the block.
+        assert_eq!(state.exec.size(), 101);
+
+        let (state, num_performed) = state.run_n_steps(1_000).unwrap();
+        assert_eq!(num_performed, 201);
+        assert!(state.exec.is_empty());
+    }
+
+    // A `DupBlock` followed by `depth` nested blocks, each of which starts
+    // with a `DupBlock`, so the exec stack grows by one for each level of
+    // nesting as the program runs, reaching `depth + 3` items.
+    fn nested_dup_blocks(depth: usize) -> Vec<PushProgram> {
+        let block = (0..depth).fold(PushProgram::Block(vec![]), |block, _| {
+            PushProgram::Block(vec![ExecInstruction::dup_block().into(), block])
+        });
+        vec![ExecInstruction::dup_block().into(), block]
+    }
+
+    #[test]
+    fn exec_max_size_is_separate_from_other_stacks() {
+        let builder = PushState::builder()
+            .with_max_stack_size(1_000)
+            .with_max_exec_stack_size(10);
+        assert_eq!(builder.max_stack_size(), Some(1_000));
+        assert_eq!(builder.max_exec_stack_size(), Some(10));
+        let state = builder.with_no_program().build();
+        assert_eq!(state.exec.max_stack_size(), 10);
+        assert_eq!(state.int.max_stack_size(), 1_000);
+    }
+
+    #[test]
+    fn exec_overflow_stops_the_program() {
+        let state = PushState::builder()
+            .with_max_stack_size(1_000)
+            .with_program(nested_dup_blocks(12))
+            .unwrap()
+            .build();
+        let state = state.run_to_completion().unwrap();
+        assert!(state.exec.is_empty());
+
+        let state = PushState::builder()
+            .with_max_stack_size(1_000)
+            .with_max_exec_stack_size(10)
+            .with_program(nested_dup_blocks(12))
+            .unwrap()
+            .build();
+        let error = state.run_to_completion().unwrap_err();
+        assert!(matches!(
+            error.error(),
+            PushInstructionError::StackError(StackError::Overflow { .. })
+        ));
+        assert!(error.state().exec.size() <= 10);
+    }
+
+    #[test]
+    fn tracing_records_items_in_program_order() {
+        let program: Vec<PushProgram> = vec_into![
+            PushInstruction::push_int(3),
+            IntInstruction::Add,
+            PushInstruction::push_int(4),
+            IntInstruction::Multiply,
+        ];
+        let state = PushState::builder()
+            .with_max_stack_size(10)
+            .with_program(program.clone())
+            .unwrap()
+            .build()
+            .with_tracing(10)
+            .run_to_completion()
+            .unwrap();
+        let trace = state.trace().unwrap();
+        assert_eq!(
+            trace.iter().map(|entry| &entry.item).collect::<Vec<_>>(),
+            program.iter().collect::<Vec<_>>()
+        );
+        // `Add` only had one value to add, so it failed (recoverably).
+        assert_eq!(
+            trace.iter().map(|entry| entry.failed).collect::<Vec<_>>(),
+            [false, true, false, false]
+        );
+        let int_sizes = trace.iter().map(|entry| {
+            entry
+                .stack_sizes
+                .iter()
+                .find_map(|&(name, size)| (name == "int").then_some(size))
+                .unwrap()
+        });
+        assert_eq!(int_sizes.collect::<Vec<_>>(), [0, 1, 1, 2]);
+        let exec_sizes = trace.iter().map(|entry| entry.stack_sizes[0]);
+        assert_eq!(
+            exec_sizes.collect::<Vec<_>>(),
+            [("exec", 3), ("exec", 2), ("exec", 1), ("exec", 0)]
+        );
+    }
+
+    #[test]
+    fn tracing_keeps_only_the_most_recent_entries() {
+        let program: Vec<PushProgram> = vec_into![
+            PushInstruction::push_int(3),
+            PushInstruction::push_int(4),
+            IntInstruction::Add,
+            PushInstruction::push_int(5),
+        ];
+        let state = PushState::builder()
+            .with_max_stack_size(10)
+            .with_program(program.clone())
+            .unwrap()
+            .build()
+            .with_tracing(2)
+            .run_to_completion()
+            .unwrap();
+        let trace = state.trace().unwrap();
+        assert_eq!(trace.len(), 2);
+        assert_eq!(trace.num_dropped(), 2);
+        assert_eq!(
+            trace.iter().map(|entry| &entry.item).collect::<Vec<_>>(),
+            program[2..].iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn tracing_is_off_by_default() {
+        let state = PushState::builder()
+            .with_max_stack_size(10)
+            .with_program([PushProgram::from(PushInstruction::push_int(3))])
+            .unwrap()
+            .build()
+            .run_to_completion()
+            .unwrap();
+        assert_eq!(state.trace(), None);
+    }
+
+    #[test]
+    fn run_outcome_reports_fatal_errors() {
+        // Pushing a third value onto an `i64` stack that can only hold two
+        // is a fatal error.
+        let (state, outcome) = PushState::builder()
+            .with_max_stack_size(10)
+            .with_int_max_size(2)
+            .with_program(vec_into![<PushProgram>
+                PushInstruction::push_int(3),
+                PushInstruction::push_int(4),
+                PushInstruction::push_int(5),
+            ])
+            .unwrap()
+            .build()
+            .run_to_outcome();
+        assert_eq!(
+            outcome,
+            RunOutcome::FatalError(PushInstructionError::StackError(StackError::Overflow {
+                stack_type: "i64"
+            }))
+        );
+        assert_eq!(state.int, vec![3, 4]);
+    }
+
+    #[test]
+    fn run_outcome_reports_step_limit() {
+        let (_, outcome) = noop_loop_state().with_step_limit(20).run_to_outcome();
+        assert_eq!(outcome, RunOutcome::StepLimitReached { step_limit: 20 });
+    }
+
+    #[test]
+    fn recoverable_errors_are_counted() {
+        let (state, outcome) = PushState::builder()
+            .with_max_stack_size(10)
+            .with_program(vec_into![<PushProgram>
+                IntInstruction::Add,
+                PushInstruction::push_int(3),
+                IntInstruction::Add,
+                PushInstruction::push_int(4),
+                IntInstruction::Add,
+            ])
+            .unwrap()
+            .build()
+            .run_to_outcome();
+        assert!(outcome.is_completed());
+        assert_eq!(state.recoverable_errors(), 2);
+        assert_eq!(state.int, vec![7]);
+    }
+
+    #[test]
+    fn stack_summary_lists_every_stack() {
+        let state = PushState::builder()
+            .with_max_stack_size(10)
+            .with_int_values([1, 2, 3])
+            .unwrap()
+            .with_bool_values([true])
+            .unwrap()
+            .with_float_values([OrderedFloat(1.5), OrderedFloat(2.5)])
+            .unwrap()
+            .with_no_program()
+            .build();
+        assert_eq!(
+            state.stack_summary(),
+            [
+                ("exec", 0),
+                ("bool", 1),
+                ("char", 0),
+                ("float", 2),
+                ("float32", 0),
+                ("int", 3),
+                ("int_vector", 0),
+                ("string", 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn builder_reports_max_stack_size() {
+        let builder = PushState::builder();
+        assert_eq!(builder.max_stack_size(), None);
+        let builder = builder.with_max_stack_size(12);
+        assert_eq!(builder.max_stack_size(), Some(12));
+        let builder = builder.with_no_program();
+        assert_eq!(builder.max_stack_size(), Some(12));
+    }
+
+    #[test]
+    fn cleared_values_empty_the_stack() {
+        let state = PushState::builder()
+            .with_max_stack_size(8)
+            .with_no_program()
+            .with_int_values([1, 2, 3])
+            .unwrap()
+            .with_bool_values([true])
+            .unwrap()
+            .with_cleared_int_values()
+            .with_int_values([4])
+            .unwrap()
+            .with_cleared_bool_values()
+            .build();
+        assert_eq!(&state.int, &vec![4]);
+        assert!(state.bool.is_empty());
+        assert_eq!(state.bool.max_stack_size(), 8);
+    }
+
+    #[test]
+    fn program_longer_than_exec_stack_is_an_error() {
+        let program: Vec<PushProgram> = vec![ExecInstruction::noop().into(); 3];
+        let result = PushState::builder()
+            .with_max_stack_size(2)
+            .with_program(program);
+        assert_eq!(result.err(), Some(ProgramTooLong { length: 3, max: 2 }));
+    }
+}
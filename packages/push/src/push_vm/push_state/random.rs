@@ -0,0 +1,183 @@
+use std::ops::RangeInclusive;
+
+use ordered_float::OrderedFloat;
+use rand::{distributions::Alphanumeric, prelude::Distribution, Rng};
+
+use super::PushState;
+use crate::push_vm::{
+    program::PushProgram,
+    stack::{Stack, VisitStackMut},
+};
+
+/// A distribution of [`PushState`]s with random values on each of the
+/// (non-exec) stacks, for property testing instructions on many different
+/// states, including edge cases like empty and full stacks.
+///
+/// Each stack gets a number of values chosen uniformly from `stack_sizes`
+/// (capped at `max_stack_size`), and the `Exec` stack is left empty. Values
+/// are drawn from the whole range of each type (e.g., any `i64`, so sums can
+/// overflow), except for floats, which are in `-1e6..1e6`, and strings and
+/// vectors, which have up to `max_stack_size` alphanumeric characters or
+/// integers.
+///
+/// Sampling with a seeded RNG gives the same state every time:
+///
+/// ```
+/// # use push::push_vm::{push_state::random::RandomPushState, HasStack};
+/// # use rand::{prelude::Distribution, rngs::StdRng, SeedableRng};
+/// let states = RandomPushState::new(10, 0..=10);
+/// let state = states.sample(&mut StdRng::seed_from_u64(0));
+/// assert_eq!(state, states.sample(&mut StdRng::seed_from_u64(0)));
+/// assert!(state.stack::<i64>().size() <= 10);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RandomPushState {
+    max_stack_size: usize,
+    stack_sizes: RangeInclusive<usize>,
+}
+
+impl RandomPushState {
+    #[must_use]
+    pub const fn new(max_stack_size: usize, stack_sizes: RangeInclusive<usize>) -> Self {
+        Self {
+            max_stack_size,
+            stack_sizes,
+        }
+    }
+
+    fn stack_size<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        let min = (*self.stack_sizes.start()).min(self.max_stack_size);
+        let max = (*self.stack_sizes.end()).min(self.max_stack_size);
+        if min >= max {
+            max
+        } else {
+            rng.gen_range(min..=max)
+        }
+    }
+}
+
+// The random values to fill each type of stack with, where `max_length` is
+// the maximum length of strings and vectors. `None` means the stack is left
+// empty.
+trait RandomValue: Sized {
+    fn random<R: Rng + ?Sized>(rng: &mut R, max_length: usize) -> Option<Self>;
+}
+
+impl RandomValue for i64 {
+    fn random<R: Rng + ?Sized>(rng: &mut R, _: usize) -> Option<Self> {
+        Some(rng.gen())
+    }
+}
+
+impl RandomValue for OrderedFloat<f64> {
+    fn random<R: Rng + ?Sized>(rng: &mut R, _: usize) -> Option<Self> {
+        Some(Self(rng.gen_range(-1e6..1e6)))
+    }
+}
+
+impl RandomValue for OrderedFloat<f32> {
+    fn random<R: Rng + ?Sized>(rng: &mut R, _: usize) -> Option<Self> {
+        Some(Self(rng.gen_range(-1e6..1e6)))
+    }
+}
+
+impl RandomValue for bool {
+    fn random<R: Rng + ?Sized>(rng: &mut R, _: usize) -> Option<Self> {
+        Some(rng.gen())
+    }
+}
+
+impl RandomValue for char {
+    fn random<R: Rng + ?Sized>(rng: &mut R, _: usize) -> Option<Self> {
+        Some(Self::from(rng.sample(Alphanumeric)))
+    }
+}
+
+impl RandomValue for String {
+    fn random<R: Rng + ?Sized>(rng: &mut R, max_length: usize) -> Option<Self> {
+        let length = rng.gen_range(0..=max_length);
+        Some(
+            (0..length)
+                .map(|_| char::from(rng.sample(Alphanumeric)))
+                .collect(),
+        )
+    }
+}
+
+impl RandomValue for Vec<i64> {
+    fn random<R: Rng + ?Sized>(rng: &mut R, max_length: usize) -> Option<Self> {
+        let length = rng.gen_range(0..=max_length);
+        Some((0..length).map(|_| rng.gen()).collect())
+    }
+}
+
+// The `Exec` stack is left empty.
+impl RandomValue for PushProgram {
+    fn random<R: Rng + ?Sized>(_: &mut R, _: usize) -> Option<Self> {
+        None
+    }
+}
+
+// Fills each stack it visits with random values.
+struct Fill<'a, R: ?Sized> {
+    states: &'a RandomPushState,
+    rng: &'a mut R,
+}
+
+impl<T, R> VisitStackMut<T> for Fill<'_, R>
+where
+    T: RandomValue,
+    R: Rng + ?Sized,
+{
+    fn visit_mut(&mut self, _: &'static str, stack: &mut Stack<T>) {
+        let size = self
+            .states
+            .stack_size(self.rng)
+            .min(stack.remaining_capacity());
+        let max_length = self.states.max_stack_size;
+        let values = (0..size)
+            .map_while(|_| T::random(self.rng, max_length))
+            .collect::<Vec<_>>();
+        // `values` fits in `stack`, so this can't overflow.
+        let result = stack.try_extend(values);
+        debug_assert!(result.is_ok());
+    }
+}
+
+impl Distribution<PushState> for RandomPushState {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PushState {
+        let mut state = PushState::builder()
+            .with_max_stack_size(self.max_stack_size)
+            .with_no_program()
+            .build();
+        state.visit_stacks_mut(&mut Fill { states: self, rng });
+        state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn stack_sizes_are_in_range() {
+        let states = RandomPushState::new(5, 2..=8);
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..100 {
+            let state = states.sample(&mut rng);
+            for size in [state.int.size(), state.bool.size(), state.string.size()] {
+                assert!((2..=5).contains(&size), "{size}");
+            }
+            assert!(state.exec.is_empty());
+        }
+    }
+
+    #[test]
+    fn full_stacks() {
+        let state = RandomPushState::new(3, 3..=3).sample(&mut StdRng::seed_from_u64(0));
+        assert!(state.int.is_full());
+        assert!(state.int_vector.is_full());
+    }
+}
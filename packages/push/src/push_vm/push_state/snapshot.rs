@@ -0,0 +1,203 @@
+//! Snapshots of a [`PushState`]'s stacks, and diffs between them, for
+//! checking exactly which stacks an instruction changed.
+//!
+//! Take a [`PushState::snapshot`] before performing an instruction, and
+//! [`Snapshot::diff`] it with a snapshot taken afterwards; e.g., performing
+//! `IntInstruction::Add` gives a diff saying that only the `int` stack
+//! shrank, by one.
+//!
+//! This is only available in tests and with the `debug` feature.
+
+use std::{any::Any, fmt::Debug};
+
+use super::PushState;
+use crate::push_vm::stack::{Stack, VisitStack};
+
+/// A copy of every stack in a [`PushState`] at some point in a run. The
+/// inputs, output channels, and other configuration aren't included.
+#[derive(Debug)]
+pub struct Snapshot {
+    // Each stack's name and a copy of it, in the order `visit_stacks`
+    // visits them.
+    stacks: Vec<(&'static str, Box<dyn SnapshotStack>)>,
+}
+
+// A copy of a stack of any type, which can be compared to an earlier copy of
+// the same stack.
+trait SnapshotStack: Debug {
+    fn as_any(&self) -> &dyn Any;
+
+    fn change_since(&self, before: &dyn SnapshotStack) -> Option<StackChange>;
+}
+
+impl<T> SnapshotStack for Stack<T>
+where
+    T: PartialEq + Debug + 'static,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn change_since(&self, before: &dyn SnapshotStack) -> Option<StackChange> {
+        // Snapshots of the same kind of state always have the same types of
+        // stacks in the same order, so the downcast only fails if `before`
+        // is from some other kind of state.
+        before
+            .as_any()
+            .downcast_ref::<Self>()
+            .map_or(Some(StackChange::Changed), |before| {
+                StackChange::between(before, self)
+            })
+    }
+}
+
+impl<T> VisitStack<T> for Snapshot
+where
+    T: Clone + PartialEq + Debug + 'static,
+{
+    fn visit(&mut self, name: &'static str, stack: &Stack<T>) {
+        self.stacks.push((name, Box::new(stack.clone())));
+    }
+}
+
+/// How a single stack differs between two [`Snapshot`]s.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum StackChange {
+    /// The stack has `by` more items than before.
+    Grew { by: usize },
+    /// The stack has `by` fewer items than before.
+    Shrank { by: usize },
+    /// The stack has the same number of items as before, but some of them
+    /// are different.
+    Changed,
+}
+
+impl StackChange {
+    fn between<T: PartialEq>(before: &Stack<T>, after: &Stack<T>) -> Option<Self> {
+        let (before_size, after_size) = (before.size(), after.size());
+        if after_size > before_size {
+            Some(Self::Grew {
+                by: after_size.abs_diff(before_size),
+            })
+        } else if after_size < before_size {
+            Some(Self::Shrank {
+                by: after_size.abs_diff(before_size),
+            })
+        } else if after == before {
+            None
+        } else {
+            Some(Self::Changed)
+        }
+    }
+}
+
+/// The stacks that differ between two [`Snapshot`]s, and how, named as in
+/// [`PushState`] (e.g., `"int"` or `"exec"`). Stacks that didn't change are
+/// left out.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct StateDiff {
+    changes: Vec<(&'static str, StackChange)>,
+}
+
+impl StateDiff {
+    /// The stacks that changed, in the order
+    /// [`PushState::stack_summary`] lists them.
+    #[must_use]
+    pub fn changes(&self) -> &[(&'static str, StackChange)] {
+        &self.changes
+    }
+
+    /// How the stack named `stack` changed, or `None` if it didn't.
+    #[must_use]
+    pub fn get(&self, stack: &str) -> Option<StackChange> {
+        self.changes
+            .iter()
+            .find(|(name, _)| *name == stack)
+            .map(|&(_, change)| change)
+    }
+
+    /// Returns `true` if no stack changed.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl Snapshot {
+    /// How the stacks in this snapshot differ from those in the earlier
+    /// snapshot `before`.
+    #[must_use]
+    pub fn diff(&self, before: &Self) -> StateDiff {
+        let changes = self
+            .stacks
+            .iter()
+            .zip(&before.stacks)
+            .filter_map(|((name, after), (_, before))| {
+                Some((*name, after.change_since(before.as_ref())?))
+            })
+            .collect();
+        StateDiff { changes }
+    }
+}
+
+impl PushState {
+    /// Copies all the stacks, so they can later be compared (with
+    /// [`Snapshot::diff`]) to the stacks after performing some instructions.
+    #[must_use]
+    pub fn snapshot(&self) -> Snapshot {
+        let mut snapshot = Snapshot { stacks: Vec::new() };
+        self.visit_stacks(&mut snapshot);
+        snapshot
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The test values fit within the stack limits"
+)]
+mod tests {
+    use super::*;
+    use crate::instruction::{BoolInstruction, Instruction, IntInstruction, PushInstruction};
+
+    #[test]
+    fn add_only_shrinks_the_int_stack() {
+        let state = PushState::builder()
+            .with_max_stack_size(4)
+            .with_int_values([5, 8])
+            .unwrap()
+            .with_bool_values([true])
+            .unwrap()
+            .with_no_program()
+            .build();
+        let before = state.snapshot();
+        let state = IntInstruction::Add.perform(state).unwrap();
+        let diff = state.snapshot().diff(&before);
+        assert_eq!(diff.changes(), [("int", StackChange::Shrank { by: 1 })]);
+        assert_eq!(diff.get("bool"), None);
+    }
+
+    #[test]
+    fn reports_growth_and_changes() {
+        let state = PushState::builder()
+            .with_max_stack_size(4)
+            .with_int_values([5, 8])
+            .unwrap()
+            .with_bool_values([true])
+            .unwrap()
+            .with_no_program()
+            .build();
+        let before = state.snapshot();
+        let state = BoolInstruction::Not.perform(state).unwrap();
+        let state = PushInstruction::push_int(3).perform(state).unwrap();
+        let diff = state.snapshot().diff(&before);
+        assert_eq!(
+            diff.changes(),
+            [
+                ("bool", StackChange::Changed),
+                ("int", StackChange::Grew { by: 1 })
+            ]
+        );
+        assert!(state.snapshot().diff(&state.snapshot()).is_empty());
+    }
+}
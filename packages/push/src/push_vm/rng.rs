@@ -0,0 +1,41 @@
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+/// A state that can provide random values to instructions that need them,
+/// e.g., [`IntRandomInstruction::RandomInRange`](crate::instruction::IntRandomInstruction::RandomInRange).
+pub trait HasRng {
+    /// Calls `f` with this state's random number generator, returning
+    /// whatever `f` returns.
+    fn using_rng<T>(&mut self, f: impl FnOnce(&mut dyn RngCore) -> T) -> T;
+}
+
+/// An optional, seedable random number generator for a state.
+///
+/// If no generator has been set (the default), then
+/// [`rand::thread_rng()`] is used, so "random" instructions will give
+/// different results on every run. Setting a generator (e.g., with
+/// [`OptionalRng::seeded`]) makes the results reproducible.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct OptionalRng(Option<StdRng>);
+
+impl OptionalRng {
+    /// Create a new `OptionalRng` using a [`StdRng`] seeded with `seed`.
+    #[must_use]
+    pub fn seeded(seed: u64) -> Self {
+        Self(Some(StdRng::seed_from_u64(seed)))
+    }
+}
+
+impl From<StdRng> for OptionalRng {
+    fn from(rng: StdRng) -> Self {
+        Self(Some(rng))
+    }
+}
+
+impl HasRng for OptionalRng {
+    fn using_rng<T>(&mut self, f: impl FnOnce(&mut dyn RngCore) -> T) -> T {
+        match &mut self.0 {
+            Some(rng) => f(rng),
+            None => f(&mut rand::thread_rng()),
+        }
+    }
+}
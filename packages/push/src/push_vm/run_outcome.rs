@@ -0,0 +1,28 @@
+use crate::instruction::instruction_error::PushInstructionError;
+
+/// How a run of a program ended; see
+/// [`PushState::run_to_outcome`](super::push_state::PushState::run_to_outcome).
+///
+/// Unlike the `Result` from `run_to_completion`, this separates hitting the
+/// step limit from other fatal errors, and strips the
+/// [`InstructionFailed`](PushInstructionError::InstructionFailed) context
+/// from fatal errors, so scorers can easily penalize different outcomes
+/// differently.
+#[derive(Debug, Eq, PartialEq)]
+pub enum RunOutcome {
+    /// The program ran to completion, i.e., the `Exec` stack is empty.
+    Completed,
+    /// The run was stopped because it exceeded the state's step limit.
+    StepLimitReached { step_limit: usize },
+    /// The run was stopped by a fatal error, e.g., a stack overflow. This is
+    /// the error the failing instruction returned (its
+    /// [`root_cause`](PushInstructionError::root_cause)).
+    FatalError(PushInstructionError),
+}
+
+impl RunOutcome {
+    #[must_use]
+    pub const fn is_completed(&self) -> bool {
+        matches!(self, Self::Completed)
+    }
+}
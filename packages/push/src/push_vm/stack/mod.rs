@@ -1,7 +1,14 @@
 use collectable::TryExtend;
 
+pub use self::{
+    transactional::{Transaction, Transactional},
+    visitor::{VisitStack, VisitStackMut},
+};
 use crate::error::{Error, InstructionResult, MapInstructionError};
 
+pub mod transactional;
+pub mod visitor;
+
 pub trait TypeEq {
     type This: ?Sized;
 }
@@ -191,11 +198,30 @@ impl<A> TryExtend<A> for Stack<A> {
         // works) in the `self.extend()` call. We can't add those constraints
         // here because we're implementing their `TryExtend`, which doesn't
         // include those constraints.
-        #[allow(clippy::needless_collect)]
         self.try_extend(iter.into_iter().collect::<Vec<_>>())
     }
 }
 
+/// Iterates over the elements of the stack from the top to the bottom, like
+/// [`Stack::iter`].
+impl<T> IntoIterator for Stack<T> {
+    type Item = T;
+    type IntoIter = std::iter::Rev<std::vec::IntoIter<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_iter().rev()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Stack<T> {
+    type Item = &'a T;
+    type IntoIter = std::iter::Rev<std::slice::Iter<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.iter().rev()
+    }
+}
+
 /// Stack
 ///
 /// It's critical that all mutating stack operations be "transactional" in
@@ -207,7 +233,7 @@ impl<T> Stack<T> {
     /// Sets the maximum size for this stack. Attempts to add elements that
     /// would take the stack above this size should return
     /// `StackError::Overflow`.
-    pub fn set_max_stack_size(&mut self, max_stack_size: usize) {
+    pub const fn set_max_stack_size(&mut self, max_stack_size: usize) {
         self.max_stack_size = max_stack_size;
     }
 
@@ -219,20 +245,36 @@ impl<T> Stack<T> {
 
     /// Returns the size of this stack.
     #[must_use]
-    pub fn size(&self) -> usize {
+    pub const fn size(&self) -> usize {
         self.values.len()
     }
 
     /// Returns `true` if the stack contains no elements.
     #[must_use]
-    pub fn is_empty(&self) -> bool {
+    pub const fn is_empty(&self) -> bool {
         self.values.is_empty()
     }
 
     /// Returns `true` if the stack has `max_stack_size()` elements.
     #[must_use]
-    pub fn is_full(&self) -> bool {
-        self.size() == self.max_stack_size
+    pub const fn is_full(&self) -> bool {
+        self.remaining_capacity() == 0
+    }
+
+    /// Returns how many more elements can be added to this stack before it
+    /// is full. This is 0 if the stack is full, or if the maximum size was
+    /// lowered below the current size.
+    #[must_use]
+    pub const fn remaining_capacity(&self) -> usize {
+        self.max_stack_size.saturating_sub(self.size())
+    }
+
+    /// Returns `true` if `n` more elements can be added to this stack without
+    /// exceeding `max_stack_size()`, i.e., if pushing them (e.g., with
+    /// [`Stack::try_extend`]) wouldn't return [`StackError::Overflow`].
+    #[must_use]
+    pub const fn can_accept(&self, n: usize) -> bool {
+        n <= self.remaining_capacity()
     }
 
     /// Returns a reference to the top value on this stack, or
@@ -248,6 +290,21 @@ impl<T> Stack<T> {
         })
     }
 
+    /// Returns an iterator over (references to) the top `n` elements of the
+    /// stack, starting with the top element. If the stack has fewer than `n`
+    /// elements, this iterates over all of them.
+    pub fn iter_top(&self, n: usize) -> impl Iterator<Item = &T> {
+        self.values.iter().rev().take(n)
+    }
+
+    /// Returns an iterator over (references to) all the elements of the
+    /// stack, from the top (the element [`top`](Self::top) returns) to the
+    /// bottom. Iterating over a `&Stack` does the same.
+    #[must_use]
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        self.values.iter().rev()
+    }
+
     /// Returns a pair of references to the top two elements of
     /// the stack, or an error if the stack has less than two
     /// elements.
@@ -257,10 +314,13 @@ impl<T> Stack<T> {
     /// Returns `StackError::Underflow` error if the stack has less than
     /// two elements.
     pub fn top2(&self) -> Result<(&T, &T), StackError> {
-        let index_second_to_top = self.size().checked_sub(2).ok_or(StackError::Underflow {
-            num_requested: 2,
-            num_present: self.size(),
-        })?;
+        let index_second_to_top =
+            self.size()
+                .checked_sub(2)
+                .ok_or_else(|| StackError::Underflow {
+                    num_requested: 2,
+                    num_present: self.size(),
+                })?;
         let x = self.top()?;
         let y = self
             .values
@@ -272,6 +332,63 @@ impl<T> Stack<T> {
         Ok((x, y))
     }
 
+    /// Returns a reference to the element `n` places below the top of the
+    /// stack, so `nth(0)` is the top element.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StackError::Underflow` if the stack has `n` or fewer
+    /// elements.
+    pub fn nth(&self, n: usize) -> Result<&T, StackError> {
+        let index = self.index_from_top(n)?;
+        self.values.get(index).ok_or_else(|| StackError::Underflow {
+            num_requested: n.saturating_add(1),
+            num_present: self.size(),
+        })
+    }
+
+    /// Moves the element `n` places below the top of the stack to the top,
+    /// so the `n` elements that were above it each move down one place.
+    /// `move_to_top(0)` leaves the stack unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StackError::Underflow` if the stack has `n` or fewer
+    /// elements, leaving the stack unchanged.
+    pub fn move_to_top(&mut self, n: usize) -> Result<(), StackError> {
+        let index = self.index_from_top(n)?;
+        let value = self.values.remove(index);
+        self.values.push(value);
+        Ok(())
+    }
+
+    /// Moves the top element of the stack down `n` places, so the `n`
+    /// elements that were below it each move up one place. This undoes
+    /// [`Stack::move_to_top`] with the same `n`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StackError::Underflow` if the stack has `n` or fewer
+    /// elements, leaving the stack unchanged.
+    pub fn move_top_down(&mut self, n: usize) -> Result<(), StackError> {
+        let index = self.index_from_top(n)?;
+        if let Some(value) = self.values.pop() {
+            self.values.insert(index, value);
+        }
+        Ok(())
+    }
+
+    // The index in `values` of the element `n` places below the top.
+    fn index_from_top(&self, n: usize) -> Result<usize, StackError> {
+        self.size()
+            .checked_sub(n)
+            .and_then(|size| size.checked_sub(1))
+            .ok_or_else(|| StackError::Underflow {
+                num_requested: n.saturating_add(1),
+                num_present: self.size(),
+            })
+    }
+
     /// Removes the top element from a stack and returns it, or
     /// `StackError::Underflow` if it is empty.
     ///
@@ -324,7 +441,7 @@ impl<T> Stack<T> {
         }
         for _ in 0..num_to_discard {
             match self.pop() {
-                Ok(_) => continue,
+                Ok(_) => {}
                 Err(error) => {
                     return Err(error);
                 }
@@ -333,6 +450,12 @@ impl<T> Stack<T> {
         Ok(())
     }
 
+    /// Removes all the elements from the stack, leaving its maximum size
+    /// unchanged.
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+
     /// Pushes `value` onto the top of the stack, returning
     /// `StackError::StackOverflow` if doing so would exceed the
     /// `max_stack_size()` for this stack.
@@ -343,13 +466,13 @@ impl<T> Stack<T> {
     /// pushing on `value` would cause the stack size to exceed
     /// `max_stack_size()`.
     pub fn push(&mut self, value: T) -> Result<(), StackError> {
-        if self.size() == self.max_stack_size {
+        if self.can_accept(1) {
+            self.values.push(value);
+            Ok(())
+        } else {
             Err(StackError::Overflow {
                 stack_type: std::any::type_name::<T>(),
             })
-        } else {
-            self.values.push(value);
-            Ok(())
         }
     }
 
@@ -403,12 +526,7 @@ impl<T> Stack<T> {
         I::IntoIter: ExactSizeIterator + DoubleEndedIterator,
     {
         let iter = iter.into_iter();
-        // Check that adding these items won't overflow the stack.
-        if !iter
-            .len()
-            .checked_add(self.size())
-            .is_some_and(|x| x <= self.max_stack_size)
-        {
+        if !self.can_accept(iter.len()) {
             return Err(StackError::Overflow {
                 stack_type: std::any::type_name::<T>(),
             });
@@ -542,11 +660,14 @@ where
 // correct `Underflow` and `Overflow` errors.
 
 #[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The test stacks hold enough values for every `top` and `pop` that's unwrapped"
+)]
 mod test {
     use super::{Stack, StackError};
 
     #[test]
-    #[allow(clippy::unwrap_used)]
     fn top_from_empty_fails() {
         let stack: Stack<bool> = Stack::default();
         let result = stack.top().unwrap_err();
@@ -558,4 +679,78 @@ mod test {
             }
         );
     }
+
+    #[test]
+    fn nth_from_top() {
+        let mut stack: Stack<i64> = Stack::default();
+        stack.try_extend([1, 2, 3]).unwrap();
+        assert_eq!(stack.nth(0), Ok(&1));
+        assert_eq!(stack.nth(2), Ok(&3));
+        assert_eq!(
+            stack.nth(3),
+            Err(StackError::Underflow {
+                num_requested: 4,
+                num_present: 3
+            })
+        );
+    }
+
+    #[test]
+    fn move_to_top_and_back() {
+        let mut stack: Stack<i64> = Stack::default();
+        stack.try_extend([1, 2, 3, 4]).unwrap();
+        stack.move_to_top(2).unwrap();
+        assert_eq!(stack.iter_top(4).copied().collect::<Vec<_>>(), [3, 1, 2, 4]);
+        stack.move_top_down(2).unwrap();
+        assert_eq!(stack.iter_top(4).copied().collect::<Vec<_>>(), [1, 2, 3, 4]);
+        assert!(stack.move_to_top(4).is_err());
+        assert!(stack.move_top_down(4).is_err());
+        assert_eq!(stack.iter_top(4).copied().collect::<Vec<_>>(), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn iteration_is_top_to_bottom() {
+        let mut stack: Stack<i64> = Stack::default();
+        stack.try_extend([1, 2, 3]).unwrap();
+        stack.try_extend([4, 5]).unwrap();
+        assert_eq!(stack.top(), Ok(&4));
+        assert_eq!(stack.iter().copied().collect::<Vec<_>>(), [4, 5, 1, 2, 3]);
+        assert!(IntoIterator::into_iter(&stack).eq(stack.iter()));
+        assert_eq!(stack.into_iter().collect::<Vec<_>>(), [4, 5, 1, 2, 3]);
+    }
+
+    #[test]
+    fn remaining_capacity_with_unbounded_stack() {
+        let mut stack: Stack<i64> = Stack::default();
+        assert_eq!(stack.max_stack_size(), usize::MAX);
+        assert_eq!(stack.remaining_capacity(), usize::MAX);
+        stack.try_extend([1, 2, 3]).unwrap();
+        assert_eq!(stack.remaining_capacity(), usize::MAX - 3);
+        assert!(stack.can_accept(usize::MAX - 3));
+        assert!(!stack.can_accept(usize::MAX));
+    }
+
+    #[test]
+    fn capacity_checks_match_push_and_extend() {
+        let mut stack: Stack<i64> = Stack::default();
+        stack.set_max_stack_size(3);
+        stack.try_extend([1, 2]).unwrap();
+        assert_eq!(stack.remaining_capacity(), 1);
+        assert!(!stack.can_accept(2));
+        assert!(stack.try_extend([3, 4]).is_err());
+        assert!(stack.can_accept(1));
+        stack.push(3).unwrap();
+        assert!(stack.is_full());
+        assert!(!stack.can_accept(1));
+        assert_eq!(
+            stack.push(4),
+            Err(StackError::Overflow {
+                stack_type: std::any::type_name::<i64>()
+            })
+        );
+        // Lowering the maximum size below the current size leaves no room.
+        stack.set_max_stack_size(1);
+        assert_eq!(stack.remaining_capacity(), 0);
+        assert!(stack.push(4).is_err());
+    }
 }
@@ -0,0 +1,236 @@
+use super::{HasStack, StackError};
+
+/// A guard that pops values from any of a state's stacks, and puts them all
+/// back (in the right order) when it's dropped unless
+/// [`Transaction::commit`] has been called.
+///
+/// This lets an instruction take its arguments from several stacks without
+/// leaving the state half-changed if a later pop fails; without it, an
+/// instruction that needs a `bool` and two `Exec` blocks could pop the
+/// `bool`, find only one block, and have to remember to push the `bool`
+/// back.
+///
+/// Popped values are cloned so that they can be restored, so this is best
+/// suited to cheap-to-clone values. Most uses go through
+/// [`Transactional::transaction`], which commits or rolls back based on the
+/// result of a closure.
+#[must_use]
+pub struct Transaction<'s, S> {
+    state: &'s mut S,
+    // Undo actions for each pop so far, in the order the pops happened.
+    undo: Vec<Undo<'s, S>>,
+}
+
+// Puts one popped value back onto its stack.
+type Undo<'s, S> = Box<dyn FnOnce(&mut S) + 's>;
+
+impl<'s, S> Transaction<'s, S> {
+    pub fn new(state: &'s mut S) -> Self {
+        Self {
+            state,
+            undo: Vec::new(),
+        }
+    }
+
+    /// Pops the top value from the `T` stack.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StackError::Underflow` if the stack is empty; nothing is
+    /// popped in that case.
+    pub fn pop<T>(&mut self) -> Result<T, StackError>
+    where
+        S: HasStack<T>,
+        T: Clone + 's,
+    {
+        let value = self.state.stack_mut::<T>().pop()?;
+        self.record_pops(vec![value.clone()]);
+        Ok(value)
+    }
+
+    /// Pops the top two values from the `T` stack, in the same order as
+    /// [`Stack::pop2`](super::Stack::pop2).
+    ///
+    /// # Errors
+    ///
+    /// Returns `StackError::Underflow` if the stack has fewer than two
+    /// values; nothing is popped in that case.
+    pub fn pop2<T>(&mut self) -> Result<(T, T), StackError>
+    where
+        S: HasStack<T>,
+        T: Clone + 's,
+    {
+        let (x, y) = self.state.stack_mut::<T>().pop2()?;
+        self.record_pops(vec![x.clone(), y.clone()]);
+        Ok((x, y))
+    }
+
+    /// Pops the top `n` values from the `T` stack, with the top of the stack
+    /// first.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StackError::Underflow` if the stack has fewer than `n`
+    /// values; nothing is popped in that case.
+    pub fn pop_n<T>(&mut self, n: usize) -> Result<Vec<T>, StackError>
+    where
+        S: HasStack<T>,
+        T: Clone + 's,
+    {
+        let stack = self.state.stack_mut::<T>();
+        if stack.size() < n {
+            return Err(StackError::Underflow {
+                num_requested: n,
+                num_present: stack.size(),
+            });
+        }
+        let values = (0..n).map(|_| stack.pop()).collect::<Result<Vec<_>, _>>()?;
+        self.record_pops(values.clone());
+        Ok(values)
+    }
+
+    /// Keeps all the pops made so far.
+    pub fn commit(mut self) {
+        self.undo.clear();
+    }
+
+    /// Puts back everything popped so far. This is what happens when a
+    /// transaction is dropped without being committed.
+    pub fn rollback(self) {}
+
+    // `values` were popped from the `T` stack, top first, so they're pushed
+    // back in reverse.
+    fn record_pops<T>(&mut self, values: Vec<T>)
+    where
+        S: HasStack<T>,
+        T: 's,
+    {
+        self.undo.push(Box::new(move |state: &mut S| {
+            let stack = state.stack_mut::<T>();
+            for value in values.into_iter().rev() {
+                // These values were just popped from this stack, so there's
+                // always room to push them back.
+                let _ = stack.push(value);
+            }
+        }));
+    }
+}
+
+impl<S> Drop for Transaction<'_, S> {
+    fn drop(&mut self) {
+        while let Some(undo) = self.undo.pop() {
+            undo(self.state);
+        }
+    }
+}
+
+pub trait Transactional: Sized {
+    /// Runs `f` with a [`Transaction`] on this state. If `f` returns an error,
+    /// every value it popped is restored, so the state is unchanged;
+    /// otherwise the pops are committed.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error returned by `f`.
+    fn transaction<R, E, F>(&mut self, f: F) -> Result<R, E>
+    where
+        F: FnOnce(&mut Transaction<'_, Self>) -> Result<R, E>;
+}
+
+impl<S> Transactional for S {
+    fn transaction<R, E, F>(&mut self, f: F) -> Result<R, E>
+    where
+        F: FnOnce(&mut Transaction<'_, Self>) -> Result<R, E>,
+    {
+        let mut transaction = Transaction::new(self);
+        let result = f(&mut transaction);
+        if result.is_ok() {
+            transaction.commit();
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::unwrap_used,
+    reason = "The test stacks hold enough values for the pops that are unwrapped"
+)]
+mod tests {
+    use super::*;
+    use crate::{
+        instruction::PushInstruction,
+        push_vm::{program::PushProgram, push_state::PushState},
+    };
+
+    fn state(bools: Vec<bool>, programs: Vec<PushProgram>) -> PushState {
+        PushState::builder()
+            .with_max_stack_size(4)
+            .with_bool_values(bools)
+            .unwrap()
+            .with_program(programs)
+            .unwrap()
+            .with_int_values([1, 2, 3])
+            .unwrap()
+            .build()
+    }
+
+    fn block(i: i64) -> PushProgram {
+        PushProgram::Instruction(PushInstruction::push_int(i))
+    }
+
+    #[test]
+    fn failed_pop_restores_earlier_pops() {
+        let mut state = state(vec![true, false], vec![block(1)]);
+        let original = state.clone();
+        let result = state.transaction(|tx| {
+            let condition = tx.pop::<bool>()?;
+            let ints = tx.pop_n::<i64>(2)?;
+            let blocks = tx.pop2::<PushProgram>()?;
+            Ok::<_, StackError>((condition, ints, blocks))
+        });
+        assert_eq!(
+            result,
+            Err(StackError::Underflow {
+                num_requested: 2,
+                num_present: 1
+            })
+        );
+        assert_eq!(state, original);
+        assert_eq!(state.stack::<bool>(), &[false, true]);
+        assert_eq!(state.stack::<i64>(), &[3, 2, 1]);
+    }
+
+    #[test]
+    fn successful_transaction_commits() {
+        let mut state = state(vec![true, false], vec![block(1), block(2)]);
+        let (condition, blocks) = state
+            .transaction(|tx| {
+                let condition = tx.pop::<bool>()?;
+                let blocks = tx.pop2::<PushProgram>()?;
+                Ok::<_, StackError>((condition, blocks))
+            })
+            .unwrap();
+        assert!(condition);
+        assert_eq!(blocks, (block(1), block(2)));
+        assert_eq!(state.stack::<bool>(), &[false]);
+        assert!(state.stack::<PushProgram>().is_empty());
+    }
+
+    #[test]
+    fn dropping_a_transaction_rolls_back() {
+        let mut state = state(vec![true], vec![block(1)]);
+        let original = state.clone();
+        {
+            let mut tx = Transaction::new(&mut state);
+            assert_eq!(tx.pop::<i64>().unwrap(), 1);
+            assert_eq!(tx.pop::<PushProgram>().unwrap(), block(1));
+        }
+        assert_eq!(state, original);
+
+        let mut tx = Transaction::new(&mut state);
+        tx.pop::<i64>().unwrap();
+        tx.commit();
+        assert_eq!(state.stack::<i64>(), &[3, 2]);
+    }
+}
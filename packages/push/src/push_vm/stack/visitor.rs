@@ -0,0 +1,20 @@
+use super::Stack;
+
+/// Something that can look at each of a state's stacks in turn, e.g., to copy
+/// or compare them, without listing the stacks by hand.
+///
+/// The [`push_state`](crate::push_state) macro generates a `visit_stacks`
+/// method that calls [`VisitStack::visit`] with the name (i.e., the field
+/// name) of each stack, in the same order as `stack_summary`. The visitor
+/// has to implement `VisitStack<T>` for the type `T` of every stack in the
+/// state, which is usually done with a single generic implementation.
+pub trait VisitStack<T> {
+    fn visit(&mut self, name: &'static str, stack: &Stack<T>);
+}
+
+/// Like [`VisitStack`], but with mutable access to each stack, as used by
+/// the `visit_stacks_mut` method generated by the
+/// [`push_state`](crate::push_state) macro.
+pub trait VisitStackMut<T> {
+    fn visit_mut(&mut self, name: &'static str, stack: &mut Stack<T>);
+}
@@ -0,0 +1,38 @@
+use super::{program::PushProgram, stack::Stack};
+
+/// How much of a state's step limit is used up by performing each item popped
+/// off the `Exec` stack.
+///
+/// With [`StepCost::Flat`] (the default) every instruction or block costs
+/// one step, so a `DupBlock` that copies a 500-instruction block costs the
+/// same as an `IntInstruction::Add`. [`StepCost::ExecGrowth`] charges for
+/// the work an item adds to the `Exec` stack, giving a fairer bound on the
+/// computation a program can do.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum StepCost {
+    /// Every item costs one step.
+    #[default]
+    Flat,
+    /// Every item costs one step plus the number of instructions it pushed
+    /// onto the `Exec` stack, counting the instructions inside any pushed
+    /// blocks.
+    ExecGrowth,
+}
+
+impl StepCost {
+    /// The cost of performing an item, given the size of the `Exec` stack
+    /// just before it was performed (after it was popped) and the `Exec`
+    /// stack just after it was performed.
+    #[must_use]
+    pub fn cost(self, exec_size_before: usize, exec: &Stack<PushProgram>) -> usize {
+        match self {
+            Self::Flat => 1,
+            Self::ExecGrowth => {
+                let num_pushed = exec.size().saturating_sub(exec_size_before);
+                exec.iter_top(num_pushed)
+                    .map(PushProgram::size)
+                    .fold(1, usize::saturating_add)
+            }
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::VecDeque;
+
+use super::program::PushProgram;
+
+/// One item performed from the `Exec` stack while running a state with
+/// tracing turned on (see
+/// [`PushState::with_tracing`](super::push_state::PushState::with_tracing)).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TraceEntry {
+    /// The instruction or block that was performed.
+    pub item: PushProgram,
+    /// Whether performing the item failed with a recoverable error, so it
+    /// was (usually) skipped without changing any stacks.
+    pub failed: bool,
+    /// The name and size of every stack just before the item was performed
+    /// (i.e., after it was popped off the `Exec` stack), in the same order
+    /// as [`PushState::stack_summary`](super::push_state::PushState::stack_summary).
+    pub stack_sizes: Vec<(&'static str, usize)>,
+}
+
+/// The most recent items performed while running a state with tracing
+/// turned on, oldest first.
+///
+/// A trace holds at most `max_len` entries; once it's full, recording a new
+/// entry drops the oldest one, so the end of a long run (including the item
+/// that failed, if the run failed) is always kept.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Trace {
+    entries: VecDeque<TraceEntry>,
+    max_len: usize,
+    num_dropped: usize,
+}
+
+impl Trace {
+    #[must_use]
+    pub const fn new(max_len: usize) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            max_len,
+            num_dropped: 0,
+        }
+    }
+
+    /// The maximum number of entries this trace holds.
+    #[must_use]
+    pub const fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    /// The number of entries that were dropped to make room for newer ones.
+    #[must_use]
+    pub const fn num_dropped(&self) -> usize {
+        self.num_dropped
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The entries in this trace, oldest first.
+    #[must_use]
+    pub fn iter(&self) -> std::collections::vec_deque::Iter<'_, TraceEntry> {
+        self.entries.iter()
+    }
+
+    /// The most recent entry, if any.
+    #[must_use]
+    pub fn last(&self) -> Option<&TraceEntry> {
+        self.entries.back()
+    }
+
+    pub(crate) fn last_mut(&mut self) -> Option<&mut TraceEntry> {
+        self.entries.back_mut()
+    }
+
+    pub(crate) fn push(&mut self, entry: TraceEntry) {
+        if self.max_len == 0 {
+            self.num_dropped = self.num_dropped.saturating_add(1);
+            return;
+        }
+        if self.entries.len() == self.max_len {
+            self.entries.pop_front();
+            self.num_dropped = self.num_dropped.saturating_add(1);
+        }
+        self.entries.push_back(entry);
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+        self.num_dropped = 0;
+    }
+}
+
+impl<'a> IntoIterator for &'a Trace {
+    type Item = &'a TraceEntry;
+    type IntoIter = std::collections::vec_deque::Iter<'a, TraceEntry>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
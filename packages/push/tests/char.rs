@@ -0,0 +1,106 @@
+#![cfg(test)]
+#![expect(
+    clippy::unwrap_used,
+    reason = "The test states are built with values that fit their stacks"
+)]
+
+use push::{
+    instruction::{
+        instruction_error::PushInstructionError, CharInstruction, Instruction, PushInstruction,
+    },
+    push_vm::{push_state::PushState, stack::StackError, HasStack},
+};
+use strum::IntoEnumIterator;
+
+fn perform(instruction: CharInstruction, c: char) -> PushState {
+    let state = PushState::builder()
+        .with_max_stack_size(2)
+        .with_char_values([c])
+        .unwrap()
+        .with_no_program()
+        .build();
+    instruction.perform(state).unwrap()
+}
+
+fn convert(instruction: CharInstruction, c: char) -> char {
+    let state = perform(instruction, c);
+    assert_eq!(state.stack::<char>().size(), 1);
+    *state.stack::<char>().top().unwrap()
+}
+
+fn predicate(instruction: CharInstruction, c: char) -> bool {
+    let state = perform(instruction, c);
+    assert!(state.stack::<char>().is_empty());
+    *state.stack::<bool>().top().unwrap()
+}
+
+#[test]
+fn to_push_instruction() {
+    let push_instruction: PushInstruction = CharInstruction::ToUppercase.into();
+    assert_eq!(push_instruction.to_string(), "Char-ToUppercase");
+}
+
+#[test]
+fn to_uppercase() {
+    assert_eq!(convert(CharInstruction::ToUppercase, 'a'), 'A');
+    assert_eq!(convert(CharInstruction::ToUppercase, 'A'), 'A');
+    assert_eq!(convert(CharInstruction::ToUppercase, '5'), '5');
+    assert_eq!(convert(CharInstruction::ToUppercase, 'é'), 'É');
+    // The uppercase form of 'ß' is "SS", so it's left alone.
+    assert_eq!(convert(CharInstruction::ToUppercase, 'ß'), 'ß');
+}
+
+#[test]
+fn to_lowercase() {
+    assert_eq!(convert(CharInstruction::ToLowercase, 'A'), 'a');
+    assert_eq!(convert(CharInstruction::ToLowercase, 'a'), 'a');
+    assert_eq!(convert(CharInstruction::ToLowercase, '5'), '5');
+    assert_eq!(convert(CharInstruction::ToLowercase, ' '), ' ');
+}
+
+#[test]
+fn is_digit() {
+    assert!(predicate(CharInstruction::IsDigit, '7'));
+    assert!(!predicate(CharInstruction::IsDigit, 'x'));
+}
+
+#[test]
+fn is_whitespace() {
+    for c in [' ', '\t', '\n'] {
+        assert!(predicate(CharInstruction::IsWhitespace, c), "{c:?}");
+    }
+    assert!(!predicate(CharInstruction::IsWhitespace, 'a'));
+}
+
+#[test]
+fn conversion_of_empty_stack_is_recoverable() {
+    for instruction in [CharInstruction::ToUppercase, CharInstruction::ToLowercase] {
+        let state = PushState::builder()
+            .with_max_stack_size(2)
+            .with_no_program()
+            .build();
+        let result = instruction.perform(state.clone()).unwrap_err();
+        assert!(result.is_recoverable());
+        assert_eq!(result.state(), &state);
+        assert_eq!(
+            result.error(),
+            &PushInstructionError::from(StackError::Underflow {
+                num_requested: 1,
+                num_present: 0
+            })
+        );
+    }
+}
+
+#[test]
+fn ops_do_not_crash() {
+    for instruction in CharInstruction::iter() {
+        let state = PushState::builder()
+            .with_max_stack_size(2)
+            .with_char_values(['q'])
+            .unwrap()
+            .with_no_program()
+            .build();
+        instruction.perform(state).unwrap();
+    }
+}
@@ -0,0 +1,233 @@
+#![cfg(test)]
+#![expect(
+    clippy::unwrap_used,
+    reason = "The test states are built with values that fit their stacks"
+)]
+#![expect(
+    clippy::arithmetic_side_effects,
+    reason = "The stack depths in the tests are small"
+)]
+
+use proptest::{prop_assert, prop_assert_eq};
+use push::{
+    instruction::{
+        common::{CommonInstruction, Dup, Pop, Shove, Swap, Yank, YankDup},
+        instruction_error::PushInstructionError,
+        Instruction, IntInstruction,
+    },
+    push_vm::{
+        push_state::{random::RandomPushState, PushState},
+        stack::StackError,
+        HasStack,
+    },
+};
+use rand::{prelude::Distribution, rngs::StdRng, SeedableRng};
+use test_strategy::proptest;
+
+fn int_state(values: Vec<i64>) -> PushState {
+    PushState::builder()
+        .with_max_stack_size(values.len().max(1))
+        .with_int_values(values)
+        .unwrap()
+        .with_no_program()
+        .build()
+}
+
+fn ints_from_top(state: &PushState) -> Vec<i64> {
+    let stack = state.stack::<i64>();
+    stack.iter_top(stack.size()).copied().collect()
+}
+
+#[test]
+fn shove_moves_top_down() {
+    // The depth (2) is on top of the `i64` stack, and `1` is shoved below
+    // two other values.
+    let state = int_state(vec![2, 1, 2, 3, 4]);
+    let result = IntInstruction::shove().perform(state).unwrap();
+    assert_eq!(ints_from_top(&result), vec![2, 3, 1, 4]);
+}
+
+#[test]
+fn yank_moves_value_to_top() {
+    let state = int_state(vec![2, 1, 2, 3, 4]);
+    let result = IntInstruction::yank().perform(state).unwrap();
+    assert_eq!(ints_from_top(&result), vec![3, 1, 2, 4]);
+}
+
+#[test]
+fn yank_undoes_shove() {
+    let state = int_state(vec![2, 1, 2, 3, 4]);
+    let shoved = IntInstruction::shove().perform(state).unwrap();
+    let mut shoved = shoved;
+    shoved.stack_mut::<i64>().push(2).unwrap();
+    let result = IntInstruction::yank().perform(shoved).unwrap();
+    assert_eq!(ints_from_top(&result), vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn yank_dup_copies_value() {
+    let state = PushState::builder()
+        .with_max_stack_size(4)
+        .with_int_values(vec![1, 1, 2, 3])
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = IntInstruction::yank_dup().perform(state).unwrap();
+    assert_eq!(ints_from_top(&result), vec![2, 1, 2, 3]);
+}
+
+#[test]
+fn depth_wraps_around() {
+    // A depth of 5 on a stack of 3 values is the same as a depth of 2, and
+    // -1 is the bottom of the stack.
+    let state = int_state(vec![5, 1, 2, 3]);
+    let result = IntInstruction::yank().perform(state).unwrap();
+    assert_eq!(ints_from_top(&result), vec![3, 1, 2]);
+
+    let state = int_state(vec![-1, 1, 2, 3]);
+    let result = IntInstruction::yank().perform(state).unwrap();
+    assert_eq!(ints_from_top(&result), vec![3, 1, 2]);
+}
+
+#[test]
+fn shove_on_another_stack() {
+    let state = PushState::builder()
+        .with_max_stack_size(3)
+        .with_bool_values([true, false, false])
+        .unwrap()
+        .with_int_values([1])
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = Shove::<bool>::new().perform(state).unwrap();
+    let bools = result.stack::<bool>();
+    assert_eq!(
+        bools.iter_top(bools.size()).copied().collect::<Vec<_>>(),
+        vec![false, true, false]
+    );
+    assert!(result.stack::<i64>().is_empty());
+}
+
+#[test]
+fn empty_stack_is_recoverable() {
+    let state = PushState::builder()
+        .with_max_stack_size(1)
+        .with_int_values([1])
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = Yank::<bool>::new().perform(state.clone()).unwrap_err();
+    assert!(result.is_recoverable());
+    assert_eq!(result.state(), &state);
+    assert_eq!(
+        result.error(),
+        &PushInstructionError::from(StackError::Underflow {
+            num_requested: 1,
+            num_present: 0
+        })
+    );
+}
+
+#[test]
+fn missing_depth_is_recoverable() {
+    let state = PushState::builder()
+        .with_max_stack_size(1)
+        .with_bool_values([true])
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = Shove::<bool>::new().perform(state.clone()).unwrap_err();
+    assert!(result.is_recoverable());
+    assert_eq!(result.state(), &state);
+}
+
+#[test]
+fn yank_dup_onto_full_stack_is_fatal() {
+    let state = PushState::builder()
+        .with_max_stack_size(2)
+        .with_bool_values([true, false])
+        .unwrap()
+        .with_int_values([1])
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = YankDup::<bool>::new().perform(state.clone()).unwrap_err();
+    assert!(result.is_fatal());
+    assert_eq!(result.state(), &state);
+}
+
+#[test]
+fn generic_dup_pop_and_swap() {
+    let state = int_state(vec![1, 2, 3]);
+    let result = Swap::<i64>::new().perform(state).unwrap();
+    assert_eq!(ints_from_top(&result), vec![2, 1, 3]);
+    let result = Pop::<i64>::new().perform(result).unwrap();
+    assert_eq!(ints_from_top(&result), vec![1, 3]);
+    let result = Dup::<i64>::new().perform(result).unwrap();
+    assert_eq!(ints_from_top(&result), vec![1, 1, 3]);
+}
+
+#[test]
+fn generic_dup_onto_full_stack_is_fatal() {
+    let state = int_state(vec![1, 2]);
+    let result = Dup::<i64>::new().perform(state).unwrap_err();
+    assert!(result.is_fatal());
+    assert_eq!(ints_from_top(result.state()), vec![1, 2]);
+}
+
+#[test]
+fn generic_swap_needs_two_values() {
+    let state = int_state(vec![1]);
+    let result = Swap::<i64>::new().perform(state).unwrap_err();
+    assert!(result.is_recoverable());
+    assert_eq!(
+        result.error(),
+        &PushInstructionError::from(StackError::Underflow {
+            num_requested: 2,
+            num_present: 1
+        })
+    );
+}
+
+#[test]
+fn common_instructions_on_the_bool_stack() {
+    let state = PushState::builder()
+        .with_max_stack_size(4)
+        .with_bool_values(vec![true, false])
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = CommonInstruction::<bool>::stack_depth()
+        .perform(state)
+        .unwrap();
+    assert_eq!(ints_from_top(&result), vec![2]);
+    let result = CommonInstruction::<bool>::flush().perform(result).unwrap();
+    let result = CommonInstruction::<bool>::is_empty()
+        .perform(result)
+        .unwrap();
+    assert_eq!(result.stack::<bool>(), &vec![true]);
+    assert_eq!(CommonInstruction::<bool>::all().len(), 6);
+}
+
+// `Dup` on a random state should add a copy of the top `i64` when there is
+// one and there's room for it, and otherwise return an error with the
+// state unchanged.
+#[proptest]
+fn dup_on_random_states(#[any] seed: u64) {
+    let state = RandomPushState::new(5, 0..=5).sample(&mut StdRng::seed_from_u64(seed));
+    let ints = state.stack::<i64>().clone();
+    match Dup::<i64>::new().perform(state.clone()) {
+        Ok(result) => {
+            let top = *ints.top().unwrap();
+            prop_assert_eq!(result.stack::<i64>().size(), ints.size() + 1);
+            prop_assert_eq!(result.stack::<i64>().top2().unwrap(), (&top, &top));
+        }
+        Err(error) => {
+            // A full stack is a fatal error, while an empty one is
+            // recoverable.
+            prop_assert!(ints.is_empty() || ints.is_full());
+            prop_assert_eq!(error.is_fatal(), ints.is_full());
+            prop_assert_eq!(error.state(), &state);
+        }
+    }
+}
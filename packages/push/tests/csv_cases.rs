@@ -0,0 +1,85 @@
+#![cfg(test)]
+#![cfg(feature = "csv")]
+#![expect(
+    clippy::unwrap_used,
+    reason = "The well-formed CSV inputs always parse"
+)]
+
+use std::num::ParseIntError;
+
+use push::evaluation::{
+    cases::{Case, Cases},
+    csv_cases::{CasePart, CsvCasesError},
+};
+
+const CSV: &str = "\
+x,y,sum
+1,2,3
+4,5,9
+-3,10,7
+";
+
+#[test]
+fn reads_columns_in_order() {
+    let cases = Cases::<i64, i64>::from_csv_columns(CSV.as_bytes(), 0, 2).unwrap();
+    assert_eq!(
+        cases.into_cases(),
+        [Case::new(1, 3), Case::new(4, 9), Case::new(-3, 7)]
+    );
+}
+
+#[test]
+fn reads_multi_column_inputs() {
+    let cases = Cases::from_csv_reader(
+        CSV.as_bytes(),
+        |record| -> Result<_, ParseIntError> { Ok((record[0].parse()?, record[1].parse()?)) },
+        |record| record[2].parse::<i64>(),
+    )
+    .unwrap();
+    assert_eq!(
+        cases.into_cases(),
+        [
+            Case::new((1, 2), 3),
+            Case::new((4, 5), 9),
+            Case::new((-3, 10), 7)
+        ]
+    );
+}
+
+#[test]
+fn empty_csv_gives_no_cases() {
+    let cases = Cases::<i64, i64>::from_csv_columns(&b""[..], 0, 1).unwrap();
+    assert!(cases.is_empty());
+    let cases = Cases::<i64, i64>::from_csv_columns(&b"x,y\n"[..], 0, 1).unwrap();
+    assert!(cases.is_empty());
+}
+
+#[test]
+fn parse_errors_report_the_row() {
+    let csv = "x,y\n1,2\n3,four\n";
+    let error = Cases::<i64, i64>::from_csv_columns(csv.as_bytes(), 0, 1).unwrap_err();
+    assert!(
+        matches!(
+            error,
+            CsvCasesError::Parse {
+                row: 2,
+                part: CasePart::Output,
+                ..
+            }
+        ),
+        "{error}"
+    );
+    assert_eq!(
+        error.to_string(),
+        "Failed to parse the output of row 2 of the CSV: invalid digit found in string"
+    );
+}
+
+#[test]
+fn missing_columns_are_an_error() {
+    let error = Cases::<i64, i64>::from_csv_columns(CSV.as_bytes(), 0, 3).unwrap_err();
+    assert!(
+        matches!(error, CsvCasesError::Parse { row: 1, .. }),
+        "{error}"
+    );
+}
@@ -1,16 +1,51 @@
 #![cfg(test)]
-#![allow(clippy::unwrap_used)]
-#![allow(clippy::tuple_array_conversions)]
-#![allow(clippy::arithmetic_side_effects)]
+#![expect(
+    clippy::unwrap_used,
+    reason = "The test states are built with values that fit their stacks"
+)]
+#![expect(
+    clippy::arithmetic_side_effects,
+    reason = "Float arithmetic can't overflow or panic"
+)]
+#![expect(
+    clippy::panic,
+    reason = "Panicking is how these tests report a failure"
+)]
 
 use ordered_float::OrderedFloat;
-use proptest::prop_assert_eq;
+use proptest::{prop_assert, prop_assert_eq};
 use push::{
-    instruction::{FloatInstruction, Instruction, PushInstruction},
+    instruction::{
+        instruction_error::PushInstructionError, FloatInstruction, FloatInstructionError,
+        Instruction, PushInstruction,
+    },
     push_vm::{push_state::PushState, stack::StackError, HasStack},
 };
 use test_strategy::proptest;
 
+// Performing `instruction` should push `expected_result` if it's finite, and
+// otherwise return a recoverable `NonFinite` error, leaving the state unchanged.
+fn check_finite_result(
+    instruction: FloatInstruction,
+    state: PushState,
+    expected_result: OrderedFloat<f64>,
+) -> Result<(), proptest::test_runner::TestCaseError> {
+    if expected_result.is_finite() {
+        let result = instruction.perform(state).unwrap();
+        let output = result.stack::<OrderedFloat<f64>>().top().unwrap();
+        prop_assert_eq!(*output, expected_result);
+    } else {
+        let result = instruction.perform(state.clone()).unwrap_err();
+        prop_assert!(result.is_recoverable());
+        prop_assert_eq!(result.state(), &state);
+        prop_assert_eq!(
+            result.error(),
+            &FloatInstructionError::NonFinite { op: instruction }.into()
+        );
+    }
+    Ok(())
+}
+
 #[test]
 fn to_push_instruction() {
     let float_instruction = FloatInstruction::Add;
@@ -30,6 +65,37 @@ fn push_float() {
     assert_eq!(*result.stack::<OrderedFloat<f64>>().top().unwrap(), x);
 }
 
+#[test]
+fn push_f64() {
+    let x = 589.632;
+    let state = PushState::builder()
+        .with_max_stack_size(1)
+        .with_no_program()
+        .build();
+    let instruction = FloatInstruction::push_f64(x).unwrap();
+    assert_eq!(instruction, FloatInstruction::Push(OrderedFloat(x)));
+    let result = instruction.perform(state).unwrap();
+    assert_eq!(*result.stack::<OrderedFloat<f64>>().top().unwrap(), x);
+}
+
+#[test]
+fn push_f64_rejects_nan() {
+    assert_eq!(FloatInstruction::push_f64(f64::NAN), None);
+    assert_eq!(FloatInstruction::push_f64(-f64::NAN), None);
+}
+
+#[test]
+fn push_f64_accepts_infinities() {
+    assert_eq!(
+        FloatInstruction::push_f64(f64::INFINITY),
+        Some(FloatInstruction::Push(OrderedFloat(f64::INFINITY)))
+    );
+    assert_eq!(
+        FloatInstruction::push_f64(f64::NEG_INFINITY),
+        Some(FloatInstruction::Push(OrderedFloat(f64::NEG_INFINITY)))
+    );
+}
+
 #[test]
 fn add() {
     let x = OrderedFloat(409.37);
@@ -83,6 +149,162 @@ fn dup() {
     assert_eq!(b, x);
 }
 
+#[test]
+fn from_int() {
+    let state = PushState::builder()
+        .with_max_stack_size(2)
+        .with_int_values([-7])
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = FloatInstruction::FromInt.perform(state).unwrap();
+    assert!(result.stack::<i64>().is_empty());
+    assert_eq!(result.stack::<OrderedFloat<f64>>().size(), 1);
+    assert_eq!(
+        *result.stack::<OrderedFloat<f64>>().top().unwrap(),
+        OrderedFloat(-7.0)
+    );
+}
+
+#[test]
+fn from_int_full_float_stack() {
+    let state = PushState::builder()
+        .with_max_stack_size(2)
+        .with_float_values([OrderedFloat(1.0), OrderedFloat(2.0)])
+        .unwrap()
+        .with_int_values([3])
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = FloatInstruction::FromInt
+        .perform(state.clone())
+        .unwrap_err();
+    assert!(result.is_fatal());
+    assert_eq!(result.state(), &state);
+    assert_eq!(
+        result.error(),
+        &StackError::Overflow {
+            stack_type: "float"
+        }
+        .into()
+    );
+}
+
+// Performs `instruction` on a float stack holding `values` (top first),
+// checking that it either pushes the expected value or returns the expected
+// (recoverable) error, leaving the state unchanged.
+fn check(
+    instruction: FloatInstruction,
+    values: &[f64],
+    expected: Result<f64, FloatInstructionError>,
+) {
+    let state = PushState::builder()
+        .with_max_stack_size(2)
+        .with_float_values(values.iter().copied().map(OrderedFloat))
+        .unwrap()
+        .with_no_program()
+        .build();
+    match (instruction.perform(state.clone()), expected) {
+        (Ok(result), Ok(expected)) => {
+            assert_eq!(
+                result.stack::<OrderedFloat<f64>>().top().unwrap(),
+                &OrderedFloat(expected)
+            );
+        }
+        (Err(error), Err(expected)) => {
+            assert!(error.is_recoverable());
+            assert_eq!(error.state(), &state);
+            assert_eq!(error.error(), &PushInstructionError::from(expected));
+        }
+        (result, expected) => panic!("Expected {expected:?} but got {result:?}"),
+    }
+}
+
+#[test]
+fn protected_divide_zero_by_zero() {
+    check(FloatInstruction::ProtectedDivide, &[0.0, 0.0], Ok(1.0));
+}
+
+#[test]
+fn divide_overflow_is_non_finite() {
+    check(
+        FloatInstruction::ProtectedDivide,
+        &[1e300, 1e-300],
+        Err(FloatInstructionError::NonFinite {
+            op: FloatInstruction::ProtectedDivide,
+        }),
+    );
+}
+
+#[test]
+fn sqrt() {
+    check(FloatInstruction::Sqrt, &[2.25], Ok(1.5));
+    check(
+        FloatInstruction::Sqrt,
+        &[-1.0],
+        Err(FloatInstructionError::NonFinite {
+            op: FloatInstruction::Sqrt,
+        }),
+    );
+}
+
+#[test]
+fn log() {
+    check(FloatInstruction::Log, &[1.0], Ok(0.0));
+    check(
+        FloatInstruction::Log,
+        &[0.0],
+        Err(FloatInstructionError::NonFinite {
+            op: FloatInstruction::Log,
+        }),
+    );
+    check(
+        FloatInstruction::Log,
+        &[-1.0],
+        Err(FloatInstructionError::NonFinite {
+            op: FloatInstruction::Log,
+        }),
+    );
+}
+
+#[test]
+fn trig() {
+    check(FloatInstruction::Sin, &[0.0], Ok(0.0));
+    check(FloatInstruction::Cos, &[0.0], Ok(1.0));
+    check(FloatInstruction::Tan, &[0.0], Ok(0.0));
+    check(
+        FloatInstruction::Sin,
+        &[f64::INFINITY],
+        Err(FloatInstructionError::NonFinite {
+            op: FloatInstruction::Sin,
+        }),
+    );
+}
+
+#[test]
+fn exp() {
+    check(FloatInstruction::Exp, &[0.0], Ok(1.0));
+    check(
+        FloatInstruction::Exp,
+        &[1_000.0],
+        Err(FloatInstructionError::NonFinite {
+            op: FloatInstruction::Exp,
+        }),
+    );
+}
+
+#[test]
+fn power() {
+    check(FloatInstruction::Power, &[2.0, 10.0], Ok(1024.0));
+    check(
+        FloatInstruction::Power,
+        &[10.0, 400.0],
+        Err(FloatInstructionError::NonFinite {
+            op: FloatInstruction::Power,
+        }),
+    );
+}
+
 #[proptest]
 fn add_prop(#[any] x: OrderedFloat<f64>, #[any] y: OrderedFloat<f64>) {
     let expected_result = x + y;
@@ -92,9 +314,7 @@ fn add_prop(#[any] x: OrderedFloat<f64>, #[any] y: OrderedFloat<f64>) {
         .unwrap()
         .with_no_program()
         .build();
-    let result = FloatInstruction::Add.perform(state).unwrap();
-    let output = result.stack::<OrderedFloat<f64>>().top().unwrap();
-    prop_assert_eq!(*output, expected_result);
+    check_finite_result(FloatInstruction::Add, state, expected_result)?;
 }
 
 #[proptest]
@@ -106,9 +326,7 @@ fn subtract_prop(#[any] x: OrderedFloat<f64>, #[any] y: OrderedFloat<f64>) {
         .unwrap()
         .with_no_program()
         .build();
-    let result = FloatInstruction::Subtract.perform(state).unwrap();
-    let output = result.stack::<OrderedFloat<f64>>().top().unwrap();
-    prop_assert_eq!(*output, expected_result);
+    check_finite_result(FloatInstruction::Subtract, state, expected_result)?;
 }
 
 #[proptest]
@@ -120,9 +338,7 @@ fn multiply_prop(#[any] x: OrderedFloat<f64>, #[any] y: OrderedFloat<f64>) {
         .unwrap()
         .with_no_program()
         .build();
-    let result = FloatInstruction::Multiply.perform(state).unwrap();
-    let output = result.stack::<OrderedFloat<f64>>().top().unwrap();
-    prop_assert_eq!(*output, expected_result);
+    check_finite_result(FloatInstruction::Multiply, state, expected_result)?;
 }
 
 #[proptest]
@@ -134,9 +350,7 @@ fn protected_divide_prop(#[any] x: OrderedFloat<f64>, #[any] y: OrderedFloat<f64
         .unwrap()
         .with_no_program()
         .build();
-    let result = FloatInstruction::ProtectedDivide.perform(state).unwrap();
-    let output = result.stack::<OrderedFloat<f64>>().top().unwrap();
-    prop_assert_eq!(*output, expected_result);
+    check_finite_result(FloatInstruction::ProtectedDivide, state, expected_result)?;
 }
 
 #[proptest]
@@ -0,0 +1,283 @@
+#![cfg(test)]
+#![expect(
+    clippy::unwrap_used,
+    reason = "The test states are built with values that fit their stacks"
+)]
+#![expect(
+    clippy::arithmetic_side_effects,
+    reason = "Float arithmetic can't overflow or panic"
+)]
+
+use num_traits::ToPrimitive;
+use ordered_float::OrderedFloat;
+use proptest::{prop_assert, prop_assert_eq};
+use push::{
+    instruction::{
+        instruction_error::PushInstructionError, Float32Instruction, Float32InstructionError,
+        Instruction, IntFromFloatInstruction, PushInstruction,
+    },
+    push_vm::{push_state::PushState, stack::StackError, HasStack},
+};
+use test_strategy::proptest;
+
+// Performing `instruction` should push `expected_result` if it's finite, and
+// otherwise return a recoverable `NonFinite` error, leaving the state unchanged.
+fn check_finite_result(
+    instruction: Float32Instruction,
+    state: PushState,
+    expected_result: OrderedFloat<f32>,
+) -> Result<(), proptest::test_runner::TestCaseError> {
+    if expected_result.is_finite() {
+        let result = instruction.perform(state).unwrap();
+        let output = result.stack::<OrderedFloat<f32>>().top().unwrap();
+        prop_assert_eq!(*output, expected_result);
+    } else {
+        let result = instruction.perform(state.clone()).unwrap_err();
+        prop_assert!(result.is_recoverable());
+        prop_assert_eq!(result.state(), &state);
+        prop_assert_eq!(
+            result.error(),
+            &Float32InstructionError::NonFinite { op: instruction }.into()
+        );
+    }
+    Ok(())
+}
+
+fn float32_state(values: [OrderedFloat<f32>; 2]) -> PushState {
+    PushState::builder()
+        .with_max_stack_size(2)
+        .with_float32_values(values)
+        .unwrap()
+        .with_no_program()
+        .build()
+}
+
+#[test]
+fn to_push_instruction() {
+    let float_instruction = Float32Instruction::Add;
+    let push_instruction: PushInstruction = float_instruction.into();
+    assert!(
+        matches!(push_instruction, PushInstruction::Float32Instruction(fi) if fi == float_instruction)
+    );
+}
+
+#[test]
+fn push_float32() {
+    let x = OrderedFloat(589.632_f32);
+    let state = PushState::builder()
+        .with_max_stack_size(1)
+        .with_no_program()
+        .build();
+    let result = Float32Instruction::Push(x).perform(state).unwrap();
+    assert_eq!(result.stack::<OrderedFloat<f32>>().size(), 1);
+    assert_eq!(*result.stack::<OrderedFloat<f32>>().top().unwrap(), x);
+    // The `f64` stack is separate.
+    assert!(result.stack::<OrderedFloat<f64>>().is_empty());
+}
+
+#[test]
+fn push_f32_rejects_nan() {
+    assert_eq!(Float32Instruction::push_f32(f32::NAN), None);
+    assert_eq!(
+        Float32Instruction::push_f32(1.5),
+        Some(Float32Instruction::Push(OrderedFloat(1.5)))
+    );
+}
+
+#[test]
+fn add() {
+    let x = OrderedFloat(409.37_f32);
+    let y = OrderedFloat(512.825_f32);
+    let result = Float32Instruction::Add
+        .perform(float32_state([x, y]))
+        .unwrap();
+    assert_eq!(result.stack::<OrderedFloat<f32>>().size(), 1);
+    assert_eq!(*result.stack::<OrderedFloat<f32>>().top().unwrap(), x + y);
+}
+
+#[test]
+fn overflow_bool_stack() {
+    let x = OrderedFloat(409.37_f32);
+    let state = PushState::builder()
+        .with_max_stack_size(2)
+        .with_float32_values(vec![x, x])
+        .unwrap()
+        .with_bool_values(vec![false, false])
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = Float32Instruction::Equal.perform(state).unwrap_err();
+    assert_eq!(
+        result.error(),
+        &StackError::Overflow { stack_type: "bool" }.into()
+    );
+}
+
+#[test]
+fn dup() {
+    let x = OrderedFloat(409.37_f32);
+    let state = PushState::builder()
+        .with_max_stack_size(2)
+        .with_float32_values(std::iter::once(x))
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = Float32Instruction::Dup.perform(state).unwrap();
+    let float_stack = result.stack::<OrderedFloat<f32>>();
+    assert_eq!(float_stack.size(), 2);
+    assert_eq!(float_stack.top2().unwrap(), (&x, &x));
+}
+
+#[test]
+fn overflow_is_non_finite() {
+    let state = float32_state([OrderedFloat(3e38), OrderedFloat(3e38)]);
+    let result = Float32Instruction::Add.perform(state.clone()).unwrap_err();
+    assert!(result.is_recoverable());
+    assert_eq!(result.state(), &state);
+    assert_eq!(
+        result.error(),
+        &PushInstructionError::from(Float32InstructionError::NonFinite {
+            op: Float32Instruction::Add
+        })
+    );
+}
+
+#[test]
+fn from_int() {
+    let state = PushState::builder()
+        .with_max_stack_size(2)
+        .with_int_values([-7])
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = Float32Instruction::FromInt.perform(state).unwrap();
+    assert!(result.stack::<i64>().is_empty());
+    assert_eq!(
+        *result.stack::<OrderedFloat<f32>>().top().unwrap(),
+        OrderedFloat(-7.0)
+    );
+}
+
+#[test]
+fn from_int_extremes_are_finite() {
+    for x in [i64::MAX, i64::MIN] {
+        let state = PushState::builder()
+            .with_max_stack_size(1)
+            .with_int_values([x])
+            .unwrap()
+            .with_no_program()
+            .build();
+        let result = Float32Instruction::FromInt.perform(state).unwrap();
+        assert!(result
+            .stack::<OrderedFloat<f32>>()
+            .top()
+            .unwrap()
+            .is_finite());
+    }
+}
+
+#[test]
+fn from_int_full_float32_stack() {
+    let state = PushState::builder()
+        .with_max_stack_size(2)
+        .with_float32_values([OrderedFloat(1.0), OrderedFloat(2.0)])
+        .unwrap()
+        .with_int_values([3])
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = Float32Instruction::FromInt
+        .perform(state.clone())
+        .unwrap_err();
+    assert!(result.is_fatal());
+    assert_eq!(result.state(), &state);
+    assert_eq!(
+        result.error(),
+        &StackError::Overflow {
+            stack_type: "float32"
+        }
+        .into()
+    );
+}
+
+fn int_from_float32(x: f32) -> i64 {
+    let state = PushState::builder()
+        .with_max_stack_size(2)
+        .with_float32_values([OrderedFloat(x)])
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = IntFromFloatInstruction::FromFloat32.perform(state).unwrap();
+    assert!(result.stack::<OrderedFloat<f32>>().is_empty());
+    *result.stack::<i64>().top().unwrap()
+}
+
+#[test]
+fn int_from_float32_truncates() {
+    assert_eq!(int_from_float32(3.9), 3);
+    assert_eq!(int_from_float32(-3.9), -3);
+    assert_eq!(int_from_float32(0.0), 0);
+}
+
+#[test]
+fn int_from_float32_saturates() {
+    assert_eq!(int_from_float32(1e30), i64::MAX);
+    assert_eq!(int_from_float32(-1e30), i64::MIN);
+    assert_eq!(int_from_float32(f32::INFINITY), i64::MAX);
+    assert_eq!(int_from_float32(f32::NEG_INFINITY), i64::MIN);
+}
+
+#[proptest]
+fn add_prop(#[any] x: OrderedFloat<f32>, #[any] y: OrderedFloat<f32>) {
+    check_finite_result(Float32Instruction::Add, float32_state([x, y]), x + y)?;
+}
+
+#[proptest]
+fn subtract_prop(#[any] x: OrderedFloat<f32>, #[any] y: OrderedFloat<f32>) {
+    check_finite_result(Float32Instruction::Subtract, float32_state([x, y]), x - y)?;
+}
+
+#[proptest]
+fn multiply_prop(#[any] x: OrderedFloat<f32>, #[any] y: OrderedFloat<f32>) {
+    check_finite_result(Float32Instruction::Multiply, float32_state([x, y]), x * y)?;
+}
+
+#[proptest]
+fn protected_divide_prop(#[any] x: OrderedFloat<f32>, #[any] y: OrderedFloat<f32>) {
+    let expected_result = if y == 0.0 { OrderedFloat(1.0) } else { x / y };
+    check_finite_result(
+        Float32Instruction::ProtectedDivide,
+        float32_state([x, y]),
+        expected_result,
+    )?;
+}
+
+#[proptest]
+fn comparisons_prop(#[any] x: OrderedFloat<f32>, #[any] y: OrderedFloat<f32>) {
+    for (instruction, expected_result) in [
+        (Float32Instruction::Equal, x == y),
+        (Float32Instruction::NotEqual, x != y),
+        (Float32Instruction::GreaterThan, x > y),
+        (Float32Instruction::LessThan, x < y),
+        (Float32Instruction::GreaterThanOrEqual, x >= y),
+        (Float32Instruction::LessThanOrEqual, x <= y),
+    ] {
+        let result = instruction.perform(float32_state([x, y])).unwrap();
+        prop_assert_eq!(*result.stack::<bool>().top().unwrap(), expected_result);
+    }
+}
+
+#[proptest]
+fn int_from_float32_prop(#[any] x: OrderedFloat<f32>) {
+    let OrderedFloat(x) = x;
+    let result = int_from_float32(x);
+    // `i64` covers exactly `-2^63..2^63`, both ends of which are `f32`s.
+    let bound = 2.0_f32.powi(63);
+    if x.is_nan() {
+        prop_assert_eq!(result, 0);
+    } else if (-bound..bound).contains(&x) {
+        prop_assert_eq!(result, x.trunc().to_i64().unwrap());
+    } else {
+        prop_assert_eq!(result, if x > 0.0 { i64::MAX } else { i64::MIN });
+    }
+}
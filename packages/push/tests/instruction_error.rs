@@ -0,0 +1,115 @@
+#![cfg(test)]
+#![expect(clippy::unwrap_used, reason = "Building the test states can't fail")]
+
+use push::{
+    instruction::{
+        instruction_error::PushInstructionError, variable_name::VariableName, Instruction,
+        IntInstruction, PushInstruction,
+    },
+    list_into::vec_into,
+    push_vm::{program::PushProgram, push_state::PushState, stack::StackError, State},
+};
+
+#[test]
+fn underflow_names_the_instruction() {
+    let state = PushState::builder()
+        .with_max_stack_size(2)
+        .with_int_values([5])
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = PushInstruction::from(IntInstruction::Add)
+        .perform(state.clone())
+        .unwrap_err();
+    assert!(result.is_recoverable());
+    assert_eq!(result.state(), &state);
+    assert_eq!(
+        result.error(),
+        &PushInstructionError::InstructionFailed {
+            instruction: Box::new(IntInstruction::Add.into()),
+            source: Box::new(
+                StackError::Underflow {
+                    num_requested: 2,
+                    num_present: 1
+                }
+                .into()
+            ),
+        }
+    );
+    assert_eq!(
+        result.error().root_cause(),
+        &PushInstructionError::from(StackError::Underflow {
+            num_requested: 2,
+            num_present: 1
+        })
+    );
+    assert!(result.error().to_string().contains("Int-Add"));
+}
+
+#[test]
+fn input_error_is_wrapped_once() {
+    // Pushing `x` onto the already full `i64` stack overflows.
+    let state = PushState::builder()
+        .with_max_stack_size(1)
+        .with_int_values([0])
+        .unwrap()
+        .with_int_input("x", 5)
+        .with_no_program()
+        .build();
+    let result = PushInstruction::InputVar(VariableName::from("x"))
+        .perform(state)
+        .unwrap_err();
+    assert_eq!(
+        result.error(),
+        &PushInstructionError::InstructionFailed {
+            instruction: Box::new(PushInstruction::push_int(5)),
+            source: Box::new(StackError::Overflow { stack_type: "i64" }.into()),
+        }
+    );
+}
+
+#[test]
+fn fatal_error_names_the_instruction() {
+    // `IsZero` has nowhere to put its result, which is a fatal error.
+    let state = PushState::builder()
+        .with_max_stack_size(1)
+        .with_int_values([0])
+        .unwrap()
+        .with_bool_values([true])
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = PushInstruction::from(IntInstruction::IsZero)
+        .perform(state)
+        .unwrap_err();
+    assert!(result.is_fatal());
+    assert_eq!(
+        result.error(),
+        &PushInstructionError::InstructionFailed {
+            instruction: Box::new(IntInstruction::IsZero.into()),
+            source: Box::new(StackError::Overflow { stack_type: "bool" }.into()),
+        }
+    );
+    assert!(result.error().to_string().contains("Int-IsZero"));
+}
+
+#[test]
+fn fatal_error_from_program_names_the_instruction() {
+    // `IsZero` has nowhere to put its result, which is a fatal error.
+    let program: Vec<PushProgram> = vec_into![PushInstruction::push_int(0), IntInstruction::IsZero];
+    let error = PushState::builder()
+        .with_max_stack_size(2)
+        .with_bool_values([true, false])
+        .unwrap()
+        .with_program(program)
+        .unwrap()
+        .build()
+        .run_to_completion()
+        .unwrap_err();
+    let message = error.error().to_string();
+    assert!(message.contains("Int-IsZero"), "{message}");
+    assert_eq!(
+        error.error().root_cause(),
+        &PushInstructionError::from(StackError::Overflow { stack_type: "bool" })
+    );
+}
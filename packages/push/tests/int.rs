@@ -1,14 +1,25 @@
 #![cfg(test)]
-#![allow(clippy::unwrap_used)]
-#![allow(clippy::tuple_array_conversions)]
-#![allow(clippy::arithmetic_side_effects)]
+#![expect(
+    clippy::unwrap_used,
+    reason = "The test states are built with values that fit their stacks"
+)]
+#![expect(
+    clippy::arithmetic_side_effects,
+    reason = "Square roots of `i64`s fit in 32 bits, so squaring them can't overflow a `u64`, and the shift amounts are small"
+)]
 
-use proptest::prop_assert_eq;
+use ordered_float::OrderedFloat;
+use proptest::{prop_assert, prop_assert_eq};
 use push::{
     instruction::{
-        instruction_error::PushInstructionError, Instruction, IntInstruction, IntInstructionError,
+        instruction_error::PushInstructionError, ArithmeticMode, Instruction,
+        IntFromFloatInstruction, IntInstruction, IntInstructionError, IntRandomInstruction,
+    },
+    push_vm::{
+        push_state::PushState,
+        stack::{Stack, StackError},
+        HasStack,
     },
-    push_vm::{push_state::PushState, HasStack},
 };
 use strum::IntoEnumIterator;
 use test_strategy::proptest;
@@ -141,6 +152,123 @@ fn sqr(#[any] x: i64) {
     }
 }
 
+fn perform_int(instruction: IntInstruction, values: &[i64]) -> i64 {
+    let state = PushState::builder()
+        .with_max_stack_size(2)
+        .with_int_values(values.iter().copied())
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = instruction.perform(state).unwrap();
+    assert_eq!(result.stack::<i64>().size(), 1);
+    *result.stack::<i64>().top().unwrap()
+}
+
+#[test]
+fn sqrt() {
+    assert_eq!(perform_int(IntInstruction::Sqrt, &[17]), 4);
+    assert_eq!(perform_int(IntInstruction::Sqrt, &[16]), 4);
+    assert_eq!(perform_int(IntInstruction::Sqrt, &[0]), 0);
+    assert_eq!(perform_int(IntInstruction::Sqrt, &[-17]), 4);
+    assert_eq!(
+        perform_int(IntInstruction::Sqrt, &[i64::MIN]),
+        3_037_000_499
+    );
+}
+
+#[proptest]
+fn sqrt_is_floor_of_root(#[any] x: i64) {
+    let root = perform_int(IntInstruction::Sqrt, &[x]).unsigned_abs();
+    let x = x.unsigned_abs();
+    prop_assert!(root * root <= x);
+    prop_assert!((root + 1)
+        .checked_mul(root + 1)
+        .is_none_or(|square| square > x));
+}
+
+#[test]
+fn shifts_wrap_around() {
+    assert_eq!(perform_int(IntInstruction::ShiftLeft, &[1, 3]), 8);
+    assert_eq!(perform_int(IntInstruction::ShiftLeft, &[1, 67]), 8);
+    assert_eq!(perform_int(IntInstruction::ShiftLeft, &[1, -61]), 8);
+    assert_eq!(perform_int(IntInstruction::ShiftLeft, &[1, 63]), i64::MIN);
+    assert_eq!(perform_int(IntInstruction::ShiftLeft, &[3, 63]), i64::MIN);
+    assert_eq!(perform_int(IntInstruction::ShiftRight, &[64, 3]), 8);
+    assert_eq!(perform_int(IntInstruction::ShiftRight, &[64, 67]), 8);
+    assert_eq!(perform_int(IntInstruction::ShiftRight, &[-64, 3]), -8);
+    assert_eq!(perform_int(IntInstruction::ShiftRight, &[-1, 63]), -1);
+}
+
+#[proptest]
+fn shift_by_multiple_of_64_is_identity(#[any] x: i64, #[strategy(-100..100_i64)] k: i64) {
+    prop_assert_eq!(perform_int(IntInstruction::ShiftLeft, &[x, 64 * k]), x);
+    prop_assert_eq!(perform_int(IntInstruction::ShiftRight, &[x, 64 * k]), x);
+}
+
+#[test]
+fn bitwise() {
+    assert_eq!(perform_int(IntInstruction::And, &[0b1100, 0b1010]), 0b1000);
+    assert_eq!(perform_int(IntInstruction::Or, &[0b1100, 0b1010]), 0b1110);
+    assert_eq!(perform_int(IntInstruction::Xor, &[0b1100, 0b1010]), 0b0110);
+}
+
+#[proptest]
+fn xor_identities(#[any] x: i64, #[any] y: i64) {
+    prop_assert_eq!(perform_int(IntInstruction::Xor, &[x, x]), 0);
+    prop_assert_eq!(perform_int(IntInstruction::Xor, &[x, 0]), x);
+    prop_assert_eq!(perform_int(IntInstruction::Xor, &[x, -1]), !x);
+    let x_xor_y = perform_int(IntInstruction::Xor, &[x, y]);
+    prop_assert_eq!(perform_int(IntInstruction::Xor, &[x_xor_y, y]), x);
+}
+
+fn int_from_float(x: f64) -> i64 {
+    let state = PushState::builder()
+        .with_max_stack_size(2)
+        .with_float_values([OrderedFloat(x)])
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = IntFromFloatInstruction::FromFloat.perform(state).unwrap();
+    assert!(result.stack::<OrderedFloat<f64>>().is_empty());
+    assert_eq!(result.stack::<i64>().size(), 1);
+    *result.stack::<i64>().top().unwrap()
+}
+
+#[test]
+fn from_float_truncates() {
+    assert_eq!(int_from_float(3.9), 3);
+    assert_eq!(int_from_float(-3.9), -3);
+    assert_eq!(int_from_float(0.0), 0);
+}
+
+#[test]
+fn from_float_saturates() {
+    assert_eq!(int_from_float(1e300), i64::MAX);
+    assert_eq!(int_from_float(-1e300), i64::MIN);
+    assert_eq!(int_from_float(f64::INFINITY), i64::MAX);
+}
+
+#[test]
+fn from_float_full_int_stack() {
+    let state = PushState::builder()
+        .with_max_stack_size(2)
+        .with_int_values([1, 2])
+        .unwrap()
+        .with_float_values([OrderedFloat(3.9)])
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = IntFromFloatInstruction::FromFloat
+        .perform(state.clone())
+        .unwrap_err();
+    assert!(result.is_fatal());
+    assert_eq!(result.state(), &state);
+    assert_eq!(
+        result.error(),
+        &StackError::Overflow { stack_type: "i64" }.into()
+    );
+}
+
 #[proptest]
 fn add_does_not_crash(#[any] x: i64, #[any] y: i64) {
     let state = PushState::builder()
@@ -161,7 +289,6 @@ fn add_adds_or_does_nothing(#[any] x: i64, #[any] y: i64) {
         .with_no_program()
         .build();
     let result = IntInstruction::Add.perform(state);
-    #[allow(clippy::unwrap_used)]
     if let Some(expected_result) = x.checked_add(y) {
         let output = result.unwrap().stack_mut::<i64>().pop().unwrap();
         prop_assert_eq!(output, expected_result);
@@ -193,7 +320,6 @@ fn subtract_subs_or_does_nothing(#[any] x: i64, #[any] y: i64) {
         .with_no_program()
         .build();
     let result = IntInstruction::Subtract.perform(state);
-    #[allow(clippy::unwrap_used)]
     if let Some(expected_result) = x.checked_sub(y) {
         let output = result.unwrap().stack_mut::<i64>().pop().unwrap();
         prop_assert_eq!(output, expected_result);
@@ -225,7 +351,6 @@ fn multiply_works_or_does_nothing(#[any] x: i64, #[any] y: i64) {
         .with_no_program()
         .build();
     let result = IntInstruction::Multiply.perform(state);
-    #[allow(clippy::unwrap_used)]
     if let Some(expected_result) = x.checked_mul(y) {
         let output = result.unwrap().stack_mut::<i64>().pop().unwrap();
         prop_assert_eq!(output, expected_result);
@@ -257,7 +382,6 @@ fn protected_divide_zero_denominator(#[any] x: i64) {
         .with_no_program()
         .build();
     let result = IntInstruction::ProtectedDivide.perform(state);
-    #[allow(clippy::unwrap_used)]
     let output = result.unwrap().stack_mut::<i64>().pop().unwrap();
     // Dividing by zero should always return 1.
     prop_assert_eq!(output, 1);
@@ -272,7 +396,6 @@ fn protected_divide_works_or_does_nothing(#[any] x: i64, #[any] y: i64) {
         .with_no_program()
         .build();
     let result = IntInstruction::ProtectedDivide.perform(state);
-    #[allow(clippy::unwrap_used)]
     if let Some(expected_result) = x.checked_div(y) {
         let output = result.unwrap().stack_mut::<i64>().pop().unwrap();
         prop_assert_eq!(output, expected_result);
@@ -304,7 +427,6 @@ fn mod_zero_denominator(#[any] x: i64) {
         .with_no_program()
         .build();
     let result = IntInstruction::Mod.perform(state);
-    #[allow(clippy::unwrap_used)]
     let output = result.unwrap().stack_mut::<i64>().pop().unwrap();
     // Modding by zero should always return 0 since x % x = 0 for all x != 0.
     prop_assert_eq!(output, 0);
@@ -319,7 +441,6 @@ fn mod_rems_or_does_nothing(#[any] x: i64, #[any] y: i64) {
         .with_no_program()
         .build();
     let result = IntInstruction::Mod.perform(state);
-    #[allow(clippy::unwrap_used)]
     if let Some(expected_result) = x.checked_rem(y) {
         let output = result.unwrap().stack_mut::<i64>().pop().unwrap();
         prop_assert_eq!(output, expected_result);
@@ -374,3 +495,195 @@ fn int_ops_do_not_crash(
         .build();
     let _ = instr.perform(state);
 }
+
+#[proptest]
+fn random_in_range_is_in_range(#[any] x: i64, #[any] y: i64) {
+    let state = PushState::builder()
+        .with_max_stack_size(2)
+        .with_int_values([x, y])
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = IntRandomInstruction::RandomInRange.perform(state).unwrap();
+    prop_assert_eq!(result.stack::<i64>().size(), 1);
+    let value = *result.stack::<i64>().top().unwrap();
+    prop_assert!(x.min(y) <= value && value <= x.max(y));
+}
+
+#[test]
+fn sum_all() {
+    let state = PushState::builder()
+        .with_max_stack_size(4)
+        .with_int_values([5, -3, 8, 2])
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = IntInstruction::sum_all().perform(state).unwrap();
+    assert_eq!(result.stack::<i64>(), &[12]);
+}
+
+#[test]
+fn sum_all_of_empty_stack_is_zero() {
+    let state = PushState::builder()
+        .with_max_stack_size(4)
+        .with_no_program()
+        .build();
+    let result = IntInstruction::sum_all().perform(state).unwrap();
+    assert_eq!(result.stack::<i64>(), &[0]);
+}
+
+#[test]
+fn sum_all_overflows() {
+    let state = PushState::builder()
+        .with_max_stack_size(4)
+        .with_int_values([i64::MAX, 1, 2])
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = IntInstruction::sum_all().perform(state).unwrap_err();
+    assert!(result.is_recoverable());
+    assert_eq!(result.state().stack::<i64>().size(), 3);
+    assert_eq!(
+        result.error(),
+        &IntInstructionError::Overflow {
+            op: IntInstruction::sum_all()
+        }
+        .into()
+    );
+}
+
+#[test]
+fn max_all() {
+    let state = PushState::builder()
+        .with_max_stack_size(4)
+        .with_int_values([5, -3, 8, 2])
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = IntInstruction::max_all().perform(state).unwrap();
+    assert_eq!(result.stack::<i64>(), &[8]);
+}
+
+#[test]
+fn max_all_of_empty_stack_fails() {
+    let state = PushState::builder()
+        .with_max_stack_size(4)
+        .with_no_program()
+        .build();
+    let result = IntInstruction::max_all().perform(state).unwrap_err();
+    assert!(result.is_recoverable());
+    assert!(result.state().stack::<i64>().is_empty());
+    assert!(matches!(
+        result.error(),
+        PushInstructionError::StackError(StackError::Underflow { .. })
+    ));
+}
+
+// Performs `instruction` on a state whose int stack has `values` (with the
+// first value on top), returning the resulting top value.
+fn perform_on(instruction: IntInstruction, values: &[i64]) -> i64 {
+    let state = PushState::builder()
+        .with_max_stack_size(2)
+        .with_int_values(values.iter().copied())
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = instruction.perform(state).unwrap();
+    assert_eq!(result.stack::<i64>().size(), 1);
+    *result.stack::<i64>().top().unwrap()
+}
+
+#[test]
+fn add_saturates_and_wraps() {
+    assert_eq!(
+        perform_on(IntInstruction::AddSaturating, &[i64::MAX, 1]),
+        i64::MAX
+    );
+    assert_eq!(
+        perform_on(IntInstruction::AddWrapping, &[i64::MAX, 1]),
+        i64::MIN
+    );
+    assert_eq!(perform_on(IntInstruction::AddSaturating, &[409, 512]), 921);
+    assert_eq!(perform_on(IntInstruction::AddWrapping, &[409, 512]), 921);
+}
+
+#[test]
+fn subtract_saturates_and_wraps() {
+    // The top value is the first operand.
+    assert_eq!(
+        perform_on(IntInstruction::SubtractSaturating, &[i64::MIN, 1]),
+        i64::MIN
+    );
+    assert_eq!(
+        perform_on(IntInstruction::SubtractWrapping, &[i64::MIN, 1]),
+        i64::MAX
+    );
+    assert_eq!(perform_on(IntInstruction::SubtractSaturating, &[5, 8]), -3);
+    assert_eq!(perform_on(IntInstruction::SubtractWrapping, &[5, 8]), -3);
+}
+
+#[test]
+fn multiply_saturates_and_wraps() {
+    assert_eq!(
+        perform_on(IntInstruction::MultiplySaturating, &[i64::MIN, -1]),
+        i64::MAX
+    );
+    assert_eq!(
+        perform_on(IntInstruction::MultiplyWrapping, &[i64::MIN, -1]),
+        i64::MIN
+    );
+    assert_eq!(
+        perform_on(IntInstruction::MultiplySaturating, &[-6, 7]),
+        -42
+    );
+    assert_eq!(perform_on(IntInstruction::MultiplyWrapping, &[-6, 7]), -42);
+}
+
+#[test]
+fn square_saturates_and_wraps() {
+    assert_eq!(
+        perform_on(IntInstruction::SquareSaturating, &[i64::MIN]),
+        i64::MAX
+    );
+    assert_eq!(perform_on(IntInstruction::SquareWrapping, &[i64::MAX]), 1);
+    assert_eq!(perform_on(IntInstruction::SquareSaturating, &[-9]), 81);
+    assert_eq!(perform_on(IntInstruction::SquareWrapping, &[-9]), 81);
+}
+
+#[test]
+fn arithmetic_modes_cover_four_instructions_each() {
+    for mode in [
+        ArithmeticMode::Checked,
+        ArithmeticMode::Saturating,
+        ArithmeticMode::Wrapping,
+    ] {
+        let num_instructions = IntInstruction::iter()
+            .filter(|instruction| instruction.arithmetic_mode() == Some(mode))
+            .count();
+        assert_eq!(num_instructions, 4, "{mode:?}");
+    }
+    assert_eq!(IntInstruction::Sqrt.arithmetic_mode(), None);
+}
+
+// A state with just the stacks that `IntInstruction` needs, and no float
+// stacks or random number generator.
+#[derive(Debug, Default, Clone)]
+#[push_macros::push_state]
+struct IntBoolState {
+    #[stack]
+    int: Stack<i64>,
+    #[stack]
+    bool: Stack<bool>,
+}
+
+#[test]
+fn int_instructions_only_need_int_and_bool_stacks() {
+    let mut state = IntBoolState::default();
+    state.int.set_max_stack_size(2);
+    state.bool.set_max_stack_size(1);
+    state.int.try_extend([3, 5]).unwrap();
+    let state = IntInstruction::Add.perform(state).unwrap();
+    let state = IntInstruction::IsOdd.perform(state).unwrap();
+    assert!(state.int.is_empty());
+    assert_eq!(state.bool.top(), Ok(&false));
+}
@@ -0,0 +1,134 @@
+#![cfg(test)]
+#![expect(
+    clippy::unwrap_used,
+    reason = "The test states and channels are set up so these steps succeed"
+)]
+
+use push::{
+    instruction::{
+        instruction_error::PushInstructionError, Instruction, OutputInstruction, PushInstruction,
+    },
+    list_into::vec_into,
+    push_vm::{
+        program::PushProgram, push_state::PushState, stack::StackError, HasNamedOutput, HasStack,
+        State,
+    },
+};
+
+#[test]
+fn to_push_instruction() {
+    let push_instruction: PushInstruction = OutputInstruction::print_int("answer").into();
+    assert_eq!(push_instruction.to_string(), "Output-PrintInt");
+}
+
+#[test]
+fn print_to_registered_channels() {
+    let program: Vec<PushProgram> = vec_into![
+        PushInstruction::push_int(42),
+        OutputInstruction::print_int("answer"),
+        PushInstruction::push_string("debug".to_string()),
+        OutputInstruction::print_string("trace"),
+        PushInstruction::push_char('!'),
+        OutputInstruction::print_char("answer"),
+    ];
+    let state = PushState::builder()
+        .with_max_stack_size(10)
+        .with_output_channel("answer")
+        .with_output_channel("trace")
+        .with_program(program)
+        .unwrap()
+        .build()
+        .run_to_completion()
+        .unwrap();
+    assert_eq!(state.named_output_string("answer"), Some("42!"));
+    assert_eq!(state.named_output_string("trace"), Some("debug"));
+    assert!(state.stack::<i64>().is_empty());
+    assert_eq!(state.named_output_string("missing"), None);
+}
+
+#[test]
+fn unregistered_channel_is_recoverable() {
+    let state = PushState::builder()
+        .with_max_stack_size(10)
+        .with_int_values([5])
+        .unwrap()
+        .with_output_channel("answer")
+        .with_no_program()
+        .build();
+    let result = OutputInstruction::print_int("missing")
+        .perform(state.clone())
+        .unwrap_err();
+    assert!(result.is_recoverable());
+    assert_eq!(result.state(), &state);
+    assert_eq!(
+        result.error(),
+        &PushInstructionError::UnknownOutputChannel {
+            channel: "missing".to_string()
+        }
+    );
+}
+
+#[test]
+fn empty_stack_is_recoverable() {
+    let state = PushState::builder()
+        .with_max_stack_size(10)
+        .with_output_channel("answer")
+        .with_no_program()
+        .build();
+    let result = OutputInstruction::print_bool("answer")
+        .perform(state.clone())
+        .unwrap_err();
+    assert!(result.is_recoverable());
+    assert_eq!(result.state(), &state);
+    assert_eq!(
+        result.error(),
+        &PushInstructionError::from(StackError::Underflow {
+            num_requested: 1,
+            num_present: 0
+        })
+    );
+}
+
+#[test]
+fn overflowing_a_channel_is_fatal() {
+    let state = PushState::builder()
+        .with_max_stack_size(10)
+        .with_output_channel("answer")
+        .with_max_output_size(3)
+        .with_string_values(["abcd".to_string()])
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = OutputInstruction::print_string("answer")
+        .perform(state.clone())
+        .unwrap_err();
+    assert!(result.is_fatal());
+    assert_eq!(result.state(), &state);
+    assert_eq!(
+        result.error(),
+        &PushInstructionError::OutputOverflow {
+            channel: "answer".to_string(),
+            max_size: 3
+        }
+    );
+}
+
+#[test]
+fn printing_up_to_the_max_output_size() {
+    let program: Vec<PushProgram> = vec_into![
+        PushInstruction::push_int(12),
+        OutputInstruction::print_int("answer"),
+        PushInstruction::push_char('!'),
+        OutputInstruction::print_char("answer"),
+    ];
+    let state = PushState::builder()
+        .with_max_stack_size(10)
+        .with_output_channel("answer")
+        .with_max_output_size(3)
+        .with_program(program)
+        .unwrap()
+        .build()
+        .run_to_completion()
+        .unwrap();
+    assert_eq!(state.named_output_string("answer"), Some("12!"));
+}
@@ -0,0 +1,77 @@
+#![cfg(test)]
+#![cfg(feature = "serde")]
+#![expect(
+    clippy::unwrap_used,
+    reason = "Serializing and deserializing the test values shouldn't fail"
+)]
+
+use ordered_float::OrderedFloat;
+use push::{
+    genome::plushy::{Plushy, PushGene},
+    instruction::{
+        variable_name::VariableName, BoolInstruction, CharInstruction, ExecInstruction,
+        FloatInstruction, IntInstruction, PushInstruction, StringInstruction,
+    },
+    list_into::vec_into,
+    push_vm::program::PushProgram,
+};
+use serde_json::json;
+
+fn mixed_plushy() -> Plushy {
+    Plushy::new(vec_into![
+        VariableName::from("x"),
+        PushInstruction::push_int(5),
+        IntInstruction::Add,
+        IntInstruction::negate(),
+        IntInstruction::sum_all(),
+        ExecInstruction::if_else(),
+        PushInstruction::push_float(OrderedFloat(-2.5)),
+        FloatInstruction::Multiply,
+        PushGene::Close,
+        BoolInstruction::Push(true),
+        ExecInstruction::dup_block(),
+        PushInstruction::push_string("hello".to_string()),
+        StringInstruction::Concat,
+        PushGene::Close,
+        PushInstruction::push_char('z'),
+        CharInstruction::IsLetter,
+    ])
+}
+
+#[test]
+fn plushy_json_round_trip() {
+    let plushy = mixed_plushy();
+    let json = serde_json::to_string(&plushy).unwrap();
+    let decoded: Plushy = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded, plushy);
+}
+
+#[test]
+fn program_json_round_trip() {
+    let program = Vec::<PushProgram>::from(mixed_plushy());
+    let json = serde_json::to_string(&program).unwrap();
+    let decoded: Vec<PushProgram> = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded, program);
+}
+
+#[test]
+fn instructions_have_readable_tags() {
+    let to_json = |instruction: PushInstruction| serde_json::to_value(instruction).unwrap();
+
+    assert_eq!(
+        to_json(IntInstruction::Add.into()),
+        json!({ "IntInstruction": "Add" })
+    );
+    assert_eq!(
+        to_json(PushInstruction::push_int(5)),
+        json!({ "IntInstruction": { "Push": 5 } })
+    );
+    assert_eq!(
+        to_json(IntInstruction::sum_all().into()),
+        json!({ "IntInstruction": { "SumAll": null } })
+    );
+    assert_eq!(
+        to_json(VariableName::from("x").into()),
+        json!({ "InputVar": "x" })
+    );
+}
@@ -0,0 +1,167 @@
+#![cfg(test)]
+#![expect(
+    clippy::unwrap_used,
+    reason = "The test states are built with values that fit their stacks"
+)]
+
+use push::{
+    instruction::{
+        instruction_error::PushInstructionError, Instruction, PushInstruction, StringInstruction,
+    },
+    push_vm::{push_state::PushState, stack::StackError, HasStack},
+};
+use strum::IntoEnumIterator;
+
+fn strings<const N: usize>(values: [&str; N]) -> [String; N] {
+    values.map(String::from)
+}
+
+#[test]
+fn to_push_instruction() {
+    let push_instruction: PushInstruction = StringInstruction::Concat.into();
+    assert_eq!(
+        push_instruction,
+        PushInstruction::StringInstruction(StringInstruction::Concat)
+    );
+    assert_eq!(push_instruction.to_string(), "String-Concat");
+}
+
+#[test]
+fn push_string() {
+    let state = PushState::builder()
+        .with_max_stack_size(1)
+        .with_no_program()
+        .build();
+    let result = PushInstruction::push_string("hello".to_string())
+        .perform(state)
+        .unwrap();
+    assert_eq!(result.stack::<String>().top().unwrap(), "hello");
+}
+
+#[test]
+fn concat() {
+    let state = PushState::builder()
+        .with_max_stack_size(10)
+        .with_string_values(strings(["cd", "ab"]))
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = StringInstruction::Concat.perform(state).unwrap();
+    assert_eq!(result.stack::<String>().size(), 1);
+    assert_eq!(result.stack::<String>().top().unwrap(), "abcd");
+}
+
+#[test]
+fn concat_overflows() {
+    let state = PushState::builder()
+        .with_max_stack_size(4)
+        .with_string_values(strings(["cde", "ab", "f"]))
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = StringInstruction::Concat
+        .perform(state.clone())
+        .unwrap_err();
+    assert!(result.is_recoverable());
+    assert_eq!(result.state(), &state);
+    assert_eq!(
+        result.error(),
+        &PushInstructionError::from(StackError::Overflow {
+            stack_type: "string"
+        })
+    );
+}
+
+#[test]
+fn length_pushes_onto_int_stack() {
+    let state = PushState::builder()
+        .with_max_stack_size(2)
+        .with_string_values(strings(["héllo"]))
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = StringInstruction::Length.perform(state).unwrap();
+    assert!(result.stack::<String>().is_empty());
+    assert_eq!(result.stack::<i64>().top().unwrap(), &5);
+}
+
+#[test]
+fn reverse() {
+    let state = PushState::builder()
+        .with_max_stack_size(1)
+        .with_string_values(strings(["abc"]))
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = StringInstruction::Reverse.perform(state).unwrap();
+    assert_eq!(result.stack::<String>().top().unwrap(), "cba");
+}
+
+fn take_or_drop(instruction: &StringInstruction, n: i64) -> String {
+    let state = PushState::builder()
+        .with_max_stack_size(1)
+        .with_string_values(strings(["abcde"]))
+        .unwrap()
+        .with_int_values([n])
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = instruction.perform(state).unwrap();
+    assert!(result.stack::<i64>().is_empty());
+    result.stack::<String>().top().unwrap().clone()
+}
+
+#[test]
+fn take_saturates() {
+    assert_eq!(take_or_drop(&StringInstruction::Take, 2), "ab");
+    assert_eq!(take_or_drop(&StringInstruction::Take, -3), "");
+    assert_eq!(take_or_drop(&StringInstruction::Take, i64::MAX), "abcde");
+}
+
+#[test]
+fn drop_saturates() {
+    assert_eq!(take_or_drop(&StringInstruction::Drop, 2), "cde");
+    assert_eq!(take_or_drop(&StringInstruction::Drop, -3), "abcde");
+    assert_eq!(take_or_drop(&StringInstruction::Drop, i64::MAX), "");
+}
+
+#[test]
+fn take_without_count_is_recoverable() {
+    let state = PushState::builder()
+        .with_max_stack_size(1)
+        .with_string_values(strings(["abc"]))
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = StringInstruction::Take.perform(state.clone()).unwrap_err();
+    assert!(result.is_recoverable());
+    assert_eq!(result.state(), &state);
+}
+
+#[test]
+fn contains() {
+    let state = PushState::builder()
+        .with_max_stack_size(2)
+        .with_string_values(strings(["a needle here", "needle"]))
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = StringInstruction::Contains.perform(state).unwrap();
+    assert!(result.stack::<String>().is_empty());
+    assert_eq!(result.stack::<bool>().top().unwrap(), &true);
+}
+
+#[test]
+fn ops_do_not_crash() {
+    for instruction in StringInstruction::iter() {
+        let state = PushState::builder()
+            .with_max_stack_size(4)
+            .with_string_values(strings(["xy", "z"]))
+            .unwrap()
+            .with_int_values([3])
+            .unwrap()
+            .with_no_program()
+            .build();
+        instruction.perform(state).unwrap();
+    }
+}
@@ -0,0 +1,145 @@
+#![cfg(test)]
+#![expect(
+    clippy::unwrap_used,
+    reason = "The test states are built with values that fit their stacks"
+)]
+
+use push::{
+    instruction::{
+        instruction_error::PushInstructionError, Instruction, PushInstruction, VectorIntInstruction,
+    },
+    push_vm::{push_state::PushState, stack::StackError, HasStack},
+};
+use strum::IntoEnumIterator;
+
+#[test]
+fn to_push_instruction() {
+    let push_instruction: PushInstruction = VectorIntInstruction::Nth.into();
+    assert_eq!(push_instruction.to_string(), "VectorInt-Nth");
+}
+
+#[test]
+fn int_vector_input() {
+    let state = PushState::builder()
+        .with_max_stack_size(2)
+        .with_no_program()
+        .with_int_vector_input("v", vec![1, 2, 3])
+        .build();
+    let result = state.with_input(&"v".into()).unwrap();
+    assert_eq!(result.stack::<Vec<i64>>().top().unwrap(), &[1, 2, 3]);
+}
+
+#[test]
+fn concat() {
+    let state = PushState::builder()
+        .with_max_stack_size(5)
+        .with_int_vector_values([vec![3, 4], vec![1, 2]])
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = VectorIntInstruction::Concat.perform(state).unwrap();
+    assert_eq!(result.stack::<Vec<i64>>().size(), 1);
+    assert_eq!(result.stack::<Vec<i64>>().top().unwrap(), &[1, 2, 3, 4]);
+}
+
+#[test]
+fn concat_respects_max_stack_size() {
+    let state = PushState::builder()
+        .with_max_stack_size(3)
+        .with_int_vector_values([vec![3, 4], vec![1, 2]])
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = VectorIntInstruction::Concat
+        .perform(state.clone())
+        .unwrap_err();
+    assert!(result.is_recoverable());
+    assert_eq!(result.state(), &state);
+    assert_eq!(
+        result.error(),
+        &PushInstructionError::from(StackError::Overflow {
+            stack_type: "int_vector"
+        })
+    );
+}
+
+fn nth(vector: Vec<i64>, index: i64) -> Option<i64> {
+    let state = PushState::builder()
+        .with_max_stack_size(4)
+        .with_int_vector_values([vector])
+        .unwrap()
+        .with_int_values([index])
+        .unwrap()
+        .with_no_program()
+        .build();
+    match VectorIntInstruction::Nth.perform(state) {
+        Ok(state) => {
+            assert!(state.stack::<Vec<i64>>().is_empty());
+            assert_eq!(state.stack::<i64>().size(), 1);
+            Some(*state.stack::<i64>().top().unwrap())
+        }
+        Err(error) => {
+            assert!(error.is_recoverable());
+            None
+        }
+    }
+}
+
+#[test]
+fn nth_wraps_index() {
+    assert_eq!(nth(vec![5, 6, 7], 1), Some(6));
+    assert_eq!(nth(vec![5, 6, 7], 4), Some(6));
+    assert_eq!(nth(vec![5, 6, 7], -1), Some(7));
+    assert_eq!(nth(vec![5, 6, 7], i64::MIN), Some(6));
+}
+
+#[test]
+fn nth_of_empty_vector() {
+    assert_eq!(nth(Vec::new(), 0), None);
+}
+
+#[test]
+fn length_pushes_onto_int_stack() {
+    let state = PushState::builder()
+        .with_max_stack_size(3)
+        .with_int_vector_values([vec![5, 6, 7]])
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = VectorIntInstruction::Length.perform(state).unwrap();
+    assert!(result.stack::<Vec<i64>>().is_empty());
+    assert_eq!(result.stack::<i64>().top().unwrap(), &3);
+}
+
+#[test]
+fn append() {
+    let state = PushState::builder()
+        .with_max_stack_size(3)
+        .with_int_vector_values([vec![5, 6]])
+        .unwrap()
+        .with_int_values([7])
+        .unwrap()
+        .with_no_program()
+        .build();
+    let result = VectorIntInstruction::Append.perform(state).unwrap();
+    assert!(result.stack::<i64>().is_empty());
+    assert_eq!(result.stack::<Vec<i64>>().top().unwrap(), &[5, 6, 7]);
+
+    let result = VectorIntInstruction::Append.perform(result).unwrap_err();
+    assert!(result.is_recoverable());
+}
+
+#[test]
+fn ops_do_not_crash() {
+    for instruction in VectorIntInstruction::iter() {
+        let state = PushState::builder()
+            .with_max_stack_size(8)
+            .with_int_vector_values([vec![1, 2], vec![3]])
+            .unwrap()
+            .with_int_values([-5])
+            .unwrap()
+            .with_no_program()
+            .build();
+        instruction.perform(state).unwrap();
+    }
+}
@@ -44,31 +44,50 @@ impl Individual for PyIndividual {
     }
 }
 
-#[pyfunction]
-fn select_one(py: Python<'_>, pop: Vec<PyObject>) -> PyResult<PyObject> {
-    let num_cases = PyIndividual::new(py, pop[0].clone())?
-        .test_results()
-        .results
-        .len();
-    let lexicase = Lexicase::new(num_cases);
+/// Converts each of the Python individuals (once), and builds a lexicase
+/// selector for the number of test cases they have.
+fn lexicase_population(
+    py: Python<'_>,
+    pop: Vec<PyObject>,
+) -> PyResult<(Lexicase, Vec<PyIndividual>)> {
     let population = pop
         .into_iter()
         .map(|i| PyIndividual::new(py, i))
         .collect::<PyResult<Vec<PyIndividual>>>()?;
+    let num_cases = population
+        .first()
+        .map_or(0, |i| i.test_results().results.len());
+    Ok((Lexicase::new(num_cases), population))
+}
+
+#[pyfunction]
+fn select_one(py: Python<'_>, pop: Vec<PyObject>) -> PyResult<PyObject> {
+    let (lexicase, population) = lexicase_population(py, pop)?;
     let mut rng = thread_rng();
     Ok(lexicase.select(&population, &mut rng)?.py_individual())
 }
 
-// #[pyfunction]
-// fn select(pop: Vec<PyshGpIndividual>, n: usize) -> PyResult<Vec<PyshGpIndividual>> {
-//     Ok(pop.into_iter().take(n).collect())
-// }
+/// Selects `n` individuals (with replacement) from `pop`, converting the
+/// population only once, which is much faster than calling `select_one` `n`
+/// times.
+#[pyfunction]
+fn select_n(py: Python<'_>, pop: Vec<PyObject>, n: usize) -> PyResult<Vec<PyObject>> {
+    let (lexicase, population) = lexicase_population(py, pop)?;
+    let mut rng = thread_rng();
+    Ok((0..n)
+        .map(|_| {
+            lexicase
+                .select(&population, &mut rng)
+                .map(PyIndividual::py_individual)
+        })
+        .collect::<Result<Vec<_>, _>>()?)
+}
 
 /// A Python module implemented in Rust.
 #[pymodule]
 fn rust_lexicase(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(select_one, m)?)?;
-    // m.add_function(wrap_pyfunction!(select, m)?)?;
+    m.add_function(wrap_pyfunction!(select_n, m)?)?;
     Ok(())
 }
 
@@ -0,0 +1,29 @@
+import numpy as np
+import rust_lexicase
+
+
+class Individual:
+    def __init__(self, errors):
+        self.error_vector = np.array(errors, dtype=np.int64)
+
+
+def test_select_n_returns_n_individuals():
+    population = [
+        Individual([5, 8, 9]),
+        Individual([3, 2, 0]),
+        Individual([6, 3, 2]),
+    ]
+    selected = rust_lexicase.select_n(population, 10)
+    assert len(selected) == 10
+    assert all(any(s is p for p in population) for s in selected)
+
+
+def test_select_n_only_selects_elite():
+    # The second individual is best on every test case.
+    population = [Individual([5, 8, 9]), Individual([0, 0, 0]), Individual([6, 3, 2])]
+    selected = rust_lexicase.select_n(population, 5)
+    assert all(s is population[1] for s in selected)
+
+
+def test_select_n_of_zero_is_empty():
+    assert rust_lexicase.select_n([Individual([1, 2])], 0) == []